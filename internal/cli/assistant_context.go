@@ -185,9 +185,14 @@ func mesdxToolsAndSkills() string {
 - ` + bt("mesdx.projectInfo") + ` — Get repo root, source roots, and database path
 - ` + bt("mesdx.goToDefinition") + ` — Find symbol definitions by cursor position or name
 - ` + bt("mesdx.findUsages") + ` — Find all references to a symbol with dependency scoring
+- ` + bt("mesdx.searchSymbols") + ` — Find symbols from a partial or abbreviated name, filtered by kind, language, path or visibility
 
 **Impact Analysis**
 - ` + bt("mesdx.dependencyGraph") + ` — Analyze inbound/outbound dependencies for refactor risk assessment
+- ` + bt("mesdx.callHierarchy") + ` — Trace callers (incoming) or callees (outgoing) of a function across several levels
+- ` + bt("mesdx.typeHierarchy") + ` — List the supertypes and subtypes of a class, interface or trait
+- ` + bt("mesdx.interfaceImplementations") + ` — List the Go types implementing an interface, or the interfaces a Go type implements
+- ` + bt("mesdx.findImplementations") + ` — List the overrides of an interface, trait or base class method in all subtypes
 
 **Code Search (Tree-sitter)**
 - ` + bt("mesdx.scmSearch") + ` — Run Tree-sitter S-expression queries in parallel across source files (raw query or predefined stubs)
@@ -210,7 +215,7 @@ For detailed step-by-step guidance on specific workflows, use MCP prompts:
 - ` + bt("mesdx.skill.security_analysis") + ` — Find and document security issues
 - ` + bt("mesdx.skill.scm_search") + ` — Write and use Tree-sitter SCM queries for structural code search
 
-**Supported languages:** Go, Java, Rust, Python, TypeScript, JavaScript`
+**Supported languages:** Go, Java, Rust, Python, TypeScript, JavaScript, C, C++, C#, Kotlin, Ruby, PHP, Protocol Buffers (proto), GraphQL (graphql) (Vue/Svelte script blocks are indexed as typescript, Jupyter notebook code cells as python). Definitions in generated code (e.g. *.pb.go) also resolve to the .proto or .graphql declaration they were generated from.`
 }
 
 func generateCursorGuidance() string {
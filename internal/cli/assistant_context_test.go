@@ -254,7 +254,12 @@ func TestMesdxToolsAndSkills(t *testing.T) {
 		"mesdx.projectInfo",
 		"mesdx.goToDefinition",
 		"mesdx.findUsages",
+		"mesdx.searchSymbols",
 		"mesdx.dependencyGraph",
+		"mesdx.callHierarchy",
+		"mesdx.typeHierarchy",
+		"mesdx.interfaceImplementations",
+		"mesdx.findImplementations",
 		"mesdx.memoryAppend",
 		"mesdx.memoryRead",
 		"mesdx.memorySearch",
@@ -9,6 +9,7 @@ import (
 	"strings"
 
 	"github.com/mesdx/cli/internal/indexer"
+	"github.com/mesdx/cli/internal/sourcefile"
 )
 
 const (
@@ -238,6 +239,20 @@ func safeJoinPath(repoRoot, relPath string) string {
 
 // readFileLines reads specific lines from a file (1-indexed, inclusive)
 func readFileLines(path string, startLine, endLine int) (string, error) {
+	if sourcefile.IsNotebookFile(path) {
+		lines, err := indexer.ReadSourceLines(path)
+		if err != nil {
+			return "", err
+		}
+		var sb strings.Builder
+		for lineNum := startLine; lineNum <= endLine && lineNum <= len(lines); lineNum++ {
+			if lineNum >= 1 {
+				sb.WriteString(fmt.Sprintf("%6d| %s\n", lineNum, lines[lineNum-1]))
+			}
+		}
+		return sb.String(), nil
+	}
+
 	file, err := os.Open(path)
 	if err != nil {
 		return "", err
@@ -267,6 +282,10 @@ func readFileLines(path string, startLine, endLine int) (string, error) {
 
 // readFileAllLines reads all lines from a file into a slice (0-indexed slice, but conceptually 1-indexed lines)
 func readFileAllLines(path string) ([]string, error) {
+	if sourcefile.IsNotebookFile(path) {
+		return indexer.ReadSourceLines(path)
+	}
+
 	file, err := os.Open(path)
 	if err != nil {
 		return nil, err
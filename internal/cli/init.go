@@ -161,6 +161,13 @@ func runInit(cmd *cobra.Command, args []string) error {
 	defer func() { _ = d.Close() }()
 
 	idx := indexer.New(d, repoRoot)
+	if err := idx.Store.EnsureProject(repoRoot); err != nil {
+		return fmt.Errorf("failed to ensure project: %w", err)
+	}
+	_, queryErrs := idx.ApplyQueryOverrides(indexer.QueryOverrideDir(mesdxDir))
+	for _, qerr := range queryErrs {
+		cmd.Printf("%s Invalid query override: %v\n", infoStyle.Render("!"), qerr)
+	}
 	stats, err := idx.FullIndex(selectedDirs)
 	if err != nil {
 		return fmt.Errorf("failed to index: %w", err)
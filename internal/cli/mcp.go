@@ -30,18 +30,70 @@ var supportedLanguages = map[string]bool{
 	"python":     true,
 	"typescript": true,
 	"javascript": true,
+	"c":          true,
+	"cpp":        true,
+	"csharp":     true,
+	"kotlin":     true,
+	"ruby":       true,
+	"php":        true,
+	"proto":      true,
+	"graphql":    true,
 }
 
+// supportedLanguageNames is the human-readable list used in errors and tool schemas.
+const supportedLanguageNames = "go, java, rust, python, typescript, javascript, c, cpp, csharp, kotlin, ruby, php, proto, graphql"
+
+// scmLanguageNames lists the languages scmSearch accepts: those with a
+// tree-sitter grammar (schema languages are indexed by a token scanner).
+const scmLanguageNames = "go, java, rust, python, typescript, javascript, c, cpp, csharp, kotlin, ruby, php"
+
 func validateLanguage(lang string) error {
 	if lang == "" {
 		return fmt.Errorf("language parameter is required")
 	}
 	if !supportedLanguages[lang] {
-		return fmt.Errorf("unsupported language %q; supported: go, java, rust, python, typescript, javascript", lang)
+		return fmt.Errorf("unsupported language %q; supported: %s", lang, supportedLanguageNames)
 	}
 	return nil
 }
 
+// toolError returns a tool result reporting a failure to the client.
+func toolError(format string, args ...any) *mcp.CallToolResult {
+	return &mcp.CallToolResult{
+		Content: []mcp.Content{
+			&mcp.TextContent{Text: "Error: " + fmt.Sprintf(format, args...)},
+		},
+		IsError: true,
+	}
+}
+
+// resolveToolTarget returns the definitions a navigation tool can start
+// from, best first: the ranked definitions named target.SymbolName, or those
+// at the target's cursor position. filter keeps the kinds the tool accepts.
+func resolveToolTarget(nav *indexer.Navigator, target SymbolTarget, filter func([]indexer.DefinitionResult) []indexer.DefinitionResult) ([]indexer.DefinitionResult, error) {
+	if target.SymbolName != "" {
+		raw, err := nav.GoToDefinitionByName(target.SymbolName, target.FilePath, target.Language)
+		if err != nil {
+			return nil, err
+		}
+		ranked := indexer.RankDefinitions(filter(raw), target.FilePath, indexer.NoiseFilterOptions{IncludeNoise: true})
+		defs := make([]indexer.DefinitionResult, len(ranked))
+		for i, rd := range ranked {
+			defs[i] = rd.DefinitionResult
+		}
+		return defs, nil
+	}
+	if target.FilePath != "" && target.Line > 0 {
+		relPath := toRepoRelative(target.FilePath, nav.RepoRoot)
+		raw, err := nav.GoToDefinitionByPosition(relPath, target.Line, target.Column, target.Language)
+		if err != nil {
+			return nil, err
+		}
+		return filter(raw), nil
+	}
+	return nil, fmt.Errorf("provide either symbolName, or filePath + line + column")
+}
+
 // GoToDefArgs is the input for the goToDefinition MCP tool.
 type GoToDefArgs struct {
 	FilePath      string  `json:"filePath,omitempty"`
@@ -52,6 +104,7 @@ type GoToDefArgs struct {
 	FetchTheCode  *bool   `json:"fetchTheCode,omitempty"`
 	MinConfidence float64 `json:"minConfidence,omitempty"`
 	IncludeNoise  bool    `json:"includeNoise,omitempty"`
+	Visibility    string  `json:"visibility,omitempty"`
 }
 
 // FindUsagesArgs is the input for the findUsages MCP tool.
@@ -64,6 +117,7 @@ type FindUsagesArgs struct {
 	FetchCodeLinesAround    int     `json:"fetchCodeLinesAround,omitempty"`
 	MinResolutionConfidence float64 `json:"minResolutionConfidence,omitempty"`
 	IncludeNoise            bool    `json:"includeNoise,omitempty"`
+	IncludeImplementations  bool    `json:"includeImplementations,omitempty"`
 }
 
 // DependencyGraphArgs is the input for the dependencyGraph MCP tool.
@@ -78,6 +132,51 @@ type DependencyGraphArgs struct {
 	MaxUsages  int     `json:"maxUsages,omitempty"`
 }
 
+// SymbolTarget selects the symbol a navigation tool starts from: a
+// definition by name, or the symbol at a cursor position.
+type SymbolTarget struct {
+	FilePath   string `json:"filePath,omitempty"`
+	Line       int    `json:"line,omitempty"`
+	Column     int    `json:"column,omitempty"`
+	SymbolName string `json:"symbolName,omitempty"`
+	Language   string `json:"language,omitempty"`
+}
+
+// CallHierarchyArgs is the input for the callHierarchy MCP tool.
+type CallHierarchyArgs struct {
+	SymbolTarget
+	Direction string `json:"direction,omitempty"`
+	MaxDepth  int    `json:"maxDepth,omitempty"`
+}
+
+// TypeHierarchyArgs is the input for the typeHierarchy MCP tool.
+type TypeHierarchyArgs struct {
+	SymbolTarget
+	MaxDepth int `json:"maxDepth,omitempty"`
+}
+
+// InterfaceImplementationsArgs is the input for the interfaceImplementations
+// MCP tool.
+type InterfaceImplementationsArgs struct {
+	SymbolTarget
+}
+
+// FindImplementationsArgs is the input for the findImplementations MCP
+// tool.
+type FindImplementationsArgs struct {
+	SymbolTarget
+}
+
+// SearchSymbolsArgs is the input for the searchSymbols MCP tool.
+type SearchSymbolsArgs struct {
+	Query      string `json:"query"`
+	Language   string `json:"language,omitempty"`
+	Kind       string `json:"kind,omitempty"`
+	PathGlob   string `json:"pathGlob,omitempty"`
+	Visibility string `json:"visibility,omitempty"`
+	Limit      int    `json:"limit,omitempty"`
+}
+
 // ScmSearchArgs is the input for the scmSearch MCP tool.
 type ScmSearchArgs struct {
 	Language           string            `json:"language"`
@@ -175,6 +274,9 @@ func runMcp(cmd *cobra.Command, args []string) error {
 		return fmt.Errorf("failed to ensure project: %w", err)
 	}
 
+	queryDir := indexer.QueryOverrideDir(mesdxDir)
+	applyQueryOverrides(idx, queryDir)
+
 	stats, err := idx.Reconcile(cfg.SourceRoots)
 	if err != nil {
 		log.Printf("reconcile error: %v", err)
@@ -226,7 +328,7 @@ func runMcp(cmd *cobra.Command, args []string) error {
 	ctx, cancel := context.WithCancel(context.Background())
 	defer cancel()
 
-	go startFileWatcher(ctx, idx, cfg, repoRoot, memMgr)
+	go startFileWatcher(ctx, idx, cfg, repoRoot, queryDir, memMgr)
 
 	// Start memory dir watcher in background (if configured)
 	if memMgr != nil {
@@ -263,35 +365,30 @@ func runMcp(cmd *cobra.Command, args []string) error {
 	// Register Go To Definition tool
 	mcp.AddTool(server, &mcp.Tool{
 		Name:        "mesdx.goToDefinition",
-		Description: "Find the definition of a symbol. Provide either (filePath + line + column) for cursor-based lookup, or (symbolName) for name-based search. Returns definition locations with file path, line, column, kind, signature, and optionally the code. The language parameter is required.",
+		Description: "Find the definition of a symbol. Provide either (filePath + line + column) for cursor-based lookup, or (symbolName) for name-based search. Returns definition locations with file path, line, column, kind, signature (with its parameters, return type, type parameters and visibility), and optionally the code. Name-based lookups can be limited to a visibility, e.g. visibility=public for the public API only. The language parameter is required.",
 		InputSchema: mustSchema(GoToDefArgs{}),
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args GoToDefArgs) (*mcp.CallToolResult, any, error) {
 		// Validate language
 		if err := validateLanguage(args.Language); err != nil {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		noiseOpts := indexer.NoiseFilterOptions{
 			MinConfidence: args.MinConfidence,
 			IncludeNoise:  args.IncludeNoise,
 		}
+		visibilities, err := indexer.ParseVisibilities(args.Visibility)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
 
 		if args.SymbolName != "" {
 			// Name-based: retrieve all candidates then rank/filter.
 			rawDefs, err := nav.GoToDefinitionByName(args.SymbolName, args.FilePath, args.Language)
 			if err != nil {
-				return &mcp.CallToolResult{
-					Content: []mcp.Content{
-						&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-					},
-					IsError: true,
-				}, nil, nil
+				return toolError("%v", err), nil, nil
 			}
+			rawDefs = indexer.FilterVisibility(rawDefs, visibilities)
 
 			ranked := indexer.RankDefinitions(rawDefs, args.FilePath, noiseOpts)
 			if len(ranked) == 0 {
@@ -332,21 +429,11 @@ func runMcp(cmd *cobra.Command, args []string) error {
 			relPath := toRepoRelative(args.FilePath, repoRoot)
 			detectedLang := indexer.DetectLang(relPath)
 			if string(detectedLang) != args.Language {
-				return &mcp.CallToolResult{
-					Content: []mcp.Content{
-						&mcp.TextContent{Text: fmt.Sprintf("Error: no identifier found at %s:%d:%d", relPath, args.Line, args.Column)},
-					},
-					IsError: true,
-				}, nil, nil
+				return toolError("no identifier found at %s:%d:%d", relPath, args.Line, args.Column), nil, nil
 			}
 			results, err := nav.GoToDefinitionByPosition(relPath, args.Line, args.Column, args.Language)
 			if err != nil {
-				return &mcp.CallToolResult{
-					Content: []mcp.Content{
-						&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-					},
-					IsError: true,
-				}, nil, nil
+				return toolError("%v", err), nil, nil
 			}
 
 			text := indexer.FormatDefinitions(results)
@@ -369,30 +456,18 @@ func runMcp(cmd *cobra.Command, args []string) error {
 			}, structuredContent, nil
 		}
 
-		return &mcp.CallToolResult{
-			Content: []mcp.Content{
-				&mcp.TextContent{
-					Text: "Error: provide either symbolName, or filePath + line + column",
-				},
-			},
-			IsError: true,
-		}, nil, nil
+		return toolError("provide either symbolName, or filePath + line + column"), nil, nil
 	})
 
 	// Register Find Usages tool
 	mcp.AddTool(server, &mcp.Tool{
 		Name:        "mesdx.findUsages",
-		Description: "Find all usage references of a symbol across the codebase. Provide either (filePath + line + column) for cursor-based lookup, or (symbolName) for name-based search. Returns reference locations with file path, line, column, context, and a dependencyScore (0-1) indicating coupling strength / refactoring risk: 1.0 = inheritance or instantiation (high risk), 0.6 = direct call, 0.25 = type annotation, 0.1 = casual mention. Scores are per-usage and do NOT compress near zero for high-usage symbols. Results are sorted by coupling score (descending) while keeping adjacent usages grouped. The language parameter is required. For fetchCodeLinesAround: prefer 0 (or more) for better context; use -1 only when context is limited.",
+		Description: "Find all usage references of a symbol across the codebase. Provide either (filePath + line + column) for cursor-based lookup, or (symbolName) for name-based search. Returns reference locations with file path, line, column, context, and a dependencyScore (0-1) indicating coupling strength / refactoring risk: 1.0 = inheritance, override or instantiation (high risk), 0.6 = direct call, 0.25 = type annotation, 0.1 = casual mention. Scores are per-usage and do NOT compress near zero for high-usage symbols. Results are sorted by coupling score (descending) while keeping adjacent usages grouped. The language parameter is required. For fetchCodeLinesAround: prefer 0 (or more) for better context; use -1 only when context is limited. Overrides marked in the source (@Override, override, Rust trait impls) are usages with the relation \"overrides\"; for a method of an interface, trait or base class, set includeImplementations to also get its unmarked overrides and implementations and their usages.",
 		InputSchema: mustSchema(FindUsagesArgs{}),
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args FindUsagesArgs) (*mcp.CallToolResult, any, error) {
 		// Validate language
 		if err := validateLanguage(args.Language); err != nil {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		noiseOpts := indexer.NoiseFilterOptions{
@@ -414,20 +489,10 @@ func runMcp(cmd *cobra.Command, args []string) error {
 			// Get all candidates, then rank/filter to pick a primary.
 			rawCandidates, rawErr := nav.GoToDefinitionByName(args.SymbolName, args.FilePath, args.Language)
 			if rawErr != nil {
-				return &mcp.CallToolResult{
-					Content: []mcp.Content{
-						&mcp.TextContent{Text: fmt.Sprintf("Error: %v", rawErr)},
-					},
-					IsError: true,
-				}, nil, nil
+				return toolError("%v", rawErr), nil, nil
 			}
 			if len(rawCandidates) == 0 {
-				return &mcp.CallToolResult{
-					Content: []mcp.Content{
-						&mcp.TextContent{Text: fmt.Sprintf("Error: no definitions found for %q", args.SymbolName)},
-					},
-					IsError: true,
-				}, nil, nil
+				return toolError("no definitions found for %q", args.SymbolName), nil, nil
 			}
 
 			rankedDefs = indexer.RankDefinitions(rawCandidates, filterFile, noiseOpts)
@@ -442,12 +507,7 @@ func runMcp(cmd *cobra.Command, args []string) error {
 			filterFile = relPath
 			detectedLang := indexer.DetectLang(relPath)
 			if string(detectedLang) != args.Language {
-				return &mcp.CallToolResult{
-					Content: []mcp.Content{
-						&mcp.TextContent{Text: fmt.Sprintf("Error: no identifier found at %s:%d:%d", relPath, args.Line, args.Column)},
-					},
-					IsError: true,
-				}, nil, nil
+				return toolError("no identifier found at %s:%d:%d", relPath, args.Line, args.Column), nil, nil
 			}
 			results, err = nav.FindUsagesByPosition(relPath, args.Line, args.Column, args.Language)
 			// Cursor-based: precise lookup; resolve primary without noise filter.
@@ -459,23 +519,11 @@ func runMcp(cmd *cobra.Command, args []string) error {
 				}
 			}
 		} else {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{
-						Text: "Error: provide either symbolName, or filePath + line + column",
-					},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("provide either symbolName, or filePath + line + column"), nil, nil
 		}
 
 		if err != nil {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		// Build the full candidate list (ranked for name-based, raw for cursor-based).
@@ -510,6 +558,45 @@ func runMcp(cmd *cobra.Command, args []string) error {
 			filteredResults = results
 		}
 
+		// For a method of an interface, trait or base class, add its
+		// overrides as usages, and the usages of the overriding methods.
+		var implementations []indexer.MethodImplementation
+		if args.IncludeImplementations && primaryDef != nil {
+			implementations, err = nav.FindImplementations(*primaryDef, args.Language)
+			if err != nil {
+				return toolError("%v", err), nil, nil
+			}
+			if resolutionMap != nil {
+				for i := range implementations {
+					impl := &implementations[i].Definition
+					for _, ru := range indexer.ResolveAndFilterUsages(results, candidateDefs, impl, repoRoot, noiseOpts) {
+						key := fmt.Sprintf("%s:%d:%d", ru.Location.Path, ru.Location.StartLine, ru.Location.StartCol)
+						if _, seen := resolutionMap[key]; seen {
+							continue
+						}
+						filteredResults = append(filteredResults, ru.UsageResult)
+						resolutionMap[key] = ru.ResolutionConfidence
+					}
+				}
+			}
+			// Overrides marked in the source (@Override, override) are
+			// indexed as usages already.
+			present := make(map[string]bool, len(filteredResults))
+			for _, u := range filteredResults {
+				present[fmt.Sprintf("%s:%d:%d", u.Location.Path, u.Location.StartLine, u.Location.StartCol)] = true
+			}
+			for _, u := range indexer.OverrideUsages(implementations) {
+				key := fmt.Sprintf("%s:%d:%d", u.Location.Path, u.Location.StartLine, u.Location.StartCol)
+				if present[key] {
+					continue
+				}
+				filteredResults = append(filteredResults, u)
+				if resolutionMap != nil {
+					resolutionMap[key] = 1.0
+				}
+			}
+		}
+
 		// Score filtered usages by coupling strength.
 		scored := indexer.CoupleUsages(filteredResults, repoRoot)
 
@@ -542,6 +629,9 @@ func runMcp(cmd *cobra.Command, args []string) error {
 		if rankedDefs != nil {
 			structuredContent["rankedDefinitions"] = rankedDefs
 		}
+		if len(implementations) > 0 {
+			structuredContent["implementations"] = implementations
+		}
 
 		// Handle fetchCodeLinesAround (default -1, clamped to [-1, 50])
 		linesAround := args.FetchCodeLinesAround
@@ -575,12 +665,7 @@ func runMcp(cmd *cobra.Command, args []string) error {
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args DependencyGraphArgs) (*mcp.CallToolResult, any, error) {
 		// Validate language
 		if err := validateLanguage(args.Language); err != nil {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		// Resolve symbol name
@@ -596,44 +681,22 @@ func runMcp(cmd *cobra.Command, args []string) error {
 			filterFile = relPath
 			detectedLang := indexer.DetectLang(relPath)
 			if string(detectedLang) != args.Language {
-				return &mcp.CallToolResult{
-					Content: []mcp.Content{
-						&mcp.TextContent{Text: fmt.Sprintf("Error: no identifier found at %s:%d:%d", relPath, args.Line, args.Column)},
-					},
-					IsError: true,
-				}, nil, nil
+				return toolError("no identifier found at %s:%d:%d", relPath, args.Line, args.Column), nil, nil
 			}
 			defs, err := nav.GoToDefinitionByPosition(relPath, args.Line, args.Column, args.Language)
 			if err != nil || len(defs) == 0 {
-				return &mcp.CallToolResult{
-					Content: []mcp.Content{
-						&mcp.TextContent{Text: fmt.Sprintf("Error: no definition found at %s:%d:%d", relPath, args.Line, args.Column)},
-					},
-					IsError: true,
-				}, nil, nil
+				return toolError("no definition found at %s:%d:%d", relPath, args.Line, args.Column), nil, nil
 			}
 			symbolName = defs[0].Name
 			primaryDef = &defs[0]
 		} else {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{
-						Text: "Error: provide either symbolName, or filePath + line + column",
-					},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("provide either symbolName, or filePath + line + column"), nil, nil
 		}
 
 		// Look up candidate definitions and rank/filter for name-based lookups.
 		rawCandidates, err := nav.GoToDefinitionByName(symbolName, filterFile, args.Language)
 		if err != nil {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		var candidates []indexer.DefinitionResult
@@ -650,6 +713,13 @@ func runMcp(cmd *cobra.Command, args []string) error {
 			if len(candidates) > 0 {
 				primaryDef = &candidates[0]
 			}
+			// A qualified name only picks the primary definition; its usages
+			// are still weighed against every definition of the bare name.
+			if bare, qualifier := indexer.SplitQualifiedName(symbolName); qualifier != "" && primaryDef != nil && primaryDef.Name == bare {
+				if all, err := nav.GoToDefinitionByName(bare, filterFile, args.Language); err == nil {
+					candidates = all
+				}
+			}
 		} else {
 			candidates = rawCandidates
 		}
@@ -677,12 +747,7 @@ func runMcp(cmd *cobra.Command, args []string) error {
 			repoRoot, maxDepth, minScore, maxUsages,
 		)
 		if err != nil {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		// Format human-readable summary
@@ -695,6 +760,200 @@ func runMcp(cmd *cobra.Command, args []string) error {
 		}, graph, nil
 	})
 
+	// Register Call Hierarchy tool
+	mcp.AddTool(server, &mcp.Tool{
+		Name:        "mesdx.callHierarchy",
+		Description: "Trace the call hierarchy of a function, method or constructor: its callers and their callers (incoming), or the functions it calls and what they call (outgoing), down to maxDepth levels. Each entry lists where the calls are made; recursion is marked as a cycle instead of being expanded. Use this to follow a code path end-to-end instead of chaining findUsages calls. The language parameter is required.",
+		InputSchema: mustSchema(CallHierarchyArgs{}),
+	}, func(ctx context.Context, req *mcp.CallToolRequest, args CallHierarchyArgs) (*mcp.CallToolResult, any, error) {
+		if err := validateLanguage(args.Language); err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		direction := indexer.CallDirection(args.Direction)
+		switch direction {
+		case "":
+			direction = indexer.CallsIncoming
+		case indexer.CallsIncoming, indexer.CallsOutgoing:
+		default:
+			return toolError("unknown direction %q (want incoming or outgoing)", args.Direction), nil, nil
+		}
+		maxDepth := args.MaxDepth
+		if maxDepth <= 0 {
+			maxDepth = 3
+		}
+		if maxDepth > 10 {
+			maxDepth = 10
+		}
+
+		defs, err := resolveToolTarget(nav, args.SymbolTarget, indexer.FilterCallable)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		if len(defs) == 0 {
+			return toolError("no function, method or constructor found"), nil, nil
+		}
+
+		h, err := nav.CallHierarchy(defs[0], direction, maxDepth, args.Language)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		return &mcp.CallToolResult{
+			Content: []mcp.Content{
+				&mcp.TextContent{Text: indexer.FormatCallHierarchy(h)},
+			},
+		}, h, nil
+	})
+
+	// Register Type Hierarchy tool
+	mcp.AddTool(server, &mcp.Tool{
+		Name:        "mesdx.typeHierarchy",
+		Description: "Show the type hierarchy of a class, interface, struct or trait: the types it extends or implements and theirs (supertypes), and the types extending or implementing it and theirs (subtypes), with file locations. Covers extends/implements clauses, base class lists, Rust impl Trait for Type and supertraits, JavaScript prototype chains and Go embedded types. Supertypes defined outside the repo are listed without a location. The language parameter is required.",
+		InputSchema: mustSchema(TypeHierarchyArgs{}),
+	}, func(ctx context.Context, req *mcp.CallToolRequest, args TypeHierarchyArgs) (*mcp.CallToolResult, any, error) {
+		if err := validateLanguage(args.Language); err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		maxDepth := args.MaxDepth
+		if maxDepth <= 0 {
+			maxDepth = 5
+		}
+		if maxDepth > 20 {
+			maxDepth = 20
+		}
+
+		defs, err := resolveToolTarget(nav, args.SymbolTarget, indexer.FilterHierarchyTypes)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		if len(defs) == 0 {
+			return toolError("no class, interface, struct or trait found"), nil, nil
+		}
+
+		h, err := nav.TypeHierarchy(defs[0], maxDepth, args.Language)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		return &mcp.CallToolResult{
+			Content: []mcp.Content{
+				&mcp.TextContent{Text: indexer.FormatTypeHierarchy(h)},
+			},
+		}, h, nil
+	})
+
+	// Register Interface Implementations tool
+	mcp.AddTool(server, &mcp.Tool{
+		Name:        "mesdx.interfaceImplementations",
+		Description: "Find which Go types implement an interface, or which interfaces a Go type implements. Go interfaces are satisfied implicitly, so this matches method sets structurally: the methods declared on a type and its pointer (*T) and those promoted from embedded fields, against the interface's methods and those of the interfaces it embeds, by name and number of parameters. Each match lists the implementing methods and whether only *T satisfies the interface. Embedded types from outside the repo (io.Reader) are listed as unresolved. The language parameter is required and must be go.",
+		InputSchema: mustSchema(InterfaceImplementationsArgs{}),
+	}, func(ctx context.Context, req *mcp.CallToolRequest, args InterfaceImplementationsArgs) (*mcp.CallToolResult, any, error) {
+		if err := validateLanguage(args.Language); err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		if args.Language != string(indexer.LangGo) {
+			return toolError("interface implementations are only computed for go, got %s", args.Language), nil, nil
+		}
+
+		defs, err := resolveToolTarget(nav, args.SymbolTarget, indexer.FilterInterfaceTypes)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		if len(defs) == 0 {
+			return toolError("no Go interface or named type found"), nil, nil
+		}
+
+		s, err := nav.InterfaceSatisfactions(defs[0])
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		return &mcp.CallToolResult{
+			Content: []mcp.Content{
+				&mcp.TextContent{Text: indexer.FormatInterfaceSatisfactions(s)},
+			},
+		}, s, nil
+	})
+
+	// Register Find Implementations tool
+	mcp.AddTool(server, &mcp.Tool{
+		Name:        "mesdx.findImplementations",
+		Description: "Find every override of a method of an interface, trait or base class: the methods of the same name declared by its subtypes, transitively, with the subtype, how it derives from the type above it and its depth below the method's type. Subtypes are followed through extends/implements clauses, base class lists, Rust impl Trait for Type and Go embedded types; overloads with another number of parameters are left out in Java, C#, Kotlin and C++. For a Go interface method, the types satisfying the interface structurally are used. Use this before changing an abstract or interface method. The language parameter is required.",
+		InputSchema: mustSchema(FindImplementationsArgs{}),
+	}, func(ctx context.Context, req *mcp.CallToolRequest, args FindImplementationsArgs) (*mcp.CallToolResult, any, error) {
+		if err := validateLanguage(args.Language); err != nil {
+			return toolError("%v", err), nil, nil
+		}
+
+		defs, err := resolveToolTarget(nav, args.SymbolTarget, indexer.FilterOverridable)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		if len(defs) == 0 {
+			return toolError("no method of a type found"), nil, nil
+		}
+
+		impls, err := nav.FindImplementations(defs[0], args.Language)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		return &mcp.CallToolResult{
+			Content: []mcp.Content{
+				&mcp.TextContent{Text: indexer.FormatImplementations(defs[0], impls)},
+			},
+		}, map[string]interface{}{
+			"method":          defs[0],
+			"implementations": impls,
+		}, nil
+	})
+
+	// Register Search Symbols tool
+	mcp.AddTool(server, &mcp.Tool{
+		Name:        "mesdx.searchSymbols",
+		Description: "Search the symbols of the workspace when only part of a name is known. Names match the query exactly, by prefix, as a camelCase abbreviation (URepo or ur for UserRepository), as a substring or fuzzily (the query's characters in order), best first; the match quality is weighted by the same kind, visibility and noisy path confidence as goToDefinition. Results can be limited to kinds, a language, a path glob (** for any number of directories) and visibilities. Pass a returned name to goToDefinition or findUsages. The language parameter is optional.",
+		InputSchema: mustSchema(SearchSymbolsArgs{}),
+	}, func(ctx context.Context, req *mcp.CallToolRequest, args SearchSymbolsArgs) (*mcp.CallToolResult, any, error) {
+		if strings.TrimSpace(args.Query) == "" {
+			return toolError("query parameter is required"), nil, nil
+		}
+		if args.Language != "" {
+			if err := validateLanguage(args.Language); err != nil {
+				return toolError("%v", err), nil, nil
+			}
+		}
+		kinds, err := indexer.ParseKinds(args.Kind)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		visibilities, err := indexer.ParseVisibilities(args.Visibility)
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		limit := args.Limit
+		if limit <= 0 {
+			limit = 50
+		}
+		if limit > 500 {
+			limit = 500
+		}
+
+		matches, err := nav.SearchSymbols(args.Query, indexer.SymbolSearchOptions{
+			Lang:         args.Language,
+			Kinds:        kinds,
+			Visibilities: visibilities,
+			PathGlob:     args.PathGlob,
+			Limit:        limit,
+		})
+		if err != nil {
+			return toolError("%v", err), nil, nil
+		}
+		return &mcp.CallToolResult{
+			Content: []mcp.Content{
+				&mcp.TextContent{Text: indexer.FormatSymbolMatches(args.Query, matches)},
+			},
+		}, map[string]interface{}{
+			"query":   args.Query,
+			"symbols": matches,
+		}, nil
+	})
+
 	// Register SCM Search tool
 	scmCache := scmsearch.NewQueryCache(64)
 	defer scmCache.Close()
@@ -733,12 +992,10 @@ The language parameter is required.`,
 		InputSchema: mustSchema(ScmSearchArgs{}),
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args ScmSearchArgs) (*mcp.CallToolResult, any, error) {
 		if err := validateLanguage(args.Language); err != nil {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("%v", err), nil, nil
+		}
+		if indexer.IsSchemaLang(args.Language) {
+			return toolError("scmSearch does not support %q; supported: %s", args.Language, scmLanguageNames), nil, nil
 		}
 
 		searchReq := scmsearch.SearchRequest{
@@ -757,12 +1014,7 @@ The language parameter is required.`,
 
 		result, err := scmEngine.Search(ctx, searchReq)
 		if err != nil {
-			return &mcp.CallToolResult{
-				Content: []mcp.Content{
-					&mcp.TextContent{Text: fmt.Sprintf("Error: %v", err)},
-				},
-				IsError: true,
-			}, nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		text := scmsearch.FormatResults(result)
@@ -786,7 +1038,7 @@ The language parameter is required.`,
 }
 
 // startFileWatcher watches source roots for file changes and incrementally updates the index.
-func startFileWatcher(ctx context.Context, idx *indexer.Indexer, cfg *config.Config, repoRoot string, memMgr *memory.Manager) {
+func startFileWatcher(ctx context.Context, idx *indexer.Indexer, cfg *config.Config, repoRoot, queryDir string, memMgr *memory.Manager) {
 	watcher, err := fsnotify.NewWatcher()
 	if err != nil {
 		log.Printf("fsnotify: failed to create watcher: %v", err)
@@ -807,6 +1059,13 @@ func startFileWatcher(ctx context.Context, idx *indexer.Indexer, cfg *config.Con
 		}
 	}
 
+	// Watch query overrides; addWatchRecursive skips .mesdx.
+	if info, err := os.Stat(queryDir); err == nil && info.IsDir() {
+		if err := watcher.Add(queryDir); err != nil {
+			log.Printf("fsnotify: failed to watch %s: %v", queryDir, err)
+		}
+	}
+
 	log.Printf("watcher started")
 
 	// Debounce timer
@@ -820,7 +1079,12 @@ func startFileWatcher(ctx context.Context, idx *indexer.Indexer, cfg *config.Con
 		pending = map[string]struct{}{}
 		mu.Unlock()
 
+		queriesChanged := false
 		for path := range files {
+			if filepath.Dir(path) == queryDir {
+				queriesChanged = true
+				continue
+			}
 			info, err := os.Stat(path)
 			if err != nil {
 				// File was deleted
@@ -848,6 +1112,15 @@ func startFileWatcher(ctx context.Context, idx *indexer.Indexer, cfg *config.Con
 				}
 			}
 		}
+
+		if queriesChanged && len(applyQueryOverrides(idx, queryDir)) > 0 {
+			stats, err := idx.Reconcile(cfg.SourceRoots)
+			if err != nil {
+				log.Printf("reconcile error: %v", err)
+			} else {
+				log.Printf("re-indexed after query change: indexed=%d errors=%d", stats.Indexed, stats.Errors)
+			}
+		}
 	}
 
 	for {
@@ -878,6 +1151,20 @@ func startFileWatcher(ctx context.Context, idx *indexer.Indexer, cfg *config.Con
 	}
 }
 
+// applyQueryOverrides loads the repository-local queries, logs invalid ones
+// with their line and column, and returns the languages whose files were
+// invalidated and need a Reconcile.
+func applyQueryOverrides(idx *indexer.Indexer, queryDir string) []indexer.Lang {
+	changed, errs := idx.ApplyQueryOverrides(queryDir)
+	for _, err := range errs {
+		log.Printf("invalid query override: %v", err)
+	}
+	if len(changed) > 0 {
+		log.Printf("query overrides changed for %v; re-indexing", changed)
+	}
+	return changed
+}
+
 // addWatchRecursive adds a directory and all its subdirectories to the watcher.
 func addWatchRecursive(watcher *fsnotify.Watcher, root string) error {
 	return filepath.Walk(root, func(path string, info os.FileInfo, err error) error {
@@ -1204,17 +1491,21 @@ func buildSchema(v interface{}) map[string]interface{} {
 		}
 		props["symbolName"] = map[string]interface{}{
 			"type":        "string",
-			"description": "Name of the symbol to look up (alternative to cursor-based lookup)",
+			"description": "Name of the symbol to look up (alternative to cursor-based lookup). May be qualified or partially qualified to pick one definition, e.g. Store.Save, catalog::Store::save or com.shop.Order.total",
 		}
 		props["language"] = map[string]interface{}{
 			"type":        "string",
-			"description": "Programming language filter (required): go, java, rust, python, typescript, javascript",
+			"description": "Programming language filter (required): " + supportedLanguageNames,
 		}
 		props["fetchTheCode"] = map[string]interface{}{
 			"type":        "boolean",
 			"description": "Whether to include the definition source code in the response (default: true)",
 			"default":     true,
 		}
+		props["visibility"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Comma-separated visibilities a name-based lookup returns: public, protected, internal (package, crate or module) and private. Example: public for the public API only (default: all)",
+		}
 	case FindUsagesArgs:
 		props["filePath"] = map[string]interface{}{
 			"type":        "string",
@@ -1230,11 +1521,11 @@ func buildSchema(v interface{}) map[string]interface{} {
 		}
 		props["symbolName"] = map[string]interface{}{
 			"type":        "string",
-			"description": "Name of the symbol to look up (alternative to cursor-based lookup)",
+			"description": "Name of the symbol to look up (alternative to cursor-based lookup). May be qualified or partially qualified to pick one definition, e.g. Store.Save, catalog::Store::save or com.shop.Order.total",
 		}
 		props["language"] = map[string]interface{}{
 			"type":        "string",
-			"description": "Programming language filter (required): go, java, rust, python, typescript, javascript",
+			"description": "Programming language filter (required): " + supportedLanguageNames,
 		}
 		props["fetchCodeLinesAround"] = map[string]interface{}{
 			"type":        "integer",
@@ -1243,6 +1534,11 @@ func buildSchema(v interface{}) map[string]interface{} {
 			"minimum":     -1,
 			"maximum":     50,
 		}
+		props["includeImplementations"] = map[string]interface{}{
+			"type":        "boolean",
+			"description": "For a method of an interface, trait or base class (including a Go interface), also return the methods overriding or implementing it in its subtypes, as usages with the relation overrides, and their own usages (default: false)",
+			"default":     false,
+		}
 	case DependencyGraphArgs:
 		props["filePath"] = map[string]interface{}{
 			"type":        "string",
@@ -1258,11 +1554,11 @@ func buildSchema(v interface{}) map[string]interface{} {
 		}
 		props["symbolName"] = map[string]interface{}{
 			"type":        "string",
-			"description": "Name of the symbol to look up (alternative to cursor-based lookup)",
+			"description": "Name of the symbol to look up (alternative to cursor-based lookup). May be qualified or partially qualified to pick one definition, e.g. Store.Save, catalog::Store::save or com.shop.Order.total",
 		}
 		props["language"] = map[string]interface{}{
 			"type":        "string",
-			"description": "Programming language filter (required): go, java, rust, python, typescript, javascript",
+			"description": "Programming language filter (required): " + supportedLanguageNames,
 		}
 		props["maxDepth"] = map[string]interface{}{
 			"type":        "integer",
@@ -1285,10 +1581,143 @@ func buildSchema(v interface{}) map[string]interface{} {
 			"minimum":     1,
 			"maximum":     5000,
 		}
+	case CallHierarchyArgs:
+		props["filePath"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Path to the source file (absolute or repo-relative)",
+		}
+		props["line"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "1-based line number of the cursor position",
+		}
+		props["column"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "0-based column number of the cursor position",
+		}
+		props["symbolName"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Name of the function, method or constructor (alternative to cursor-based lookup). May be qualified or partially qualified to pick one definition, e.g. Store.Save",
+		}
+		props["language"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Programming language filter (required): " + supportedLanguageNames,
+		}
+		props["direction"] = map[string]interface{}{
+			"type":        "string",
+			"enum":        []string{"incoming", "outgoing"},
+			"description": "incoming for the callers, outgoing for the callees (default: incoming)",
+			"default":     "incoming",
+		}
+		props["maxDepth"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "Number of call levels to follow (default: 3, max: 10)",
+			"default":     3,
+			"minimum":     1,
+			"maximum":     10,
+		}
+	case TypeHierarchyArgs:
+		props["filePath"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Path to the source file (absolute or repo-relative)",
+		}
+		props["line"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "1-based line number of the cursor position",
+		}
+		props["column"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "0-based column number of the cursor position",
+		}
+		props["symbolName"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Name of the class, interface, struct or trait (alternative to cursor-based lookup). May be qualified or partially qualified to pick one definition, e.g. com.shop.Order",
+		}
+		props["language"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Programming language filter (required): " + supportedLanguageNames,
+		}
+		props["maxDepth"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "Number of levels to follow up and down the hierarchy (default: 5, max: 20)",
+			"default":     5,
+			"minimum":     1,
+			"maximum":     20,
+		}
+	case InterfaceImplementationsArgs:
+		props["filePath"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Path to the Go source file (absolute or repo-relative)",
+		}
+		props["line"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "1-based line number of the cursor position",
+		}
+		props["column"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "0-based column number of the cursor position",
+		}
+		props["symbolName"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Name of the interface, struct or other named type (alternative to cursor-based lookup). May be qualified to pick one definition, e.g. store.Repository",
+		}
+		props["language"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Programming language (required): go",
+		}
+	case FindImplementationsArgs:
+		props["filePath"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Path to the source file (absolute or repo-relative)",
+		}
+		props["line"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "1-based line number of the cursor position",
+		}
+		props["column"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "0-based column number of the cursor position",
+		}
+		props["symbolName"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Name of the method (alternative to cursor-based lookup). Qualify it with its type to pick one definition, e.g. Repository.save or Shape::area",
+		}
+		props["language"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Programming language filter (required): " + supportedLanguageNames,
+		}
+	case SearchSymbolsArgs:
+		schema["required"] = []string{"query"}
+		props["query"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Whole or partial symbol name: a prefix, a camelCase abbreviation (URepo), a substring or characters of the name in order",
+		}
+		props["language"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Programming language filter (default: all languages): " + supportedLanguageNames,
+		}
+		props["kind"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Comma-separated symbol kinds to return, e.g. class,interface or method,function. Kinds: package, module, class, interface, struct, enum, trait, type_alias, function, method, constructor, property, field, variable, constant (default: all)",
+		}
+		props["pathGlob"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Glob the repo-relative file path must match, with ** for any number of directories, e.g. internal/**/*.go; a pattern without a slash may match the file name (default: all files)",
+		}
+		props["visibility"] = map[string]interface{}{
+			"type":        "string",
+			"description": "Comma-separated visibilities to return: public, protected, internal (package, crate or module) and private (default: all)",
+		}
+		props["limit"] = map[string]interface{}{
+			"type":        "integer",
+			"description": "Maximum number of symbols to return (default: 50, max: 500)",
+			"default":     50,
+			"minimum":     1,
+			"maximum":     500,
+		}
 	case ScmSearchArgs:
 		props["language"] = map[string]interface{}{
 			"type":        "string",
-			"description": "Programming language (required): go, java, rust, python, typescript, javascript",
+			"description": "Programming language (required): " + scmLanguageNames,
 		}
 		props["query"] = map[string]interface{}{
 			"type":        "string",
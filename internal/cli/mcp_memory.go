@@ -69,12 +69,12 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		InputSchema: memorySchema(MemoryAppendArgs{}),
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args MemoryAppendArgs) (*mcp.CallToolResult, any, error) {
 		if args.Content == "" {
-			return mcpError("content is required"), nil, nil
+			return toolError("content is required"), nil, nil
 		}
 
 		elem, err := mgr.Append(args.Scope, args.File, args.Title, args.Content, args.Symbols)
 		if err != nil {
-			return mcpError(err.Error()), nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		text := fmt.Sprintf("Memory created: %s\nScope: %s\nPath: %s", elem.Meta.ID, elem.Meta.Scope, elem.MdRelPath)
@@ -99,7 +99,7 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		if args.MemoryID != "" {
 			elem, err := mgr.Read(args.MemoryID)
 			if err != nil {
-				return mcpError(err.Error()), nil, nil
+				return toolError("%v", err), nil, nil
 			}
 			text := formatMemoryElement(elem)
 			return &mcp.CallToolResult{
@@ -111,7 +111,7 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		if args.MdRelPath != "" {
 			elem, err := mgr.ReadByPath(args.MdRelPath)
 			if err != nil {
-				return mcpError(err.Error()), nil, nil
+				return toolError("%v", err), nil, nil
 			}
 			text := formatMemoryElement(elem)
 			return &mcp.CallToolResult{
@@ -122,7 +122,7 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		// List
 		rows, err := mgr.List(args.Scope, args.File)
 		if err != nil {
-			return mcpError(err.Error()), nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		text := formatMemoryList(rows)
@@ -138,12 +138,12 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		InputSchema: memorySchema(MemoryUpdateArgs{}),
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args MemoryUpdateArgs) (*mcp.CallToolResult, any, error) {
 		if args.MemoryID == "" {
-			return mcpError("memoryId is required"), nil, nil
+			return toolError("memoryId is required"), nil, nil
 		}
 
 		elem, err := mgr.Update(args.MemoryID, args.Title, args.Content, args.Symbols)
 		if err != nil {
-			return mcpError(err.Error()), nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		text := fmt.Sprintf("Memory updated: %s\nPath: %s", elem.Meta.ID, elem.MdRelPath)
@@ -159,11 +159,11 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		InputSchema: memorySchema(MemoryDeleteArgs{}),
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args MemoryDeleteArgs) (*mcp.CallToolResult, any, error) {
 		if args.MemoryID == "" {
-			return mcpError("memoryId is required"), nil, nil
+			return toolError("memoryId is required"), nil, nil
 		}
 
 		if err := mgr.Delete(args.MemoryID); err != nil {
-			return mcpError(err.Error()), nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		text := fmt.Sprintf("Memory deleted (soft): %s", args.MemoryID)
@@ -179,14 +179,14 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		InputSchema: memorySchema(MemoryGrepReplaceArgs{}),
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args MemoryGrepReplaceArgs) (*mcp.CallToolResult, any, error) {
 		if args.Pattern == "" {
-			return mcpError("pattern is required"), nil, nil
+			return toolError("pattern is required"), nil, nil
 		}
 
 		// If explicit target is given, proceed directly
 		if args.MemoryID != "" || args.MdRelPath != "" {
 			result, err := mgr.GrepReplace(args.MemoryID, args.MdRelPath, args.Pattern, args.Replacement)
 			if err != nil {
-				return mcpError(err.Error()), nil, nil
+				return toolError("%v", err), nil, nil
 			}
 			text := fmt.Sprintf("Grep/replace on %s: %d replacement(s)", result.MdRelPath, result.Replacements)
 			return &mcp.CallToolResult{
@@ -197,7 +197,7 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		// No explicit target — resolve via scope/file filters and enforce single-target
 		rows, err := mgr.List(args.Scope, args.File)
 		if err != nil {
-			return mcpError(err.Error()), nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		// Filter out deleted
@@ -209,7 +209,7 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		}
 
 		if len(active) == 0 {
-			return mcpError("no matching memories found"), nil, nil
+			return toolError("no matching memories found"), nil, nil
 		}
 		if len(active) > 1 {
 			// Ambiguity — fail with candidates
@@ -227,7 +227,7 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		// Exactly one — proceed
 		result, err := mgr.GrepReplace(active[0].MemoryUID, "", args.Pattern, args.Replacement)
 		if err != nil {
-			return mcpError(err.Error()), nil, nil
+			return toolError("%v", err), nil, nil
 		}
 		text := fmt.Sprintf("Grep/replace on %s: %d replacement(s)", result.MdRelPath, result.Replacements)
 		return &mcp.CallToolResult{
@@ -242,12 +242,12 @@ func registerMemoryTools(server *mcp.Server, mgr *memory.Manager) {
 		InputSchema: memorySchema(MemorySearchArgs{}),
 	}, func(ctx context.Context, req *mcp.CallToolRequest, args MemorySearchArgs) (*mcp.CallToolResult, any, error) {
 		if args.Query == "" {
-			return mcpError("query is required"), nil, nil
+			return toolError("query is required"), nil, nil
 		}
 
 		results, err := mgr.Search(args.Query, args.Scope, args.File, args.Limit)
 		if err != nil {
-			return mcpError(err.Error()), nil, nil
+			return toolError("%v", err), nil, nil
 		}
 
 		text := formatSearchResults(results)
@@ -410,15 +410,6 @@ func memorySchema(v interface{}) json.RawMessage {
 
 // --- Formatters ---
 
-func mcpError(msg string) *mcp.CallToolResult {
-	return &mcp.CallToolResult{
-		Content: []mcp.Content{
-			&mcp.TextContent{Text: fmt.Sprintf("Error: %s", msg)},
-		},
-		IsError: true,
-	}
-}
-
 func formatMemoryElement(elem *memory.MemoryElement) string {
 	var b strings.Builder
 	fmt.Fprintf(&b, "# Memory: %s\n\n", elem.Meta.ID)
@@ -142,6 +142,99 @@ var migrations = []migration{
 			ALTER TABLE refs ADD COLUMN target_type TEXT NOT NULL DEFAULT '';
 		`,
 	},
+	{
+		Version: 4,
+		Name:    "add_ref_import_resolution",
+		SQL: `
+			-- Qualifier written before the name (pkg in pkg.Func)
+			ALTER TABLE refs ADD COLUMN qualifier TEXT NOT NULL DEFAULT '';
+
+			-- Import the ref goes through, and the repo-relative package
+			-- directory or file it resolves to ('' when outside the repo)
+			ALTER TABLE refs ADD COLUMN import_path TEXT NOT NULL DEFAULT '';
+			ALTER TABLE refs ADD COLUMN resolved_path TEXT NOT NULL DEFAULT '';
+		`,
+	},
+	{
+		Version: 5,
+		Name:    "add_symbol_qualified_name",
+		SQL: `
+			-- Fully qualified name (com.shop.Order.Builder.build), '' when not computed
+			ALTER TABLE symbols ADD COLUMN qualified_name TEXT NOT NULL DEFAULT '';
+			CREATE INDEX IF NOT EXISTS idx_symbols_qualified_name ON symbols(qualified_name);
+		`,
+	},
+	{
+		Version: 6,
+		Name:    "add_ref_local_binding",
+		SQL: `
+			-- Declaration of the local variable or parameter a ref names
+			-- (0 when the ref does not name a local)
+			ALTER TABLE refs ADD COLUMN binding_line INTEGER NOT NULL DEFAULT 0;
+			ALTER TABLE refs ADD COLUMN binding_col INTEGER NOT NULL DEFAULT 0;
+		`,
+	},
+	{
+		Version: 7,
+		Name:    "add_symbol_structured_signature",
+		SQL: `
+			-- public, protected, internal, private, or '' when unknown
+			ALTER TABLE symbols ADD COLUMN visibility TEXT NOT NULL DEFAULT '';
+			CREATE INDEX IF NOT EXISTS idx_symbols_visibility ON symbols(visibility);
+
+			-- Receiver and return type as written; type parameters and
+			-- parameters as JSON arrays ('' when the symbol has none)
+			ALTER TABLE symbols ADD COLUMN receiver TEXT NOT NULL DEFAULT '';
+			ALTER TABLE symbols ADD COLUMN type_params TEXT NOT NULL DEFAULT '';
+			ALTER TABLE symbols ADD COLUMN params TEXT NOT NULL DEFAULT '';
+			ALTER TABLE symbols ADD COLUMN return_type TEXT NOT NULL DEFAULT '';
+		`,
+	},
+	{
+		Version: 8,
+		Name:    "add_symbol_doc",
+		SQL: `
+			-- Doc comment or docstring without comment markers ('' when undocumented)
+			ALTER TABLE symbols ADD COLUMN doc TEXT NOT NULL DEFAULT '';
+		`,
+	},
+	{
+		Version: 9,
+		Name:    "add_call_edges",
+		SQL: `
+			-- Call graph edges: a call ref and the function, method or
+			-- constructor it is made from. Callees are resolved when queried.
+			CREATE TABLE IF NOT EXISTS calls (
+				id INTEGER PRIMARY KEY AUTOINCREMENT,
+				file_id INTEGER NOT NULL,
+				caller_id INTEGER NOT NULL,
+				ref_id INTEGER NOT NULL,
+				FOREIGN KEY (file_id) REFERENCES files(id) ON DELETE CASCADE,
+				FOREIGN KEY (caller_id) REFERENCES symbols(id) ON DELETE CASCADE,
+				FOREIGN KEY (ref_id) REFERENCES refs(id) ON DELETE CASCADE
+			);
+			CREATE INDEX IF NOT EXISTS idx_calls_file ON calls(file_id);
+			CREATE INDEX IF NOT EXISTS idx_calls_caller ON calls(caller_id);
+			CREATE INDEX IF NOT EXISTS idx_calls_ref ON calls(ref_id);
+		`,
+	},
+	{
+		Version: 10,
+		Name:    "add_symbol_name_grams",
+		SQL: `
+			-- Symbol search index: the trigrams of each lowercased symbol
+			-- name, plus a ^ gram for its first two characters and a ~ gram
+			-- for the initials of its words. Rows are deleted with their file.
+			CREATE TABLE IF NOT EXISTS symbol_name_grams (
+				gram TEXT NOT NULL,
+				symbol_id INTEGER NOT NULL,
+				file_id INTEGER NOT NULL,
+				PRIMARY KEY (gram, symbol_id),
+				FOREIGN KEY (file_id) REFERENCES files(id) ON DELETE CASCADE
+			) WITHOUT ROWID;
+			CREATE INDEX IF NOT EXISTS idx_symbol_name_grams_file ON symbol_name_grams(file_id);
+		`,
+	},
 }
 
 // Migrate runs all pending versioned migrations inside transactions.
@@ -0,0 +1,367 @@
+package indexer
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// CallDirection selects the edges a call hierarchy follows.
+type CallDirection string
+
+const (
+	// CallsIncoming follows a function to its callers, then theirs.
+	CallsIncoming CallDirection = "incoming"
+	// CallsOutgoing follows a function to its callees, then theirs.
+	CallsOutgoing CallDirection = "outgoing"
+)
+
+// maxCallHierarchyNodes bounds the size of a call hierarchy; deep
+// hierarchies of widely called functions grow exponentially.
+const maxCallHierarchyNodes = 500
+
+// CallHierarchy is the tree of callers or callees of a function, method or
+// constructor, down to a maximum depth.
+type CallHierarchy struct {
+	Direction CallDirection     `json:"direction"`
+	MaxDepth  int               `json:"maxDepth"`
+	Root      CallHierarchyNode `json:"root"`
+	Nodes     int               `json:"nodes"`
+
+	// Truncated is set when the hierarchy was cut at maxCallHierarchyNodes.
+	Truncated bool `json:"truncated,omitempty"`
+}
+
+// CallHierarchyNode is one function, method or constructor of a call
+// hierarchy.
+type CallHierarchyNode struct {
+	Definition DefinitionResult `json:"definition"`
+
+	// CallSites locates the calls linking the node to its parent: the
+	// calls it makes to the parent (incoming), or the calls the parent
+	// makes to it (outgoing).
+	CallSites []Location `json:"callSites,omitempty"`
+
+	// Cycle is set when the node is one of its own ancestors (recursion);
+	// it is not expanded again.
+	Cycle bool `json:"cycle,omitempty"`
+
+	Children []CallHierarchyNode `json:"children,omitempty"`
+}
+
+// isCallable reports whether calls can be made to or from a symbol kind.
+func isCallable(kind string) bool {
+	switch kind {
+	case "function", "method", "constructor":
+		return true
+	}
+	return false
+}
+
+// callerSymbols returns, for each ref of a file, the index of the innermost
+// function, method or constructor making the call, or -1 when the ref is
+// not a call or is made outside of any (at the top level of a script).
+func callerSymbols(fr *symbols.FileResult) []int {
+	callers := make([]int, len(fr.Refs))
+	for i, ref := range fr.Refs {
+		callers[i] = -1
+		if ref.Kind != symbols.RefCall {
+			continue
+		}
+		for j, sym := range fr.Symbols {
+			if !isCallable(sym.Kind.String()) || !encloses(sym, ref) {
+				continue
+			}
+			if c := callers[i]; c < 0 || fr.Symbols[c].StartLine < sym.StartLine ||
+				(fr.Symbols[c].StartLine == sym.StartLine && fr.Symbols[c].StartCol < sym.StartCol) {
+				callers[i] = j
+			}
+		}
+	}
+	return callers
+}
+
+// encloses reports whether a ref lies within the declaration of sym, i.e.
+// after its name and up to the last line of its body.
+func encloses(sym symbols.Symbol, ref symbols.Ref) bool {
+	if ref.StartLine < sym.StartLine || ref.StartLine > sym.EndLine {
+		return false
+	}
+	return ref.StartLine > sym.StartLine || ref.StartCol > sym.StartCol
+}
+
+// CallHierarchy returns the callers (incoming) or callees (outgoing) of a
+// function, method or constructor, transitively up to maxDepth levels.
+// Calls are resolved to their targets when queried, through receiver types
+// and imports when known and else to the nearest definition of the name, so
+// the hierarchy follows the files indexed last. A function reached again
+// below itself is marked as a cycle instead of being expanded.
+func (n *Navigator) CallHierarchy(root DefinitionResult, direction CallDirection, maxDepth int, lang string) (*CallHierarchy, error) {
+	h := &CallHierarchy{
+		Direction: direction,
+		MaxDepth:  maxDepth,
+		Root:      CallHierarchyNode{Definition: root},
+		Nodes:     1,
+	}
+	if err := n.expandCalls(h, &h.Root, 0, map[string]bool{}, lang); err != nil {
+		return nil, err
+	}
+	return h, nil
+}
+
+// expandCalls adds the callers or callees of node as its children. onPath
+// holds the functions between the root and node.
+func (n *Navigator) expandCalls(h *CallHierarchy, node *CallHierarchyNode, depth int, onPath map[string]bool, lang string) error {
+	def := node.Definition
+	id := nodeID(def.Location.Path, def.Name, def.Location.StartLine)
+	if onPath[id] {
+		node.Cycle = true
+		return nil
+	}
+	if depth >= h.MaxDepth {
+		return nil
+	}
+
+	var children []CallHierarchyNode
+	var err error
+	if h.Direction == CallsIncoming {
+		children, err = n.incomingCalls(def, lang)
+	} else {
+		children, err = n.outgoingCalls(def, lang)
+	}
+	if err != nil {
+		return err
+	}
+
+	onPath[id] = true
+	defer delete(onPath, id)
+	for _, child := range children {
+		if h.Nodes >= maxCallHierarchyNodes {
+			h.Truncated = true
+			break
+		}
+		h.Nodes++
+		if err := n.expandCalls(h, &child, depth+1, onPath, lang); err != nil {
+			return err
+		}
+		node.Children = append(node.Children, child)
+	}
+	return nil
+}
+
+// callColumns lists the columns of a call ref read by outgoingCalls, from a
+// query joining refs r with files f.
+const callColumns = `r.name, r.kind, r.context_container, r.relation, r.receiver_type, r.target_type,
+		       r.qualifier, r.import_path, r.resolved_path,
+		       f.path, r.start_line, r.start_col, r.end_line, r.end_col`
+
+// outgoingCalls returns the functions called from def, each with the
+// places it is called from, in the order of their first call.
+func (n *Navigator) outgoingCalls(def DefinitionResult, lang string) ([]CallHierarchyNode, error) {
+	rows, err := n.DB.Query(`
+		SELECT `+callColumns+`
+		FROM calls c
+		JOIN symbols s ON c.caller_id = s.id
+		JOIN refs r ON c.ref_id = r.id
+		JOIN files f ON c.file_id = f.id
+		WHERE f.project_id = ? AND f.path = ? AND s.name = ?
+		  AND s.start_line = ? AND s.start_col = ?
+		ORDER BY r.start_line ASC, r.start_col ASC
+	`, n.ProjectID, def.Location.Path, def.Name, def.Location.StartLine, def.Location.StartCol)
+	if err != nil {
+		return nil, fmt.Errorf("query outgoing calls: %w", err)
+	}
+	var calls []UsageResult
+	for rows.Next() {
+		var u UsageResult
+		var kindInt int
+		if err := rows.Scan(&u.Name, &kindInt, &u.ContextContainer,
+			&u.Relation, &u.ReceiverType, &u.TargetType,
+			&u.Qualifier, &u.ImportPath, &u.ResolvedPath,
+			&u.Location.Path, &u.Location.StartLine, &u.Location.StartCol,
+			&u.Location.EndLine, &u.Location.EndCol); err != nil {
+			_ = rows.Close()
+			return nil, err
+		}
+		u.Kind = symbols.RefKind(kindInt).String()
+		calls = append(calls, u)
+	}
+	_ = rows.Close()
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+
+	// Candidates are looked up once per name, after the rows are closed.
+	targets := map[string][]DefinitionResult{}
+	var nodes []CallHierarchyNode
+	for _, call := range calls {
+		defs, ok := targets[call.Name]
+		if !ok {
+			if defs, err = n.callableDefinitions(call.Name, def.Location.Path, lang); err != nil {
+				return nil, err
+			}
+			targets[call.Name] = defs
+		}
+		if callee := refTarget(call, defs); callee != nil {
+			nodes = addCallSite(nodes, *callee, call.Location)
+		}
+	}
+	n.annotateCallSites(nodes)
+	return nodes, nil
+}
+
+// incomingCalls returns the functions calling def, each with the places it
+// calls def from, in path and line order. Callers are looked up in every
+// language that can see def (a Kotlin function calling a Java method).
+func (n *Navigator) incomingCalls(def DefinitionResult, lang string) ([]CallHierarchyNode, error) {
+	defs, err := n.callableDefinitions(def.Name, "", lang)
+	if err != nil {
+		return nil, err
+	}
+	langs := InteropLangs(lang)
+	placeholders := strings.TrimSuffix(strings.Repeat("?,", len(langs)), ",")
+	args := []interface{}{n.ProjectID, def.Name}
+	for _, l := range langs {
+		args = append(args, l)
+	}
+	rows, err := n.DB.Query(`
+		SELECT `+definitionColumns+`,
+		       r.receiver_type, r.qualifier, r.import_path, r.resolved_path,
+		       r.start_line, r.start_col, r.end_line, r.end_col
+		FROM calls c
+		JOIN refs r ON c.ref_id = r.id
+		JOIN symbols s ON c.caller_id = s.id
+		JOIN files f ON c.file_id = f.id
+		WHERE f.project_id = ? AND r.name = ? AND f.lang IN (`+placeholders+`)
+		ORDER BY f.path ASC, r.start_line ASC, r.start_col ASC
+	`, args...)
+	if err != nil {
+		return nil, fmt.Errorf("query incoming calls: %w", err)
+	}
+	defer func() { _ = rows.Close() }()
+
+	var nodes []CallHierarchyNode
+	for rows.Next() {
+		call := UsageResult{Name: def.Name, Kind: symbols.RefCall.String()}
+		caller, err := scanDefinition(rows,
+			&call.ReceiverType, &call.Qualifier, &call.ImportPath, &call.ResolvedPath,
+			&call.Location.StartLine, &call.Location.StartCol,
+			&call.Location.EndLine, &call.Location.EndCol)
+		if err != nil {
+			return nil, err
+		}
+		call.Location.Path = caller.Location.Path
+		if callee := refTarget(call, defs); callee != nil && sameDefinition(*callee, def) {
+			nodes = addCallSite(nodes, caller, call.Location)
+		}
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	n.annotateCallSites(nodes)
+	return nodes, nil
+}
+
+// callableDefinitions returns the functions, methods and constructors named
+// name, as found by GoToDefinitionByName.
+func (n *Navigator) callableDefinitions(name, filterFile, lang string) ([]DefinitionResult, error) {
+	defs, err := n.definitionsByName(name, filterFile, lang)
+	if err != nil {
+		return nil, err
+	}
+	return FilterCallable(defs), nil
+}
+
+// FilterCallable returns the functions, methods and constructors among
+// defs, in their original order.
+func FilterCallable(defs []DefinitionResult) []DefinitionResult {
+	callable := []DefinitionResult{}
+	for _, d := range defs {
+		if isCallable(d.Kind) {
+			callable = append(callable, d)
+		}
+	}
+	return callable
+}
+
+// refTarget returns the definition a call or other ref resolves to among
+// defs: the one its receiver type or import names, or else the nearest to
+// the ref. It returns nil for a target outside the repo (fmt.Println, a
+// builtin).
+func refTarget(ref UsageResult, defs []DefinitionResult) *DefinitionResult {
+	if matches, ok := refResolution(ref, defs); ok {
+		if len(matches) == 0 {
+			return nil
+		}
+		return &defs[matches[0]]
+	}
+	return pickBestCandidate(defs, ref.Location.Path)
+}
+
+// addCallSite records a call site of def, adding def to nodes the first
+// time it is seen.
+func addCallSite(nodes []CallHierarchyNode, def DefinitionResult, site Location) []CallHierarchyNode {
+	for i := range nodes {
+		if sameDefinition(nodes[i].Definition, def) {
+			nodes[i].CallSites = append(nodes[i].CallSites, site)
+			return nodes
+		}
+	}
+	return append(nodes, CallHierarchyNode{Definition: def, CallSites: []Location{site}})
+}
+
+// annotateCallSites adds the notebook cells of the definitions and call
+// sites of nodes.
+func (n *Navigator) annotateCallSites(nodes []CallHierarchyNode) {
+	cells := newNotebookLocator(n.RepoRoot)
+	for i := range nodes {
+		cells.annotate(&nodes[i].Definition.Location)
+		for j := range nodes[i].CallSites {
+			cells.annotate(&nodes[i].CallSites[j])
+		}
+	}
+}
+
+// FormatCallHierarchy formats a call hierarchy as an indented tree for MCP.
+func FormatCallHierarchy(h *CallHierarchy) string {
+	var b strings.Builder
+	root := h.Root.Definition
+	verb := "Callers"
+	if h.Direction == CallsOutgoing {
+		verb = "Callees"
+	}
+	fmt.Fprintf(&b, "%s of %s (%s) at %s, depth %d\n", verb, root.Name, root.Kind, root.Location, h.MaxDepth)
+	if len(h.Root.Children) == 0 {
+		b.WriteString("No calls found.")
+		return b.String()
+	}
+	writeCallNodes(&b, h.Root.Children, 0)
+	if h.Truncated {
+		fmt.Fprintf(&b, "... truncated at %d functions\n", maxCallHierarchyNodes)
+	}
+	return strings.TrimSuffix(b.String(), "\n")
+}
+
+// writeCallNodes writes nodes and their children, indented by depth.
+func writeCallNodes(b *strings.Builder, nodes []CallHierarchyNode, depth int) {
+	indent := strings.Repeat("  ", depth)
+	for _, node := range nodes {
+		d := node.Definition
+		name := d.Name
+		if d.Container != "" {
+			name = d.Container + "." + d.Name
+		}
+		fmt.Fprintf(b, "%s- %s (%s) at %s", indent, name, d.Kind, d.Location)
+		lines := make([]string, len(node.CallSites))
+		for i, site := range node.CallSites {
+			lines[i] = itoa(site.StartLine)
+		}
+		fmt.Fprintf(b, "; calls at %s:%s", node.CallSites[0].Path, strings.Join(lines, ","))
+		if node.Cycle {
+			b.WriteString(" (cycle)")
+		}
+		b.WriteString("\n")
+		writeCallNodes(b, node.Children, depth+1)
+	}
+}
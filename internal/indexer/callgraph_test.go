@@ -0,0 +1,136 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// callNames returns the names of nodes, marking cycles with a trailing *.
+func callNames(nodes []CallHierarchyNode) []string {
+	var names []string
+	for _, n := range nodes {
+		name := n.Definition.Name
+		if n.Cycle {
+			name += "*"
+		}
+		names = append(names, name)
+	}
+	return names
+}
+
+func TestCallHierarchyOutgoing(t *testing.T) {
+	h, err := sharedNav.CallHierarchy(lookupOne(t, sharedNav, "Checkout", "", "go", FilterCallable), CallsOutgoing, 3, "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := strings.Join(callNames(h.Root.Children), ","); got != "SumCart,ApplyFees" {
+		t.Fatalf("callees of Checkout = %s, want SumCart,ApplyFees", got)
+	}
+	sum := h.Root.Children[0]
+	if len(sum.CallSites) != 1 || sum.CallSites[0].StartLine != 4 {
+		t.Errorf("call sites of SumCart = %+v, want line 4", sum.CallSites)
+	}
+	// SumCart calls itself: the recursion is reported once, not expanded.
+	if got := strings.Join(callNames(sum.Children), ","); got != "SumCart*" {
+		t.Errorf("callees of SumCart = %s, want SumCart*", got)
+	}
+}
+
+func TestCallHierarchyIncoming(t *testing.T) {
+	h, err := sharedNav.CallHierarchy(lookupOne(t, sharedNav, "ApplyFees", "", "go", FilterCallable), CallsIncoming, 3, "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := strings.Join(callNames(h.Root.Children), ","); got != "Checkout" {
+		t.Fatalf("callers of ApplyFees = %s, want Checkout", got)
+	}
+	if got := strings.Join(callNames(h.Root.Children[0].Children), ","); got != "Reorder" {
+		t.Errorf("callers of Checkout = %s, want Reorder", got)
+	}
+
+	shallow, err := sharedNav.CallHierarchy(lookupOne(t, sharedNav, "ApplyFees", "", "go", FilterCallable), CallsIncoming, 1, "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(shallow.Root.Children) != 1 || len(shallow.Root.Children[0].Children) != 0 {
+		t.Errorf("depth 1: got %+v, want Checkout alone", shallow.Root.Children)
+	}
+	if out := FormatCallHierarchy(h); !strings.Contains(out, "- Checkout (function) at calls/checkout.go:3:5; calls at calls/checkout.go:5") {
+		t.Errorf("unexpected output:\n%s", out)
+	}
+}
+
+func TestCallHierarchyIncomingAcrossInteropLangs(t *testing.T) {
+	root := t.TempDir()
+	writeTempFile(t, root, "src/com/acme/Ledger.java", `package com.acme;
+
+public class Ledger {
+    public static long postEntry(long cents) {
+        return cents;
+    }
+}
+`)
+	writeTempFile(t, root, "src/com/acme/Settle.kt", `package com.acme
+
+fun settle(cents: Long): Long = Ledger.postEntry(cents)
+`)
+	nav, _, cleanup := setupTempRepo(t, root)
+	defer cleanup()
+
+	h, err := nav.CallHierarchy(lookupOne(t, nav, "postEntry", "", "java", FilterCallable), CallsIncoming, 1, "java")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := strings.Join(callNames(h.Root.Children), ","); got != "settle" {
+		t.Errorf("callers of postEntry = %s, want settle", got)
+	}
+}
+
+func TestCallGraphFollowsSingleFileUpdates(t *testing.T) {
+	store, cleanup := setupTestDB(t)
+	defer cleanup()
+	repoRoot := t.TempDir()
+	idx := &Indexer{Store: store, RepoRoot: repoRoot}
+	if err := store.EnsureProject(repoRoot); err != nil {
+		t.Fatal(err)
+	}
+	nav := &Navigator{DB: store.DB, ProjectID: store.ProjectID, RepoRoot: repoRoot}
+
+	write := func(name, src string) string {
+		t.Helper()
+		path := filepath.Join(repoRoot, name)
+		if err := os.WriteFile(path, []byte(src), 0o644); err != nil {
+			t.Fatal(err)
+		}
+		if _, err := idx.IndexSingleFile(path); err != nil {
+			t.Fatal(err)
+		}
+		return path
+	}
+	callers := func() string {
+		t.Helper()
+		h, err := nav.CallHierarchy(lookupOne(t, nav, "Target", "", "go", FilterCallable), CallsIncoming, 1, "go")
+		if err != nil {
+			t.Fatal(err)
+		}
+		return strings.Join(callNames(h.Root.Children), ",")
+	}
+
+	write("target.go", "package p\n\nfunc Target() {}\n")
+	caller := write("caller.go", "package p\n\nfunc First() {\n\tTarget()\n}\n")
+	if got := callers(); got != "First" {
+		t.Fatalf("callers = %q, want First", got)
+	}
+	write("caller.go", "package p\n\nfunc Second() {\n\tTarget()\n}\n")
+	if got := callers(); got != "Second" {
+		t.Errorf("callers after edit = %q, want Second", got)
+	}
+	if err := idx.RemoveSingleFile(caller); err != nil {
+		t.Fatal(err)
+	}
+	if got := callers(); got != "" {
+		t.Errorf("callers after removal = %q, want none", got)
+	}
+}
@@ -0,0 +1,85 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+func TestComponentLang(t *testing.T) {
+	for _, path := range []string{"src/App.vue", "lib/Button.svelte"} {
+		if got := DetectLang(path); got != LangTypeScript {
+			t.Errorf("DetectLang(%q) = %q, want %q", path, got, LangTypeScript)
+		}
+	}
+}
+
+func TestVueComponentParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "vue", "TodoList.vue"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("typescript")
+	result, err := parser.Parse("TodoList.vue", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "TodoItem", symbols.KindInterface)
+	expectSymbol(t, result, "toggleTodo", symbols.KindFunction)
+
+	// Positions refer to the original component, not the script block.
+	for _, s := range result.Symbols {
+		if s.Name == "toggleTodo" && s.Kind == symbols.KindFunction {
+			if s.StartLine != 20 || s.StartCol != 9 {
+				t.Errorf("toggleTodo at %d:%d, want 20:9", s.StartLine, s.StartCol)
+			}
+		}
+	}
+
+	// Template expressions are not parsed as code.
+	for _, r := range result.Refs {
+		if r.StartLine < 9 {
+			t.Errorf("unexpected ref %q from template at line %d", r.Name, r.StartLine)
+		}
+	}
+}
+
+func TestSvelteComponentParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "svelte", "Counter.svelte"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("typescript")
+	result, err := parser.Parse("Counter.svelte", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "counterStep", symbols.KindVariable)
+	expectSymbol(t, result, "incrementCounter", symbols.KindFunction)
+
+	for _, s := range result.Symbols {
+		if s.Name == "incrementCounter" && s.StartLine != 8 {
+			t.Errorf("incrementCounter at line %d, want 8", s.StartLine)
+		}
+	}
+}
+
+func TestGoToDefinitionInVueComponent(t *testing.T) {
+	defs, err := sharedNav.GoToDefinitionByName("toggleTodo", "", "typescript")
+	if err != nil {
+		t.Fatalf("GoToDefinitionByName: %v", err)
+	}
+	if len(defs) == 0 {
+		t.Fatal("expected a definition for toggleTodo")
+	}
+	if defs[0].Location.Path != filepath.Join("vue", "TodoList.vue") || defs[0].Location.StartLine != 20 {
+		t.Errorf("toggleTodo resolved to %s:%d, want vue/TodoList.vue:20",
+			defs[0].Location.Path, defs[0].Location.StartLine)
+	}
+}
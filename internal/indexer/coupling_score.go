@@ -24,7 +24,7 @@ var couplingWeights = map[string]float64{
 //
 // The score reflects how hard it would be to change the referenced symbol
 // without touching this usage site:
-//   - 1.0 = structural dependency (inheritance, instantiation)
+//   - 1.0 = structural dependency (inheritance, override, instantiation)
 //   - 0.6 = direct call coupling
 //   - 0.1 = casual mention (instanceof check, comment-like identifier)
 //
@@ -36,9 +36,11 @@ func CouplingScore(usage UsageResult, srcLine string) float64 {
 		base = 0.10 // default for unrecognised kinds
 	}
 
-	// Structural-relation override (set by tree-sitter inherit/implements captures).
+	// Structural-relation override (set by tree-sitter inherit/implements
+	// captures, and on the overriding methods of FindImplementations, which
+	// must follow any change to the overridden method's signature).
 	switch usage.Relation {
-	case "inherits", "implements":
+	case "inherits", "implements", "overrides":
 		return 1.0
 	case "prototype":
 		if base < 0.80 {
@@ -608,3 +608,41 @@ func TestCrossFileGoToDefinitionByPosition(t *testing.T) {
 		})
 	}
 }
+
+func TestGoToDefinitionByNameKotlinJavaInterop(t *testing.T) {
+	nav, cleanup := setupCrossFileTest(t)
+	defer cleanup()
+
+	// A Kotlin call site resolves the Java class it uses.
+	defs, err := nav.GoToDefinitionByName("Sample", "kotlin/Shop.kt", "kotlin")
+	if err != nil {
+		t.Fatalf("GoToDefinitionByName: %v", err)
+	}
+	found := false
+	for _, d := range defs {
+		if strings.HasSuffix(d.Location.Path, "java/Sample.java") {
+			found = true
+		}
+	}
+	if !found {
+		t.Errorf("expected Kotlin lookup of Sample to find java/Sample.java, got %+v", defs)
+	}
+
+	// And Java code sees Kotlin declarations.
+	defs, err = nav.GoToDefinitionByName("CartRegistry", "", "java")
+	if err != nil {
+		t.Fatalf("GoToDefinitionByName: %v", err)
+	}
+	if len(defs) == 0 || !strings.HasSuffix(defs[0].Location.Path, "kotlin/Shop.kt") {
+		t.Errorf("expected Java lookup of CartRegistry to find kotlin/Shop.kt, got %+v", defs)
+	}
+
+	// Languages outside the JVM group stay isolated.
+	defs, err = nav.GoToDefinitionByName("CartRegistry", "", "go")
+	if err != nil {
+		t.Fatalf("GoToDefinitionByName: %v", err)
+	}
+	if len(defs) != 0 {
+		t.Errorf("expected no Go definitions for CartRegistry, got %+v", defs)
+	}
+}
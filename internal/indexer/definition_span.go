@@ -116,7 +116,7 @@ func isDocLine(trimmed string, lang Lang) bool {
 			strings.HasPrefix(trimmed, "/*") ||
 			strings.HasPrefix(trimmed, "*") ||
 			strings.HasSuffix(trimmed, "*/")
-	case LangJava:
+	case LangJava, LangKotlin:
 		return strings.HasPrefix(trimmed, "//") ||
 			strings.HasPrefix(trimmed, "/*") ||
 			strings.HasPrefix(trimmed, "/**") ||
@@ -132,6 +132,36 @@ func isDocLine(trimmed string, lang Lang) bool {
 	case LangPython:
 		return strings.HasPrefix(trimmed, "#") ||
 			strings.HasPrefix(trimmed, "@")
+	case LangRuby:
+		return strings.HasPrefix(trimmed, "#")
+	case LangProto:
+		return strings.HasPrefix(trimmed, "//") ||
+			strings.HasPrefix(trimmed, "/*") ||
+			strings.HasPrefix(trimmed, "*") ||
+			strings.HasSuffix(trimmed, "*/")
+	case LangGraphQL:
+		return strings.HasPrefix(trimmed, "#") ||
+			strings.HasPrefix(trimmed, `"`) ||
+			strings.HasSuffix(trimmed, `"`)
+	case LangPHP:
+		return strings.HasPrefix(trimmed, "//") ||
+			strings.HasPrefix(trimmed, "/*") ||
+			strings.HasPrefix(trimmed, "*") ||
+			strings.HasSuffix(trimmed, "*/") ||
+			strings.HasPrefix(trimmed, "#")
+	case LangC, LangCPP:
+		return strings.HasPrefix(trimmed, "//") ||
+			strings.HasPrefix(trimmed, "/*") ||
+			strings.HasPrefix(trimmed, "*") ||
+			strings.HasSuffix(trimmed, "*/") ||
+			strings.HasPrefix(trimmed, "template")
+	case LangCSharp:
+		return strings.HasPrefix(trimmed, "///") ||
+			strings.HasPrefix(trimmed, "//") ||
+			strings.HasPrefix(trimmed, "/*") ||
+			strings.HasPrefix(trimmed, "*") ||
+			strings.HasSuffix(trimmed, "*/") ||
+			strings.HasPrefix(trimmed, "[")
 	case LangTypeScript, LangJavaScript:
 		return strings.HasPrefix(trimmed, "//") ||
 			strings.HasPrefix(trimmed, "/*") ||
@@ -8,6 +8,8 @@ import (
 	"regexp"
 	"sort"
 	"strings"
+
+	"github.com/mesdx/cli/internal/sourcefile"
 )
 
 // ---------------------------------------------------------------------------
@@ -166,6 +168,34 @@ func scoreOneUsage(
 	repoRoot string,
 	lineCache map[string]string,
 ) (float64, *DefinitionResult) {
+	// A ref resolved through its receiver type or imports names its
+	// definition exactly.
+	if def := usage.ResolvedDefinition; def != nil && primaryDef != nil {
+		if sameDefinition(*def, *primaryDef) {
+			return 1.0, primaryDef
+		}
+		return 0, def
+	}
+	// An overriding method overriding none of the candidates (a library
+	// method) is no usage of them.
+	if usage.Relation == "overrides" && usage.ResolvedDefinition == nil {
+		return 0, nil
+	}
+	if matches, ok := refResolution(usage, candidates); ok {
+		if len(matches) == 0 {
+			return 0, nil
+		}
+		if primaryDef != nil {
+			for _, i := range matches {
+				if sameDefinition(candidates[i], *primaryDef) {
+					return 1.0, &candidates[i]
+				}
+			}
+			return 0, &candidates[matches[0]]
+		}
+		return 1.0, &candidates[matches[0]]
+	}
+
 	numCandidates := float64(len(candidates))
 
 	// Read the source line for lexical-context analysis.
@@ -222,9 +252,7 @@ func scoreOneUsage(
 	if primaryDef != nil {
 		// Cursor-based: compute P(primaryDef | ref).
 		for i, def := range candidates {
-			if def.Location.Path == primaryDef.Location.Path &&
-				def.Location.StartLine == primaryDef.Location.StartLine &&
-				def.Location.StartCol == primaryDef.Location.StartCol {
+			if sameDefinition(def, *primaryDef) {
 				return round4(probs[i]), &candidates[i]
 			}
 		}
@@ -245,6 +273,13 @@ func scoreOneUsage(
 // Signal helpers
 // ---------------------------------------------------------------------------
 
+// sameDefinition reports whether two results point at the same definition site.
+func sameDefinition(a, b DefinitionResult) bool {
+	return a.Location.Path == b.Location.Path &&
+		a.Location.StartLine == b.Location.StartLine &&
+		a.Location.StartCol == b.Location.StartCol
+}
+
 // sameDir checks if two repo-relative paths share the same parent directory.
 func sameDir(a, b string) bool {
 	return filepath.Dir(a) == filepath.Dir(b)
@@ -352,6 +387,8 @@ func relationBoost(relation string) float64 {
 		return 1.5 // interface implementation is highly semantic
 	case "inherits":
 		return 1.3 // class inheritance is highly semantic
+	case "overrides":
+		return 1.3 // method override
 	case "annotation":
 		return 1.1
 	case "prototype":
@@ -520,6 +557,16 @@ func BuildDependencyGraph(
 	if err != nil {
 		return graph, err
 	}
+	// Schema definitions are used through the code generated from them, so
+	// usages of the generated names count as usages of the schema symbol.
+	if isSchemaFile(primaryDef.Location.Path) {
+		genUsages, err := nav.GeneratedCodeUsages(*primaryDef)
+		if err != nil {
+			return graph, err
+		}
+		usages = mergeUsages(usages, genUsages)
+	}
+	usages = dropUsagesResolvedElsewhere(usages, candidates, *primaryDef)
 	if maxUsages > 0 && len(usages) > maxUsages {
 		usages = usages[:maxUsages]
 	}
@@ -603,6 +650,9 @@ func BuildDependencyGraph(
 			graph.Outbound.Nodes = append(graph.Outbound.Nodes, outbound.nodes...)
 			graph.Outbound.Edges = append(graph.Outbound.Edges, outbound.edges...)
 		}
+		generated := schemaOutbound(nav, primaryDef, primaryNodeID)
+		graph.Outbound.Nodes = append(graph.Outbound.Nodes, generated.nodes...)
+		graph.Outbound.Edges = append(graph.Outbound.Edges, generated.edges...)
 	}
 
 	// -----------------------------------------------------------------------
@@ -699,8 +749,16 @@ func computeOutbound(
 			continue
 		}
 
-		// Pick the best candidate (simple heuristic: same file > same dir > first).
+		// Pick the best candidate (simple heuristic: same file > same dir > first),
+		// unless the refs resolve through their imports.
 		best := pickBestCandidate(defs, def.Location.Path)
+		score := 1.0 / math.Sqrt(float64(len(defs))) // uniqueness-based score
+		if resolved, ok := resolveOutbound(refs, refName, defs); ok {
+			if resolved == nil {
+				continue // the name refers to code outside the repo
+			}
+			best, score = resolved, 1.0
+		}
 		if best == nil {
 			continue
 		}
@@ -734,7 +792,7 @@ func computeOutbound(
 		edge := DepGraphEdge{
 			From:     defNodeID,
 			To:       targetNodeID,
-			Score:    1.0 / math.Sqrt(float64(len(defs))), // uniqueness-based score
+			Score:    score,
 			Count:    count,
 			FilePath: best.Location.Path,
 		}
@@ -749,6 +807,25 @@ func computeOutbound(
 	return result, nil
 }
 
+// mergeUsages appends extra usages to usages, skipping any already present
+// at the same position.
+func mergeUsages(usages, extra []UsageResult) []UsageResult {
+	seen := make(map[string]bool, len(usages))
+	key := func(u UsageResult) string {
+		return u.Location.Path + ":" + itoa(u.Location.StartLine) + ":" + itoa(u.Location.StartCol)
+	}
+	for _, u := range usages {
+		seen[key(u)] = true
+	}
+	for _, u := range extra {
+		if !seen[key(u)] {
+			seen[key(u)] = true
+			usages = append(usages, u)
+		}
+	}
+	return usages
+}
+
 // refPriority returns priority for choosing most semantic ref (for outbound edges).
 func refPriority(refKind string) int {
 	switch refKind {
@@ -888,6 +965,14 @@ func safeJoinRepoPath(repoRoot, relPath string) string {
 
 // readSingleLine reads a single 1-based line from a file.
 func readSingleLine(absPath string, lineNum int) (string, error) {
+	if sourcefile.IsNotebookFile(absPath) {
+		lines, err := ReadSourceLines(absPath)
+		if err != nil || lineNum < 1 || lineNum > len(lines) {
+			return "", err
+		}
+		return lines[lineNum-1], nil
+	}
+
 	f, err := os.Open(absPath)
 	if err != nil {
 		return "", err
@@ -0,0 +1,206 @@
+package indexer
+
+import (
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// maxDocstringLines bounds the lines scanned for the end of a Python def or
+// class header and for the end of its docstring.
+const maxDocstringLines = 200
+
+// attachDocs sets the documentation of each symbol of a file: the comment
+// block written directly above its declaration (Go doc comments, Javadoc,
+// rustdoc, JSDoc, KDoc, C# XML docs, Ruby and PHP comments, schema
+// descriptions) or, for a Python def or class, the docstring opening its
+// body. Comment markers, annotations, decorators and attributes are left
+// out. src is the file as read from disk.
+func attachDocs(relPath string, lang Lang, src []byte, fr *symbols.FileResult) {
+	if len(fr.Symbols) == 0 {
+		return
+	}
+	lines, err := sourceLines(relPath, src)
+	if err != nil {
+		return
+	}
+	for i := range fr.Symbols {
+		s := &fr.Symbols[i]
+		if s.StartLine < 1 || s.StartLine > len(lines) {
+			continue
+		}
+		if lang == LangPython {
+			if s.Doc = pythonDocstring(lines, s.StartLine); s.Doc != "" {
+				continue
+			}
+		}
+		s.Doc = commentDoc(lines, s.StartLine, lang)
+	}
+}
+
+// commentDoc returns the text of the comment block above declLine (1-based)
+// without its comment markers.
+func commentDoc(lines []string, declLine int, lang Lang) string {
+	start := FindDocStartLine(lines, declLine, lang)
+	var doc []string
+	for _, line := range lines[start-1 : declLine-1] {
+		trimmed := strings.TrimSpace(line)
+		if isAttributeLine(trimmed, lang) {
+			continue
+		}
+		doc = append(doc, stripCommentMarkers(trimmed, lang))
+	}
+	text := joinDocLines(doc)
+	if lang == LangCSharp {
+		text = strings.TrimSpace(csharpDocTags.Replace(text))
+	}
+	return text
+}
+
+// csharpDocTags removes the summary element wrapping most C# XML docs.
+var csharpDocTags = strings.NewReplacer("<summary>", "", "</summary>", "")
+
+// isAttributeLine reports whether a line accepted by isDocLine is an
+// annotation, decorator, attribute or template header rather than
+// documentation. Go directives (//go:generate) are not documentation
+// either, and Rust //! lines document the enclosing module, not the item
+// below them.
+func isAttributeLine(trimmed string, lang Lang) bool {
+	switch lang {
+	case LangGo:
+		return strings.HasPrefix(trimmed, "//go:") || strings.HasPrefix(trimmed, "//nolint")
+	case LangJava, LangKotlin, LangPython, LangTypeScript, LangJavaScript:
+		return strings.HasPrefix(trimmed, "@")
+	case LangRust:
+		return strings.HasPrefix(trimmed, "#[") || strings.HasPrefix(trimmed, "#![") ||
+			strings.HasPrefix(trimmed, "//!")
+	case LangCSharp:
+		return strings.HasPrefix(trimmed, "[")
+	case LangPHP:
+		return strings.HasPrefix(trimmed, "#[")
+	case LangC, LangCPP:
+		return strings.HasPrefix(trimmed, "template")
+	}
+	return false
+}
+
+// stripCommentMarkers returns the text of one comment line without its
+// markers (///, /**, */, the leading * of a block comment line, #, the
+// quotes of a GraphQL description) and the space that follows them.
+func stripCommentMarkers(trimmed string, lang Lang) string {
+	text := trimmed
+	for _, marker := range []string{"/**", "/*", "///", "//"} {
+		if strings.HasPrefix(text, marker) {
+			text = text[len(marker):]
+			break
+		}
+	}
+	text = strings.TrimSuffix(text, "*/")
+	if strings.HasPrefix(trimmed, "*") { // inside a block comment
+		text = strings.TrimPrefix(text, "*")
+	}
+	switch lang {
+	case LangPython, LangRuby, LangPHP:
+		text = strings.TrimPrefix(text, "#")
+	case LangGraphQL:
+		text = strings.Trim(strings.TrimPrefix(text, "#"), `"`)
+	}
+	return strings.TrimRight(strings.TrimPrefix(text, " "), " \t")
+}
+
+// joinDocLines joins the lines of a documentation block, dropping the blank
+// lines before and after it.
+func joinDocLines(doc []string) string {
+	for len(doc) > 0 && strings.TrimSpace(doc[0]) == "" {
+		doc = doc[1:]
+	}
+	for len(doc) > 0 && strings.TrimSpace(doc[len(doc)-1]) == "" {
+		doc = doc[:len(doc)-1]
+	}
+	return strings.Join(doc, "\n")
+}
+
+// pythonDocstring returns the docstring opening the body of the def or
+// class declared on declLine (1-based), dedented as inspect.cleandoc does,
+// or "" when the declaration is not a def or class or has no docstring.
+func pythonDocstring(lines []string, declLine int) string {
+	header := strings.TrimSpace(lines[declLine-1])
+	if !strings.HasPrefix(header, "def ") && !strings.HasPrefix(header, "async def ") &&
+		!strings.HasPrefix(header, "class ") {
+		return ""
+	}
+
+	// The header ends at the first line ending with a colon, which may be
+	// below the def line when the parameters span several lines.
+	i := declLine - 1
+	for ; i < len(lines) && i < declLine-1+maxDocstringLines; i++ {
+		code := strings.TrimSpace(lines[i])
+		if hash := strings.Index(code, "#"); hash >= 0 && !strings.ContainsAny(code, `"'`) {
+			code = strings.TrimSpace(code[:hash])
+		}
+		if strings.HasSuffix(code, ":") {
+			break
+		}
+	}
+	for i++; i < len(lines) && strings.TrimSpace(lines[i]) == ""; i++ {
+	}
+	if i >= len(lines) {
+		return ""
+	}
+
+	first := strings.TrimLeft(strings.TrimSpace(lines[i]), "rRuU") // r"""raw""", u"unicode"
+	quote := ""
+	for _, q := range []string{`"""`, `'''`, `"`, `'`} {
+		if strings.HasPrefix(first, q) {
+			quote = q
+			break
+		}
+	}
+	if quote == "" {
+		return ""
+	}
+
+	var doc []string
+	body := first[len(quote):]
+	for end := i + maxDocstringLines; ; {
+		if j := strings.Index(body, quote); j >= 0 {
+			doc = append(doc, body[:j])
+			break
+		}
+		doc = append(doc, body)
+		if i++; len(quote) == 1 || i >= len(lines) || i >= end {
+			break
+		}
+		body = lines[i]
+	}
+	return cleanDocstring(doc)
+}
+
+// cleanDocstring removes the indentation shared by the lines after the
+// first, and the blank lines around the docstring.
+func cleanDocstring(doc []string) string {
+	indent := -1
+	for _, line := range doc[1:] {
+		if strings.TrimSpace(line) == "" {
+			continue
+		}
+		if n := leadingWhitespaceCount(line); indent < 0 || n < indent {
+			indent = n
+		}
+	}
+	doc[0] = strings.TrimSpace(doc[0])
+	for i := 1; i < len(doc); i++ {
+		if len(doc[i]) >= indent && indent > 0 {
+			doc[i] = doc[i][indent:]
+		}
+		doc[i] = strings.TrimRight(doc[i], " \t")
+	}
+	return joinDocLines(doc)
+}
+
+// docSummary returns the first paragraph of a documentation block on one
+// line, for text output.
+func docSummary(doc string) string {
+	paragraph, _, _ := strings.Cut(doc, "\n\n")
+	return strings.Join(strings.Fields(paragraph), " ")
+}
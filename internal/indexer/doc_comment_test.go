@@ -0,0 +1,66 @@
+package indexer
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestGoToDefinitionReturnsDoc(t *testing.T) {
+	tests := []struct {
+		name string
+		lang string
+		want string
+	}{
+		{"ApplyDiscount", "go", "ApplyDiscount returns the price after the discount.\n\nThe discount is a fraction between 0 and 1."},
+		{"apply_surcharge", "python", "Return the price with the surcharge added.\n\nThe rate is a fraction of the price."},
+		{"round_cents", "rust", "Rounds a price to whole cents."},
+		{"computeTax", "java", "Returns the tax owed on a price."},
+		{"formatPrice", "typescript", "Formats a price for display.\n@param price the price in euros"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.lang, func(t *testing.T) {
+			defs, err := sharedNav.GoToDefinitionByName(tt.name, "", tt.lang)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if len(defs) != 1 {
+				t.Fatalf("got %d definitions of %s, want 1: %+v", len(defs), tt.name, defs)
+			}
+			if defs[0].Doc != tt.want {
+				t.Errorf("doc of %s = %q, want %q", tt.name, defs[0].Doc, tt.want)
+			}
+		})
+	}
+}
+
+func TestFormatDefinitionsShowsDocSummary(t *testing.T) {
+	defs, err := sharedNav.GoToDefinitionByName("ApplyDiscount", "", "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	out := FormatDefinitions(defs)
+	if !strings.Contains(out, "ApplyDiscount returns the price after the discount.") {
+		t.Errorf("output lacks the doc summary:\n%s", out)
+	}
+	if strings.Contains(out, "fraction") {
+		t.Errorf("output includes more than the first paragraph of the doc:\n%s", out)
+	}
+}
+
+func TestPythonDocstring(t *testing.T) {
+	lines := []string{
+		"class Cart(",
+		"    Base,",
+		"):  # shopping cart",
+		"",
+		`    r'''Holds the items of an order.'''`,
+		"def total(self):",
+		`    return 0`,
+	}
+	if got := pythonDocstring(lines, 1); got != "Holds the items of an order." {
+		t.Errorf("class docstring = %q", got)
+	}
+	if got := pythonDocstring(lines, 6); got != "" {
+		t.Errorf("docstring of an undocumented def = %q, want none", got)
+	}
+}
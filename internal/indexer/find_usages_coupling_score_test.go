@@ -53,6 +53,14 @@ func TestCouplingScore_RelationOverride_Implements(t *testing.T) {
 	}
 }
 
+func TestCouplingScore_RelationOverride_Overrides(t *testing.T) {
+	u := UsageResult{Name: "area", Kind: "other", Relation: "overrides"}
+	got := CouplingScore(u, "")
+	if got != 1.0 {
+		t.Errorf("CouplingScore(relation=overrides) = %.4f, want 1.0", got)
+	}
+}
+
 func TestCouplingScore_UnknownKind(t *testing.T) {
 	u := UsageResult{Name: "X", Kind: "completely_unknown"}
 	got := CouplingScore(u, "")
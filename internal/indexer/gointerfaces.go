@@ -0,0 +1,426 @@
+package indexer
+
+import (
+	"fmt"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// InterfaceSatisfaction is a Go type satisfying an interface structurally:
+// its method set has every method of the interface, with the same number of
+// parameters.
+type InterfaceSatisfaction struct {
+	Interface DefinitionResult `json:"interface"`
+	Type      DefinitionResult `json:"type"`
+
+	// Pointer is set when only the pointer type (*T) satisfies the
+	// interface, through methods declared with pointer receivers.
+	Pointer bool `json:"pointer,omitempty"`
+
+	// Methods are the methods of the type implementing those of the
+	// interface, by name; promoted methods are those of the embedded type.
+	Methods []DefinitionResult `json:"methods,omitempty"`
+}
+
+// InterfaceSatisfactions lists the types implementing a Go interface and the
+// interfaces a Go type implements.
+type InterfaceSatisfactions struct {
+	Type DefinitionResult `json:"type"`
+
+	// Implementations are the types other than interfaces satisfying Type,
+	// when it is an interface.
+	Implementations []InterfaceSatisfaction `json:"implementations,omitempty"`
+
+	// Interfaces are the interfaces Type satisfies.
+	Interfaces []InterfaceSatisfaction `json:"interfaces,omitempty"`
+
+	// Unresolved lists the types embedded in Type, directly or not, that
+	// are defined outside the repo (io.Reader, sync.Mutex): their methods
+	// are unknown, so they are not matched.
+	Unresolved []string `json:"unresolved,omitempty"`
+}
+
+// goBuiltinMethods lists the methods of the predeclared interfaces, which
+// may be embedded without being defined in the repo.
+var goBuiltinMethods = map[string][]goMethod{
+	"error": {{name: "Error"}},
+}
+
+// goMethod is a method of a Go type or interface.
+type goMethod struct {
+	name    string
+	arity   int
+	pointer bool // declared with a pointer receiver
+
+	// def is nil for the methods of the predeclared interfaces.
+	def *DefinitionResult
+}
+
+// goMethodSet is a method set by method name.
+type goMethodSet map[string]goMethod
+
+// sorted returns the methods of the set by name.
+func (s goMethodSet) sorted() []goMethod {
+	methods := make([]goMethod, 0, len(s))
+	for _, m := range s {
+		methods = append(methods, m)
+	}
+	sort.Slice(methods, func(i, j int) bool { return methods[i].name < methods[j].name })
+	return methods
+}
+
+// goTypeIndex holds the Go named types of the index with their declared
+// methods and embedded types, keyed by package directory and type name.
+type goTypeIndex struct {
+	types   []DefinitionResult
+	byName  map[string][]DefinitionResult
+	methods map[string][]goMethod
+	embeds  map[string][]UsageResult
+}
+
+// goTypeKey identifies a named type by its package directory and name.
+func goTypeKey(path, name string) string {
+	return filepath.Dir(path) + ":" + name
+}
+
+// receiverBase returns the type a Go receiver is declared on: Ledger for
+// *Ledger[T].
+func receiverBase(receiver string) string {
+	base := strings.TrimPrefix(receiver, "*")
+	if i := strings.IndexByte(base, '['); i >= 0 {
+		base = base[:i]
+	}
+	return base
+}
+
+// loadGoTypes reads the Go structs, interfaces and other named types of the
+// index with their methods and embedded fields.
+func (n *Navigator) loadGoTypes() (*goTypeIndex, error) {
+	g := &goTypeIndex{
+		byName:  map[string][]DefinitionResult{},
+		methods: map[string][]goMethod{},
+		embeds:  map[string][]UsageResult{},
+	}
+	rows, err := n.DB.Query(`
+		SELECT `+definitionColumns+`
+		FROM symbols s
+		JOIN files f ON s.file_id = f.id
+		WHERE f.project_id = ? AND f.lang = ? AND s.kind IN (?, ?, ?, ?)
+		ORDER BY f.path ASC, s.start_line ASC, s.start_col ASC
+	`, n.ProjectID, string(LangGo), int(symbols.KindStruct), int(symbols.KindInterface),
+		int(symbols.KindTypeAlias), int(symbols.KindMethod))
+	if err != nil {
+		return nil, fmt.Errorf("query go types: %w", err)
+	}
+	defer func() { _ = rows.Close() }()
+	for rows.Next() {
+		d, err := scanDefinition(rows)
+		if err != nil {
+			return nil, err
+		}
+		if d.Kind != "method" {
+			g.types = append(g.types, d)
+			g.byName[d.Name] = append(g.byName[d.Name], d)
+			continue
+		}
+		// Interface methods have no receiver; their container is the
+		// interface.
+		m, typ := goMethod{name: d.Name, arity: len(d.Params), def: &d}, d.Container
+		if d.Receiver != "" {
+			typ = receiverBase(d.Receiver)
+			m.pointer = strings.HasPrefix(d.Receiver, "*")
+		}
+		key := goTypeKey(d.Location.Path, typ)
+		g.methods[key] = append(g.methods[key], m)
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+
+	embeds, err := n.DB.Query(`
+		SELECT r.context_container, r.name, r.target_type,
+		       r.qualifier, r.import_path, r.resolved_path,
+		       f.path, r.start_line, r.start_col, r.end_line, r.end_col
+		FROM refs r
+		JOIN files f ON r.file_id = f.id
+		WHERE f.project_id = ? AND f.lang = ? AND r.kind = ? AND r.relation = 'embeds'
+		  AND r.context_container != ''
+		ORDER BY f.path ASC, r.start_line ASC, r.start_col ASC
+	`, n.ProjectID, string(LangGo), int(symbols.RefInherit))
+	if err != nil {
+		return nil, fmt.Errorf("query embedded types: %w", err)
+	}
+	defer func() { _ = embeds.Close() }()
+	for embeds.Next() {
+		var u UsageResult
+		var container string
+		if err := embeds.Scan(&container, &u.Name, &u.TargetType,
+			&u.Qualifier, &u.ImportPath, &u.ResolvedPath,
+			&u.Location.Path, &u.Location.StartLine, &u.Location.StartCol,
+			&u.Location.EndLine, &u.Location.EndCol); err != nil {
+			return nil, err
+		}
+		u.Kind, u.Relation, u.ContextContainer = symbols.RefInherit.String(), "embeds", container
+		key := goTypeKey(u.Location.Path, container)
+		g.embeds[key] = append(g.embeds[key], u)
+	}
+	return g, embeds.Err()
+}
+
+// embedded returns the definition of an embedded type, or nil for a type
+// outside the repo or a predeclared one.
+func (g *goTypeIndex) embedded(ref UsageResult) *DefinitionResult {
+	defs := g.byName[ref.Name]
+	if ref.ImportPath == "" {
+		// Without import resolution, an unqualified type is declared in the
+		// embedding package, and a qualified one in a package directory
+		// named like its qualifier.
+		var candidates []DefinitionResult
+		for _, d := range defs {
+			dir := filepath.Dir(d.Location.Path)
+			if (ref.Qualifier == "" && dir == filepath.Dir(ref.Location.Path)) ||
+				(ref.Qualifier != "" && filepath.Base(dir) == ref.Qualifier) {
+				candidates = append(candidates, d)
+			}
+		}
+		defs = candidates
+	}
+	return refTarget(ref, defs)
+}
+
+// methodSet returns the method set of the type declared by def, or with
+// pointer that of *T: its methods and those promoted from its embedded
+// fields, or for an interface its methods and those of the interfaces it
+// embeds. unresolved lists the embedded types outside the repo.
+func (g *goTypeIndex) methodSet(def DefinitionResult, pointer bool) (set goMethodSet, unresolved []string) {
+	set = goMethodSet{}
+	g.collect(def, pointer, set, &unresolved, map[string]bool{})
+	return set, unresolved
+}
+
+// collect adds the methods of def to set, those declared on it first so
+// that they win over promoted ones. visiting holds the types being
+// collected, against embedding cycles.
+func (g *goTypeIndex) collect(def DefinitionResult, pointer bool, set goMethodSet, unresolved *[]string, visiting map[string]bool) {
+	key := goTypeKey(def.Location.Path, def.Name)
+	if visiting[key] {
+		return
+	}
+	visiting[key] = true
+	defer delete(visiting, key)
+
+	for _, m := range g.methods[key] {
+		if _, ok := set[m.name]; !ok && (pointer || !m.pointer) {
+			set[m.name] = m
+		}
+	}
+	for _, e := range g.embeds[key] {
+		target := g.embedded(e)
+		if target == nil {
+			if builtin, ok := goBuiltinMethods[e.TargetType]; ok {
+				for _, m := range builtin {
+					if _, ok := set[m.name]; !ok {
+						set[m.name] = m
+					}
+				}
+			} else {
+				*unresolved = append(*unresolved, e.TargetType)
+			}
+			continue
+		}
+		// Embedding *T promotes the methods of *T to both S and *S.
+		g.collect(*target, pointer || strings.HasPrefix(e.TargetType, "*"), set, unresolved, visiting)
+	}
+}
+
+// satisfies returns the methods of set implementing those of iface, by
+// name, or ok false when one is missing or takes another number of
+// parameters.
+func satisfies(iface, set goMethodSet) (methods []DefinitionResult, ok bool) {
+	for _, want := range iface.sorted() {
+		got, found := set[want.name]
+		if !found || got.arity != want.arity {
+			return nil, false
+		}
+		if got.def != nil {
+			methods = append(methods, *got.def)
+		}
+	}
+	return methods, true
+}
+
+// satisfaction reports whether typ, or else *typ, satisfies the interface
+// whose method set is iface.
+func (g *goTypeIndex) satisfaction(iface DefinitionResult, methods goMethodSet, typ DefinitionResult) (InterfaceSatisfaction, bool) {
+	s := InterfaceSatisfaction{Interface: iface, Type: typ}
+	set, _ := g.methodSet(typ, false)
+	var ok bool
+	if s.Methods, ok = satisfies(methods, set); ok || typ.Kind == "interface" {
+		return s, ok
+	}
+	set, _ = g.methodSet(typ, true)
+	s.Pointer = true
+	s.Methods, ok = satisfies(methods, set)
+	return s, ok
+}
+
+// implementations returns the types other than interfaces satisfying
+// iface, and the embedded interfaces of iface outside the repo.
+func (g *goTypeIndex) implementations(iface DefinitionResult) ([]InterfaceSatisfaction, []string) {
+	methods, unresolved := g.methodSet(iface, false)
+	if len(methods) == 0 {
+		// Every type satisfies an empty interface.
+		return nil, unresolved
+	}
+	var impls []InterfaceSatisfaction
+	for _, typ := range g.types {
+		if typ.Kind == "interface" {
+			continue
+		}
+		if s, ok := g.satisfaction(iface, methods, typ); ok {
+			impls = append(impls, s)
+		}
+	}
+	return impls, unresolved
+}
+
+// interfaces returns the interfaces other than typ itself that typ
+// satisfies. Interfaces that are empty or embed interfaces outside the repo
+// are left out: their methods are not all known.
+func (g *goTypeIndex) interfaces(typ DefinitionResult) []InterfaceSatisfaction {
+	var ifaces []InterfaceSatisfaction
+	for _, iface := range g.types {
+		if iface.Kind != "interface" || sameDefinition(iface, typ) {
+			continue
+		}
+		methods, unresolved := g.methodSet(iface, false)
+		if len(methods) == 0 || len(unresolved) > 0 {
+			continue
+		}
+		if s, ok := g.satisfaction(iface, methods, typ); ok {
+			ifaces = append(ifaces, s)
+		}
+	}
+	return ifaces
+}
+
+// InterfaceSatisfactions returns the types implementing the Go interface
+// root, or the interfaces the Go type root implements (both for an
+// interface, which satisfies the interfaces whose methods it has). Go
+// types implement interfaces implicitly: a type does when its method set,
+// with the methods declared on the type or its pointer and those promoted
+// from embedded fields, has every method of the interface with the same
+// number of parameters.
+func (n *Navigator) InterfaceSatisfactions(root DefinitionResult) (*InterfaceSatisfactions, error) {
+	g, err := n.loadGoTypes()
+	if err != nil {
+		return nil, err
+	}
+	res := &InterfaceSatisfactions{Type: root}
+	if root.Kind == "interface" {
+		res.Implementations, res.Unresolved = g.implementations(root)
+	} else {
+		_, res.Unresolved = g.methodSet(root, true)
+	}
+	res.Interfaces = g.interfaces(root)
+	return res, nil
+}
+
+// InterfaceMethodImplementations returns the methods implementing a method
+// of a Go interface in the types satisfying it, or nil for other
+// definitions.
+func (n *Navigator) InterfaceMethodImplementations(method DefinitionResult) ([]DefinitionResult, error) {
+	if DetectLang(method.Location.Path) != LangGo || method.Kind != "method" ||
+		method.Receiver != "" || method.Container == "" {
+		return nil, nil
+	}
+	g, err := n.loadGoTypes()
+	if err != nil {
+		return nil, err
+	}
+	var impls []DefinitionResult
+	for _, iface := range g.byName[method.Container] {
+		if iface.Kind != "interface" || iface.Location.Path != method.Location.Path {
+			continue
+		}
+		satisfactions, _ := g.implementations(iface)
+		for _, s := range satisfactions {
+			for _, m := range s.Methods {
+				if m.Name == method.Name && !containsDefinitionResult(impls, m) {
+					impls = append(impls, m)
+				}
+			}
+		}
+	}
+	return impls, nil
+}
+
+// containsDefinitionResult reports whether defs holds def.
+func containsDefinitionResult(defs []DefinitionResult, def DefinitionResult) bool {
+	for _, d := range defs {
+		if sameDefinition(d, def) {
+			return true
+		}
+	}
+	return false
+}
+
+// FilterInterfaceTypes returns the Go named types among defs, in their
+// original order.
+func FilterInterfaceTypes(defs []DefinitionResult) []DefinitionResult {
+	types := []DefinitionResult{}
+	for _, d := range defs {
+		switch d.Kind {
+		case "interface", "struct", "type_alias":
+			types = append(types, d)
+		}
+	}
+	return types
+}
+
+// FormatInterfaceSatisfactions formats the implementations and implemented
+// interfaces of a Go type for MCP.
+func FormatInterfaceSatisfactions(s *InterfaceSatisfactions) string {
+	var b strings.Builder
+	fmt.Fprintf(&b, "Interface satisfaction of %s (%s) at %s\n", s.Type.Name, s.Type.Kind, s.Type.Location)
+	if s.Type.Kind == "interface" {
+		b.WriteString("\nImplementations:\n")
+		if len(s.Implementations) == 0 {
+			b.WriteString("(none)\n")
+		}
+		for _, impl := range s.Implementations {
+			name := impl.Type.Name
+			if impl.Pointer {
+				name = "*" + name
+			}
+			writeSatisfaction(&b, name, impl.Type, impl.Methods, "")
+		}
+	}
+	b.WriteString("\nImplements:\n")
+	if len(s.Interfaces) == 0 {
+		b.WriteString("(none)\n")
+	}
+	for _, iface := range s.Interfaces {
+		note := ""
+		if iface.Pointer {
+			note = " (by *" + s.Type.Name + ")"
+		}
+		writeSatisfaction(&b, iface.Interface.Name, iface.Interface, iface.Methods, note)
+	}
+	if len(s.Unresolved) > 0 {
+		fmt.Fprintf(&b, "\nEmbedded types outside the repo, not matched: %s\n", strings.Join(s.Unresolved, ", "))
+	}
+	return strings.TrimSuffix(b.String(), "\n")
+}
+
+// writeSatisfaction writes a type or interface of a satisfaction under
+// name and the methods implementing the interface.
+func writeSatisfaction(b *strings.Builder, name string, def DefinitionResult, methods []DefinitionResult, note string) {
+	fmt.Fprintf(b, "- %s (%s) at %s%s\n", name, def.Kind, def.Location, note)
+	for _, m := range methods {
+		fmt.Fprintf(b, "  %s at %s\n", m.Signature, m.Location)
+	}
+}
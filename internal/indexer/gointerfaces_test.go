@@ -0,0 +1,110 @@
+package indexer
+
+import (
+	"strings"
+	"testing"
+)
+
+// satisfactionNames renders the types or interfaces of satisfactions as
+// names, marking those only the pointer type satisfies: *Type, Interface*.
+func satisfactionNames(satisfactions []InterfaceSatisfaction, ifaces bool) string {
+	var names []string
+	for _, s := range satisfactions {
+		name := s.Type.Name
+		if s.Pointer {
+			name = "*" + name
+		}
+		if ifaces {
+			name = s.Interface.Name
+			if s.Pointer {
+				name += "*"
+			}
+		}
+		names = append(names, name)
+	}
+	return strings.Join(names, " ")
+}
+
+func TestInterfaceSatisfactions(t *testing.T) {
+	tests := []struct {
+		name            string
+		implementations string
+		interfaces      string
+		unresolved      string
+	}{
+		{"Poster", "*Journal AuditedJournal", "", ""},
+		{"Auditor", "AuditedJournal", "Poster", ""},
+		{"Faulter", "Fault", "", ""},
+		{"Streamer", "", "", "io.Reader"},
+		{"Journal", "", "Poster*", ""},
+		{"AuditedJournal", "", "Poster Auditor", ""},
+		{"Tally", "", "", ""},
+		{"Fault", "", "Faulter", ""},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s, err := sharedNav.InterfaceSatisfactions(lookupOne(t, sharedNav, tt.name, "gosatisfy/ledger.go", "go", FilterInterfaceTypes))
+			if err != nil {
+				t.Fatal(err)
+			}
+			if got := satisfactionNames(s.Implementations, false); got != tt.implementations {
+				t.Errorf("implementations = %q, want %q", got, tt.implementations)
+			}
+			if got := satisfactionNames(s.Interfaces, true); got != tt.interfaces {
+				t.Errorf("interfaces = %q, want %q", got, tt.interfaces)
+			}
+			if got := strings.Join(s.Unresolved, " "); got != tt.unresolved {
+				t.Errorf("unresolved = %q, want %q", got, tt.unresolved)
+			}
+		})
+	}
+}
+
+func TestInterfaceMethodImplementations(t *testing.T) {
+	defs, err := sharedNav.GoToDefinitionByName("Poster.Post", "", "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(defs) != 1 || defs[0].Container != "Poster" {
+		t.Fatalf("Poster.Post = %+v, want the interface method", defs)
+	}
+	impls, err := sharedNav.InterfaceMethodImplementations(defs[0])
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(impls) != 1 || impls[0].Receiver != "*Journal" || impls[0].Location.StartLine != 44 {
+		t.Errorf("implementations of Poster.Post = %+v, want (*Journal).Post at line 44", impls)
+	}
+
+	s, err := sharedNav.InterfaceSatisfactions(lookupOne(t, sharedNav, "Poster", "gosatisfy/ledger.go", "go", FilterInterfaceTypes))
+	if err != nil {
+		t.Fatal(err)
+	}
+	out := FormatInterfaceSatisfactions(s)
+	for _, want := range []string{
+		"- *Journal (struct) at gosatisfy/ledger.go:30:5",
+		"  func (j *Journal) Post(amount int, memo string) error at gosatisfy/ledger.go:44:18",
+		"- AuditedJournal (struct) at gosatisfy/ledger.go:50:5",
+	} {
+		if !strings.Contains(out, want) {
+			t.Errorf("format missing %q:\n%s", want, out)
+		}
+	}
+}
+
+func TestTypeHierarchyGoEmbedding(t *testing.T) {
+	h, err := sharedNav.TypeHierarchy(lookupOne(t, sharedNav, "Auditor", "gosatisfy/ledger.go", "go", FilterInterfaceTypes), 5, "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got, want := typeTree(h.Supertypes), "Poster(embeds)"; got != want {
+		t.Errorf("supertypes of Auditor = %q, want %q", got, want)
+	}
+	h, err = sharedNav.TypeHierarchy(lookupOne(t, sharedNav, "Journal", "gosatisfy/ledger.go", "go", FilterInterfaceTypes), 5, "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got, want := typeTree(h.Subtypes), "AuditedJournal(embeds)"; got != want {
+		t.Errorf("subtypes of Journal = %q, want %q", got, want)
+	}
+}
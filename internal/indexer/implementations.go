@@ -0,0 +1,293 @@
+package indexer
+
+import (
+	"fmt"
+	"path/filepath"
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// maxImplementationTypes bounds the subtypes searched for the
+// implementations of a method.
+const maxImplementationTypes = 500
+
+// MethodImplementation is a method overriding or implementing a method of
+// one of the supertypes of its type.
+type MethodImplementation struct {
+	Definition DefinitionResult `json:"definition"`
+
+	// Type is the subtype declaring the method, and Relation how it derives
+	// from the type above it: inherits, implements, prototype or embeds.
+	Type     string `json:"type"`
+	Relation string `json:"relation"`
+
+	// Depth is the number of inheritance steps between the type of the
+	// overridden method and Type.
+	Depth int `json:"depth"`
+}
+
+// memberOwner returns the name of the type declaring a method: the last
+// scope of its container, or else of its qualified name (Java and Python
+// methods carry no container).
+func memberOwner(def DefinitionResult) string {
+	scope := qualifiedNameSeparators.Replace(def.Container)
+	if scope == "" {
+		qualified := qualifiedNameSeparators.Replace(def.QualifiedName)
+		if scope = strings.TrimSuffix(qualified, "."+def.Name); scope == qualified {
+			return ""
+		}
+	}
+	if i := strings.LastIndex(scope, "."); i >= 0 {
+		scope = scope[i+1:]
+	}
+	return scope
+}
+
+// FilterOverridable returns the methods and functions among defs that a
+// subtype can override, in their original order.
+func FilterOverridable(defs []DefinitionResult) []DefinitionResult {
+	methods := []DefinitionResult{}
+	for _, d := range defs {
+		if isCallable(d.Kind) && d.Kind != "constructor" && memberOwner(d) != "" {
+			methods = append(methods, d)
+		}
+	}
+	return methods
+}
+
+// FindImplementations returns the methods overriding or implementing
+// method, a method of an interface, trait or class, in all the subtypes of
+// its type, nearest first. Subtypes are followed through the inheritance
+// refs of the index as in TypeHierarchy, and a subtype overrides the method
+// when it declares a method of the same name (in its body, a Rust impl
+// block or out of line), with the same number of parameters in the
+// languages with overloading. The methods of a Go interface are
+// implemented structurally, by the types whose method set satisfies it.
+func (n *Navigator) FindImplementations(method DefinitionResult, lang string) ([]MethodImplementation, error) {
+	if !isCallable(method.Kind) || method.Kind == "constructor" {
+		return nil, nil
+	}
+	if DetectLang(method.Location.Path) == LangGo && method.Receiver == "" && method.Container != "" {
+		defs, err := n.InterfaceMethodImplementations(method)
+		if err != nil {
+			return nil, err
+		}
+		var impls []MethodImplementation
+		for _, d := range defs {
+			impls = append(impls, MethodImplementation{
+				Definition: d, Type: receiverBase(d.Receiver), Relation: "implements", Depth: 1,
+			})
+		}
+		return impls, nil
+	}
+
+	owner := memberOwner(method)
+	if owner == "" {
+		return nil, nil
+	}
+	w := &typeWalker{nav: n, lang: lang, types: map[string][]DefinitionResult{}}
+	types, err := w.definitions(owner)
+	if err != nil {
+		return nil, err
+	}
+	root := declaringType(types, method)
+	if root == nil {
+		return nil, nil
+	}
+	candidates, err := n.definitionsByName(method.Name, "", lang)
+	if err != nil {
+		return nil, err
+	}
+
+	type step struct {
+		def   DefinitionResult
+		depth int
+	}
+	queue := []step{{*root, 0}}
+	seen := map[string]bool{nodeID(root.Location.Path, root.Name, root.Location.StartLine): true}
+	var impls []MethodImplementation
+	for len(queue) > 0 {
+		cur := queue[0]
+		queue = queue[1:]
+		edges, err := w.subtypeEdges(cur.def)
+		if err != nil {
+			return nil, err
+		}
+		for _, e := range edges {
+			subs, err := w.definitions(e.sub)
+			if err != nil {
+				return nil, err
+			}
+			sub := pickBestCandidate(subs, e.ref.Location.Path)
+			for _, c := range candidates {
+				if declaredWith(c, method, e, sub, subs) && !containsImplementation(impls, c) {
+					impls = append(impls, MethodImplementation{
+						Definition: c, Type: e.sub, Relation: e.relation, Depth: cur.depth + 1,
+					})
+				}
+			}
+			if sub == nil || len(seen) >= maxImplementationTypes {
+				continue
+			}
+			if id := nodeID(sub.Location.Path, sub.Name, sub.Location.StartLine); !seen[id] {
+				seen[id] = true
+				queue = append(queue, step{*sub, cur.depth + 1})
+			}
+		}
+	}
+	return impls, nil
+}
+
+// declaringType returns the type among types declaring method: the one
+// around it, or else the nearest (a Rust impl block or Go method apart from
+// its type).
+func declaringType(types []DefinitionResult, method DefinitionResult) *DefinitionResult {
+	for i, t := range types {
+		if t.Location.Path == method.Location.Path &&
+			t.Location.StartLine <= method.Location.StartLine && method.Location.StartLine <= t.Location.EndLine {
+			return &types[i]
+		}
+	}
+	return pickBestCandidate(types, method.Location.Path)
+}
+
+// declaredWith reports whether def overrides method in sub, the subtype of
+// the inheritance edge e: a method of sub named alike. types, the
+// definitions named e.sub, tell sub from other types of that name; a method
+// belongs to the one around it or else the nearest (a C++ Derived::m
+// defined in a .cpp file, a Rust impl block in another file). A Go method
+// is declared in the package of the embedding type.
+func declaredWith(def, method DefinitionResult, e inheritanceEdge, sub *DefinitionResult, types []DefinitionResult) bool {
+	if !overridesMember(def, method, e.sub) {
+		return false
+	}
+	if DetectLang(def.Location.Path) == LangGo {
+		return filepath.Dir(def.Location.Path) == filepath.Dir(e.ref.Location.Path)
+	}
+	owner := declaringType(types, def)
+	return sub != nil && owner != nil && sameDefinition(*owner, *sub)
+}
+
+// overridesMember reports whether def, as a method of the type named owner,
+// and method override one another: def is a method of owner, with the
+// same number of parameters as method in the languages with overloading.
+func overridesMember(def, method DefinitionResult, owner string) bool {
+	if !isCallable(def.Kind) || def.Kind == "constructor" || memberOwner(def) != owner {
+		return false
+	}
+	switch DetectLang(def.Location.Path) {
+	case LangJava, LangCSharp, LangKotlin, LangCPP:
+		// An overload is another method.
+		return len(def.Params) == len(method.Params)
+	}
+	return true
+}
+
+// overriddenMethod returns the method that override, an overrides ref
+// recorded on the declaration of an overriding method, overrides: the
+// method named alike of the nearest supertype of its type declaring one, as
+// matched by overridesMember. defs are the definitions of its name. The
+// method is nil when it is declared outside the repo.
+func (n *Navigator) overriddenMethod(override UsageResult, defs []DefinitionResult, lang string) (*DefinitionResult, error) {
+	var self *DefinitionResult
+	for i, d := range defs {
+		if d.Location.Path == override.Location.Path &&
+			d.Location.StartLine == override.Location.StartLine && d.Location.StartCol == override.Location.StartCol {
+			self = &defs[i]
+		}
+	}
+	if self == nil || override.ContextContainer == "" {
+		return nil, nil
+	}
+	w := &typeWalker{nav: n, lang: lang, types: map[string][]DefinitionResult{}}
+	types, err := w.definitions(override.ContextContainer)
+	if err != nil {
+		return nil, err
+	}
+	root := declaringType(types, *self)
+	if root == nil {
+		return nil, nil
+	}
+
+	queue := []DefinitionResult{*root}
+	seen := map[string]bool{nodeID(root.Location.Path, root.Name, root.Location.StartLine): true}
+	for len(queue) > 0 {
+		cur := queue[0]
+		queue = queue[1:]
+		supers, err := w.supertypes(cur)
+		if err != nil {
+			return nil, err
+		}
+		for _, node := range supers {
+			super := node.Definition
+			if super == nil {
+				continue
+			}
+			superTypes, err := w.definitions(node.Name)
+			if err != nil {
+				return nil, err
+			}
+			for i, d := range defs {
+				if !overridesMember(d, *self, node.Name) {
+					continue
+				}
+				if owner := declaringType(superTypes, d); owner != nil && sameDefinition(*owner, *super) {
+					return &defs[i], nil
+				}
+			}
+			if len(seen) >= maxImplementationTypes {
+				continue
+			}
+			if id := nodeID(super.Location.Path, super.Name, super.Location.StartLine); !seen[id] {
+				seen[id] = true
+				queue = append(queue, *super)
+			}
+		}
+	}
+	return nil, nil
+}
+
+// containsImplementation reports whether impls holds def.
+func containsImplementation(impls []MethodImplementation, def DefinitionResult) bool {
+	for _, impl := range impls {
+		if sameDefinition(impl.Definition, def) {
+			return true
+		}
+	}
+	return false
+}
+
+// OverrideUsages returns the overriding methods of impls as usages of the
+// method they override, with the relation "overrides": a change to that
+// method's signature must be made to them too.
+func OverrideUsages(impls []MethodImplementation) []UsageResult {
+	usages := make([]UsageResult, 0, len(impls))
+	for _, impl := range impls {
+		usages = append(usages, UsageResult{
+			Name:             impl.Definition.Name,
+			Kind:             symbols.RefInherit.String(),
+			Relation:         "overrides",
+			ContextContainer: impl.Type,
+			Location:         impl.Definition.Location,
+		})
+	}
+	return usages
+}
+
+// FormatImplementations formats the implementations of a method for MCP.
+func FormatImplementations(method DefinitionResult, impls []MethodImplementation) string {
+	var b strings.Builder
+	fmt.Fprintf(&b, "Implementations of %s (%s) at %s\n", method.Name, method.Kind, method.Location)
+	if len(impls) == 0 {
+		b.WriteString("(none)\n")
+	}
+	for _, impl := range impls {
+		sig := impl.Definition.Signature
+		if sig == "" {
+			sig = impl.Definition.Name
+		}
+		fmt.Fprintf(&b, "- %s in %s (%s, depth %d) at %s\n", sig, impl.Type, impl.Relation, impl.Depth, impl.Definition.Location)
+	}
+	return strings.TrimSuffix(b.String(), "\n")
+}
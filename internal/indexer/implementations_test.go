@@ -0,0 +1,121 @@
+package indexer
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+)
+
+// implementationList renders impls as Type(relation,depth).
+func implementationList(impls []MethodImplementation) string {
+	var parts []string
+	for _, impl := range impls {
+		parts = append(parts, fmt.Sprintf("%s(%s,%d)", impl.Type, impl.Relation, impl.Depth))
+	}
+	return strings.Join(parts, " ")
+}
+
+func TestFindImplementations(t *testing.T) {
+	tests := []struct {
+		name string
+		lang string
+		want string
+	}{
+		{"Tile.area", "java", "SquareTile(inherits,2) RoundTile(inherits,2)"},
+		{"Tile.label", "java", "BaseTile(implements,1) SquareTile(inherits,2)"},
+		{"BaseTile.label", "java", "SquareTile(inherits,1)"},
+		{"Toll.toll", "rust", "Carillon(implements,1)"},
+		{"Toll.echo", "rust", ""},
+		{"Hull.displace", "python", "Dinghy(inherits,1) Punt(inherits,3)"},
+		{"Poster.Post", "go", "Journal(implements,1)"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			impls, err := sharedNav.FindImplementations(lookupOne(t, sharedNav, tt.name, "", tt.lang, FilterOverridable), tt.lang)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if got := implementationList(impls); got != tt.want {
+				t.Errorf("implementations = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestFindImplementationsSkipsOverloads(t *testing.T) {
+	impls, err := sharedNav.FindImplementations(lookupOne(t, sharedNav, "BaseTile.label", "", "java", FilterOverridable), "java")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(impls) != 1 || impls[0].Definition.Location.StartLine != 21 {
+		t.Fatalf("implementations = %+v, want SquareTile.label(String) at line 21", impls)
+	}
+
+	usages := OverrideUsages(impls)
+	if len(usages) != 1 || usages[0].Relation != "overrides" || usages[0].ContextContainer != "SquareTile" {
+		t.Fatalf("override usages = %+v", usages)
+	}
+	if got := CouplingScore(usages[0], ""); got != 1.0 {
+		t.Errorf("CouplingScore(override) = %.2f, want 1.0", got)
+	}
+}
+
+func TestFormatImplementations(t *testing.T) {
+	method := lookupOne(t, sharedNav, "Tile.area", "", "java", FilterOverridable)
+	impls, err := sharedNav.FindImplementations(method, "java")
+	if err != nil {
+		t.Fatal(err)
+	}
+	out := FormatImplementations(method, impls)
+	want := "- public double area() in SquareTile (inherits, depth 2) at overrides/Tiles.java:16:18"
+	if !strings.Contains(out, want) {
+		t.Errorf("format missing %q:\n%s", want, out)
+	}
+}
+
+func TestOverridesRefsResolveToOverriddenMethod(t *testing.T) {
+	tests := []struct {
+		name, lang string
+		file       string
+		line       int // the overriding method, marked in the source
+		overridden string
+	}{
+		{"label", "java", "overrides/Tiles.java", 21, "BaseTile.label"},
+		{"toll", "rust", "overrides/bells.rs", 12, "Toll.toll"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			overridden := lookupOne(t, sharedNav, tt.overridden, "", tt.lang, FilterOverridable)
+			usages, err := sharedNav.FindUsagesByName(tt.name, "", tt.lang)
+			if err != nil {
+				t.Fatal(err)
+			}
+			var override *UsageResult
+			for i, u := range usages {
+				if u.Relation == "overrides" && u.Location.Path == tt.file && u.Location.StartLine == tt.line {
+					override = &usages[i]
+				}
+			}
+			if override == nil {
+				t.Fatalf("no overrides ref at %s:%d in %+v", tt.file, tt.line, usages)
+			}
+			if def := override.ResolvedDefinition; def == nil || !sameDefinition(*def, overridden) {
+				t.Fatalf("override resolved to %+v, want %s", override.ResolvedDefinition, tt.overridden)
+			}
+
+			candidates, err := sharedNav.GoToDefinitionByName(tt.name, "", tt.lang)
+			if err != nil {
+				t.Fatal(err)
+			}
+			kept := false
+			for _, ru := range ResolveAndFilterUsages(usages, candidates, &overridden, sharedNav.RepoRoot, NoiseFilterOptions{}) {
+				if ru.Location == override.Location {
+					kept = ru.Relation == "overrides" && CouplingScore(ru.UsageResult, "") == 1.0
+				}
+			}
+			if !kept {
+				t.Errorf("override at %s:%d is not a usage of %s scored 1.0", tt.file, tt.line, tt.overridden)
+			}
+		})
+	}
+}
@@ -19,6 +19,9 @@ import (
 type Indexer struct {
 	Store    *Store
 	RepoRoot string
+
+	resolver     *importResolver
+	resolverOnce sync.Once
 }
 
 // New creates an Indexer for the given DB and repo root.
@@ -47,6 +50,16 @@ var excludedDirs = map[string]bool{
 	".mypy_cache":  true,
 }
 
+// indexFormatVersion identifies what the indexer stores per file. Bump it
+// when extraction or resolution changes so that rows written by an older
+// build are stale; Reconcile then re-indexes every file once.
+const indexFormatVersion = "16"
+
+// indexFormatKey is the meta key holding a project's indexFormatVersion.
+func (idx *Indexer) indexFormatKey() string {
+	return fmt.Sprintf("index_format:%d", idx.Store.ProjectID)
+}
+
 // indexWorkItem holds the data needed to index a single file.
 type indexWorkItem struct {
 	absPath string
@@ -65,11 +78,15 @@ func (idx *Indexer) FullIndex(sourceRoots []string) (*IndexStats, error) {
 	if err := idx.Store.EnsureSourceRoots(sourceRoots); err != nil {
 		return nil, fmt.Errorf("ensure source roots: %w", err)
 	}
+	idx.importer().setSourceRoots(sourceRoots)
 
 	// Wipe existing indexed data
 	if err := idx.Store.DeleteAllFiles(); err != nil {
 		return nil, fmt.Errorf("delete all files: %w", err)
 	}
+	if err := idx.Store.SetMeta(idx.indexFormatKey(), indexFormatVersion); err != nil {
+		return nil, fmt.Errorf("store index format: %w", err)
+	}
 
 	// Phase 1: discover files sequentially
 	var workItems []indexWorkItem
@@ -192,6 +209,10 @@ func (idx *Indexer) FullIndex(sourceRoots []string) (*IndexStats, error) {
 			continue
 		}
 
+		idx.resolveImports(relPath, pr.item.lang, pr.fr)
+		idx.qualifyNames(relPath, pr.item.lang, pr.fr)
+		attachDocs(relPath, pr.item.lang, pr.src, pr.fr)
+		attachInheritingTypes(pr.fr)
 		if err := idx.Store.UpsertFile(relPath, pr.item.lang, pr.sha,
 			pr.item.info.Size(), pr.item.info.ModTime().Unix(), pr.fr); err != nil {
 			stats.Errors++
@@ -213,6 +234,21 @@ func (idx *Indexer) Reconcile(sourceRoots []string) (*IndexStats, error) {
 	if err := idx.Store.EnsureProject(idx.RepoRoot); err != nil {
 		return nil, err
 	}
+	idx.importer().setSourceRoots(sourceRoots)
+
+	// Rows written by an older index format are re-indexed from scratch.
+	format, err := idx.Store.GetMeta(idx.indexFormatKey())
+	if err != nil {
+		return nil, fmt.Errorf("load index format: %w", err)
+	}
+	if format != indexFormatVersion {
+		if err := idx.Store.DeleteAllFiles(); err != nil {
+			return nil, fmt.Errorf("delete all files: %w", err)
+		}
+		if err := idx.Store.SetMeta(idx.indexFormatKey(), indexFormatVersion); err != nil {
+			return nil, fmt.Errorf("store index format: %w", err)
+		}
+	}
 
 	// Get existing file→SHA map from DB
 	existing, err := idx.Store.AllFiles()
@@ -355,6 +391,10 @@ func (idx *Indexer) indexFile(absPath string, lang Lang, info os.FileInfo) (int,
 	if err != nil {
 		return 0, 0, fmt.Errorf("parse %s: %w", relPath, err)
 	}
+	idx.resolveImports(relPath, lang, result)
+	idx.qualifyNames(relPath, lang, result)
+	attachDocs(relPath, lang, src, result)
+	attachInheritingTypes(result)
 
 	if err := idx.Store.UpsertFile(relPath, lang, sha, info.Size(), info.ModTime().Unix(), result); err != nil {
 		return 0, 0, fmt.Errorf("upsert %s: %w", relPath, err)
@@ -262,6 +262,332 @@ func TestIndexSingleFile(t *testing.T) {
 	}
 }
 
+func TestCParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "c", "sample.c"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("c")
+	result, err := parser.Parse("sample.c", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "MAX_RETRIES", symbols.KindConstant)
+	expectSymbol(t, result, "SQUARE", symbols.KindFunction)
+	expectSymbol(t, result, "Person", symbols.KindStruct)
+	expectSymbol(t, result, "Person", symbols.KindTypeAlias)
+	expectSymbol(t, result, "name", symbols.KindField)
+	expectSymbol(t, result, "Color", symbols.KindEnum)
+	expectSymbol(t, result, "COLOR_RED", symbols.KindConstant)
+	expectSymbol(t, result, "default_name", symbols.KindVariable)
+	expectSymbol(t, result, "new_person", symbols.KindFunction)
+	expectSymbol(t, result, "say_hello", symbols.KindFunction)
+
+	expectRef(t, result, "new_person")
+	expectRef(t, result, "say_hello")
+	expectRef(t, result, "MAX_RETRIES")
+	expectRef(t, result, "sample.h")
+}
+
+func TestCppParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "cpp", "sample.cpp"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("cpp")
+	result, err := parser.Parse("sample.cpp", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "shapes", symbols.KindModule)
+	expectSymbol(t, result, "Shape", symbols.KindClass)
+	expectSymbol(t, result, "Circle", symbols.KindClass)
+	expectSymbol(t, result, "Circle", symbols.KindConstructor)
+	expectSymbol(t, result, "area", symbols.KindMethod)
+	expectSymbol(t, result, "radius_", symbols.KindField)
+	expectSymbol(t, result, "Point", symbols.KindStruct)
+	expectSymbol(t, result, "Kind", symbols.KindEnum)
+	expectSymbol(t, result, "Area", symbols.KindTypeAlias)
+	expectSymbol(t, result, "total_area", symbols.KindFunction)
+	expectSymbol(t, result, "print_area", symbols.KindFunction)
+
+	expectRef(t, result, "Shape")
+	expectRef(t, result, "print_area")
+	expectRef(t, result, "total_area")
+
+	foundInherit := false
+	for _, r := range result.Refs {
+		if r.Name == "Shape" && r.Relation == "inherits" {
+			foundInherit = true
+			break
+		}
+	}
+	if !foundInherit {
+		t.Error("expected Circle to record an inherits ref to Shape")
+	}
+}
+
+func TestCppClassInHeader(t *testing.T) {
+	path := filepath.Join(testdataDir(t), "cpp", "gauge.h")
+	src, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := GetParser(DetectLang(path))
+	if parser == nil {
+		t.Fatalf("no parser for %s", path)
+	}
+	result, err := parser.Parse("gauge.h", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "meters", symbols.KindModule)
+	expectSymbol(t, result, "Gauge", symbols.KindClass)
+	expectSymbol(t, result, "read", symbols.KindMethod)
+	expectSymbol(t, result, "level_", symbols.KindField)
+	expectSymbol(t, result, "clamp_level", symbols.KindFunction)
+}
+
+func TestCSharpParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "csharp", "Sample.cs"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("csharp")
+	result, err := parser.Parse("Sample.cs", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "Acme.Billing", symbols.KindModule)
+	expectSymbol(t, result, "IGreeter", symbols.KindInterface)
+	expectSymbol(t, result, "Person", symbols.KindClass)
+	expectSymbol(t, result, "Person", symbols.KindConstructor)
+	expectSymbol(t, result, "Name", symbols.KindProperty)
+	expectSymbol(t, result, "MaxRetries", symbols.KindField)
+	expectSymbol(t, result, "Greet", symbols.KindMethod)
+	expectSymbol(t, result, "Point", symbols.KindStruct)
+	expectSymbol(t, result, "Invoice", symbols.KindClass)
+	expectSymbol(t, result, "Status", symbols.KindEnum)
+	expectSymbol(t, result, "Active", symbols.KindConstant)
+
+	expectRef(t, result, "Greet")
+	expectRef(t, result, "AddAlias")
+
+	relations := map[string]string{}
+	for _, r := range result.Refs {
+		if r.Relation != "" {
+			relations[r.Name] = r.Relation
+		}
+	}
+	if relations["Entity"] != "inherits" {
+		t.Errorf("Entity relation = %q, want inherits", relations["Entity"])
+	}
+	if relations["IGreeter"] != "implements" {
+		t.Errorf("IGreeter relation = %q, want implements", relations["IGreeter"])
+	}
+}
+
+func TestCSharpPartialClassContainer(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "csharp", "Person.Aliases.cs"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("csharp")
+	result, err := parser.Parse("Person.Aliases.cs", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "Person", symbols.KindClass)
+	for _, want := range []string{"Aliases", "AddAlias"} {
+		found := false
+		for _, s := range result.Symbols {
+			if s.Name == want {
+				found = true
+				if s.ContainerName != "Person" {
+					t.Errorf("%s container = %q, want Person", want, s.ContainerName)
+				}
+			}
+		}
+		if !found {
+			t.Errorf("expected symbol %q in partial class part", want)
+		}
+	}
+}
+
+func TestCSharpObjectMembersAreNotBuiltins(t *testing.T) {
+	src := []byte(`class Lease : System.IDisposable {
+    public override string ToString() { return Name.ToString(); }
+    public void Dispose() { Console.WriteLine(ToString()); }
+    void Renew(Lease old) { old.Dispose(); }
+}
+`)
+	parser := NewTreeSitterParser("csharp")
+	result, err := parser.Parse("Lease.cs", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectRefBuiltin(t, result, "ToString", false)
+	expectRefBuiltin(t, result, "Dispose", false)
+	expectRefBuiltin(t, result, "WriteLine", true)
+}
+
+func TestKotlinParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "kotlin", "Shop.kt"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("kotlin")
+	result, err := parser.Parse("Shop.kt", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "Sku", symbols.KindTypeAlias)
+	expectSymbol(t, result, "Priced", symbols.KindClass)
+	expectSymbol(t, result, "Product", symbols.KindClass)
+	expectSymbol(t, result, "CartLine", symbols.KindClass)
+	expectSymbol(t, result, "quantity", symbols.KindProperty)
+	expectSymbol(t, result, "CartRegistry", symbols.KindClass)
+	expectSymbol(t, result, "register", symbols.KindMethod)
+	expectSymbol(t, result, "carts", symbols.KindProperty)
+	expectSymbol(t, result, "free", symbols.KindMethod)
+	expectSymbol(t, result, "Currency", symbols.KindClass)
+	expectSymbol(t, result, "EUR", symbols.KindConstant)
+	expectSymbol(t, result, "describe", symbols.KindFunction)
+
+	containers := map[string]string{}
+	for _, s := range result.Symbols {
+		containers[s.Name] = s.ContainerName
+	}
+	if containers["free"] != "Product" {
+		t.Errorf("companion member free container = %q, want Product", containers["free"])
+	}
+	if containers["subtotal"] != "CartLine" {
+		t.Errorf("extension function subtotal container = %q, want CartLine", containers["subtotal"])
+	}
+	if containers["label"] != "CartLine" {
+		t.Errorf("extension property label container = %q, want CartLine", containers["label"])
+	}
+
+	expectRef(t, result, "Sample")
+	expectRef(t, result, "register")
+}
+
+func TestRubyParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "ruby", "billing.rb"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("ruby")
+	result, err := parser.Parse("billing.rb", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "Billing", symbols.KindModule)
+	expectSymbol(t, result, "TAX_RATE", symbols.KindConstant)
+	expectSymbol(t, result, "Account", symbols.KindClass)
+	expectSymbol(t, result, "SavingsAccount", symbols.KindClass)
+	expectSymbol(t, result, "owner", symbols.KindProperty)
+	expectSymbol(t, result, "balance", symbols.KindProperty)
+	expectSymbol(t, result, "currency", symbols.KindProperty)
+	expectSymbol(t, result, "deposit", symbols.KindMethod)
+	expectSymbol(t, result, "open_for", symbols.KindMethod)
+	expectSymbol(t, result, "accrue_interest", symbols.KindMethod)
+	expectSymbol(t, result, "format_invoice", symbols.KindFunction)
+
+	for _, s := range result.Symbols {
+		if s.Name == "open_for" && s.ContainerName != "Account" {
+			t.Errorf("open_for container = %q, want Account", s.ContainerName)
+		}
+	}
+
+	expectRef(t, result, "deposit")
+	expectRef(t, result, "format_invoice")
+	expectRef(t, result, "json")
+
+	foundInherit := false
+	for _, r := range result.Refs {
+		if r.Name == "puts" && !r.IsBuiltin {
+			t.Error("expected puts to be flagged as builtin")
+		}
+		if r.Name == "Account" && r.Relation == "inherits" {
+			foundInherit = true
+		}
+	}
+	if !foundInherit {
+		t.Error("expected SavingsAccount to record an inherits ref to Account")
+	}
+}
+
+func TestPHPParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "php", "Invoice.php"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("php")
+	result, err := parser.Parse("Invoice.php", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "App\\Billing", symbols.KindModule)
+	expectSymbol(t, result, "Payable", symbols.KindInterface)
+	expectSymbol(t, result, "CURRENCY", symbols.KindConstant)
+	expectSymbol(t, result, "Timestamps", symbols.KindTrait)
+	expectSymbol(t, result, "InvoiceStatus", symbols.KindEnum)
+	expectSymbol(t, result, "Paid", symbols.KindConstant)
+	expectSymbol(t, result, "Invoice", symbols.KindClass)
+	expectSymbol(t, result, "lines", symbols.KindProperty)
+	expectSymbol(t, result, "invoiceNumber", symbols.KindProperty)
+	expectSymbol(t, result, "addLine", symbols.KindMethod)
+	expectSymbol(t, result, "label", symbols.KindMethod)
+	expectSymbol(t, result, "create_invoice", symbols.KindFunction)
+
+	for _, s := range result.Symbols {
+		if s.Name == "Invoice" && s.Kind == symbols.KindClass && s.ContainerName != "App\\Billing" {
+			t.Errorf("Invoice container = %q, want App\\Billing", s.ContainerName)
+		}
+		if s.Name == "addLine" && s.ContainerName != "Invoice" {
+			t.Errorf("addLine container = %q, want Invoice", s.ContainerName)
+		}
+	}
+
+	expectRef(t, result, "Money")
+	expectRef(t, result, "addLine")
+
+	relations := map[string]symbols.Ref{}
+	for _, r := range result.Refs {
+		if r.Relation != "" {
+			relations[r.Name] = r
+		}
+	}
+	for name, want := range map[string]string{
+		"Document":   "inherits",
+		"Payable":    "implements",
+		"Timestamps": "implements",
+	} {
+		r, ok := relations[name]
+		if !ok || r.Relation != want || r.Kind != symbols.RefInherit {
+			t.Errorf("%s ref = %+v, want RefInherit with relation %q", name, r, want)
+		}
+	}
+}
+
 func TestLangDetection(t *testing.T) {
 	tests := []struct {
 		path string
@@ -275,6 +601,20 @@ func TestLangDetection(t *testing.T) {
 		{"app.tsx", LangTypeScript},
 		{"main.js", LangJavaScript},
 		{"main.jsx", LangJavaScript},
+		{"util.c", LangC},
+		{"util.h", LangCPP},
+		{"widget.cpp", LangCPP},
+		{"widget.cc", LangCPP},
+		{"widget.hpp", LangCPP},
+		{"Program.cs", LangCSharp},
+		{"Main.kt", LangKotlin},
+		{"build.gradle.kts", LangKotlin},
+		{"app/models/user.rb", LangRuby},
+		{"lib/tasks/db.rake", LangRuby},
+		{"Gemfile", LangRuby},
+		{"sub/Rakefile", LangRuby},
+		{"src/Invoice.php", LangPHP},
+		{"notebooks/analysis.ipynb", LangPython},
 		{"readme.md", LangUnknown},
 		{"image.png", LangUnknown},
 	}
@@ -15,6 +15,14 @@ const (
 	LangPython     Lang = "python"
 	LangTypeScript Lang = "typescript"
 	LangJavaScript Lang = "javascript"
+	LangC          Lang = "c"
+	LangCPP        Lang = "cpp"
+	LangCSharp     Lang = "csharp"
+	LangKotlin     Lang = "kotlin"
+	LangRuby       Lang = "ruby"
+	LangPHP        Lang = "php"
+	LangProto      Lang = "proto"
+	LangGraphQL    Lang = "graphql"
 	LangUnknown    Lang = ""
 )
 
@@ -33,10 +41,47 @@ var extMap = map[string]Lang{
 	".cjs":  LangJavaScript,
 	".mts":  LangTypeScript,
 	".cts":  LangTypeScript,
+	".c":    LangC,
+	".h":    LangCPP, // shared by C and C++; the C++ grammar also parses C declarations
+	".cc":   LangCPP,
+	".cpp":  LangCPP,
+	".cxx":  LangCPP,
+	".hpp":  LangCPP,
+	".hh":   LangCPP,
+	".hxx":  LangCPP,
+	".cs":   LangCSharp,
+	".kt":   LangKotlin,
+	".kts":  LangKotlin,
+	".rb":   LangRuby,
+	".rake": LangRuby,
+	".php":  LangPHP,
+
+	// Single-file components: only their <script> blocks are indexed (see component.go).
+	".vue":    LangTypeScript,
+	".svelte": LangTypeScript,
+
+	// Jupyter notebooks: code cells are indexed as Python (see notebook.go).
+	".ipynb": LangPython,
+
+	// Schemas whose definitions are linked to generated code (see schema_link.go).
+	".proto":    LangProto,
+	".graphql":  LangGraphQL,
+	".graphqls": LangGraphQL,
+	".gql":      LangGraphQL,
 }
 
-// DetectLang returns the language for a given file path based on extension.
+// fileNameMap maps extension-less file names to languages.
+var fileNameMap = map[string]Lang{
+	"Gemfile":  LangRuby,
+	"Rakefile": LangRuby,
+}
+
+// DetectLang returns the language for a given file path based on extension,
+// falling back to well-known file names such as Gemfile.
 func DetectLang(path string) Lang {
+	if l, ok := fileNameMap[filepath.Base(path)]; ok {
+		return l
+	}
 	ext := strings.ToLower(filepath.Ext(path))
 	if l, ok := extMap[ext]; ok {
 		return l
@@ -52,3 +97,27 @@ func SupportedExtensions() []string {
 	}
 	return exts
 }
+
+// interopLangs groups languages that compile to the same runtime and can
+// reference each other's symbols directly (e.g. Kotlin calling a Java class,
+// or C code calling a function declared in a .h header).
+var interopLangs = map[Lang][]Lang{
+	LangJava:   {LangJava, LangKotlin},
+	LangKotlin: {LangKotlin, LangJava},
+	LangC:      {LangC, LangCPP},
+	LangCPP:    {LangCPP, LangC},
+}
+
+// InteropLangs returns the languages whose definitions are visible from code
+// written in lang, starting with lang itself.
+func InteropLangs(lang string) []string {
+	group, ok := interopLangs[Lang(lang)]
+	if !ok {
+		return []string{lang}
+	}
+	out := make([]string, len(group))
+	for i, l := range group {
+		out[i] = string(l)
+	}
+	return out
+}
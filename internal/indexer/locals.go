@@ -0,0 +1,109 @@
+package indexer
+
+import (
+	"fmt"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// localBindingAt returns the name and declaration of the local variable or
+// parameter referenced or declared at the given position. ok is false when
+// the identifier there is not a local.
+func (n *Navigator) localBindingAt(filePath string, line, col int) (name string, binding Location, ok bool) {
+	var bindingLine, bindingCol int
+	err := n.DB.QueryRow(`
+		SELECT r.name, r.binding_line, r.binding_col
+		FROM refs r
+		JOIN files f ON r.file_id = f.id
+		WHERE f.project_id = ? AND f.path = ? AND r.binding_line > 0
+		  AND r.start_line = ? AND r.start_col <= ? AND r.end_col >= ?
+		LIMIT 1
+	`, n.ProjectID, filePath, line, col, col).Scan(&name, &bindingLine, &bindingCol)
+	if err != nil {
+		// A declaration that is not itself a ref (a Python parameter) is
+		// found through the refs bound to it.
+		err = n.DB.QueryRow(`
+			SELECT r.name, r.binding_line, r.binding_col
+			FROM refs r
+			JOIN files f ON r.file_id = f.id
+			WHERE f.project_id = ? AND f.path = ? AND r.binding_line = ?
+			  AND r.binding_col <= ? AND r.binding_col + length(r.name) >= ?
+			LIMIT 1
+		`, n.ProjectID, filePath, line, col, col).Scan(&name, &bindingLine, &bindingCol)
+	}
+	if err != nil {
+		return "", Location{}, false
+	}
+	binding = Location{
+		Path:      filePath,
+		StartLine: bindingLine,
+		StartCol:  bindingCol,
+		EndLine:   bindingLine,
+		EndCol:    bindingCol + len(name),
+	}
+	newNotebookLocator(n.RepoRoot).annotate(&binding)
+	return name, binding, true
+}
+
+// localDefinition returns the definition of a local declared at binding:
+// the symbol indexed there, when the language indexes locals as symbols,
+// or else a variable spanning the declared name.
+func (n *Navigator) localDefinition(name string, binding Location) DefinitionResult {
+	def := DefinitionResult{
+		Name:     name,
+		Kind:     symbols.KindVariable.String(),
+		Location: binding,
+	}
+	var kindInt int
+	err := n.DB.QueryRow(`
+		SELECT s.kind, s.signature, s.end_line, s.end_col
+		FROM symbols s
+		JOIN files f ON s.file_id = f.id
+		WHERE f.project_id = ? AND f.path = ? AND s.name = ?
+		  AND s.start_line = ? AND s.start_col = ?
+		LIMIT 1
+	`, n.ProjectID, binding.Path, name, binding.StartLine, binding.StartCol).Scan(
+		&kindInt, &def.Signature, &def.Location.EndLine, &def.Location.EndCol)
+	if err == nil {
+		def.Kind = symbols.SymbolKind(kindInt).String()
+	}
+	return def
+}
+
+// localUsages returns the references to the local declared at binding,
+// which all lie in its scope, leaving out the declaration itself.
+func (n *Navigator) localUsages(name string, binding Location) ([]UsageResult, error) {
+	rows, err := n.DB.Query(`
+		SELECT r.name, r.kind, r.context_container, r.relation, r.receiver_type, r.target_type,
+		       r.qualifier, r.import_path, r.resolved_path,
+		       f.path, r.start_line, r.start_col, r.end_line, r.end_col
+		FROM refs r
+		JOIN files f ON r.file_id = f.id
+		WHERE f.project_id = ? AND f.path = ? AND r.name = ?
+		  AND r.binding_line = ? AND r.binding_col = ?
+		  AND NOT (r.start_line = r.binding_line AND r.start_col = r.binding_col)
+		ORDER BY r.start_line ASC, r.start_col ASC
+	`, n.ProjectID, binding.Path, name, binding.StartLine, binding.StartCol)
+	if err != nil {
+		return nil, fmt.Errorf("query local usages: %w", err)
+	}
+	defer func() { _ = rows.Close() }()
+
+	results := []UsageResult{}
+	cells := newNotebookLocator(n.RepoRoot)
+	for rows.Next() {
+		var r UsageResult
+		var kindInt int
+		if err := rows.Scan(&r.Name, &kindInt, &r.ContextContainer,
+			&r.Relation, &r.ReceiverType, &r.TargetType,
+			&r.Qualifier, &r.ImportPath, &r.ResolvedPath,
+			&r.Location.Path, &r.Location.StartLine, &r.Location.StartCol,
+			&r.Location.EndLine, &r.Location.EndCol); err != nil {
+			return nil, err
+		}
+		r.Kind = symbols.RefKind(kindInt).String()
+		cells.annotate(&r.Location)
+		results = append(results, r)
+	}
+	return results, rows.Err()
+}
@@ -0,0 +1,102 @@
+package indexer
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestGoToDefinitionResolvesLocals(t *testing.T) {
+	goFile := filepath.Join("locals", "scope.go")
+	pyFile := filepath.Join("locals", "scope.py")
+	tests := []struct {
+		file, lang        string
+		line, col         int
+		wantLine, wantCol int
+	}{
+		// the parameter shadowing the package-level limit
+		{goFile, "go", 8, 9, 6, 23},
+		{goFile, "go", 7, 12, 6, 23},
+		// the declaration itself
+		{goFile, "go", 6, 23, 6, 23},
+		// a local indexed as a symbol
+		{pyFile, "python", 5, 11, 2, 4},
+	}
+	for _, tt := range tests {
+		defs, err := sharedNav.GoToDefinitionByPosition(tt.file, tt.line, tt.col, tt.lang)
+		if err != nil {
+			t.Fatalf("GoToDefinitionByPosition(%s:%d:%d): %v", tt.file, tt.line, tt.col, err)
+		}
+		if len(defs) != 1 || defs[0].Location.Path != tt.file ||
+			defs[0].Location.StartLine != tt.wantLine || defs[0].Location.StartCol != tt.wantCol {
+			t.Errorf("%s:%d:%d resolved to %+v, want only %d:%d", tt.file, tt.line, tt.col, defs, tt.wantLine, tt.wantCol)
+		}
+	}
+
+	// Outside ClampScore, limit is the package-level var.
+	defs, err := sharedNav.GoToDefinitionByPosition(goFile, 14, 8, "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, d := range defs {
+		if d.Location.Path == goFile && d.Location.StartLine != 4 {
+			t.Errorf("limit in DefaultLimit resolved to %s, want the var at line 4", d.Location)
+		}
+	}
+	if len(defs) == 0 {
+		t.Error("limit in DefaultLimit did not resolve")
+	}
+}
+
+func TestFindUsagesOfLocalsStayInScope(t *testing.T) {
+	goFile := filepath.Join("locals", "scope.go")
+	pyFile := filepath.Join("locals", "scope.py")
+	tests := []struct {
+		file, lang string
+		line, col  int
+		wantLines  []int
+	}{
+		{goFile, "go", 6, 23, []int{7, 8}},
+		{goFile, "go", 10, 8, []int{7, 10}},
+		{pyFile, "python", 2, 4, []int{5}},
+	}
+	for _, tt := range tests {
+		usages, err := sharedNav.FindUsagesByPosition(tt.file, tt.line, tt.col, tt.lang)
+		if err != nil {
+			t.Fatalf("FindUsagesByPosition(%s:%d:%d): %v", tt.file, tt.line, tt.col, err)
+		}
+		var lines []int
+		for _, u := range usages {
+			if u.Location.Path != tt.file {
+				t.Errorf("%s:%d:%d: usage outside the file at %s", tt.file, tt.line, tt.col, u.Location)
+			}
+			lines = append(lines, u.Location.StartLine)
+		}
+		if len(lines) != len(tt.wantLines) {
+			t.Errorf("%s:%d:%d: usages on lines %v, want %v", tt.file, tt.line, tt.col, lines, tt.wantLines)
+			continue
+		}
+		for i := range lines {
+			if lines[i] != tt.wantLines[i] {
+				t.Errorf("%s:%d:%d: usages on lines %v, want %v", tt.file, tt.line, tt.col, lines, tt.wantLines)
+				break
+			}
+		}
+	}
+}
+
+func TestFindUsagesByNameSkipsLocals(t *testing.T) {
+	goFile := filepath.Join("locals", "scope.go")
+	usages, err := sharedNav.FindUsagesByName("limit", "", "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	var lines []int
+	for _, u := range usages {
+		if u.Location.Path == goFile {
+			lines = append(lines, u.Location.StartLine)
+		}
+	}
+	if len(lines) != 1 || lines[0] != 14 {
+		t.Errorf("usages of limit on lines %v, want only the package-level use on line 14", lines)
+	}
+}
@@ -9,6 +9,7 @@ import (
 	"strings"
 	"unicode"
 
+	"github.com/mesdx/cli/internal/sourcefile"
 	"github.com/mesdx/cli/internal/symbols"
 )
 
@@ -19,15 +20,42 @@ type Location struct {
 	StartCol  int    `json:"startCol"`
 	EndLine   int    `json:"endLine"`
 	EndCol    int    `json:"endCol"`
+
+	// Cell is set for locations inside Jupyter notebooks, whose line numbers
+	// refer to the concatenated code cells.
+	Cell *sourcefile.CellLocation `json:"cell,omitempty"`
+}
+
+// String formats the location as path:line:col, adding the notebook cell
+// and line within it when present.
+func (l Location) String() string {
+	s := fmt.Sprintf("%s:%d:%d", l.Path, l.StartLine, l.StartCol)
+	if l.Cell != nil {
+		s += fmt.Sprintf(" (cell %d, line %d)", l.Cell.Index, l.Cell.Line)
+	}
+	return s
 }
 
 // DefinitionResult is the output of a go-to-definition query.
 type DefinitionResult struct {
-	Name      string   `json:"name"`
-	Kind      string   `json:"kind"`
-	Container string   `json:"container,omitempty"`
-	Signature string   `json:"signature,omitempty"`
-	Location  Location `json:"location"`
+	Name          string   `json:"name"`
+	Kind          string   `json:"kind"`
+	Container     string   `json:"container,omitempty"`
+	QualifiedName string   `json:"qualifiedName,omitempty"`
+	Signature     string   `json:"signature,omitempty"`
+	Location      Location `json:"location"`
+
+	// Visibility, Receiver, TypeParams, Params and ReturnType are the
+	// structured parts of the signature (see symbols.Symbol).
+	Visibility string              `json:"visibility,omitempty"`
+	Receiver   string              `json:"receiver,omitempty"`
+	TypeParams []string            `json:"typeParams,omitempty"`
+	Params     []symbols.Parameter `json:"params,omitempty"`
+	ReturnType string              `json:"returnType,omitempty"`
+
+	// Doc is the symbol's doc comment or docstring, returned whether or
+	// not the code is fetched.
+	Doc string `json:"doc,omitempty"`
 }
 
 // UsageResult is the output of a find-usages query.
@@ -38,8 +66,16 @@ type UsageResult struct {
 	Relation         string   `json:"relation,omitempty"`
 	ReceiverType     string   `json:"receiverType,omitempty"`
 	TargetType       string   `json:"targetType,omitempty"`
+	Qualifier        string   `json:"qualifier,omitempty"`
+	ImportPath       string   `json:"importPath,omitempty"`
+	ResolvedPath     string   `json:"resolvedPath,omitempty"`
 	Location         Location `json:"location"`
 	DependencyScore  float64  `json:"dependencyScore,omitempty"`
+
+	// ResolvedDefinition is the definition the usage's receiver type or
+	// import resolution names, when it names exactly one, or the method an
+	// overrides ref overrides.
+	ResolvedDefinition *DefinitionResult `json:"resolvedDefinition,omitempty"`
 }
 
 // Navigator provides go-to-definition and find-usages queries.
@@ -51,43 +87,114 @@ type Navigator struct {
 
 // GoToDefinitionByName finds symbol definitions matching the given name.
 // An optional filterFile (repo-relative path) ranks results from that file higher.
-// The lang parameter filters results to files of the specified language.
+// The lang parameter filters results to files of the specified language, plus
+// any languages it interoperates with (Java and Kotlin search each other);
+// definitions in lang itself rank first. When a match lives in generated code,
+// the schema definitions it was generated from are appended.
+// A qualified or partially qualified name (Store.Save, catalog::Store::save)
+// that is not itself a symbol name returns the definitions whose qualified
+// name ends with it.
 func (n *Navigator) GoToDefinitionByName(name string, filterFile string, lang string) ([]DefinitionResult, error) {
+	results, err := n.definitionsByName(name, filterFile, lang)
+	bare, qualifier := SplitQualifiedName(name)
+	if err != nil || len(results) > 0 || qualifier == "" {
+		return results, err
+	}
+	all, err := n.definitionsByName(bare, filterFile, lang)
+	if err != nil {
+		return nil, err
+	}
+	return filterQualified(all, name), nil
+}
+
+// definitionsByName returns the definitions of a bare name, as described
+// for GoToDefinitionByName.
+func (n *Navigator) definitionsByName(name string, filterFile string, lang string) ([]DefinitionResult, error) {
+	langs := InteropLangs(lang)
+	placeholders := strings.TrimSuffix(strings.Repeat("?,", len(langs)), ",")
 	query := `
-		SELECT s.name, s.kind, s.container_name, s.signature,
-		       f.path, s.start_line, s.start_col, s.end_line, s.end_col
+		SELECT ` + definitionColumns + `
 		FROM symbols s
 		JOIN files f ON s.file_id = f.id
-		WHERE f.project_id = ? AND s.name = ? AND f.lang = ?
+		WHERE f.project_id = ? AND s.name = ? AND f.lang IN (` + placeholders + `)
 		ORDER BY
 			CASE WHEN f.path = ? THEN 0 ELSE 1 END,
+			CASE WHEN f.lang = ? THEN 0 ELSE 1 END,
 			s.kind ASC
 	`
-	rows, err := n.DB.Query(query, n.ProjectID, name, lang, filterFile)
+	args := []interface{}{n.ProjectID, name}
+	for _, l := range langs {
+		args = append(args, l)
+	}
+	args = append(args, filterFile, lang)
+	rows, err := n.DB.Query(query, args...)
 	if err != nil {
 		return nil, fmt.Errorf("query definitions: %w", err)
 	}
 	defer func() { _ = rows.Close() }()
 
 	results := []DefinitionResult{}
+	cells := newNotebookLocator(n.RepoRoot)
 	for rows.Next() {
-		var r DefinitionResult
-		var kindInt int
-		if err := rows.Scan(&r.Name, &kindInt, &r.Container, &r.Signature,
-			&r.Location.Path, &r.Location.StartLine, &r.Location.StartCol,
-			&r.Location.EndLine, &r.Location.EndCol); err != nil {
+		r, err := scanDefinition(rows)
+		if err != nil {
 			return nil, err
 		}
-		r.Kind = symbols.SymbolKind(kindInt).String()
+		cells.annotate(&r.Location)
 		results = append(results, r)
 	}
-	return results, rows.Err()
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+
+	// A generated symbol (e.g. a type in a .pb.go file) also resolves to the
+	// .proto or .graphql declaration it was generated from.
+	if !IsSchemaLang(lang) && hasGeneratedDefinition(results) {
+		schemaDefs, err := n.SchemaDefinitionsFor(name)
+		if err != nil {
+			return nil, err
+		}
+		results = append(results, schemaDefs...)
+	}
+	return results, nil
+}
+
+// definitionColumns lists the columns scanDefinition reads, from a query
+// joining symbols s with files f.
+const definitionColumns = `s.name, s.kind, s.container_name, s.qualified_name, s.signature,
+		       s.visibility, s.receiver, s.type_params, s.params, s.return_type, s.doc,
+		       f.path, s.start_line, s.start_col, s.end_line, s.end_col`
+
+// scanDefinition reads a row starting with definitionColumns; extra holds
+// the destinations of the columns selected after them.
+func scanDefinition(rows *sql.Rows, extra ...interface{}) (DefinitionResult, error) {
+	var r DefinitionResult
+	var kindInt int
+	var typeParams, params string
+	dest := []interface{}{&r.Name, &kindInt, &r.Container, &r.QualifiedName, &r.Signature,
+		&r.Visibility, &r.Receiver, &typeParams, &params, &r.ReturnType, &r.Doc,
+		&r.Location.Path, &r.Location.StartLine, &r.Location.StartCol,
+		&r.Location.EndLine, &r.Location.EndCol}
+	if err := rows.Scan(append(dest, extra...)...); err != nil {
+		return r, err
+	}
+	if err := decodeSignatureLists(&r, typeParams, params); err != nil {
+		return r, err
+	}
+	r.Kind = symbols.SymbolKind(kindInt).String()
+	return r, nil
 }
 
 // GoToDefinitionByPosition resolves the identifier at the given cursor position,
-// then looks up its definition.
+// then looks up its definition. A local variable or parameter resolves to
+// its declaration in the enclosing function.
 // The lang parameter filters results to files of the specified language.
 func (n *Navigator) GoToDefinitionByPosition(filePath string, line, col int, lang string) ([]DefinitionResult, error) {
+	// A local variable or parameter resolves to its declaration in scope.
+	if name, binding, ok := n.localBindingAt(filePath, line, col); ok {
+		return []DefinitionResult{n.localDefinition(name, binding)}, nil
+	}
+
 	// First, find the symbol/ref name at the cursor position.
 	name, err := n.identifierAt(filePath, line, col)
 	if err != nil {
@@ -96,18 +203,103 @@ func (n *Navigator) GoToDefinitionByPosition(filePath string, line, col int, lan
 	if name == "" {
 		return nil, fmt.Errorf("no identifier found at %s:%d:%d", filePath, line, col)
 	}
-	return n.GoToDefinitionByName(name, filePath, lang)
+	results, err := n.GoToDefinitionByName(name, filePath, lang)
+	if err != nil {
+		return nil, err
+	}
+
+	// A ref resolved through its receiver type or imports jumps straight to
+	// its definition.
+	if ref := n.refAt(filePath, line, col); ref != nil {
+		if matches, ok := refResolution(*ref, results); ok && len(matches) > 0 {
+			exact := make([]DefinitionResult, 0, len(matches))
+			for _, i := range matches {
+				exact = append(exact, results[i])
+			}
+			return exact, nil
+		}
+	}
+	return results, nil
+}
+
+// refAt returns the stored ref covering the given position, or nil.
+func (n *Navigator) refAt(filePath string, line, col int) *UsageResult {
+	var r UsageResult
+	var kindInt int
+	err := n.DB.QueryRow(`
+		SELECT r.name, r.kind, r.receiver_type, r.qualifier, r.import_path, r.resolved_path,
+		       f.path, r.start_line, r.start_col, r.end_line, r.end_col
+		FROM refs r
+		JOIN files f ON r.file_id = f.id
+		WHERE f.project_id = ? AND f.path = ?
+		  AND r.start_line = ? AND r.start_col <= ? AND r.end_col >= ?
+		LIMIT 1
+	`, n.ProjectID, filePath, line, col, col).Scan(&r.Name, &kindInt,
+		&r.ReceiverType, &r.Qualifier, &r.ImportPath, &r.ResolvedPath,
+		&r.Location.Path, &r.Location.StartLine, &r.Location.StartCol,
+		&r.Location.EndLine, &r.Location.EndCol)
+	if err != nil {
+		return nil
+	}
+	r.Kind = symbols.RefKind(kindInt).String()
+	return &r
 }
 
 // FindUsagesByName finds all references to the given name across the project.
+// References to local variables and parameters are left out. A usage whose
+// receiver type or import resolution names a single definition carries it
+// as its ResolvedDefinition, and so does an overriding method marked in the
+// source (@Override, override, a Rust trait impl), with the relation
+// "overrides", for the method it overrides.
 // The lang parameter filters results to files of the specified language.
+// A qualified or partially qualified name keeps the references attributed
+// to the definitions it names (see GoToDefinitionByName).
 func (n *Navigator) FindUsagesByName(name string, filterFile string, lang string) ([]UsageResult, error) {
+	results, err := n.usagesByName(name, filterFile, lang)
+	bare, qualifier := SplitQualifiedName(name)
+	if err != nil || len(results) > 0 || qualifier == "" {
+		return results, err
+	}
+	return n.qualifiedUsages(bare, name, filterFile, lang)
+}
+
+// qualifiedUsages returns the usages of bare attributed to the definitions
+// whose qualified name ends with qualified: by their import resolution, or
+// else by heuristic scoring against every definition of bare.
+func (n *Navigator) qualifiedUsages(bare, qualified, filterFile, lang string) ([]UsageResult, error) {
+	usages, err := n.usagesByName(bare, filterFile, lang)
+	if err != nil || len(usages) == 0 {
+		return usages, err
+	}
+	defs, err := n.definitionsByName(bare, "", lang)
+	if err != nil {
+		return nil, err
+	}
+	switch targets := filterQualified(defs, qualified); len(targets) {
+	case 0:
+		return []UsageResult{}, nil
+	case len(defs):
+		return usages, nil
+	}
+	kept := []UsageResult{}
+	for _, su := range ScoreUsages(usages, defs, nil, n.RepoRoot) {
+		if su.BestDefinition != nil && matchesQualifiedName(*su.BestDefinition, qualified) {
+			kept = append(kept, su.UsageResult)
+		}
+	}
+	return kept, nil
+}
+
+// usagesByName returns the references named name, as described for
+// FindUsagesByName.
+func (n *Navigator) usagesByName(name string, filterFile string, lang string) ([]UsageResult, error) {
 	query := `
 		SELECT r.name, r.kind, r.context_container, r.relation, r.receiver_type, r.target_type,
+		       r.qualifier, r.import_path, r.resolved_path,
 		       f.path, r.start_line, r.start_col, r.end_line, r.end_col
 		FROM refs r
 		JOIN files f ON r.file_id = f.id
-		WHERE f.project_id = ? AND r.name = ? AND f.lang = ?
+		WHERE f.project_id = ? AND r.name = ? AND f.lang = ? AND r.binding_line = 0
 		ORDER BY
 			CASE WHEN f.path = ? THEN 0 ELSE 1 END,
 			f.path ASC, r.start_line ASC
@@ -119,25 +311,119 @@ func (n *Navigator) FindUsagesByName(name string, filterFile string, lang string
 	defer func() { _ = rows.Close() }()
 
 	results := []UsageResult{}
+	cells := newNotebookLocator(n.RepoRoot)
 	for rows.Next() {
 		var r UsageResult
 		var kindInt int
 		if err := rows.Scan(&r.Name, &kindInt, &r.ContextContainer,
 			&r.Relation, &r.ReceiverType, &r.TargetType,
+			&r.Qualifier, &r.ImportPath, &r.ResolvedPath,
 			&r.Location.Path, &r.Location.StartLine, &r.Location.StartCol,
 			&r.Location.EndLine, &r.Location.EndCol); err != nil {
 			return nil, err
 		}
 		r.Kind = symbols.RefKind(kindInt).String()
+		cells.annotate(&r.Location)
 		results = append(results, r)
 	}
-	return results, rows.Err()
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+	return results, n.attachResolvedDefinitions(name, lang, results)
+}
+
+// attachResolvedDefinitions sets the ResolvedDefinition of the usages whose
+// receiver type or import resolution names a single definition of name,
+// and of the overrides refs to the method they override.
+func (n *Navigator) attachResolvedDefinitions(name, lang string, usages []UsageResult) error {
+	var defs []DefinitionResult
+	for i := range usages {
+		u := &usages[i]
+		overrides := u.Relation == "overrides"
+		if !overrides && u.ImportPath == "" && u.ResolvedPath == "" && u.ReceiverType == "" {
+			continue
+		}
+		if defs == nil {
+			var err error
+			if defs, err = n.definitionsByName(name, "", lang); err != nil {
+				return err
+			}
+		}
+		if overrides {
+			def, err := n.overriddenMethod(*u, defs, lang)
+			if err != nil {
+				return err
+			}
+			u.ResolvedDefinition = def
+			continue
+		}
+		if matches, ok := refResolution(*u, defs); ok && len(matches) == 1 {
+			def := defs[matches[0]]
+			u.ResolvedDefinition = &def
+		}
+	}
+	return nil
+}
+
+// qualifiedNameSeparators maps the scope separators of the supported
+// languages to the one used for matching.
+var qualifiedNameSeparators = strings.NewReplacer("::", ".", "\\", ".", "#", ".")
+
+// SplitQualifiedName splits a qualified or partially qualified symbol name
+// (pkg.Type.Method, module::Type::method, Type#method) into the name of the
+// symbol and the qualifier before it, which is "" for a bare name.
+func SplitQualifiedName(name string) (bare, qualifier string) {
+	i := strings.LastIndexAny(name, ".:\\#")
+	if i <= 0 || i == len(name)-1 {
+		return name, ""
+	}
+	return name[i+1:], strings.TrimRight(name[:i], ":")
+}
+
+// matchesQualifiedName reports whether the qualified name of def ends with
+// the segments of qualified, whichever scope separators either uses.
+// Definitions without a stored qualified name are qualified by their
+// container.
+func matchesQualifiedName(def DefinitionResult, qualified string) bool {
+	have := def.QualifiedName
+	if have == "" {
+		have = def.Name
+		if def.Container != "" {
+			have = def.Container + "." + def.Name
+		}
+	}
+	have = qualifiedNameSeparators.Replace(have)
+	want := qualifiedNameSeparators.Replace(qualified)
+	if have == want {
+		return true
+	}
+	if !strings.HasSuffix(have, want) {
+		return false
+	}
+	boundary := have[len(have)-len(want)-1]
+	return boundary == '.' || boundary == '/'
+}
+
+// filterQualified returns the definitions whose qualified name ends with
+// qualified.
+func filterQualified(defs []DefinitionResult, qualified string) []DefinitionResult {
+	out := []DefinitionResult{}
+	for _, d := range defs {
+		if matchesQualifiedName(d, qualified) {
+			out = append(out, d)
+		}
+	}
+	return out
 }
 
 // FindUsagesByPosition resolves the identifier at the given cursor position,
-// then looks up its usages.
+// then looks up its usages. The usages of a local variable or parameter
+// are those within its scope.
 // The lang parameter filters results to files of the specified language.
 func (n *Navigator) FindUsagesByPosition(filePath string, line, col int, lang string) ([]UsageResult, error) {
+	if name, binding, ok := n.localBindingAt(filePath, line, col); ok {
+		return n.localUsages(name, binding)
+	}
 	name, err := n.identifierAt(filePath, line, col)
 	if err != nil {
 		return nil, err
@@ -149,15 +435,17 @@ func (n *Navigator) FindUsagesByPosition(filePath string, line, col int, lang st
 }
 
 // RefsInFileRange returns all references in the given file within the
-// specified line range [startLine, endLine] (1-based, inclusive).
+// specified line range [startLine, endLine] (1-based, inclusive), other
+// than references to local variables and parameters.
 func (n *Navigator) RefsInFileRange(filePath string, startLine, endLine int, lang string) ([]UsageResult, error) {
 	query := `
-		SELECT r.name, r.kind, r.context_container,
+		SELECT r.name, r.kind, r.context_container, r.receiver_type,
+		       r.qualifier, r.import_path, r.resolved_path,
 		       f.path, r.start_line, r.start_col, r.end_line, r.end_col
 		FROM refs r
 		JOIN files f ON r.file_id = f.id
 		WHERE f.project_id = ? AND f.path = ? AND f.lang = ?
-		  AND r.start_line >= ? AND r.start_line <= ?
+		  AND r.start_line >= ? AND r.start_line <= ? AND r.binding_line = 0
 		ORDER BY r.start_line ASC, r.start_col ASC
 	`
 	rows, err := n.DB.Query(query, n.ProjectID, filePath, lang, startLine, endLine)
@@ -167,15 +455,18 @@ func (n *Navigator) RefsInFileRange(filePath string, startLine, endLine int, lan
 	defer func() { _ = rows.Close() }()
 
 	results := []UsageResult{}
+	cells := newNotebookLocator(n.RepoRoot)
 	for rows.Next() {
 		var r UsageResult
 		var kindInt int
-		if err := rows.Scan(&r.Name, &kindInt, &r.ContextContainer,
+		if err := rows.Scan(&r.Name, &kindInt, &r.ContextContainer, &r.ReceiverType,
+			&r.Qualifier, &r.ImportPath, &r.ResolvedPath,
 			&r.Location.Path, &r.Location.StartLine, &r.Location.StartCol,
 			&r.Location.EndLine, &r.Location.EndCol); err != nil {
 			return nil, err
 		}
 		r.Kind = symbols.RefKind(kindInt).String()
+		cells.annotate(&r.Location)
 		results = append(results, r)
 	}
 	return results, rows.Err()
@@ -229,6 +520,14 @@ func extractWordAtPosition(absPath string, line, col int) (string, error) {
 		return "", nil
 	}
 
+	if sourcefile.IsNotebookFile(absPath) {
+		lines, err := ReadSourceLines(absPath)
+		if err != nil || line > len(lines) {
+			return "", nil
+		}
+		return identifierAtCol(lines[line-1], col), nil
+	}
+
 	f, err := os.Open(absPath)
 	if err != nil {
 		return "", nil
@@ -283,14 +582,20 @@ func FormatDefinitions(results []DefinitionResult) string {
 		if i > 0 {
 			b.WriteString("\n")
 		}
-		fmt.Fprintf(&b, "[%d] %s (%s)", i+1, r.Name, r.Kind)
+		fmt.Fprintf(&b, "[%d] %s (%s)", i+1, r.Name, kindAndVisibility(r))
 		if r.Container != "" {
 			fmt.Fprintf(&b, " in %s", r.Container)
 		}
-		fmt.Fprintf(&b, "\n    %s:%d:%d", r.Location.Path, r.Location.StartLine, r.Location.StartCol)
+		if r.QualifiedName != "" {
+			fmt.Fprintf(&b, "\n    %s", r.QualifiedName)
+		}
+		fmt.Fprintf(&b, "\n    %s", r.Location)
 		if r.Signature != "" {
 			fmt.Fprintf(&b, "\n    %s", r.Signature)
 		}
+		if r.Doc != "" {
+			fmt.Fprintf(&b, "\n    %s", docSummary(r.Doc))
+		}
 	}
 	return b.String()
 }
@@ -306,14 +611,20 @@ func FormatRankedDefinitions(ranked []RankedDefinition) string {
 		if i > 0 {
 			b.WriteString("\n")
 		}
-		fmt.Fprintf(&b, "[%d] %s (%s) conf=%.2f", i+1, r.Name, r.Kind, r.Confidence)
+		fmt.Fprintf(&b, "[%d] %s (%s) conf=%.2f", i+1, r.Name, kindAndVisibility(r.DefinitionResult), r.Confidence)
 		if r.Container != "" {
 			fmt.Fprintf(&b, " in %s", r.Container)
 		}
-		fmt.Fprintf(&b, "\n    %s:%d:%d", r.Location.Path, r.Location.StartLine, r.Location.StartCol)
+		if r.QualifiedName != "" {
+			fmt.Fprintf(&b, "\n    %s", r.QualifiedName)
+		}
+		fmt.Fprintf(&b, "\n    %s", r.Location)
 		if r.Signature != "" {
 			fmt.Fprintf(&b, "\n    %s", r.Signature)
 		}
+		if r.Doc != "" {
+			fmt.Fprintf(&b, "\n    %s", docSummary(r.Doc))
+		}
 	}
 	return b.String()
 }
@@ -333,7 +644,7 @@ func FormatUsages(results []UsageResult) string {
 		if r.ContextContainer != "" {
 			fmt.Fprintf(&b, " (in %s)", r.ContextContainer)
 		}
-		fmt.Fprintf(&b, "\n    %s:%d:%d", r.Location.Path, r.Location.StartLine, r.Location.StartCol)
+		fmt.Fprintf(&b, "\n    %s", r.Location)
 		if r.DependencyScore > 0 {
 			fmt.Fprintf(&b, "\n    score: %.4f", r.DependencyScore)
 		}
@@ -355,7 +666,7 @@ func FormatScoredUsages(results []ScoredUsage) string {
 		if r.ContextContainer != "" {
 			fmt.Fprintf(&b, " (in %s)", r.ContextContainer)
 		}
-		fmt.Fprintf(&b, "\n    %s:%d:%d", r.Location.Path, r.Location.StartLine, r.Location.StartCol)
+		fmt.Fprintf(&b, "\n    %s", r.Location)
 		fmt.Fprintf(&b, "\n    score: %.4f", r.DependencyScore)
 		if r.BestDefinition != nil {
 			fmt.Fprintf(&b, " → %s (%s) at %s:%d",
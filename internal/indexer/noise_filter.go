@@ -73,6 +73,19 @@ func kindPriority(kind string) float64 {
 	}
 }
 
+// visibilityBonus returns a confidence adjustment from a definition's
+// visibility: exported definitions are the ones callers usually mean, and
+// private ones are only reachable from their own type or file.
+func visibilityBonus(visibility string) float64 {
+	switch visibility {
+	case "public":
+		return 0.05
+	case "private":
+		return -0.05
+	}
+	return 0
+}
+
 // isTypeKind reports whether kind is a type-level definition (class, struct, …).
 func isTypeKind(kind string) bool {
 	switch kind {
@@ -151,7 +164,7 @@ func isNoisyPath(relPath string) bool {
 	// Check each directory segment.
 	segments := strings.Split(lower, "/")
 	for _, seg := range segments {
-		if noisySegments[seg] {
+		if noisySegments[seg] && !isSchemaSourceDir(lower, seg) {
 			return true
 		}
 	}
@@ -189,7 +202,7 @@ func noisePenalty(relPath string) float64 {
 
 	// Test directories and generated code.
 	for _, seg := range []string{"testdata", "test", "tests", "__tests__", "spec", "specs", "generated", "gen", "vendor", "pb", "proto"} {
-		if containsSegment(lower, seg) {
+		if containsSegment(lower, seg) && !isSchemaSourceDir(lower, seg) {
 			return 0.35
 		}
 	}
@@ -205,6 +218,12 @@ func noisePenalty(relPath string) float64 {
 	return 0.0
 }
 
+// isSchemaSourceDir reports whether seg is a proto/ directory holding the
+// .proto or .graphql schema itself rather than code generated from it.
+func isSchemaSourceDir(lowerPath, seg string) bool {
+	return seg == "proto" && isSchemaFile(lowerPath)
+}
+
 // containsSegment returns true if any path segment equals seg.
 func containsSegment(lowerPath, seg string) bool {
 	for _, s := range strings.Split(lowerPath, "/") {
@@ -237,14 +256,32 @@ func localityBonus(candidatePath, filterFile string) float64 {
 // Core: RankDefinitions
 // ---------------------------------------------------------------------------
 
+// definitionConfidence scores a candidate definition as described for
+// RankDefinitions (steps 1-5).
+func definitionConfidence(d DefinitionResult, filterFile string) float64 {
+	conf := 0.5 // balanced base
+	conf += kindPriority(d.Kind)
+	conf += localityBonus(d.Location.Path, filterFile)
+	conf += visibilityBonus(d.Visibility)
+	conf -= noisePenalty(d.Location.Path)
+	if conf < 0 {
+		conf = 0
+	}
+	if conf > 1 {
+		conf = 1
+	}
+	return round4(conf)
+}
+
 // RankDefinitions scores and (optionally) filters a set of candidate
 // definitions for the given name, returning them in descending confidence order.
 //
 // The algorithm is language-agnostic:
 //  1. Assign a base confidence from symbol kind.
 //  2. Add a locality bonus if the candidate is near filterFile.
-//  3. Subtract a noise penalty for migration / test / generated paths.
-//  4. Clamp to [0, 1].
+//  3. Prefer exported over private candidates.
+//  4. Subtract a noise penalty for migration / test / generated paths.
+//  5. Clamp to [0, 1].
 //
 // After scoring, if IncludeNoise is false:
 //   - Value-kind candidates are dropped when any type-kind candidate exists.
@@ -258,19 +295,9 @@ func RankDefinitions(defs []DefinitionResult, filterFile string, opts NoiseFilte
 
 	ranked := make([]RankedDefinition, len(defs))
 	for i, d := range defs {
-		conf := 0.5 // balanced base
-		conf += kindPriority(d.Kind)
-		conf += localityBonus(d.Location.Path, filterFile)
-		conf -= noisePenalty(d.Location.Path)
-		if conf < 0 {
-			conf = 0
-		}
-		if conf > 1 {
-			conf = 1
-		}
 		ranked[i] = RankedDefinition{
 			DefinitionResult: d,
-			Confidence:       round4(conf),
+			Confidence:       definitionConfidence(d, filterFile),
 		}
 	}
 
@@ -341,7 +368,9 @@ type ScoredUsageResolved struct {
 // resolution confidence, and then — unless IncludeNoise is true — filters out
 // usages that are likely from a different same-name symbol.
 //
-// Two complementary filters are applied:
+// Usages resolved through their receiver type or imports (see refResolution)
+// to another definition, to another package or outside the repo are always
+// dropped. Two complementary heuristic filters are then applied:
 //
 //  1. Path-noise filter: if the primary definition lives in a non-noisy path
 //     (e.g. models/) and a usage comes from a noisy path (migrations/, tests/
@@ -383,6 +412,22 @@ func ResolveAndFilterUsages(
 		return resolved
 	}
 
+	// ---------- Filter 0: receiver and import resolution ----------
+	// A usage resolved through its receiver type to another type's member,
+	// or through its imports to another package or to code outside the
+	// repo, cannot refer to primaryDef however few candidates there are.
+	// This is exact, so it has no never-empty safeguard.
+	if primaryDef != nil {
+		cands := withDefinition(candidates, *primaryDef)
+		filtered := make([]ScoredUsageResolved, 0, len(resolved))
+		for _, r := range resolved {
+			if !resolvedAway(r.UsageResult, cands, *primaryDef) {
+				filtered = append(filtered, r)
+			}
+		}
+		resolved = filtered
+	}
+
 	// Determine whether the primary definition is itself in a "clean" path.
 	// If it is noisy we cannot reliably filter usage paths by context.
 	primaryIsClean := primaryDef != nil && !isNoisyPath(primaryDef.Location.Path)
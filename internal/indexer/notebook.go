@@ -0,0 +1,67 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/mesdx/cli/internal/sourcefile"
+)
+
+// ReadSourceLines returns the lines of a file as the extractor saw them:
+// the synthetic source for notebooks and the file contents otherwise, so
+// stored line numbers can be used to index the result directly.
+func ReadSourceLines(absPath string) ([]string, error) {
+	data, err := os.ReadFile(absPath)
+	if err != nil {
+		return nil, err
+	}
+	return sourceLines(absPath, data)
+}
+
+// sourceLines returns the lines of the contents of the file at path as the
+// extractor saw them, as described for ReadSourceLines.
+func sourceLines(path string, data []byte) ([]string, error) {
+	if sourcefile.IsNotebookFile(path) {
+		var err error
+		data, _, err = sourcefile.NotebookSource(data)
+		if err != nil {
+			return nil, err
+		}
+	}
+	text := strings.TrimSuffix(strings.ReplaceAll(string(data), "\r\n", "\n"), "\n")
+	if text == "" {
+		return nil, nil
+	}
+	return strings.Split(text, "\n"), nil
+}
+
+// notebookLocator annotates locations inside notebooks with their cell,
+// caching each notebook's cell map for the lifetime of one query.
+type notebookLocator struct {
+	repoRoot string
+	cells    map[string][]sourcefile.NotebookCell
+}
+
+func newNotebookLocator(repoRoot string) *notebookLocator {
+	return &notebookLocator{repoRoot: repoRoot, cells: map[string][]sourcefile.NotebookCell{}}
+}
+
+// annotate sets loc.Cell when loc points into a notebook.
+func (nl *notebookLocator) annotate(loc *Location) {
+	if !sourcefile.IsNotebookFile(loc.Path) {
+		return
+	}
+	cells, ok := nl.cells[loc.Path]
+	if !ok {
+		absPath := loc.Path
+		if !filepath.IsAbs(absPath) && nl.repoRoot != "" {
+			absPath = filepath.Join(nl.repoRoot, absPath)
+		}
+		if data, err := os.ReadFile(absPath); err == nil {
+			_, cells, _ = sourcefile.NotebookSource(data)
+		}
+		nl.cells[loc.Path] = cells
+	}
+	loc.Cell = sourcefile.NotebookCellAt(cells, loc.StartLine)
+}
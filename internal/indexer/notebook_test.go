@@ -0,0 +1,65 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+func TestNotebookParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "notebooks", "exploration.ipynb"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	parser := NewTreeSitterParser("python")
+	result, err := parser.Parse("exploration.ipynb", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "shout_names", symbols.KindFunction)
+	expectRef(t, result, "format_name")
+
+	if _, err := parser.Parse("broken.ipynb", []byte("{not json")); err == nil {
+		t.Error("expected an error for malformed notebook JSON")
+	}
+}
+
+func TestFindUsagesInNotebook(t *testing.T) {
+	usages, err := sharedNav.FindUsagesByName("format_name", "", "python")
+	if err != nil {
+		t.Fatalf("FindUsagesByName: %v", err)
+	}
+
+	nbPath := filepath.Join("notebooks", "exploration.ipynb")
+	var inNotebook []UsageResult
+	for _, u := range usages {
+		if u.Location.Path == nbPath {
+			inNotebook = append(inNotebook, u)
+		}
+	}
+	if len(inNotebook) == 0 {
+		t.Fatal("expected usages of format_name in the notebook")
+	}
+
+	found := false
+	for _, u := range inNotebook {
+		if u.Location.Cell == nil {
+			t.Errorf("usage at line %d has no cell location", u.Location.StartLine)
+			continue
+		}
+		if u.Location.Cell.Index == 3 && u.Location.Cell.Line == 2 {
+			found = true
+			if !strings.Contains(u.Location.String(), "(cell 3, line 2)") {
+				t.Errorf("Location.String() = %q, want cell annotation", u.Location.String())
+			}
+		}
+	}
+	if !found {
+		t.Errorf("expected a usage in cell 3, line 2; got %+v", inNotebook)
+	}
+}
@@ -1,6 +1,8 @@
 package indexer
 
 import (
+	"sync"
+
 	"github.com/mesdx/cli/internal/symbols"
 )
 
@@ -10,8 +12,12 @@ type Parser interface {
 	Parse(filename string, src []byte) (*symbols.FileResult, error)
 }
 
-// parserRegistry maps languages to their parser implementations.
-var parserRegistry = map[Lang]Parser{}
+// parserRegistry maps languages to their parser implementations. It is
+// rebuilt when repository-local queries change (see query_override.go).
+var (
+	parserRegistry   = map[Lang]Parser{}
+	parserRegistryMu sync.RWMutex
+)
 
 func init() {
 	// Use tree-sitter parsers for all languages
@@ -21,9 +27,21 @@ func init() {
 	parserRegistry[LangPython] = NewTreeSitterParser("python")
 	parserRegistry[LangTypeScript] = NewTreeSitterParser("typescript")
 	parserRegistry[LangJavaScript] = NewTreeSitterParser("javascript")
+	parserRegistry[LangC] = NewTreeSitterParser("c")
+	parserRegistry[LangCPP] = NewTreeSitterParser("cpp")
+	parserRegistry[LangCSharp] = NewTreeSitterParser("csharp")
+	parserRegistry[LangKotlin] = NewTreeSitterParser("kotlin")
+	parserRegistry[LangRuby] = NewTreeSitterParser("ruby")
+	parserRegistry[LangPHP] = NewTreeSitterParser("php")
+
+	// Schema languages use a token scanner (see schema.go)
+	parserRegistry[LangProto] = ProtoParser{}
+	parserRegistry[LangGraphQL] = GraphQLParser{}
 }
 
 // GetParser returns the parser for the given language, or nil if unsupported.
 func GetParser(lang Lang) Parser {
+	parserRegistryMu.RLock()
+	defer parserRegistryMu.RUnlock()
 	return parserRegistry[lang]
 }
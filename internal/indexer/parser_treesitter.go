@@ -4,6 +4,7 @@ import (
 	"fmt"
 	"sync"
 
+	"github.com/mesdx/cli/internal/sourcefile"
 	"github.com/mesdx/cli/internal/symbols"
 	"github.com/mesdx/cli/internal/treesitter"
 )
@@ -11,9 +12,15 @@ import (
 // TreeSitterParser uses tree-sitter for parsing.
 type TreeSitterParser struct {
 	langName  string
+	queryDir  string
 	extractor *treesitter.Extractor
 	once      sync.Once
 	initErr   error
+
+	// mu is held for reading by the parses in progress, which Close waits
+	// for.
+	mu     sync.RWMutex
+	closed bool
 }
 
 // NewTreeSitterParser creates a new tree-sitter parser for the given language.
@@ -23,16 +30,66 @@ func NewTreeSitterParser(langName string) *TreeSitterParser {
 	}
 }
 
+// NewTreeSitterParserWithQueryDir creates a tree-sitter parser whose query
+// is extended or replaced by <queryDir>/<lang>.scm.
+func NewTreeSitterParserWithQueryDir(langName, queryDir string) *TreeSitterParser {
+	return &TreeSitterParser{
+		langName: langName,
+		queryDir: queryDir,
+	}
+}
+
 // Parse parses the source using tree-sitter.
 func (p *TreeSitterParser) Parse(filename string, src []byte) (*symbols.FileResult, error) {
+	p.mu.RLock()
+	defer p.mu.RUnlock()
+	if p.closed {
+		// Replaced (by ApplyQueryOverrides) while the caller held it: parse
+		// with an extractor of its own.
+		extractor, err := treesitter.NewExtractorWithQueryDir(p.langName, p.queryDir)
+		if err != nil {
+			return nil, fmt.Errorf("tree-sitter parser init for %s: %w", p.langName, err)
+		}
+		defer extractor.Close()
+		return extractSource(extractor, filename, src)
+	}
+
 	// Lazy initialization
 	p.once.Do(func() {
-		p.extractor, p.initErr = treesitter.NewExtractor(p.langName)
+		p.extractor, p.initErr = treesitter.NewExtractorWithQueryDir(p.langName, p.queryDir)
 	})
 
 	if p.initErr != nil {
 		return nil, fmt.Errorf("tree-sitter parser init for %s: %w", p.langName, p.initErr)
 	}
+	return extractSource(p.extractor, filename, src)
+}
 
-	return p.extractor.Extract(filename, src)
+// Close releases the extractor of the parser once the parses in progress
+// are done.
+func (p *TreeSitterParser) Close() {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if p.extractor != nil {
+		p.extractor.Close()
+		p.extractor = nil
+	}
+	p.closed = true
+}
+
+// extractSource extracts the symbols and refs of a source file, reduced to
+// its scripts for a Vue or Svelte component and to its code cells for a
+// notebook.
+func extractSource(extractor *treesitter.Extractor, filename string, src []byte) (*symbols.FileResult, error) {
+	if sourcefile.IsComponentFile(filename) {
+		src = sourcefile.MaskNonScript(src)
+	}
+	if sourcefile.IsNotebookFile(filename) {
+		nbSrc, _, err := sourcefile.NotebookSource(src)
+		if err != nil {
+			return nil, err
+		}
+		src = nbSrc
+	}
+	return extractor.Extract(filename, src)
 }
@@ -0,0 +1,73 @@
+package indexer
+
+import (
+	"path/filepath"
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// qualifyNames prefixes the qualified names the parser gives symbols
+// (Type.Method, relative to the file) with the package or module the file
+// belongs to:
+//
+//	Go                github.com/acme/shop/catalog.Store.Save
+//	Rust              shop::catalog::Store::save
+//	Python            shop.catalog.Store.save
+//	JavaScript, TS    src/catalog/store.Store.save (the file, without extension)
+//
+// Java, Kotlin, C#, C++ and PHP names are qualified by the parser with their
+// package or namespace. Symbols of other languages keep their
+// container-qualified name.
+func (idx *Indexer) qualifyNames(relPath string, lang Lang, fr *symbols.FileResult) {
+	scope, sep := idx.importer().fileScope(relPath, lang)
+	if scope == "" {
+		return
+	}
+	for i := range fr.Symbols {
+		s := &fr.Symbols[i]
+		if s.QualifiedName == "" {
+			s.QualifiedName = s.Name
+		}
+		s.QualifiedName = scope + sep + s.QualifiedName
+	}
+}
+
+// fileScope returns the package or module path of a repo-relative file and
+// the separator that joins it to a name, or "" when it has none.
+func (r *importResolver) fileScope(relPath string, lang Lang) (string, string) {
+	switch lang {
+	case LangGo:
+		dir := filepath.Dir(relPath)
+		m := r.goModuleFor(dir)
+		if m == nil {
+			if dir == "." {
+				return "", "" // a root-level file outside any module
+			}
+			return filepath.ToSlash(dir), "."
+		}
+		rel, err := filepath.Rel(m.dir, dir)
+		if err != nil || rel == "." {
+			return m.path, "."
+		}
+		return m.path + "/" + filepath.ToSlash(rel), "."
+	case LangRust:
+		mod := r.rustModuleOf(relPath)
+		if mod == nil {
+			return "", ""
+		}
+		crate := mod.crate.name
+		if crate == "" {
+			crate = "crate"
+		}
+		return strings.Join(append([]string{crate}, mod.path...), "::"), "::"
+	case LangPython:
+		if filepath.Ext(relPath) != ".py" {
+			return "", "" // notebook cells
+		}
+		return r.pythonFilePath(relPath), "."
+	case LangJavaScript, LangTypeScript:
+		return filepath.ToSlash(strings.TrimSuffix(relPath, filepath.Ext(relPath))), "."
+	}
+	return "", ""
+}
@@ -0,0 +1,90 @@
+package indexer
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestSplitQualifiedName(t *testing.T) {
+	tests := []struct {
+		name, bare, qualifier string
+	}{
+		{"Save", "Save", ""},
+		{"Store.Save", "Save", "Store"},
+		{"example.com/shop/catalog.Store.Save", "Save", "example.com/shop/catalog.Store"},
+		{"catalog::Store::save", "save", "catalog::Store"},
+		{"App\\Billing\\Invoice", "Invoice", "App\\Billing"},
+		{"Order#total", "total", "Order"},
+	}
+	for _, tt := range tests {
+		bare, qualifier := SplitQualifiedName(tt.name)
+		if bare != tt.bare || qualifier != tt.qualifier {
+			t.Errorf("SplitQualifiedName(%q) = %q, %q, want %q, %q", tt.name, bare, qualifier, tt.bare, tt.qualifier)
+		}
+	}
+}
+
+func TestFileScopeOutsideGoModule(t *testing.T) {
+	r := newImportResolver(t.TempDir())
+	if scope, _ := r.fileScope("main.go", LangGo); scope != "" {
+		t.Errorf("root-level scope = %q, want none", scope)
+	}
+	if scope, sep := r.fileScope(filepath.Join("tools", "gen.go"), LangGo); scope != "tools" || sep != "." {
+		t.Errorf("tools/gen.go scope = %q %q, want tools .", scope, sep)
+	}
+}
+
+func TestGoToDefinitionByQualifiedName(t *testing.T) {
+	tests := []struct {
+		name, lang    string
+		wantPath      string
+		wantQualified string
+	}{
+		{"catalog.Catalog.Add", "go", filepath.Join("gomod", "catalog", "catalog.go"), "example.com/shop/catalog.Catalog.Add"},
+		{"Invoice.Add", "go", filepath.Join("gomod", "billing", "billing.go"), "example.com/shop/billing.Invoice.Add"},
+		{"example.com/shop/billing.New", "go", filepath.Join("gomod", "billing", "billing.go"), "example.com/shop/billing.New"},
+		{"config::Config::new", "rust", filepath.Join("rustws", "crates", "settings", "src", "config.rs"), "shop_settings::config::Config::new"},
+		{"Cart.render", "python", fixturePath(pyimportsRoot, "src", "shop", "cart.py"), "shop.cart.Cart.render"},
+		{"shop.catalog.render", "python", fixturePath(pyimportsRoot, "src", "shop", "catalog.py"), "shop.catalog.render"},
+		{"shapes/create.create", "typescript", fixturePath(jsmodulesRoot, "src", "lib", "shapes", "create.ts"), "jsmodules/src/lib/shapes/create.create"},
+	}
+	for _, tt := range tests {
+		defs, err := sharedNav.GoToDefinitionByName(tt.name, "", tt.lang)
+		if err != nil {
+			t.Fatalf("GoToDefinitionByName(%s): %v", tt.name, err)
+		}
+		if len(defs) != 1 || defs[0].Location.Path != tt.wantPath || defs[0].QualifiedName != tt.wantQualified {
+			t.Errorf("%s resolved to %+v, want exactly %s in %s", tt.name, defs, tt.wantQualified, tt.wantPath)
+		}
+	}
+
+	if defs, err := sharedNav.GoToDefinitionByName("Catalog.Missing", "", "go"); err != nil || len(defs) != 0 {
+		t.Errorf("Catalog.Missing resolved to %+v (%v), want nothing", defs, err)
+	}
+}
+
+func TestFindUsagesByQualifiedName(t *testing.T) {
+	mainFile := fixturePath(pyimportsRoot, "main.py")
+	usages, err := sharedNav.FindUsagesByName("shop.catalog.render", "", "python")
+	if err != nil {
+		t.Fatal(err)
+	}
+	kept := false
+	for _, u := range usages {
+		if u.Name != "render" {
+			t.Errorf("usage of %s returned for shop.catalog.render", u.Name)
+		}
+		if u.Location.Path != mainFile {
+			continue
+		}
+		switch u.Location.StartLine {
+		case 8:
+			kept = true
+		case 9:
+			t.Errorf("usage of helpers.render at line 9 returned for shop.catalog.render")
+		}
+	}
+	if !kept {
+		t.Errorf("usage of shop.catalog.render at line 8 missing")
+	}
+}
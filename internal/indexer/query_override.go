@@ -0,0 +1,110 @@
+package indexer
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/mesdx/cli/internal/treesitter"
+)
+
+// QueryOverrideDirName is the directory under .mesdx holding
+// repository-local tree-sitter queries, one <lang>.scm per language.
+const QueryOverrideDirName = "queries"
+
+// QueryOverrideDir returns the query override directory for a .mesdx dir.
+func QueryOverrideDir(mesdxDir string) string {
+	return filepath.Join(mesdxDir, QueryOverrideDirName)
+}
+
+// ApplyQueryOverrides rebuilds the tree-sitter parsers so they pick up the
+// queries in queryDir. A language whose override is invalid keeps its
+// embedded query; the returned errors (one per invalid file, with line and
+// column) are meant to be reported to the user.
+//
+// Languages whose override changed since the last run have their files
+// dropped from the index, so the next Reconcile re-extracts them. The
+// affected languages are returned.
+func (idx *Indexer) ApplyQueryOverrides(queryDir string) ([]Lang, []error) {
+	var errs []error
+
+	parserRegistryMu.Lock()
+	langs := make([]Lang, 0, len(parserRegistry))
+	var replaced []*TreeSitterParser
+	for lang, p := range parserRegistry {
+		tp, ok := p.(*TreeSitterParser)
+		if !ok {
+			continue
+		}
+		dir := queryDir
+		if err := treesitter.ValidateUserQuery(queryDir, tp.langName); err != nil {
+			errs = append(errs, err)
+			dir = ""
+		}
+		parserRegistry[lang] = NewTreeSitterParserWithQueryDir(tp.langName, dir)
+		replaced = append(replaced, tp)
+		langs = append(langs, lang)
+	}
+	parserRegistryMu.Unlock()
+	for _, tp := range replaced {
+		tp.Close()
+	}
+
+	errs = append(errs, unknownQueryFiles(queryDir)...)
+
+	sort.Slice(langs, func(i, j int) bool { return langs[i] < langs[j] })
+	var changed []Lang
+	for _, lang := range langs {
+		fingerprint := ""
+		if tp, ok := GetParser(lang).(*TreeSitterParser); ok && tp.queryDir != "" {
+			fingerprint = treesitter.UserQueryFingerprint(queryDir, tp.langName)
+		}
+		key := fmt.Sprintf("query_override:%d:%s", idx.Store.ProjectID, lang)
+		prev, err := idx.Store.GetMeta(key)
+		if err != nil {
+			errs = append(errs, fmt.Errorf("read query fingerprint for %s: %w", lang, err))
+			continue
+		}
+		if prev == fingerprint {
+			continue
+		}
+		if err := idx.Store.DeleteFilesByLang(lang); err != nil {
+			errs = append(errs, fmt.Errorf("invalidate %s files: %w", lang, err))
+			continue
+		}
+		if err := idx.Store.SetMeta(key, fingerprint); err != nil {
+			errs = append(errs, fmt.Errorf("store query fingerprint for %s: %w", lang, err))
+			continue
+		}
+		changed = append(changed, lang)
+	}
+	return changed, errs
+}
+
+// unknownQueryFiles reports .scm files in queryDir that do not name a
+// tree-sitter language, which would otherwise be silently ignored.
+func unknownQueryFiles(queryDir string) []error {
+	if queryDir == "" {
+		return nil
+	}
+	entries, err := os.ReadDir(queryDir)
+	if err != nil {
+		return nil
+	}
+	known := map[string]bool{}
+	for _, name := range treesitter.RequiredLanguages() {
+		known[name] = true
+	}
+	var errs []error
+	for _, e := range entries {
+		name, ok := strings.CutSuffix(e.Name(), ".scm")
+		if e.IsDir() || !ok || known[name] {
+			continue
+		}
+		errs = append(errs, fmt.Errorf("%s: no tree-sitter language named %q",
+			filepath.Join(queryDir, e.Name()), name))
+	}
+	return errs
+}
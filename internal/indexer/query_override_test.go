@@ -0,0 +1,112 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestApplyQueryOverridesReindexesChangedLanguage(t *testing.T) {
+	store, cleanup := setupTestDB(t)
+	defer cleanup()
+
+	repoRoot := t.TempDir()
+	src := "package main\n\nfunc run() {\nretry:\n\tfor {\n\t\tbreak retry\n\t}\n}\n"
+	if err := os.WriteFile(filepath.Join(repoRoot, "main.go"), []byte(src), 0644); err != nil {
+		t.Fatal(err)
+	}
+	queryDir := filepath.Join(repoRoot, ".mesdx", QueryOverrideDirName)
+	if err := os.MkdirAll(queryDir, 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	idx := &Indexer{Store: store, RepoRoot: repoRoot}
+	if err := store.EnsureProject(repoRoot); err != nil {
+		t.Fatal(err)
+	}
+	// Restore the embedded queries for the other tests in this package.
+	defer idx.ApplyQueryOverrides("")
+
+	if changed, errs := idx.ApplyQueryOverrides(queryDir); len(changed) != 0 || len(errs) != 0 {
+		t.Fatalf("empty query dir: changed=%v errs=%v", changed, errs)
+	}
+	if _, err := idx.Reconcile([]string{"."}); err != nil {
+		t.Fatal(err)
+	}
+	countRetry := func() int {
+		var n int
+		if err := store.DB.QueryRow(`SELECT COUNT(*) FROM symbols WHERE name = 'retry'`).Scan(&n); err != nil {
+			t.Fatal(err)
+		}
+		return n
+	}
+	if n := countRetry(); n != 0 {
+		t.Fatalf("retry indexed before the override: %d", n)
+	}
+
+	query := "(labeled_statement label: (label_name) @def.const)\n"
+	if err := os.WriteFile(filepath.Join(queryDir, "go.scm"), []byte(query), 0644); err != nil {
+		t.Fatal(err)
+	}
+	changed, errs := idx.ApplyQueryOverrides(queryDir)
+	if len(errs) != 0 || len(changed) != 1 || changed[0] != LangGo {
+		t.Fatalf("after adding go.scm: changed=%v errs=%v", changed, errs)
+	}
+	if _, err := idx.Reconcile([]string{"."}); err != nil {
+		t.Fatal(err)
+	}
+	if n := countRetry(); n != 1 {
+		t.Errorf("expected retry to be indexed after the override, got %d", n)
+	}
+
+	// Unchanged overrides do not invalidate anything.
+	if changed, _ := idx.ApplyQueryOverrides(queryDir); len(changed) != 0 {
+		t.Errorf("unchanged overrides reported changes: %v", changed)
+	}
+
+	// An invalid override is reported and the embedded query is used again.
+	if err := os.WriteFile(filepath.Join(queryDir, "go.scm"), []byte("(labeled_statement"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(queryDir, "cobol.scm"), []byte("(x) @ref.call"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	changed, errs = idx.ApplyQueryOverrides(queryDir)
+	if len(errs) != 2 {
+		t.Errorf("expected errors for go.scm and cobol.scm, got %v", errs)
+	}
+	if len(changed) != 1 || changed[0] != LangGo {
+		t.Errorf("expected go to be re-indexed with the embedded query, got %v", changed)
+	}
+}
+
+func TestApplyQueryOverridesClosesReplacedParsers(t *testing.T) {
+	store, cleanup := setupTestDB(t)
+	defer cleanup()
+	idx := &Indexer{Store: store, RepoRoot: t.TempDir()}
+	if err := store.EnsureProject(idx.RepoRoot); err != nil {
+		t.Fatal(err)
+	}
+
+	old, ok := GetParser(LangGo).(*TreeSitterParser)
+	if !ok {
+		t.Fatal("go parser is not a tree-sitter parser")
+	}
+	if _, err := old.Parse("a.go", []byte("package a\n")); err != nil {
+		t.Fatal(err)
+	}
+	idx.ApplyQueryOverrides("")
+	if !old.closed || old.extractor != nil {
+		t.Fatal("replaced parser still holds its extractor")
+	}
+
+	// A caller still holding the replaced parser parses with an extractor
+	// of its own.
+	fr, err := old.Parse("a.go", []byte("package a\n\nfunc Run() {}\n"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(fr.Symbols) == 0 {
+		t.Error("no symbols parsed by the replaced parser")
+	}
+}
@@ -0,0 +1,85 @@
+package indexer
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestFindUsagesByNameResolvesReceiverTypes(t *testing.T) {
+	goFile := filepath.Join("receivers", "repos.go")
+	pyFile := filepath.Join("receivers", "repos.py")
+	tests := []struct {
+		name, lang, file string
+		wantLines        []int
+	}{
+		{"UserRepo.Archive", "go", goFile, []int{21, 25}},
+		{"AuditRepo.Archive", "go", goFile, []int{18}},
+		{"UserRepo.archive", "python", pyFile, []int{18}},
+		{"AuditRepo.archive", "python", pyFile, []int{17}},
+	}
+	for _, tt := range tests {
+		usages, err := sharedNav.FindUsagesByName(tt.name, "", tt.lang)
+		if err != nil {
+			t.Fatalf("FindUsagesByName(%s): %v", tt.name, err)
+		}
+		var lines []int
+		for _, u := range usages {
+			if u.Location.Path == tt.file {
+				lines = append(lines, u.Location.StartLine)
+			}
+		}
+		if len(lines) != len(tt.wantLines) {
+			t.Errorf("%s: usages on lines %v, want %v", tt.name, lines, tt.wantLines)
+			continue
+		}
+		for i := range lines {
+			if lines[i] != tt.wantLines[i] {
+				t.Errorf("%s: usages on lines %v, want %v", tt.name, lines, tt.wantLines)
+				break
+			}
+		}
+	}
+}
+
+func TestScoreUsagesUsesReceiverType(t *testing.T) {
+	goFile := filepath.Join("receivers", "repos.go")
+	usages, err := sharedNav.FindUsagesByName("Archive", "", "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defs, err := sharedNav.GoToDefinitionByName("Archive", "", "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	var userArchive *DefinitionResult
+	for i := range defs {
+		if matchesQualifiedName(defs[i], "UserRepo.Archive") {
+			userArchive = &defs[i]
+		}
+	}
+	if userArchive == nil {
+		t.Fatalf("no UserRepo.Archive among %+v", defs)
+	}
+
+	want := map[int]float64{18: 0, 21: 1, 25: 1}
+	for _, su := range ScoreUsages(usages, defs, userArchive, sharedRepoRoot) {
+		if su.Location.Path != goFile {
+			continue
+		}
+		if score, ok := want[su.Location.StartLine]; !ok || su.DependencyScore != score {
+			t.Errorf("usage at line %d scored %.4f, want %v", su.Location.StartLine, su.DependencyScore, score)
+		}
+		if su.ResolvedDefinition == nil {
+			t.Errorf("usage at line %d has no resolved definition", su.Location.StartLine)
+		}
+	}
+
+	// Cursor-based goToDefinition on repo.Archive() jumps to UserRepo.Archive.
+	got, err := sharedNav.GoToDefinitionByPosition(goFile, 25, 13, "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(got) != 1 || got[0].Location.StartLine != 6 {
+		t.Errorf("repo.Archive resolved to %+v, want only UserRepo.Archive at line 6", got)
+	}
+}
@@ -0,0 +1,320 @@
+package indexer
+
+import (
+	"path/filepath"
+	"strings"
+	"sync"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// importResolver resolves references through the import declarations of a
+// file, recording on each ref the import it goes through and the
+// repo-relative package directory or file that defines its target.
+// Repo-level inputs (go.mod, Cargo.toml and tsconfig.json files, Go package
+// names, the items of Rust, Python and JS/TS modules) are cached for the
+// lifetime of the Indexer and refreshed when they change on disk.
+type importResolver struct {
+	repoRoot string
+
+	mu            sync.Mutex
+	sourceRoots   []string                    // repo-relative configured source roots
+	goMods        map[string]*goModule        // by repo-relative dir holding go.mod
+	pkgNames      map[string]string           // Go package dir -> package clause name
+	rustCrates    map[string]*rustCrate       // by repo-relative dir holding Cargo.toml
+	rustModules   map[string]*rustFileItems   // by repo-relative .rs file
+	pythonModules map[string]*pythonFileItems // by repo-relative .py file
+	jsModules     map[string]*jsFileItems     // by repo-relative JS/TS file
+	tsConfigs     map[string]*tsConfig        // by repo-relative tsconfig.json
+}
+
+func newImportResolver(repoRoot string) *importResolver {
+	return &importResolver{
+		repoRoot:      repoRoot,
+		goMods:        map[string]*goModule{},
+		pkgNames:      map[string]string{},
+		rustCrates:    map[string]*rustCrate{},
+		rustModules:   map[string]*rustFileItems{},
+		pythonModules: map[string]*pythonFileItems{},
+		jsModules:     map[string]*jsFileItems{},
+		tsConfigs:     map[string]*tsConfig{},
+	}
+}
+
+// importer returns the Indexer's import resolver, creating it on first use.
+func (idx *Indexer) importer() *importResolver {
+	idx.resolverOnce.Do(func() {
+		idx.resolver = newImportResolver(idx.RepoRoot)
+	})
+	return idx.resolver
+}
+
+// setSourceRoots records the configured source roots, which Python
+// absolute imports are looked up in.
+func (r *importResolver) setSourceRoots(roots []string) {
+	rel := make([]string, 0, len(roots))
+	for _, root := range roots {
+		if filepath.IsAbs(root) {
+			var err error
+			if root, err = filepath.Rel(r.repoRoot, root); err != nil {
+				continue
+			}
+		}
+		rel = append(rel, filepath.Clean(root))
+	}
+	r.mu.Lock()
+	r.sourceRoots = rel
+	r.mu.Unlock()
+}
+
+// resolveImports annotates the refs of a freshly parsed file with their
+// import resolution. relPath is repo-relative.
+func (idx *Indexer) resolveImports(relPath string, lang Lang, fr *symbols.FileResult) {
+	r := idx.importer()
+	switch lang {
+	case LangGo:
+		r.resolveGo(relPath, fr)
+	case LangRust:
+		r.resolveRust(relPath, fr)
+	case LangJava:
+		r.resolveJava(relPath, fr)
+	case LangPython:
+		r.resolvePython(relPath, fr)
+	case LangJavaScript, LangTypeScript:
+		r.resolveJS(relPath, fr)
+	}
+	// An overriding method names the method it overrides, which is found
+	// through the type hierarchy when queried, not through the imports.
+	for i := range fr.Refs {
+		if ref := &fr.Refs[i]; ref.Relation == "overrides" {
+			ref.ImportPath, ref.ResolvedPath, ref.IsExternal = "", "", false
+		}
+	}
+}
+
+// isMemberAccess reports whether a ref is written after a dot, as the
+// attribute or member of a value whose qualifier was not recorded
+// (f().name).
+func isMemberAccess(lines []string, ref *symbols.Ref) bool {
+	if ref.StartLine < 1 || ref.StartLine > len(lines) {
+		return false
+	}
+	line := lines[ref.StartLine-1]
+	if ref.StartCol > len(line) {
+		return false
+	}
+	before := strings.TrimRight(line[:ref.StartCol], " \t")
+	return strings.HasSuffix(before, ".")
+}
+
+// inResolvedPath reports whether a definition in defPath lies in the package
+// directory or file a ref resolved to.
+func inResolvedPath(defPath, resolvedPath string) bool {
+	return defPath == resolvedPath || filepath.Dir(defPath) == resolvedPath
+}
+
+// isPackageLevel reports whether a definition can be reached through a
+// package or module qualifier (pkg.Name), i.e. it is not a member.
+func isPackageLevel(def DefinitionResult) bool {
+	if def.Container != "" {
+		return false
+	}
+	switch def.Kind {
+	case "field", "method", "property", "constructor":
+		return false
+	}
+	return true
+}
+
+// importResolution applies a usage's import resolution to a candidate list.
+// ok is false when the usage carries no resolution that decides between
+// these candidates, in which case heuristic scoring applies. Otherwise
+// matches holds the indices of the candidates the usage resolves to; it is
+// empty when the usage points outside the repo or to another package.
+// Definitions carrying a qualified name (Java) are matched on that name
+// rather than on their location. A resolution through another module's
+// re-exports that points at a file no longer defining the name is stale
+// (the module changed after the usage's file was indexed) and decides
+// nothing.
+func importResolution(usage UsageResult, candidates []DefinitionResult) (matches []int, ok bool) {
+	if usage.ResolvedPath == "" && usage.ImportPath == "" {
+		return nil, false
+	}
+	qualified := usage.Qualifier != ""
+	usageLang := DetectLang(usage.Location.Path)
+	container := importedContainer(usage.ImportPath)
+	target := qualifiedTarget(usage, usageLang)
+	sameLang, hasMember, byName := false, false, false
+	var memberMatches []int
+	inResolved := false
+	for i, def := range candidates {
+		if DetectLang(def.Location.Path) != usageLang {
+			continue // e.g. a schema declaration reached through generated code
+		}
+		sameLang = true
+		inPath := usage.ResolvedPath != "" && inResolvedPath(def.Location.Path, usage.ResolvedPath)
+		inResolved = inResolved || inPath
+		if target != "" && def.QualifiedName != "" {
+			byName = true
+			if def.QualifiedName == target {
+				matches = append(matches, i)
+			}
+			continue
+		}
+		if !isPackageLevel(def) {
+			hasMember = true
+			if inPath && container != "" && def.Container == container {
+				memberMatches = append(memberMatches, i)
+			}
+			continue
+		}
+		if inPath {
+			matches = append(matches, i)
+		}
+	}
+	if !sameLang {
+		return nil, false
+	}
+	if usage.ResolvedPath != "" && !inResolved && resolvedThroughModules(usageLang) {
+		return nil, false
+	}
+	if byName {
+		return matches, true
+	}
+	if len(memberMatches) > 0 {
+		return memberMatches, true // Type::member
+	}
+	// A bare name may refer to a member, except in Python and JS/TS, where
+	// members are only reached through an attribute or this; and a
+	// qualifier that names a repo package lacking the name is most likely a
+	// local variable shadowing the import (store := store.New(); store.Save()).
+	bareMember := !qualified && usageLang != LangPython &&
+		usageLang != LangJavaScript && usageLang != LangTypeScript
+	if hasMember && (bareMember || (len(matches) == 0 && usage.ResolvedPath != "")) {
+		return nil, false
+	}
+	return matches, true
+}
+
+// resolvedThroughModules reports whether lang's import resolutions follow
+// re-exports through other modules, so that they can go stale when only
+// those modules change.
+func resolvedThroughModules(lang Lang) bool {
+	switch lang {
+	case LangRust, LangPython, LangJavaScript, LangTypeScript:
+		return true
+	}
+	return false
+}
+
+// receiverResolution applies the inferred type of a usage's receiver to a
+// candidate list, as importResolution does its imports: x.Save() where x is
+// a UserRepo resolves to the Save members of UserRepo. ok is false when the
+// receiver's type is unknown or declares none of the candidates (the member
+// is inherited, embedded or defined outside the repo).
+func receiverResolution(usage UsageResult, candidates []DefinitionResult) (matches []int, ok bool) {
+	if usage.ReceiverType == "" {
+		return nil, false
+	}
+	usageLang := DetectLang(usage.Location.Path)
+	member := usage.ReceiverType + "." + usage.Name
+	for i, def := range candidates {
+		if DetectLang(def.Location.Path) != usageLang || isPackageLevel(def) {
+			continue
+		}
+		if matchesQualifiedName(def, member) {
+			matches = append(matches, i)
+		}
+	}
+	return matches, len(matches) > 0
+}
+
+// refResolution applies a usage's receiver type, or else its import
+// resolution, to a candidate list, as described for importResolution.
+func refResolution(usage UsageResult, candidates []DefinitionResult) (matches []int, ok bool) {
+	if matches, ok := receiverResolution(usage, candidates); ok {
+		return matches, true
+	}
+	return importResolution(usage, candidates)
+}
+
+// qualifiedTarget returns the fully qualified name a usage resolves to, for
+// languages whose definitions are matched by qualified name, or "".
+func qualifiedTarget(usage UsageResult, lang Lang) string {
+	if lang == LangJava && usage.ImportPath != "" {
+		return usage.ImportPath + "." + usage.Name
+	}
+	return ""
+}
+
+// importedContainer returns the type a Type::member ref goes through, i.e.
+// the last segment of a Rust-style import path, or "".
+func importedContainer(importPath string) string {
+	if i := strings.LastIndex(importPath, "::"); i >= 0 {
+		return importPath[i+2:]
+	}
+	return ""
+}
+
+// containsDefinition reports whether def is among candidates[indices].
+func containsDefinition(candidates []DefinitionResult, indices []int, def DefinitionResult) bool {
+	for _, i := range indices {
+		if sameDefinition(candidates[i], def) {
+			return true
+		}
+	}
+	return false
+}
+
+// withDefinition returns candidates with def appended unless already present.
+func withDefinition(candidates []DefinitionResult, def DefinitionResult) []DefinitionResult {
+	for _, c := range candidates {
+		if sameDefinition(c, def) {
+			return candidates
+		}
+	}
+	return append(append([]DefinitionResult{}, candidates...), def)
+}
+
+// dropUsagesResolvedElsewhere removes usages whose receiver type or import
+// resolution rules out primaryDef.
+func dropUsagesResolvedElsewhere(usages []UsageResult, candidates []DefinitionResult, primaryDef DefinitionResult) []UsageResult {
+	cands := withDefinition(candidates, primaryDef)
+	kept := make([]UsageResult, 0, len(usages))
+	for _, u := range usages {
+		if !resolvedAway(u, cands, primaryDef) {
+			kept = append(kept, u)
+		}
+	}
+	return kept
+}
+
+// resolvedAway reports whether a usage's receiver type or import
+// resolution rules out primaryDef. candidates must contain primaryDef.
+func resolvedAway(usage UsageResult, candidates []DefinitionResult, primaryDef DefinitionResult) bool {
+	if DetectLang(usage.Location.Path) != DetectLang(primaryDef.Location.Path) {
+		return false
+	}
+	matches, ok := refResolution(usage, candidates)
+	return ok && !containsDefinition(candidates, matches, primaryDef)
+}
+
+// resolveOutbound resolves the refs named name through their receiver types
+// and imports. ok is false when none of them carries a deciding resolution;
+// a nil definition with ok means every such ref points outside the repo or
+// to another package (fmt.Println next to a repo function Println).
+func resolveOutbound(refs []UsageResult, name string, defs []DefinitionResult) (*DefinitionResult, bool) {
+	decided := false
+	for _, r := range refs {
+		if r.Name != name {
+			continue
+		}
+		if matches, ok := refResolution(r, defs); ok {
+			if len(matches) > 0 {
+				return &defs[matches[0]], true
+			}
+			decided = true
+		}
+	}
+	return nil, decided
+}
@@ -0,0 +1,289 @@
+package indexer
+
+import (
+	"bufio"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// Go refs resolve through the file's import block: a qualified ref pkg.Func
+// goes through the import whose local name is pkg, and the import path maps
+// to a repo-relative package directory through the nearest go.mod (its
+// module path and local replace directives). Bare refs resolve to the
+// file's own package directory unless the file has a dot import.
+
+// goModule is a parsed go.mod file inside the repo.
+type goModule struct {
+	path     string            // module path
+	dir      string            // repo-relative directory holding go.mod
+	replaces map[string]string // module path -> repo-relative dir, local replaces only
+	modTime  time.Time
+}
+
+// goMajorVersionRe matches a module major-version path element ("v2").
+var goMajorVersionRe = regexp.MustCompile(`^v[0-9]+$`)
+
+// goPkgDotVersionRe matches a gopkg.in style version suffix (".v3").
+var goPkgDotVersionRe = regexp.MustCompile(`\.v[0-9]+$`)
+
+// goImportTarget is an import as seen from one file.
+type goImportTarget struct {
+	path string // import path
+	dir  string // repo-relative package dir, "" outside the repo
+}
+
+// resolveGo records the import path and package directory of every ref in
+// a Go file.
+func (r *importResolver) resolveGo(relPath string, fr *symbols.FileResult) {
+	ownDir := filepath.Dir(relPath)
+	for _, s := range fr.Symbols {
+		if s.Kind == symbols.KindPackage {
+			r.setGoPackageName(ownDir, s.Name)
+			break
+		}
+	}
+
+	mod := r.goModuleFor(ownDir)
+	if mod == nil {
+		return // outside a module, import paths cannot be mapped to directories
+	}
+	byName := map[string]goImportTarget{}
+	byLine := map[int]goImportTarget{}
+	dotImport := false
+	for _, imp := range fr.Imports {
+		t := goImportTarget{path: imp.Path, dir: mod.importDir(imp.Path)}
+		byLine[imp.Line] = t
+
+		switch imp.Alias {
+		case "_":
+		case ".":
+			dotImport = true
+		case "":
+			name := ""
+			if t.dir != "" {
+				name = r.goPackageName(t.dir)
+			}
+			if name == "" {
+				name = defaultGoImportName(imp.Path)
+			}
+			byName[name] = t
+		default:
+			byName[imp.Alias] = t
+		}
+	}
+
+	for i := range fr.Refs {
+		ref := &fr.Refs[i]
+		switch {
+		case ref.Kind == symbols.RefImport:
+			if t, ok := byLine[ref.StartLine]; ok {
+				ref.ImportPath, ref.ResolvedPath = t.path, t.dir
+			}
+		case ref.Qualifier != "":
+			if t, ok := byName[ref.Qualifier]; ok {
+				ref.ImportPath, ref.ResolvedPath = t.path, t.dir
+				ref.IsExternal = t.dir == ""
+			}
+		case !dotImport:
+			ref.ResolvedPath = ownDir
+		}
+	}
+}
+
+// goModuleFor returns the module owning the repo-relative directory dir,
+// or nil when no go.mod is found between dir and the repo root.
+func (r *importResolver) goModuleFor(dir string) *goModule {
+	for {
+		if m := r.loadGoMod(dir); m != nil {
+			return m
+		}
+		parent := filepath.Dir(dir)
+		if parent == dir || dir == "." {
+			return nil
+		}
+		dir = parent
+	}
+}
+
+// loadGoMod parses <dir>/go.mod, reusing the cached result while the file
+// is unchanged.
+func (r *importResolver) loadGoMod(dir string) *goModule {
+	info, err := os.Stat(filepath.Join(r.repoRoot, dir, "go.mod"))
+	if err != nil {
+		return nil
+	}
+
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	if m, ok := r.goMods[dir]; ok && m.modTime.Equal(info.ModTime()) {
+		return m
+	}
+	data, err := os.ReadFile(filepath.Join(r.repoRoot, dir, "go.mod"))
+	if err != nil {
+		return nil
+	}
+	m := parseGoMod(string(data), dir)
+	m.modTime = info.ModTime()
+	r.goMods[dir] = m
+	return m
+}
+
+// parseGoMod reads the module directive and local replace directives
+// (replace a/b => ../b) of a go.mod located in the repo-relative dir.
+func parseGoMod(src, dir string) *goModule {
+	m := &goModule{dir: dir, replaces: map[string]string{}}
+	inReplaceBlock := false
+	for _, line := range strings.Split(src, "\n") {
+		if i := strings.Index(line, "//"); i >= 0 {
+			line = line[:i]
+		}
+		fields := strings.Fields(line)
+		if len(fields) == 0 {
+			continue
+		}
+
+		switch {
+		case inReplaceBlock && fields[0] == ")":
+			inReplaceBlock = false
+			continue
+		case inReplaceBlock:
+		case fields[0] == "module" && len(fields) >= 2:
+			m.path = strings.Trim(fields[1], "\"`")
+			continue
+		case fields[0] == "replace" && len(fields) == 2 && fields[1] == "(":
+			inReplaceBlock = true
+			continue
+		case fields[0] == "replace":
+			fields = fields[1:]
+		default:
+			continue
+		}
+
+		// fields: old [version] => new [version]
+		arrow := -1
+		for i, f := range fields {
+			if f == "=>" {
+				arrow = i
+			}
+		}
+		if arrow < 1 || arrow+1 >= len(fields) {
+			continue
+		}
+		target := fields[arrow+1]
+		if !strings.HasPrefix(target, "./") && !strings.HasPrefix(target, "../") && target != "." && target != ".." {
+			continue // replaced by another module version, not a local dir
+		}
+		local := filepath.Join(dir, filepath.FromSlash(target))
+		if local == ".." || strings.HasPrefix(local, ".."+string(filepath.Separator)) {
+			continue // outside the repo
+		}
+		m.replaces[fields[0]] = local
+	}
+	return m
+}
+
+// importDir maps an import path to a repo-relative package directory, or
+// returns "" when the package lives outside the repo.
+func (m *goModule) importDir(importPath string) string {
+	if dir, ok := moduleSubdir(m.path, m.dir, importPath); ok {
+		return dir
+	}
+	best, bestLen := "", 0
+	for modPath, modDir := range m.replaces {
+		if dir, ok := moduleSubdir(modPath, modDir, importPath); ok && len(modPath) > bestLen {
+			best, bestLen = dir, len(modPath)
+		}
+	}
+	return best
+}
+
+// moduleSubdir returns the directory of importPath inside the module
+// modPath rooted at modDir.
+func moduleSubdir(modPath, modDir, importPath string) (string, bool) {
+	if modPath == "" {
+		return "", false
+	}
+	if importPath == modPath {
+		return modDir, true
+	}
+	if rest, ok := strings.CutPrefix(importPath, modPath+"/"); ok {
+		return filepath.Join(modDir, filepath.FromSlash(rest)), true
+	}
+	return "", false
+}
+
+// setGoPackageName records the package clause of a package directory, as
+// seen in a file that was just parsed.
+func (r *importResolver) setGoPackageName(dir, name string) {
+	if strings.HasSuffix(name, "_test") {
+		return // external test package sharing the directory
+	}
+	r.mu.Lock()
+	r.pkgNames[dir] = name
+	r.mu.Unlock()
+}
+
+// goPackageName returns the package clause name of a repo-relative package
+// directory, reading the first non-test .go file when not yet known.
+func (r *importResolver) goPackageName(dir string) string {
+	r.mu.Lock()
+	name, ok := r.pkgNames[dir]
+	r.mu.Unlock()
+	if ok {
+		return name
+	}
+
+	entries, err := os.ReadDir(filepath.Join(r.repoRoot, dir))
+	if err != nil {
+		return ""
+	}
+	for _, e := range entries {
+		if e.IsDir() || !strings.HasSuffix(e.Name(), ".go") || strings.HasSuffix(e.Name(), "_test.go") {
+			continue
+		}
+		if name = readGoPackageClause(filepath.Join(r.repoRoot, dir, e.Name())); name != "" {
+			break
+		}
+	}
+	r.mu.Lock()
+	r.pkgNames[dir] = name
+	r.mu.Unlock()
+	return name
+}
+
+// readGoPackageClause returns the name in the package clause of a Go file.
+func readGoPackageClause(absPath string) string {
+	f, err := os.Open(absPath)
+	if err != nil {
+		return ""
+	}
+	defer func() { _ = f.Close() }()
+	scanner := bufio.NewScanner(f)
+	for scanner.Scan() {
+		fields := strings.Fields(scanner.Text())
+		if len(fields) >= 2 && fields[0] == "package" {
+			return fields[1]
+		}
+	}
+	return ""
+}
+
+// defaultGoImportName guesses the package name of an import path outside
+// the repo: its last element, skipping a major-version element and
+// dropping gopkg.in versions and go- prefixes.
+func defaultGoImportName(importPath string) string {
+	parts := strings.Split(importPath, "/")
+	name := parts[len(parts)-1]
+	if goMajorVersionRe.MatchString(name) && len(parts) > 1 {
+		name = parts[len(parts)-2]
+	}
+	name = goPkgDotVersionRe.ReplaceAllString(name, "")
+	name = strings.TrimPrefix(name, "go-")
+	name = strings.TrimSuffix(name, "-go")
+	return strings.ReplaceAll(name, "-", "_")
+}
@@ -0,0 +1,194 @@
+package indexer
+
+import (
+	"path/filepath"
+	"testing"
+)
+
+func TestParseGoMod(t *testing.T) {
+	src := `module example.com/app // the app
+
+go 1.22
+
+require example.com/lib v1.2.0
+
+replace example.com/lib => ../lib
+
+replace (
+	example.com/tools v0.1.0 => ./tools
+	example.com/fork => example.com/fork/v2 v2.0.0
+	example.com/outside => ../../outside
+)
+`
+	m := parseGoMod(src, "app")
+	if m.path != "example.com/app" {
+		t.Errorf("module path = %q", m.path)
+	}
+	want := map[string]string{
+		"example.com/lib":   "lib",
+		"example.com/tools": filepath.Join("app", "tools"),
+	}
+	if len(m.replaces) != len(want) {
+		t.Errorf("replaces = %v, want %v", m.replaces, want)
+	}
+	for mod, dir := range want {
+		if m.replaces[mod] != dir {
+			t.Errorf("replace %s = %q, want %q", mod, m.replaces[mod], dir)
+		}
+	}
+
+	tests := map[string]string{
+		"example.com/app":             "app",
+		"example.com/app/internal/db": filepath.Join("app", "internal", "db"),
+		"example.com/lib/util":        filepath.Join("lib", "util"),
+		"example.com/application":     "",
+		"fmt":                         "",
+	}
+	for importPath, dir := range tests {
+		if got := m.importDir(importPath); got != dir {
+			t.Errorf("importDir(%q) = %q, want %q", importPath, got, dir)
+		}
+	}
+}
+
+func TestDefaultGoImportName(t *testing.T) {
+	tests := map[string]string{
+		"fmt":                                   "fmt",
+		"net/http":                              "http",
+		"gopkg.in/yaml.v3":                      "yaml",
+		"github.com/spf13/cobra":                "cobra",
+		"github.com/tree-sitter/go-tree-sitter": "tree_sitter",
+		"github.com/jackc/pgx/v5":               "pgx",
+	}
+	for importPath, want := range tests {
+		if got := defaultGoImportName(importPath); got != want {
+			t.Errorf("defaultGoImportName(%q) = %q, want %q", importPath, got, want)
+		}
+	}
+}
+
+func TestGoRefsResolveThroughImports(t *testing.T) {
+	mainFile := filepath.Join("gomod", "cmd", "shop", "main.go")
+	tests := []struct {
+		name       string
+		line, col  int
+		importPath string
+		resolved   string
+	}{
+		{"New", 12, 14, "example.com/shop/catalog", filepath.Join("gomod", "catalog")},
+		{"New", 14, 13, "example.com/shop/billing", filepath.Join("gomod", "billing")},
+		{"Quote", 14, 25, "example.com/shop/internal/go-pricing", filepath.Join("gomod", "internal", "go-pricing")},
+		{"Println", 15, 5, "fmt", ""},
+		{"Println", 16, 9, "example.com/shop/catalog", filepath.Join("gomod", "catalog")},
+	}
+	for _, tt := range tests {
+		usages, err := sharedNav.FindUsagesByName(tt.name, "", "go")
+		if err != nil {
+			t.Fatalf("FindUsagesByName(%s): %v", tt.name, err)
+		}
+		if u := usageAt(t, usages, mainFile, tt.line, tt.col); u != nil && (u.ImportPath != tt.importPath || u.ResolvedPath != tt.resolved) {
+			t.Errorf("%s at line %d: import %q resolved %q, want %q %q",
+				tt.name, tt.line, u.ImportPath, u.ResolvedPath, tt.importPath, tt.resolved)
+		}
+	}
+}
+
+func TestGoToDefinitionByPositionFollowsImports(t *testing.T) {
+	mainFile := filepath.Join("gomod", "cmd", "shop", "main.go")
+	tests := []struct {
+		line, col int
+		wantPath  string
+	}{
+		{12, 14, filepath.Join("gomod", "catalog", "catalog.go")},
+		{14, 13, filepath.Join("gomod", "billing", "billing.go")},
+		{14, 25, filepath.Join("gomod", "internal", "go-pricing", "pricing.go")},
+	}
+	for _, tt := range tests {
+		defs, err := sharedNav.GoToDefinitionByPosition(mainFile, tt.line, tt.col, "go")
+		if err != nil {
+			t.Fatalf("GoToDefinitionByPosition(%d:%d): %v", tt.line, tt.col, err)
+		}
+		if len(defs) != 1 || defs[0].Location.Path != tt.wantPath {
+			t.Errorf("%d:%d resolved to %+v, want exactly %s", tt.line, tt.col, defs, tt.wantPath)
+		}
+	}
+}
+
+func TestFindUsagesDropsRefsIntoOtherPackages(t *testing.T) {
+	mainFile := filepath.Join("gomod", "cmd", "shop", "main.go")
+
+	check := func(name, wantDefPath string, keepLine, dropLine int) {
+		t.Helper()
+		defs, err := sharedNav.GoToDefinitionByName(name, "", "go")
+		if err != nil {
+			t.Fatal(err)
+		}
+		var primary *DefinitionResult
+		for i := range defs {
+			if defs[i].Location.Path == wantDefPath {
+				primary = &defs[i]
+			}
+		}
+		if primary == nil {
+			t.Fatalf("no %s definition in %s: %+v", name, wantDefPath, defs)
+		}
+		usages, err := sharedNav.FindUsagesByName(name, "", "go")
+		if err != nil {
+			t.Fatal(err)
+		}
+
+		kept := false
+		for _, r := range ResolveAndFilterUsages(usages, defs, primary, sharedRepoRoot, NoiseFilterOptions{}) {
+			if r.Location.Path != mainFile {
+				continue
+			}
+			switch r.Location.StartLine {
+			case keepLine:
+				kept = true
+				if r.ResolutionConfidence != 1.0 {
+					t.Errorf("%s at line %d: resolution confidence %v, want 1.0", name, keepLine, r.ResolutionConfidence)
+				}
+			case dropLine:
+				t.Errorf("%s at line %d resolves elsewhere but was kept", name, dropLine)
+			}
+		}
+		if !kept {
+			t.Errorf("%s at line %d was dropped", name, keepLine)
+		}
+	}
+
+	check("New", filepath.Join("gomod", "billing", "billing.go"), 14, 12)
+	check("New", filepath.Join("gomod", "catalog", "catalog.go"), 12, 14)
+	check("Println", filepath.Join("gomod", "catalog", "catalog.go"), 16, 15)
+}
+
+func TestDependencyGraphFollowsGoImports(t *testing.T) {
+	defs, err := sharedNav.GoToDefinitionByName("New", "", "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	var billingNew *DefinitionResult
+	for i := range defs {
+		if defs[i].Location.Path == filepath.Join("gomod", "billing", "billing.go") {
+			billingNew = &defs[i]
+		}
+	}
+	if billingNew == nil {
+		t.Fatalf("no New in billing.go: %+v", defs)
+	}
+
+	graph, err := BuildDependencyGraph(sharedNav, billingNew, defs, "go", sharedRepoRoot, 1, 0.0, 500)
+	if err != nil {
+		t.Fatal(err)
+	}
+	mainFile := filepath.Join("gomod", "cmd", "shop", "main.go")
+	for _, e := range graph.Inbound.Edges {
+		if e.FilePath == mainFile {
+			if e.Count != 1 || e.Score != 1.0 {
+				t.Errorf("main.go edge count=%d score=%v, want 1 and 1.0", e.Count, e.Score)
+			}
+			return
+		}
+	}
+	t.Errorf("expected an inbound edge from %s; got %+v", mainFile, graph.Inbound.Edges)
+}
@@ -0,0 +1,125 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"unicode"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// Java refs resolve to fully qualified names, which definitions carry
+// themselves (see importResolution), so no other file needs to be indexed
+// first. A simple type name resolves to a type declared in the file, a
+// single-type import, or a type of the file's own package. An on-demand
+// import (a.b.*) may supply the name as well, so the package is then only
+// assumed when <Name>.java sits next to the file. Members resolve through
+// the type qualifying them (Type.member) or a single static import.
+// ImportPath is set to the scope of the target: its package, or its class
+// for a member.
+
+// resolveJava records the qualified scope of every resolvable ref in a Java
+// file.
+func (r *importResolver) resolveJava(relPath string, fr *symbols.FileResult) {
+	types := map[string]string{}   // simple type name -> FQN
+	statics := map[string]string{} // statically imported member -> class FQN
+	onDemand := false
+	for _, imp := range fr.Imports {
+		path, wildcard := strings.CutSuffix(imp.Path, ".*")
+		i := strings.LastIndex(path, ".")
+		switch {
+		case wildcard:
+			onDemand = onDemand || !imp.Static
+		case i < 0:
+			// a type of the unnamed package
+		case imp.Static:
+			statics[path[i+1:]] = path[:i]
+		default:
+			types[path[i+1:]] = path
+		}
+	}
+	// Declarations of the file shadow imported names.
+	for _, s := range fr.Symbols {
+		switch s.Kind {
+		case symbols.KindClass, symbols.KindInterface, symbols.KindEnum:
+			if s.QualifiedName != "" {
+				types[s.Name] = s.QualifiedName
+			}
+		default:
+			delete(statics, s.Name)
+		}
+	}
+
+	dir := filepath.Dir(relPath)
+	// typeFQN returns the FQN of a simple type name, or "" when undecided.
+	// assume allows falling back to the file's package without evidence.
+	typeFQN := func(name string, assume bool) string {
+		if fqn, ok := types[name]; ok {
+			return fqn
+		}
+		if !javaTypeName(name) {
+			return ""
+		}
+		if _, err := os.Stat(filepath.Join(r.repoRoot, dir, name+".java")); err == nil {
+			return javaJoin(fr.Package, name)
+		}
+		if assume && !onDemand {
+			return javaJoin(fr.Package, name)
+		}
+		return ""
+	}
+
+	for i := range fr.Refs {
+		ref := &fr.Refs[i]
+		if ref.Qualifier != "" {
+			ref.ImportPath = javaQualifierScope(ref, typeFQN)
+			continue
+		}
+		if class, ok := statics[ref.Name]; ok && ref.Kind != symbols.RefTypeRef {
+			ref.ImportPath = class
+			continue
+		}
+		typeRef := ref.Kind == symbols.RefTypeRef || ref.Kind == symbols.RefInherit
+		if fqn := typeFQN(ref.Name, typeRef); fqn != "" && fqn != ref.Name {
+			ref.ImportPath = strings.TrimSuffix(fqn, "."+ref.Name)
+		}
+	}
+}
+
+// javaQualifierScope returns the FQN a qualified ref's qualifier stands for,
+// or "" when it cannot tell, e.g. for a variable (order.total()).
+func javaQualifierScope(ref *symbols.Ref, typeFQN func(string, bool) string) string {
+	first, rest, _ := strings.Cut(ref.Qualifier, ".")
+	if javaTypeName(first) {
+		if fqn := typeFQN(first, true); fqn != "" {
+			if rest != "" {
+				return fqn + "." + rest // Outer.Inner.member
+			}
+			return fqn
+		}
+		return ""
+	}
+	// Only imports and type positions are qualified by a package name.
+	if ref.Kind == symbols.RefImport || ref.Kind == symbols.RefTypeRef {
+		return ref.Qualifier
+	}
+	return ""
+}
+
+// javaTypeName reports whether name follows the Java type naming
+// convention, telling Request apart from a variable or a CONSTANT.
+func javaTypeName(name string) bool {
+	if name == "" || !unicode.IsUpper(rune(name[0])) {
+		return false
+	}
+	return strings.IndexFunc(name, unicode.IsLower) >= 0
+}
+
+// javaJoin qualifies name by pkg, which is "" for the unnamed package.
+func javaJoin(pkg, name string) string {
+	if pkg == "" {
+		return name
+	}
+	return pkg + "." + name
+}
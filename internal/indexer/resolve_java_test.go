@@ -0,0 +1,110 @@
+package indexer
+
+import "testing"
+
+// javafqnRoot is the source root of the javafqn fixture.
+const javafqnRoot = "javafqn/src/com/shop"
+
+func TestJavaRefsResolveToQualifiedNames(t *testing.T) {
+	mainFile := fixturePath(javafqnRoot, "app", "Main.java")
+	tests := []struct {
+		line, col  int
+		name       string
+		importPath string
+	}{
+		// single-type import, as a type and as a qualifier
+		{8, 8, "Request", "com.shop.http"},
+		{8, 30, "of", "com.shop.http.Request"},
+		// fully qualified type and a static import
+		{9, 23, "Request", "com.shop.queue"},
+		{9, 37, "of", "com.shop.queue.Request"},
+		// same package, without an import
+		{10, 30, "Handler", "com.shop.app"},
+		// a variable is not a type
+		{11, 16, "handle", ""},
+	}
+	for _, tt := range tests {
+		usages, err := sharedNav.FindUsagesByName(tt.name, "", "java")
+		if err != nil {
+			t.Fatalf("FindUsagesByName(%s): %v", tt.name, err)
+		}
+		if u := usageAt(t, usages, mainFile, tt.line, tt.col); u != nil && u.ImportPath != tt.importPath {
+			t.Errorf("%s at %d:%d: import %q, want %q", tt.name, tt.line, tt.col, u.ImportPath, tt.importPath)
+		}
+	}
+}
+
+func TestJavaGoToDefinitionByQualifiedName(t *testing.T) {
+	mainFile := fixturePath(javafqnRoot, "app", "Main.java")
+	tests := []struct {
+		line, col int
+		wantPath  string
+	}{
+		{8, 8, fixturePath(javafqnRoot, "http", "Request.java")},
+		{8, 30, fixturePath(javafqnRoot, "http", "Request.java")},
+		{9, 23, fixturePath(javafqnRoot, "queue", "Request.java")},
+		{9, 37, fixturePath(javafqnRoot, "queue", "Request.java")},
+		{10, 8, fixturePath(javafqnRoot, "app", "Handler.java")},
+	}
+	for _, tt := range tests {
+		defs, err := sharedNav.GoToDefinitionByPosition(mainFile, tt.line, tt.col, "java")
+		if err != nil {
+			t.Fatalf("GoToDefinitionByPosition(%d:%d): %v", tt.line, tt.col, err)
+		}
+		if len(defs) == 0 {
+			t.Errorf("%d:%d resolved to nothing, want %s", tt.line, tt.col, tt.wantPath)
+		}
+		for _, d := range defs {
+			if d.Location.Path != tt.wantPath {
+				t.Errorf("%d:%d resolved to %s (%s), want only %s", tt.line, tt.col, d.Location.Path, d.QualifiedName, tt.wantPath)
+			}
+		}
+	}
+}
+
+func TestJavaFindUsagesDropsOtherPackages(t *testing.T) {
+	mainFile := fixturePath(javafqnRoot, "app", "Main.java")
+	defs, err := sharedNav.GoToDefinitionByName("of", "", "java")
+	if err != nil {
+		t.Fatal(err)
+	}
+	usages, err := sharedNav.FindUsagesByName("of", "", "java")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	check := func(defPath string, keepLine, dropLine int) {
+		t.Helper()
+		var primary *DefinitionResult
+		for i := range defs {
+			if defs[i].Location.Path == defPath {
+				primary = &defs[i]
+			}
+		}
+		if primary == nil {
+			t.Fatalf("no of in %s", defPath)
+		}
+
+		kept := false
+		for _, r := range ResolveAndFilterUsages(usages, defs, primary, sharedRepoRoot, NoiseFilterOptions{}) {
+			if r.Location.Path != mainFile {
+				continue
+			}
+			switch r.Location.StartLine {
+			case keepLine:
+				kept = true
+				if r.ResolutionConfidence != 1.0 {
+					t.Errorf("line %d: resolution confidence %v, want 1.0", keepLine, r.ResolutionConfidence)
+				}
+			case dropLine:
+				t.Errorf("usage of %s kept at line %d, which resolves elsewhere", defPath, dropLine)
+			}
+		}
+		if !kept {
+			t.Errorf("usage of %s at line %d was dropped", defPath, keepLine)
+		}
+	}
+
+	check(fixturePath(javafqnRoot, "http", "Request.java"), 8, 9)
+	check(fixturePath(javafqnRoot, "queue", "Request.java"), 9, 8)
+}
@@ -0,0 +1,479 @@
+package indexer
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// JavaScript and TypeScript refs resolve through the module specifier they
+// are imported from. Relative specifiers are tried as written, with each
+// source extension, and as a directory's index file; TypeScript's "./x.js"
+// also finds x.ts. Bare specifiers go through the paths and baseUrl of the
+// nearest tsconfig.json (or jsconfig.json), following relative extends. A
+// name is looked up in the module's own definitions, then its re-exports
+// and imports, so barrel files (index.ts re-exporting siblings) resolve to
+// the file that declares the name. A bare specifier found under
+// node_modules is outside the repo; any other unresolved one (a workspace
+// package, or dependencies that are not installed) is left to heuristics.
+
+// jsMaxReexportDepth bounds the number of re-exports one name may go
+// through, guarding against export * cycles.
+const jsMaxReexportDepth = 8
+
+// jsCommonJSExport matches exports.name = and module.exports.name =.
+var jsCommonJSExport = regexp.MustCompile(`^(?:module\.)?exports\.([A-Za-z_$][\w$]*)\s*=[^=]`)
+
+// jsExtensions are tried, in order, for a specifier without one.
+var jsExtensions = []string{".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs", ".mts", ".cts", ".vue", ".svelte"}
+
+// jsFileItems are the names a JS/TS module declares, and its imports and
+// re-exports.
+type jsFileItems struct {
+	defs    map[string]bool
+	imports []symbols.Import
+	modTime time.Time
+}
+
+// tsConfig is the module resolution part of a tsconfig.json.
+type tsConfig struct {
+	baseURL string              // repo-relative, "" when unset
+	paths   map[string][]string // pattern -> repo-relative substitutions
+	modTime time.Time
+}
+
+// resolveJS records the specifier and defining file of the refs of a
+// JavaScript or TypeScript file that go through its imports or name its
+// own declarations.
+func (r *importResolver) resolveJS(relPath string, fr *symbols.FileResult) {
+	abs := filepath.Join(r.repoRoot, relPath)
+	src, err := os.ReadFile(abs)
+	if err != nil {
+		return
+	}
+	own := jsItemsOf(fr, src)
+	if info, err := os.Stat(abs); err == nil {
+		own.modTime = info.ModTime()
+		r.mu.Lock()
+		r.jsModules[relPath] = own
+		r.mu.Unlock()
+	}
+
+	bindings := map[string]symbols.Import{} // bound name -> import
+	for _, imp := range fr.Imports {
+		if !imp.Reexport {
+			bindings[jsBinding(imp)] = imp
+		}
+	}
+
+	lines := strings.Split(string(src), "\n")
+	for i := range fr.Refs {
+		ref := &fr.Refs[i]
+		var imp symbols.Import
+		var bound bool
+		name := ref.Name
+		switch {
+		case ref.Qualifier != "":
+			// ns.name through import * as ns or const ns = require()
+			imp, bound = bindings[ref.Qualifier]
+			bound = bound && imp.Name == "*"
+		case ref.Kind == symbols.RefImport:
+			imp, bound = jsImportAt(fr.Imports, ref)
+			name = imp.Name
+		case isMemberAccess(lines, ref):
+			// obj().name, this.name: a member of a value
+		default:
+			if imp, bound = bindings[name]; bound {
+				name = imp.Name
+				bound = name != "*"
+			} else if own.defs[name] {
+				ref.ResolvedPath = relPath
+			}
+		}
+		if !bound {
+			continue
+		}
+		switch file, ok := r.jsModule(relPath, imp.Path); {
+		case file != "":
+			// A name the module does not visibly export (export * from an
+			// unresolved package) is left undecided.
+			if target := r.jsLookup(file, name, 0); target != "" {
+				ref.ImportPath = imp.Path
+				ref.ResolvedPath = target
+			}
+		case ok:
+			ref.ImportPath = imp.Path // a package outside the repo
+		}
+	}
+}
+
+// jsImportAt returns the import declaring the name of an import ref: the
+// imported name, or the local name of a default import.
+func jsImportAt(imports []symbols.Import, ref *symbols.Ref) (symbols.Import, bool) {
+	for _, imp := range imports {
+		if imp.Line != ref.StartLine || imp.Reexport {
+			continue
+		}
+		if imp.Name == ref.Name || (imp.Name == "default" && imp.Alias == ref.Name) {
+			return imp, true
+		}
+	}
+	return symbols.Import{}, false
+}
+
+// jsBinding returns the local name an import binds.
+func jsBinding(imp symbols.Import) string {
+	if imp.Alias != "" {
+		return imp.Alias
+	}
+	return imp.Name
+}
+
+// jsLookup returns the repo-relative file declaring name as exported by
+// module file, following re-exports, or "" when it cannot be found.
+func (r *importResolver) jsLookup(file, name string, depth int) string {
+	if depth > jsMaxReexportDepth {
+		return ""
+	}
+	if name == "default" {
+		return file
+	}
+	items := r.jsItems(file)
+	if items == nil {
+		return ""
+	}
+	if items.defs[name] {
+		return file
+	}
+	// export {a as name} from './x', or import {a as name} ... export {name}
+	for _, imp := range items.imports {
+		if imp.Name == "*" || jsBinding(imp) != name {
+			continue
+		}
+		if next, _ := r.jsModule(file, imp.Path); next != "" {
+			return r.jsLookup(next, imp.Name, depth+1)
+		}
+		return ""
+	}
+	for _, imp := range items.imports {
+		if imp.Reexport && imp.Name == "*" && imp.Alias == "" {
+			if next, _ := r.jsModule(file, imp.Path); next != "" {
+				if target := r.jsLookup(next, name, depth+1); target != "" {
+					return target
+				}
+			}
+		}
+	}
+	return ""
+}
+
+// jsModule resolves a module specifier used in file to a repo-relative
+// file. ok without a file means the specifier names a package outside the
+// repo; neither means it could not be resolved.
+func (r *importResolver) jsModule(file, spec string) (string, bool) {
+	if strings.HasPrefix(spec, "./") || strings.HasPrefix(spec, "../") || spec == "." || spec == ".." {
+		found := r.jsFile(filepath.Join(filepath.Dir(file), spec))
+		return found, found != ""
+	}
+	if strings.HasPrefix(spec, "/") {
+		return "", false
+	}
+	if strings.HasPrefix(spec, "node:") {
+		return "", true
+	}
+	if cfg := r.tsConfigFor(filepath.Dir(file)); cfg != nil {
+		for _, candidate := range cfg.candidates(spec) {
+			if found := r.jsFile(candidate); found != "" {
+				return found, true
+			}
+		}
+	}
+	return "", r.jsInstalled(filepath.Dir(file), spec)
+}
+
+// jsFile returns the source file a repo-relative module path refers to,
+// or "".
+func (r *importResolver) jsFile(path string) string {
+	if path == ".." || strings.HasPrefix(path, "../") {
+		return "" // outside the repo
+	}
+	if DetectLang(path) != LangUnknown && r.isFile(path) {
+		return path
+	}
+	if trimmed, ok := strings.CutSuffix(path, ".js"); ok {
+		for _, ext := range []string{".ts", ".tsx"} {
+			if r.isFile(trimmed + ext) {
+				return trimmed + ext
+			}
+		}
+	}
+	for _, ext := range jsExtensions {
+		if r.isFile(path + ext) {
+			return path + ext
+		}
+	}
+	for _, ext := range jsExtensions {
+		if index := filepath.Join(path, "index"+ext); r.isFile(index) {
+			return index
+		}
+	}
+	return ""
+}
+
+// jsInstalled reports whether the package a bare specifier names is
+// installed in a node_modules directory at or above dir. Symlinked
+// (workspace) packages live in the repo and do not count.
+func (r *importResolver) jsInstalled(dir, spec string) bool {
+	parts := strings.SplitN(spec, "/", 3)
+	pkg := parts[0]
+	if strings.HasPrefix(pkg, "@") && len(parts) > 1 {
+		pkg += "/" + parts[1] // @scope/name
+	}
+	for {
+		info, err := os.Lstat(filepath.Join(r.repoRoot, dir, "node_modules", pkg))
+		if err == nil {
+			return info.Mode()&os.ModeSymlink == 0
+		}
+		if dir == "." {
+			return false
+		}
+		dir = filepath.Dir(dir)
+	}
+}
+
+// jsItems returns the declarations and imports of a repo-relative JS/TS
+// file, parsing it unless cached.
+func (r *importResolver) jsItems(file string) *jsFileItems {
+	abs := filepath.Join(r.repoRoot, file)
+	info, err := os.Stat(abs)
+	if err != nil {
+		return nil
+	}
+	r.mu.Lock()
+	items, ok := r.jsModules[file]
+	r.mu.Unlock()
+	if ok && items.modTime.Equal(info.ModTime()) {
+		return items
+	}
+
+	parser := GetParser(DetectLang(file))
+	if parser == nil {
+		return nil
+	}
+	src, err := os.ReadFile(abs)
+	if err != nil {
+		return nil
+	}
+	fr, err := parser.Parse(abs, src)
+	if err != nil {
+		return nil
+	}
+	items = jsItemsOf(fr, src)
+	items.modTime = info.ModTime()
+	r.mu.Lock()
+	r.jsModules[file] = items
+	r.mu.Unlock()
+	return items
+}
+
+// jsItemsOf collects the top-level declarations of a parsed file and the
+// names it assigns to exports. Top-level statements start in the first
+// column.
+func jsItemsOf(fr *symbols.FileResult, src []byte) *jsFileItems {
+	items := &jsFileItems{defs: map[string]bool{}, imports: fr.Imports}
+	lines := strings.Split(string(src), "\n")
+	topLevel := func(line int) bool {
+		return line >= 1 && line <= len(lines) && lines[line-1] != "" &&
+			lines[line-1][0] != ' ' && lines[line-1][0] != '\t'
+	}
+	for _, s := range fr.Symbols {
+		switch s.Kind {
+		case symbols.KindMethod, symbols.KindProperty, symbols.KindField, symbols.KindConstructor:
+			continue
+		}
+		if topLevel(s.StartLine) {
+			items.defs[s.Name] = true
+		}
+	}
+	for _, line := range lines {
+		if m := jsCommonJSExport.FindStringSubmatch(line); m != nil {
+			items.defs[m[1]] = true
+		}
+	}
+	return items
+}
+
+// tsConfigFor returns the tsconfig.json or jsconfig.json closest above the
+// repo-relative directory dir, or nil.
+func (r *importResolver) tsConfigFor(dir string) *tsConfig {
+	for {
+		for _, name := range []string{"tsconfig.json", "jsconfig.json"} {
+			if cfg := r.loadTSConfig(filepath.Join(dir, name)); cfg != nil {
+				return cfg
+			}
+		}
+		if dir == "." {
+			return nil
+		}
+		dir = filepath.Dir(dir)
+	}
+}
+
+// loadTSConfig reads a repo-relative tsconfig file, cached by modification
+// time.
+func (r *importResolver) loadTSConfig(file string) *tsConfig {
+	info, err := os.Stat(filepath.Join(r.repoRoot, file))
+	if err != nil || info.IsDir() {
+		return nil
+	}
+	r.mu.Lock()
+	cfg, ok := r.tsConfigs[file]
+	r.mu.Unlock()
+	if ok && cfg.modTime.Equal(info.ModTime()) {
+		return cfg
+	}
+
+	cfg = &tsConfig{paths: map[string][]string{}, modTime: info.ModTime()}
+	r.readTSConfig(file, cfg, 0)
+	r.mu.Lock()
+	r.tsConfigs[file] = cfg
+	r.mu.Unlock()
+	return cfg
+}
+
+// readTSConfig merges the compiler options of a tsconfig file into cfg,
+// the file's own settings overriding those it extends.
+func (r *importResolver) readTSConfig(file string, cfg *tsConfig, depth int) {
+	src, err := os.ReadFile(filepath.Join(r.repoRoot, file))
+	if err != nil || depth > jsMaxReexportDepth {
+		return
+	}
+	var raw struct {
+		Extends         any `json:"extends"` // a path, or a list of them
+		CompilerOptions struct {
+			BaseURL string              `json:"baseUrl"`
+			Paths   map[string][]string `json:"paths"`
+		} `json:"compilerOptions"`
+	}
+	if json.Unmarshal(stripJSONC(src), &raw) != nil {
+		return
+	}
+	dir := filepath.Dir(file)
+	var extends []any
+	switch e := raw.Extends.(type) {
+	case string:
+		extends = []any{e}
+	case []any:
+		extends = e
+	}
+	for _, e := range extends {
+		// Configs extended from packages (@tsconfig/node20) are skipped.
+		if base, ok := e.(string); ok && strings.HasPrefix(base, ".") {
+			base = filepath.Join(dir, base)
+			if filepath.Ext(base) != ".json" {
+				base += ".json"
+			}
+			r.readTSConfig(base, cfg, depth+1)
+		}
+	}
+	opts := raw.CompilerOptions
+	if opts.BaseURL != "" {
+		cfg.baseURL = filepath.Join(dir, opts.BaseURL)
+	}
+	if opts.Paths != nil {
+		// paths are relative to baseUrl, or to the file declaring them
+		base := dir
+		if opts.BaseURL != "" {
+			base = cfg.baseURL
+		}
+		cfg.paths = map[string][]string{}
+		for pattern, subs := range opts.Paths {
+			for _, sub := range subs {
+				cfg.paths[pattern] = append(cfg.paths[pattern], filepath.Join(base, sub))
+			}
+		}
+	}
+}
+
+// candidates returns the repo-relative module paths a bare specifier may
+// map to: the substitutions of the paths pattern with the longest prefix,
+// then the specifier below baseUrl.
+func (c *tsConfig) candidates(spec string) []string {
+	var out []string
+	best := -1
+	for pattern, subs := range c.paths {
+		prefix, suffix, wildcard := strings.Cut(pattern, "*")
+		var matched string
+		switch {
+		case !wildcard && spec == pattern:
+		case wildcard && len(spec) >= len(prefix)+len(suffix) &&
+			strings.HasPrefix(spec, prefix) && strings.HasSuffix(spec, suffix):
+			matched = spec[len(prefix) : len(spec)-len(suffix)]
+		default:
+			continue
+		}
+		if len(prefix) <= best {
+			continue
+		}
+		best = len(prefix)
+		out = out[:0]
+		for _, sub := range subs {
+			out = append(out, strings.Replace(sub, "*", matched, 1))
+		}
+	}
+	if c.baseURL != "" {
+		out = append(out, filepath.Join(c.baseURL, spec))
+	}
+	return out
+}
+
+// stripJSONC removes the comments and trailing commas tsconfig files may
+// contain, leaving plain JSON.
+func stripJSONC(src []byte) []byte {
+	out := make([]byte, 0, len(src))
+	inString := false
+	for i := 0; i < len(src); i++ {
+		c := src[i]
+		switch {
+		case inString:
+			out = append(out, c)
+			if c == '\\' && i+1 < len(src) {
+				i++
+				out = append(out, src[i])
+			} else if c == '"' {
+				inString = false
+			}
+		case c == '"':
+			inString = true
+			out = append(out, c)
+		case c == '/' && i+1 < len(src) && src[i+1] == '/':
+			for i < len(src) && src[i] != '\n' {
+				i++
+			}
+			i--
+		case c == '/' && i+1 < len(src) && src[i+1] == '*':
+			end := strings.Index(string(src[i+2:]), "*/")
+			if end < 0 {
+				return out
+			}
+			i += end + 3
+		case c == ',':
+			j := i + 1
+			for j < len(src) && strings.ContainsRune(" \t\r\n", rune(src[j])) {
+				j++
+			}
+			if j < len(src) && (src[j] == '}' || src[j] == ']') {
+				continue
+			}
+			out = append(out, c)
+		default:
+			out = append(out, c)
+		}
+	}
+	return out
+}
@@ -0,0 +1,155 @@
+package indexer
+
+import "testing"
+
+// jsmodulesRoot is the source root of the jsmodules fixture.
+const jsmodulesRoot = "jsmodules"
+
+func TestJSRefsResolveThroughModules(t *testing.T) {
+	mainFile := fixturePath(jsmodulesRoot, "src", "app", "main.ts")
+	appFile := fixturePath(jsmodulesRoot, "legacy", "app.js")
+	widgetsCreate := fixturePath(jsmodulesRoot, "src", "lib", "widgets", "create.ts")
+	widgetsRender := fixturePath(jsmodulesRoot, "src", "lib", "widgets", "render.ts")
+	shapesCreate := fixturePath(jsmodulesRoot, "src", "lib", "shapes", "create.ts")
+	factoryFile := fixturePath(jsmodulesRoot, "legacy", "factory.js")
+	tests := []struct {
+		file       string
+		lang       string
+		line, col  int
+		name       string
+		importPath string
+		resolved   string
+	}{
+		// tsconfig paths alias to a barrel file re-exporting its siblings
+		{mainFile, "typescript", 1, 9, "create", "@lib/widgets", widgetsCreate},
+		{mainFile, "typescript", 1, 17, "render", "@lib/widgets", widgetsRender},
+		{mainFile, "typescript", 6, 17, "create", "@lib/widgets", widgetsCreate},
+		// namespace import of ./x.js written for a TypeScript file
+		{mainFile, "typescript", 7, 9, "create", "../lib/shapes/create.js", shapesCreate},
+		// a Node built-in
+		{mainFile, "typescript", 8, 9, "join", "node:path", ""},
+		// CommonJS require and exports
+		{appFile, "javascript", 5, 24, "build", "./factory", factoryFile},
+		{appFile, "javascript", 6, 2, "destroy", "./factory.js", factoryFile},
+	}
+	for _, tt := range tests {
+		usages, err := sharedNav.FindUsagesByName(tt.name, "", tt.lang)
+		if err != nil {
+			t.Fatalf("FindUsagesByName(%s): %v", tt.name, err)
+		}
+		if u := usageAt(t, usages, tt.file, tt.line, tt.col); u != nil && (u.ImportPath != tt.importPath || u.ResolvedPath != tt.resolved) {
+			t.Errorf("%s at %s:%d:%d: import %q resolved %q, want %q %q",
+				tt.name, tt.file, tt.line, tt.col, u.ImportPath, u.ResolvedPath, tt.importPath, tt.resolved)
+		}
+	}
+}
+
+func TestJSUsagesCarryResolvedDefinition(t *testing.T) {
+	mainFile := fixturePath(jsmodulesRoot, "src", "app", "main.ts")
+	usages, err := sharedNav.FindUsagesByName("create", "", "typescript")
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := map[int]string{
+		6: fixturePath(jsmodulesRoot, "src", "lib", "widgets", "create.ts"),
+		7: fixturePath(jsmodulesRoot, "src", "lib", "shapes", "create.ts"),
+	}
+	for _, u := range usages {
+		wantPath, ok := want[u.Location.StartLine]
+		if u.Location.Path != mainFile || !ok {
+			continue
+		}
+		delete(want, u.Location.StartLine)
+		if u.ResolvedDefinition == nil || u.ResolvedDefinition.Location.Path != wantPath {
+			t.Errorf("line %d: resolved definition %+v, want one in %s", u.Location.StartLine, u.ResolvedDefinition, wantPath)
+		}
+	}
+	if len(want) > 0 {
+		t.Errorf("no usages of create at lines %v", want)
+	}
+}
+
+func TestJSGoToDefinitionFollowsReexports(t *testing.T) {
+	mainFile := fixturePath(jsmodulesRoot, "src", "app", "main.ts")
+	tests := []struct {
+		line, col int
+		wantPath  string
+	}{
+		{6, 17, fixturePath(jsmodulesRoot, "src", "lib", "widgets", "create.ts")},
+		{7, 9, fixturePath(jsmodulesRoot, "src", "lib", "shapes", "create.ts")},
+		{8, 14, fixturePath(jsmodulesRoot, "src", "lib", "widgets", "render.ts")},
+	}
+	for _, tt := range tests {
+		defs, err := sharedNav.GoToDefinitionByPosition(mainFile, tt.line, tt.col, "typescript")
+		if err != nil {
+			t.Fatalf("GoToDefinitionByPosition(%d:%d): %v", tt.line, tt.col, err)
+		}
+		if len(defs) != 1 || defs[0].Location.Path != tt.wantPath {
+			t.Errorf("%d:%d resolved to %+v, want exactly %s", tt.line, tt.col, defs, tt.wantPath)
+		}
+	}
+}
+
+func TestJSFindUsagesDropsOtherModules(t *testing.T) {
+	mainFile := fixturePath(jsmodulesRoot, "src", "app", "main.ts")
+	defs, err := sharedNav.GoToDefinitionByName("create", "", "typescript")
+	if err != nil {
+		t.Fatal(err)
+	}
+	usages, err := sharedNav.FindUsagesByName("create", "", "typescript")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	check := func(defPath string, keepLine, dropLine int) {
+		t.Helper()
+		var primary *DefinitionResult
+		for i := range defs {
+			if defs[i].Location.Path == defPath {
+				primary = &defs[i]
+			}
+		}
+		if primary == nil {
+			t.Fatalf("no create in %s", defPath)
+		}
+
+		kept := false
+		for _, r := range ResolveAndFilterUsages(usages, defs, primary, sharedRepoRoot, NoiseFilterOptions{}) {
+			if r.Location.Path != mainFile {
+				continue
+			}
+			switch r.Location.StartLine {
+			case keepLine:
+				kept = true
+				if r.ResolutionConfidence != 1.0 {
+					t.Errorf("line %d: resolution confidence %v, want 1.0", keepLine, r.ResolutionConfidence)
+				}
+			case dropLine:
+				t.Errorf("usage of %s kept at line %d, which resolves elsewhere", defPath, dropLine)
+			}
+		}
+		if !kept {
+			t.Errorf("usage of %s at line %d was dropped", defPath, keepLine)
+		}
+	}
+
+	check(fixturePath(jsmodulesRoot, "src", "lib", "widgets", "create.ts"), 6, 7)
+	check(fixturePath(jsmodulesRoot, "src", "lib", "shapes", "create.ts"), 7, 6)
+}
+
+func TestJSStaleResolutionIsUndecided(t *testing.T) {
+	// main.ts imported create through the widgets barrel, which now
+	// re-exports it from another file.
+	usage := UsageResult{
+		Name:         "create",
+		ImportPath:   "@lib/widgets",
+		ResolvedPath: "src/lib/widgets/create.ts",
+		Location:     Location{Path: "src/app/main.ts", StartLine: 6},
+	}
+	moved := []DefinitionResult{
+		{Name: "create", Kind: "function", Location: Location{Path: "src/lib/widgets/factory.ts"}},
+	}
+	if matches, ok := importResolution(usage, moved); ok {
+		t.Errorf("resolution into a module without create decided %v, want undecided", matches)
+	}
+}
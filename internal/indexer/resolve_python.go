@@ -0,0 +1,428 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// Python refs resolve through the module a name is imported from. A file's
+// dotted module path follows from the chain of __init__.py packages above
+// it; absolute imports are looked up below the top of that chain, the
+// configured source roots, and each directory above them (and its src/
+// subdirectory). A name is looked up in a module's own top-level
+// definitions, then its from-imports (following re-exports), then its
+// from m import * imports, restricted to m's __all__ when it declares one.
+// Modules that cannot be found in the repo are left unresolved: without a
+// lockfile to go by, they may just live under an unconfigured root.
+
+// pythonMaxImportDepth bounds the number of re-exports one name may go
+// through, guarding against import cycles.
+const pythonMaxImportDepth = 8
+
+// pythonFileItems are the module-level names of a .py file.
+type pythonFileItems struct {
+	defs    map[string]bool // names defined at the top level
+	imports []symbols.Import
+	exports []string // __all__, nil when undeclared
+	modTime time.Time
+}
+
+// pythonModule is a module or package found in the repo.
+type pythonModule struct {
+	path string // dotted module path
+	file string // repo-relative .py file (__init__.py for a package), "" for a namespace package
+	dir  string // repo-relative package directory, "" for a plain module
+}
+
+// resolvePython records the module and defining file of the refs of a
+// Python file that go through its imports or name its own top-level
+// definitions.
+func (r *importResolver) resolvePython(relPath string, fr *symbols.FileResult) {
+	if filepath.Ext(relPath) != ".py" {
+		return // notebooks have no importable module path
+	}
+	abs := filepath.Join(r.repoRoot, relPath)
+	src, err := os.ReadFile(abs)
+	if err != nil {
+		return
+	}
+	own := pythonItemsOf(fr, src)
+	if info, err := os.Stat(abs); err == nil {
+		own.modTime = info.ModTime()
+		r.mu.Lock()
+		r.pythonModules[relPath] = own
+		r.mu.Unlock()
+	}
+	self := &pythonModule{path: r.pythonFilePath(relPath), file: relPath}
+
+	bindings := map[string]symbols.Import{} // bound name -> import, the last one winning
+	var stars []*pythonModule
+	for _, imp := range fr.Imports {
+		if imp.Name == "*" {
+			if m := r.pythonFrom(relPath, imp.Path); m != nil {
+				stars = append(stars, m)
+			}
+			continue
+		}
+		bindings[pythonBinding(imp)] = imp
+	}
+
+	lines := strings.Split(string(src), "\n")
+	for i := range fr.Refs {
+		ref := &fr.Refs[i]
+		var target *pythonModule
+		var ok bool
+		switch {
+		case ref.Qualifier != "":
+			target, ok = r.pythonQualified(relPath, ref, bindings)
+		case ref.Kind == symbols.RefImport:
+			target, ok = r.pythonImported(relPath, ref, fr.Imports)
+		case isMemberAccess(lines, ref):
+			// obj().name: a member of a value
+		default:
+			if imp, bound := bindings[ref.Name]; bound && imp.Name != "" {
+				target, ok = r.pythonFromImport(relPath, imp)
+			} else if own.defs[ref.Name] {
+				target, ok = self, true
+			} else {
+				for _, m := range stars {
+					if r.pythonExported(m, ref.Name) {
+						if target = r.pythonLookup(m, ref.Name, 0); target != nil {
+							ok = true
+							break
+						}
+					}
+				}
+			}
+		}
+		if ok {
+			ref.ImportPath = target.path
+			ref.ResolvedPath = target.file
+		}
+	}
+}
+
+// pythonImported resolves the name of a from-import statement itself.
+func (r *importResolver) pythonImported(relPath string, ref *symbols.Ref, imports []symbols.Import) (*pythonModule, bool) {
+	for _, imp := range imports {
+		if imp.Line == ref.StartLine && imp.Name == ref.Name {
+			return r.pythonFromImport(relPath, imp)
+		}
+	}
+	return nil, false
+}
+
+// pythonFromImport resolves the name a from-import brings in to the module
+// defining it. ok is false when the name is a submodule or the module is
+// not in the repo.
+func (r *importResolver) pythonFromImport(relPath string, imp symbols.Import) (*pythonModule, bool) {
+	m := r.pythonFrom(relPath, imp.Path)
+	if m == nil || m.file == "" {
+		return nil, false
+	}
+	if target := r.pythonLookup(m, imp.Name, 0); target != nil {
+		return target, true
+	}
+	if r.pythonSubmodule(m, imp.Name) != nil {
+		return nil, false
+	}
+	return m, true // defined dynamically, or not at all
+}
+
+// pythonQualified resolves a ref written as module.name, where module is a
+// dotted path starting at an imported name.
+func (r *importResolver) pythonQualified(relPath string, ref *symbols.Ref, bindings map[string]symbols.Import) (*pythonModule, bool) {
+	segs := strings.Split(ref.Qualifier, ".")
+	imp, bound := bindings[segs[0]]
+	if !bound {
+		return nil, false // a local variable, self, a builtin
+	}
+	var m *pythonModule
+	switch {
+	case imp.Name != "":
+		// from pkg import mod: a submodule unless pkg defines the name
+		from := r.pythonFrom(relPath, imp.Path)
+		if from == nil || r.pythonLookup(from, imp.Name, 0) != nil {
+			return nil, false
+		}
+		m = r.pythonSubmodule(from, imp.Name)
+	case imp.Alias != "":
+		m = r.pythonFind(relPath, imp.Path)
+	default:
+		// import a.b binds a
+		m = r.pythonFind(relPath, segs[0])
+	}
+	for _, seg := range segs[1:] {
+		if m == nil {
+			return nil, false
+		}
+		m = r.pythonSubmodule(m, seg) // a class or object otherwise: a member
+	}
+	if m == nil || m.file == "" || r.pythonSubmodule(m, ref.Name) != nil {
+		return nil, false
+	}
+	if target := r.pythonLookup(m, ref.Name, 0); target != nil {
+		return target, true
+	}
+	return m, true
+}
+
+// pythonLookup returns the module defining name as seen from module m,
+// following re-exports, or nil.
+func (r *importResolver) pythonLookup(m *pythonModule, name string, depth int) *pythonModule {
+	if m == nil || m.file == "" || depth > pythonMaxImportDepth {
+		return nil
+	}
+	items := r.pythonItems(m.file)
+	if items == nil {
+		return nil
+	}
+	if items.defs[name] {
+		return m
+	}
+	for _, imp := range slices.Backward(items.imports) {
+		if imp.Name != "" && imp.Name != "*" && pythonBinding(imp) == name {
+			return r.pythonLookup(r.pythonFrom(m.file, imp.Path), imp.Name, depth+1)
+		}
+	}
+	for _, imp := range items.imports {
+		if imp.Name != "*" {
+			continue
+		}
+		if from := r.pythonFrom(m.file, imp.Path); r.pythonExported(from, name) {
+			if target := r.pythonLookup(from, name, depth+1); target != nil {
+				return target
+			}
+		}
+	}
+	return nil
+}
+
+// pythonExported reports whether from m import * brings in name.
+func (r *importResolver) pythonExported(m *pythonModule, name string) bool {
+	if m == nil || m.file == "" {
+		return false
+	}
+	items := r.pythonItems(m.file)
+	if items == nil {
+		return false
+	}
+	if items.exports != nil {
+		return slices.Contains(items.exports, name)
+	}
+	return !strings.HasPrefix(name, "_")
+}
+
+// pythonFrom returns the module a from-import in file reads from: a
+// relative path (.x, ..) is based on the file's package directory.
+func (r *importResolver) pythonFrom(file, path string) *pythonModule {
+	rest := strings.TrimLeft(path, ".")
+	dots := len(path) - len(rest)
+	if dots == 0 {
+		return r.pythonFind(file, path)
+	}
+	dir := filepath.Dir(file)
+	for range dots - 1 {
+		if dir == "." {
+			return nil
+		}
+		dir = filepath.Dir(dir)
+	}
+	m := r.pythonPackage(dir, r.pythonFilePath(filepath.Join(dir, "__init__.py")))
+	for _, seg := range strings.Split(rest, ".") {
+		if seg == "" || m == nil {
+			break
+		}
+		m = r.pythonSubmodule(m, seg)
+	}
+	return m
+}
+
+// pythonFind resolves an absolute module path imported by file.
+func (r *importResolver) pythonFind(file, path string) *pythonModule {
+	segs := strings.Split(path, ".")
+	for _, root := range r.pythonRoots(file) {
+		m := r.pythonPackageOrModule(root, segs[0], segs[0])
+		for _, seg := range segs[1:] {
+			if m == nil {
+				break
+			}
+			m = r.pythonSubmodule(m, seg)
+		}
+		if m != nil {
+			return m
+		}
+	}
+	return nil
+}
+
+// pythonRoots returns the directories absolute imports in file are looked
+// up in, most specific first.
+func (r *importResolver) pythonRoots(file string) []string {
+	top := r.pythonTopDir(file)
+	r.mu.Lock()
+	configured := r.sourceRoots
+	r.mu.Unlock()
+
+	var roots []string
+	add := func(dir string) {
+		if !slices.Contains(roots, dir) {
+			roots = append(roots, dir)
+		}
+	}
+	add(top)
+	for _, root := range configured {
+		add(root)
+	}
+	for dir := top; ; dir = filepath.Dir(dir) {
+		add(dir)
+		add(filepath.Join(dir, "src"))
+		if dir == "." {
+			break
+		}
+	}
+	return roots
+}
+
+// pythonTopDir returns the directory above the outermost package holding
+// file, i.e. the directory its module path is relative to.
+func (r *importResolver) pythonTopDir(file string) string {
+	dir := filepath.Dir(file)
+	for dir != "." && r.isFile(filepath.Join(dir, "__init__.py")) {
+		dir = filepath.Dir(dir)
+	}
+	return dir
+}
+
+// pythonFilePath returns the dotted module path of a .py file; an
+// __init__.py is its package.
+func (r *importResolver) pythonFilePath(file string) string {
+	rel, err := filepath.Rel(r.pythonTopDir(file), strings.TrimSuffix(file, ".py"))
+	if err != nil {
+		return ""
+	}
+	path := strings.ReplaceAll(filepath.ToSlash(rel), "/", ".")
+	if path == "__init__" {
+		return ""
+	}
+	return strings.TrimSuffix(path, ".__init__")
+}
+
+// pythonSubmodule returns the module or package name inside package m, or
+// nil.
+func (r *importResolver) pythonSubmodule(m *pythonModule, name string) *pythonModule {
+	if m == nil || m.dir == "" {
+		return nil
+	}
+	path := name
+	if m.path != "" {
+		path = m.path + "." + name
+	}
+	return r.pythonPackageOrModule(m.dir, name, path)
+}
+
+// pythonPackageOrModule returns dir/name.py or the package dir/name, with
+// the given dotted path, or nil.
+func (r *importResolver) pythonPackageOrModule(dir, name, path string) *pythonModule {
+	file := filepath.Join(dir, name)
+	if r.isFile(file + ".py") {
+		return &pythonModule{path: path, file: file + ".py"}
+	}
+	return r.pythonPackage(file, path)
+}
+
+// pythonPackage returns the package in dir: a regular package with an
+// __init__.py, or a namespace package holding .py files. It returns nil
+// for any other directory.
+func (r *importResolver) pythonPackage(dir, path string) *pythonModule {
+	if init := filepath.Join(dir, "__init__.py"); r.isFile(init) {
+		return &pythonModule{path: path, file: init, dir: dir}
+	}
+	entries, err := os.ReadDir(filepath.Join(r.repoRoot, dir))
+	if err != nil {
+		return nil
+	}
+	for _, e := range entries {
+		if filepath.Ext(e.Name()) == ".py" || (e.IsDir() && r.isFile(filepath.Join(dir, e.Name(), "__init__.py"))) {
+			return &pythonModule{path: path, dir: dir}
+		}
+	}
+	return nil
+}
+
+func (r *importResolver) isFile(relPath string) bool {
+	info, err := os.Stat(filepath.Join(r.repoRoot, relPath))
+	return err == nil && !info.IsDir()
+}
+
+// pythonItems returns the module-level names of a repo-relative .py file,
+// parsing it unless cached.
+func (r *importResolver) pythonItems(file string) *pythonFileItems {
+	abs := filepath.Join(r.repoRoot, file)
+	info, err := os.Stat(abs)
+	if err != nil {
+		return nil
+	}
+	r.mu.Lock()
+	items, ok := r.pythonModules[file]
+	r.mu.Unlock()
+	if ok && items.modTime.Equal(info.ModTime()) {
+		return items
+	}
+
+	parser := GetParser(LangPython)
+	if parser == nil {
+		return nil
+	}
+	src, err := os.ReadFile(abs)
+	if err != nil {
+		return nil
+	}
+	fr, err := parser.Parse(abs, src)
+	if err != nil {
+		return nil
+	}
+	items = pythonItemsOf(fr, src)
+	items.modTime = info.ModTime()
+	r.mu.Lock()
+	r.pythonModules[file] = items
+	r.mu.Unlock()
+	return items
+}
+
+// pythonItemsOf collects the top-level definitions and the imports of a
+// parsed file. Top-level statements start in the first column.
+func pythonItemsOf(fr *symbols.FileResult, src []byte) *pythonFileItems {
+	items := &pythonFileItems{defs: map[string]bool{}, imports: fr.Imports, exports: fr.Exports}
+	lines := strings.Split(string(src), "\n")
+	for _, s := range fr.Symbols {
+		switch s.Kind {
+		case symbols.KindMethod, symbols.KindProperty, symbols.KindField:
+			continue
+		}
+		if s.StartLine < 1 || s.StartLine > len(lines) {
+			continue
+		}
+		if line := lines[s.StartLine-1]; line != "" && line[0] != ' ' && line[0] != '\t' {
+			items.defs[s.Name] = true
+		}
+	}
+	return items
+}
+
+// pythonBinding returns the name an import binds in the importing module.
+func pythonBinding(imp symbols.Import) string {
+	switch {
+	case imp.Alias != "":
+		return imp.Alias
+	case imp.Name != "":
+		return imp.Name
+	}
+	first, _, _ := strings.Cut(imp.Path, ".")
+	return first
+}
@@ -0,0 +1,131 @@
+package indexer
+
+import "testing"
+
+// pyimportsRoot is the source root of the pyimports fixture.
+const pyimportsRoot = "pyimports"
+
+func TestPythonRefsResolveThroughImports(t *testing.T) {
+	mainFile := fixturePath(pyimportsRoot, "main.py")
+	checkoutFile := fixturePath(pyimportsRoot, "src", "shop", "orders", "checkout.py")
+	helpersFile := fixturePath(pyimportsRoot, "src", "shop", "util", "helpers.py")
+	tests := []struct {
+		file       string
+		line, col  int
+		name       string
+		importPath string
+		resolved   string
+	}{
+		// import a.b as c
+		{mainFile, 8, 8, "render", "shop.catalog", fixturePath(pyimportsRoot, "src", "shop", "catalog.py")},
+		// from package import submodule
+		{mainFile, 9, 16, "render", "shop.util.helpers", helpersFile},
+		// re-exported by the package's __init__.py
+		{mainFile, 10, 4, "search", "shop.catalog", fixturePath(pyimportsRoot, "src", "shop", "catalog.py")},
+		{mainFile, 11, 4, "Cart", "shop.cart", fixturePath(pyimportsRoot, "src", "shop", "cart.py")},
+		// the attribute of a value is not resolved
+		{mainFile, 11, 11, "render", "", ""},
+		// star import restricted to __all__
+		{mainFile, 12, 4, "shout", "shop.util.helpers", helpersFile},
+		{mainFile, 13, 4, "whisper", "", ""},
+		// relative imports and the module's own definitions
+		{checkoutFile, 5, 19, "Cart", "shop.cart", fixturePath(pyimportsRoot, "src", "shop", "cart.py")},
+		{checkoutFile, 6, 12, "render", "shop.util.helpers", helpersFile},
+		{checkoutFile, 7, 11, "render", "shop.orders.checkout", checkoutFile},
+	}
+	for _, tt := range tests {
+		usages, err := sharedNav.FindUsagesByName(tt.name, "", "python")
+		if err != nil {
+			t.Fatalf("FindUsagesByName(%s): %v", tt.name, err)
+		}
+		if u := usageAt(t, usages, tt.file, tt.line, tt.col); u != nil && (u.ImportPath != tt.importPath || u.ResolvedPath != tt.resolved) {
+			t.Errorf("%s at %s:%d:%d: import %q resolved %q, want %q %q",
+				tt.name, tt.file, tt.line, tt.col, u.ImportPath, u.ResolvedPath, tt.importPath, tt.resolved)
+		}
+	}
+}
+
+func TestPythonGoToDefinitionFollowsModules(t *testing.T) {
+	mainFile := fixturePath(pyimportsRoot, "main.py")
+	tests := []struct {
+		line, col int
+		wantPath  string
+	}{
+		{8, 8, fixturePath(pyimportsRoot, "src", "shop", "catalog.py")},
+		{9, 16, fixturePath(pyimportsRoot, "src", "shop", "util", "helpers.py")},
+		{10, 4, fixturePath(pyimportsRoot, "src", "shop", "catalog.py")},
+	}
+	for _, tt := range tests {
+		defs, err := sharedNav.GoToDefinitionByPosition(mainFile, tt.line, tt.col, "python")
+		if err != nil {
+			t.Fatalf("GoToDefinitionByPosition(%d:%d): %v", tt.line, tt.col, err)
+		}
+		if len(defs) != 1 || defs[0].Location.Path != tt.wantPath {
+			t.Errorf("%d:%d resolved to %+v, want exactly %s", tt.line, tt.col, defs, tt.wantPath)
+		}
+	}
+}
+
+func TestPythonFindUsagesDropsOtherModules(t *testing.T) {
+	mainFile := fixturePath(pyimportsRoot, "main.py")
+	defs, err := sharedNav.GoToDefinitionByName("render", "", "python")
+	if err != nil {
+		t.Fatal(err)
+	}
+	usages, err := sharedNav.FindUsagesByName("render", "", "python")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	check := func(defPath string, keepLine, dropLine int) {
+		t.Helper()
+		var primary *DefinitionResult
+		for i := range defs {
+			if defs[i].Location.Path == defPath {
+				primary = &defs[i]
+			}
+		}
+		if primary == nil {
+			t.Fatalf("no render in %s", defPath)
+		}
+
+		kept := false
+		for _, r := range ResolveAndFilterUsages(usages, defs, primary, sharedRepoRoot, NoiseFilterOptions{}) {
+			if r.Location.Path != mainFile {
+				continue
+			}
+			switch r.Location.StartLine {
+			case keepLine:
+				kept = true
+				if r.ResolutionConfidence != 1.0 {
+					t.Errorf("line %d: resolution confidence %v, want 1.0", keepLine, r.ResolutionConfidence)
+				}
+			case dropLine:
+				t.Errorf("usage of %s kept at line %d, which resolves elsewhere", defPath, dropLine)
+			}
+		}
+		if !kept {
+			t.Errorf("usage of %s at line %d was dropped", defPath, keepLine)
+		}
+	}
+
+	check(fixturePath(pyimportsRoot, "src", "shop", "catalog.py"), 8, 9)
+	check(fixturePath(pyimportsRoot, "src", "shop", "util", "helpers.py"), 9, 8)
+}
+
+func TestPythonStaleResolutionIsUndecided(t *testing.T) {
+	// main.py imported Cart through shop/__init__.py, which now re-exports
+	// it from another module.
+	usage := UsageResult{
+		Name:         "Cart",
+		ImportPath:   "shop.cart",
+		ResolvedPath: "src/shop/cart.py",
+		Location:     Location{Path: "main.py", StartLine: 4},
+	}
+	moved := []DefinitionResult{
+		{Name: "Cart", Kind: "class", Location: Location{Path: "src/shop/basket.py"}},
+	}
+	if matches, ok := importResolution(usage, moved); ok {
+		t.Errorf("resolution into a module without Cart decided %v, want undecided", matches)
+	}
+}
@@ -0,0 +1,606 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// Rust refs resolve through the crate's module tree. A file's module path
+// follows from its place below the crate root (src/lib.rs or src/main.rs;
+// a/b.rs and a/b/mod.rs are both crate::a::b) and from the mod items that
+// declare it; inline mod blocks live in the file declaring them. A path is
+// resolved segment by segment starting from crate::, self::, super::, a
+// name brought in by use, a child module or item of the current module, or
+// a dependency crate, following use and pub use re-exports of every module
+// it passes through.
+
+// rustMaxUseDepth bounds the number of use declarations one path may go
+// through, guarding against glob re-export cycles.
+const rustMaxUseDepth = 8
+
+// rustExternalCrates are always in scope and never part of the repo.
+var rustExternalCrates = map[string]bool{
+	"std":        true,
+	"core":       true,
+	"alloc":      true,
+	"proc_macro": true,
+	"test":       true,
+}
+
+// rustCrate is a package found through a Cargo.toml inside the repo.
+type rustCrate struct {
+	name    string            // crate name as written in paths ("-" as "_"), "" for a virtual manifest
+	root    string            // repo-relative library (or binary) root file, "" if none
+	deps    map[string]string // in-repo dependency crate name -> repo-relative crate dir
+	extern  map[string]bool   // dependency crate names outside the repo
+	modTime time.Time
+}
+
+// rustFileItems are the module-level declarations of a .rs file. Items of
+// inline mod blocks are attributed to the file.
+type rustFileItems struct {
+	items   map[string]symbols.SymbolKind
+	uses    []symbols.Import
+	modTime time.Time
+}
+
+// rustModule is a module of a crate's module tree.
+type rustModule struct {
+	crate  *rustCrate
+	file   string      // repo-relative file holding the module's items
+	path   []string    // module path below the crate root
+	inline bool        // declared by a mod block inside file
+	parent *rustModule // nil for a crate root
+}
+
+// rustTarget is what a path resolves to.
+type rustTarget struct {
+	file     string // repo-relative file declaring the target, "" when external
+	path     string // canonical module path, followed by ::Type for a member
+	external bool   // the path starts at a crate outside the repo
+}
+
+// resolveRust records the module path and defining file of every ref in a
+// Rust file.
+func (r *importResolver) resolveRust(relPath string, fr *symbols.FileResult) {
+	own := rustItemsOf(fr)
+	if info, err := os.Stat(filepath.Join(r.repoRoot, relPath)); err == nil {
+		own.modTime = info.ModTime()
+		r.mu.Lock()
+		r.rustModules[relPath] = own
+		r.mu.Unlock()
+	}
+
+	mod := r.rustModuleOf(relPath)
+	if mod == nil {
+		return // outside a crate's module tree, paths cannot be mapped to files
+	}
+
+	src, err := os.ReadFile(filepath.Join(r.repoRoot, relPath))
+	if err != nil {
+		return
+	}
+	lines := strings.Split(string(src), "\n")
+
+	resolved := map[string]*rustTarget{}
+	for i := range fr.Refs {
+		ref := &fr.Refs[i]
+		// value.field and value.method() are not paths
+		if isMemberAccess(lines, ref) || strings.ContainsAny(ref.Name, ":*") {
+			continue
+		}
+		path := ref.Name
+		if ref.Qualifier != "" {
+			path = ref.Qualifier + "::" + ref.Name
+		}
+		t, ok := resolved[path]
+		if !ok {
+			t = r.resolveRustPath(mod, strings.Split(path, "::"), 0)
+			resolved[path] = t
+		}
+		if t != nil {
+			ref.ImportPath, ref.ResolvedPath = t.path, t.file
+			ref.IsExternal = t.external
+		}
+	}
+}
+
+// resolveRustPath resolves a path written in module mod, or returns nil
+// when it cannot be resolved.
+func (r *importResolver) resolveRustPath(mod *rustModule, segs []string, depth int) *rustTarget {
+	if depth > rustMaxUseDepth || len(segs) == 0 {
+		return nil
+	}
+	cur := mod
+	switch first := segs[0]; first {
+	case "crate":
+		cur, segs = mod.crateRoot(), segs[1:]
+	case "self":
+		segs = segs[1:]
+	case "super":
+		for len(segs) > 0 && segs[0] == "super" {
+			if cur.parent == nil {
+				return nil
+			}
+			cur, segs = cur.parent, segs[1:]
+		}
+	case "Self":
+		return nil // the enclosing impl type is not known here
+	default:
+		if use, ok := r.rustUse(cur, first); ok {
+			return r.resolveRustPath(cur, append(use, segs[1:]...), depth+1)
+		}
+		if r.rustItemKind(cur, first) != symbols.KindUnknown || r.rustChild(cur, first) != nil {
+			break
+		}
+		if root := r.rustExternRoot(cur, first); root != nil {
+			cur, segs = root, segs[1:]
+		} else if rustExternalCrates[first] || cur.crate.extern[first] {
+			return &rustTarget{path: strings.Join(segs[:max(len(segs)-1, 1)], "::"), external: true}
+		}
+	}
+	return r.walkRustPath(cur, segs, depth)
+}
+
+// walkRustPath resolves the remaining segments of a path from module cur.
+func (r *importResolver) walkRustPath(cur *rustModule, segs []string, depth int) *rustTarget {
+	for j, seg := range segs {
+		if j < len(segs)-1 {
+			if child := r.rustChild(cur, seg); child != nil {
+				cur = child
+				continue
+			}
+		}
+		if r.rustItemKind(cur, seg) != symbols.KindUnknown {
+			switch len(segs) - j {
+			case 1:
+				return &rustTarget{file: cur.file, path: cur.canonicalPath()}
+			case 2:
+				return &rustTarget{file: cur.file, path: cur.canonicalPath() + "::" + seg}
+			}
+			return nil
+		}
+		if use, ok := r.rustUse(cur, seg); ok {
+			return r.resolveRustPath(cur, append(use, segs[j+1:]...), depth+1)
+		}
+		for _, glob := range r.rustGlobs(cur) {
+			if t := r.resolveRustPath(cur, append(glob, segs[j:]...), depth+1); t != nil && !t.external {
+				return t
+			}
+		}
+		return nil
+	}
+	return nil
+}
+
+// rustModuleOf returns the module held by a repo-relative .rs file, or nil
+// when the file is not part of a crate's module tree.
+func (r *importResolver) rustModuleOf(relPath string) *rustModule {
+	crate := r.rustCrateFor(filepath.Dir(relPath))
+	if crate == nil {
+		return nil
+	}
+	if crate.root == "" || relPath == crate.root {
+		return &rustModule{crate: crate, file: relPath}
+	}
+	rel, err := filepath.Rel(filepath.Dir(crate.root), relPath)
+	if err != nil || rel == ".." || strings.HasPrefix(rel, ".."+string(filepath.Separator)) ||
+		rel == "main.rs" || strings.HasPrefix(rel, "bin"+string(filepath.Separator)) {
+		// A crate root of its own: a binary next to the library, or a
+		// test, example or bench target.
+		return &rustModule{crate: crate, file: relPath}
+	}
+
+	parts := strings.Split(strings.TrimSuffix(filepath.ToSlash(rel), ".rs"), "/")
+	if parts[len(parts)-1] == "mod" {
+		parts = parts[:len(parts)-1]
+	}
+	m := &rustModule{crate: crate, file: crate.root}
+	for _, name := range parts {
+		if m = r.rustChild(m, name); m == nil {
+			return nil // no mod item declares the file
+		}
+	}
+	if m.file != relPath {
+		return nil
+	}
+	return m
+}
+
+// rustChild returns the child module name declared by a mod item of m, or
+// nil.
+func (r *importResolver) rustChild(m *rustModule, name string) *rustModule {
+	if r.rustItemKind(m, name) != symbols.KindModule {
+		return nil
+	}
+	dir := rustChildDir(m)
+	for _, file := range []string{filepath.Join(dir, name+".rs"), filepath.Join(dir, name, "mod.rs")} {
+		if info, err := os.Stat(filepath.Join(r.repoRoot, file)); err == nil && !info.IsDir() {
+			return m.child(name, file, false)
+		}
+	}
+	return m.child(name, m.file, true)
+}
+
+// rustChildDir returns the directory holding the files of m's child modules.
+func rustChildDir(m *rustModule) string {
+	switch {
+	case m.inline:
+		return filepath.Join(rustChildDir(m.parent), m.path[len(m.path)-1])
+	case m.parent == nil || filepath.Base(m.file) == "mod.rs":
+		return filepath.Dir(m.file)
+	}
+	return strings.TrimSuffix(m.file, ".rs")
+}
+
+func (m *rustModule) child(name, file string, inline bool) *rustModule {
+	path := append(append([]string{}, m.path...), name)
+	return &rustModule{crate: m.crate, file: file, path: path, inline: inline, parent: m}
+}
+
+func (m *rustModule) crateRoot() *rustModule {
+	for m.parent != nil {
+		m = m.parent
+	}
+	return m
+}
+
+// canonicalPath returns the module path starting with the crate name.
+func (m *rustModule) canonicalPath() string {
+	return strings.Join(append([]string{m.crate.name}, m.path...), "::")
+}
+
+// rustExternRoot returns the root module of the in-repo crate name as seen
+// from m: a path dependency, a workspace dependency, or the library of m's
+// own package when m belongs to one of its other targets.
+func (r *importResolver) rustExternRoot(m *rustModule, name string) *rustModule {
+	crate := m.crate
+	if name == crate.name && crate.root != "" && m.crateRoot().file != crate.root {
+		return &rustModule{crate: crate, file: crate.root}
+	}
+	dir, ok := crate.deps[name]
+	if !ok {
+		return nil
+	}
+	dep := r.loadRustCrate(dir)
+	if dep == nil || dep.root == "" {
+		return nil
+	}
+	return &rustModule{crate: dep, file: dep.root}
+}
+
+// rustItemKind returns the kind of the module-level item name of m, or
+// KindUnknown. A mod item wins over a same-name function or value.
+func (r *importResolver) rustItemKind(m *rustModule, name string) symbols.SymbolKind {
+	items := r.rustItems(m.file)
+	if items == nil {
+		return symbols.KindUnknown
+	}
+	return items.items[name]
+}
+
+// rustUse returns the path imported under name by a use declaration of m.
+func (r *importResolver) rustUse(m *rustModule, name string) ([]string, bool) {
+	items := r.rustItems(m.file)
+	if items == nil {
+		return nil, false
+	}
+	for _, u := range items.uses {
+		if rustUseName(u) == name {
+			return strings.Split(u.Path, "::"), true
+		}
+	}
+	return nil, false
+}
+
+// rustGlobs returns the prefixes of the glob imports (use a::b::*) of m.
+func (r *importResolver) rustGlobs(m *rustModule) [][]string {
+	items := r.rustItems(m.file)
+	if items == nil {
+		return nil
+	}
+	var globs [][]string
+	for _, u := range items.uses {
+		if prefix, ok := strings.CutSuffix(u.Path, "::*"); ok {
+			globs = append(globs, strings.Split(prefix, "::"))
+		}
+	}
+	return globs
+}
+
+// rustUseName returns the local name a use declaration binds, or "" for
+// globs and use a as _.
+func rustUseName(u symbols.Import) string {
+	switch {
+	case u.Alias == "_":
+		return ""
+	case u.Alias != "":
+		return u.Alias
+	case strings.HasSuffix(u.Path, "*"):
+		return ""
+	}
+	return u.Path[strings.LastIndex(u.Path, ":")+1:]
+}
+
+// rustItems returns the items of a repo-relative .rs file, parsing it when
+// not cached or changed on disk.
+func (r *importResolver) rustItems(file string) *rustFileItems {
+	abs := filepath.Join(r.repoRoot, file)
+	info, err := os.Stat(abs)
+	if err != nil {
+		return nil
+	}
+	r.mu.Lock()
+	items, ok := r.rustModules[file]
+	r.mu.Unlock()
+	if ok && items.modTime.Equal(info.ModTime()) {
+		return items
+	}
+
+	parser := GetParser(LangRust)
+	if parser == nil {
+		return nil
+	}
+	src, err := os.ReadFile(abs)
+	if err != nil {
+		return nil
+	}
+	fr, err := parser.Parse(abs, src)
+	if err != nil {
+		return nil
+	}
+	items = rustItemsOf(fr)
+	items.modTime = info.ModTime()
+	r.mu.Lock()
+	r.rustModules[file] = items
+	r.mu.Unlock()
+	return items
+}
+
+// rustItemsOf collects the module-level items and use declarations of a
+// parsed file.
+func rustItemsOf(fr *symbols.FileResult) *rustFileItems {
+	items := &rustFileItems{items: map[string]symbols.SymbolKind{}, uses: fr.Imports}
+	for _, s := range fr.Symbols {
+		if s.ContainerName != "" || items.items[s.Name] == symbols.KindModule {
+			continue
+		}
+		switch s.Kind {
+		case symbols.KindVariable, symbols.KindMethod, symbols.KindField:
+			continue // let bindings and members
+		}
+		items.items[s.Name] = s.Kind
+	}
+	return items
+}
+
+// rustCrateFor returns the crate whose Cargo.toml is closest above the
+// repo-relative directory dir, or nil.
+func (r *importResolver) rustCrateFor(dir string) *rustCrate {
+	for {
+		if c := r.loadRustCrate(dir); c != nil {
+			return c
+		}
+		parent := filepath.Dir(dir)
+		if parent == dir || dir == "." {
+			return nil
+		}
+		dir = parent
+	}
+}
+
+// loadRustCrate reads <dir>/Cargo.toml, reusing the cached result while the
+// file is unchanged. It returns nil for a missing or virtual manifest.
+func (r *importResolver) loadRustCrate(dir string) *rustCrate {
+	manifest := filepath.Join(r.repoRoot, dir, "Cargo.toml")
+	info, err := os.Stat(manifest)
+	if err != nil {
+		return nil
+	}
+	r.mu.Lock()
+	c, ok := r.rustCrates[dir]
+	r.mu.Unlock()
+	if !ok || !c.modTime.Equal(info.ModTime()) {
+		data, err := os.ReadFile(manifest)
+		if err != nil {
+			return nil
+		}
+		c = r.rustCrateFromManifest(dir, parseCargoToml(string(data)))
+		c.modTime = info.ModTime()
+		r.mu.Lock()
+		r.rustCrates[dir] = c
+		r.mu.Unlock()
+	}
+	if c.name == "" {
+		return nil
+	}
+	return c
+}
+
+func (r *importResolver) rustCrateFromManifest(dir string, m *cargoManifest) *rustCrate {
+	c := &rustCrate{deps: map[string]string{}, extern: map[string]bool{}}
+	if m.packageName == "" {
+		return c
+	}
+	c.name = rustCrateName(m.packageName)
+	if m.libName != "" {
+		c.name = rustCrateName(m.libName)
+	}
+	for _, root := range []string{m.libPath, "src/lib.rs", "src/main.rs"} {
+		if root == "" {
+			continue
+		}
+		file := filepath.Join(dir, filepath.FromSlash(root))
+		if info, err := os.Stat(filepath.Join(r.repoRoot, file)); err == nil && !info.IsDir() {
+			c.root = file
+			break
+		}
+	}
+
+	var wsDir string
+	var wsDeps map[string]cargoDep
+	for key, dep := range m.deps {
+		base := dir
+		if dep.workspace {
+			if wsDeps == nil {
+				wsDir, wsDeps = r.cargoWorkspaceDeps(dir)
+			}
+			dep, base = wsDeps[key], wsDir
+		}
+		name := rustCrateName(key)
+		local := ""
+		if dep.path != "" {
+			local = filepath.Join(base, filepath.FromSlash(dep.path))
+		}
+		if local == "" || local == ".." || strings.HasPrefix(local, ".."+string(filepath.Separator)) {
+			c.extern[name] = true
+		} else {
+			c.deps[name] = local
+		}
+	}
+	return c
+}
+
+// cargoWorkspaceDeps returns the directory and [workspace.dependencies] of
+// the workspace enclosing the crate in dir.
+func (r *importResolver) cargoWorkspaceDeps(dir string) (string, map[string]cargoDep) {
+	for {
+		if data, err := os.ReadFile(filepath.Join(r.repoRoot, dir, "Cargo.toml")); err == nil {
+			if m := parseCargoToml(string(data)); m.workspace {
+				return dir, m.workspaceDeps
+			}
+		}
+		parent := filepath.Dir(dir)
+		if parent == dir || dir == "." {
+			return "", map[string]cargoDep{}
+		}
+		dir = parent
+	}
+}
+
+// rustCrateName turns a package name into the name used in paths.
+func rustCrateName(name string) string {
+	return strings.ReplaceAll(name, "-", "_")
+}
+
+// cargoManifest holds the parts of a Cargo.toml used to build module paths.
+type cargoManifest struct {
+	packageName   string
+	libName       string
+	libPath       string
+	workspace     bool                // has a [workspace] table
+	deps          map[string]cargoDep // by dependency key, all dependency tables
+	workspaceDeps map[string]cargoDep // [workspace.dependencies]
+}
+
+// cargoDep is one dependency of a Cargo.toml.
+type cargoDep struct {
+	path      string // local path relative to the manifest, "" for registry and git deps
+	workspace bool   // inherited from [workspace.dependencies]
+}
+
+var (
+	cargoSectionRe   = regexp.MustCompile(`^\[\[?\s*([^\]]+?)\s*\]\]?$`)
+	cargoKeyValueRe  = regexp.MustCompile(`^([A-Za-z0-9_.\-"]+)\s*=\s*(.*)$`)
+	cargoPathRe      = regexp.MustCompile(`\bpath\s*=\s*"([^"]*)"`)
+	cargoWorkspaceRe = regexp.MustCompile(`\bworkspace\s*=\s*true\b`)
+)
+
+// parseCargoToml reads the package and lib names, the lib path and the
+// dependencies of a Cargo.toml, line by line.
+func parseCargoToml(src string) *cargoManifest {
+	m := &cargoManifest{deps: map[string]cargoDep{}, workspaceDeps: map[string]cargoDep{}}
+	section := ""
+	for _, line := range strings.Split(src, "\n") {
+		if i := strings.Index(line, "#"); i >= 0 {
+			line = line[:i]
+		}
+		line = strings.TrimSpace(line)
+		if line == "" {
+			continue
+		}
+		if sm := cargoSectionRe.FindStringSubmatch(line); sm != nil {
+			section = sm[1]
+			if section == "workspace" || strings.HasPrefix(section, "workspace.") {
+				m.workspace = true
+			}
+			continue
+		}
+		kv := cargoKeyValueRe.FindStringSubmatch(line)
+		if kv == nil {
+			continue
+		}
+		key, value := strings.Trim(kv[1], `"`), kv[2]
+
+		switch section {
+		case "package":
+			if key == "name" {
+				m.packageName = cargoString(value)
+			}
+			continue
+		case "lib":
+			switch key {
+			case "name":
+				m.libName = cargoString(value)
+			case "path":
+				m.libPath = cargoString(value)
+			}
+			continue
+		}
+
+		deps, name, ok := m.dependencyTable(section)
+		if !ok {
+			continue
+		}
+		attr := ""
+		if name == "" {
+			// name = "1.0", name = { path = "..." } or name.path = "..."
+			name, attr, _ = strings.Cut(key, ".")
+		} else {
+			attr = key // [dependencies.name] table
+		}
+		dep := deps[name]
+		switch attr {
+		case "":
+			if pm := cargoPathRe.FindStringSubmatch(value); pm != nil {
+				dep.path = pm[1]
+			}
+			dep.workspace = cargoWorkspaceRe.MatchString(value)
+		case "path":
+			dep.path = cargoString(value)
+		case "workspace":
+			dep.workspace = cargoString(value) == "true"
+		}
+		deps[name] = dep
+	}
+	return m
+}
+
+// dependencyTable maps a section name to the dependency map it fills and,
+// for [dependencies.name] tables, the dependency name.
+func (m *cargoManifest) dependencyTable(section string) (map[string]cargoDep, string, bool) {
+	parts := strings.Split(section, ".")
+	for i := len(parts) - 1; i >= 0 && i >= len(parts)-2; i-- {
+		switch parts[i] {
+		case "dependencies", "dev-dependencies", "build-dependencies":
+			name := ""
+			if i == len(parts)-2 {
+				name = strings.Trim(parts[i+1], `"`)
+			}
+			if parts[0] == "workspace" {
+				return m.workspaceDeps, name, true
+			}
+			return m.deps, name, true
+		}
+	}
+	return nil, "", false
+}
+
+func cargoString(value string) string {
+	return strings.Trim(strings.TrimSpace(value), `"'`)
+}
@@ -0,0 +1,181 @@
+package indexer
+
+import "testing"
+
+func TestParseCargoToml(t *testing.T) {
+	src := `[package]
+name = "shop-server" # the binary
+version = "0.1.0"
+
+[lib]
+name = "shop"
+path = "src/shop.rs"
+
+[dependencies]
+serde = "1"
+shop-settings = { path = "../settings", version = "0.1" }
+shop-db.workspace = true
+tokio = { version = "1", features = ["full"] }
+
+[dev-dependencies.shop-testing]
+path = "../testing"
+
+[workspace.dependencies]
+shop-db = { path = "crates/db" }
+`
+	m := parseCargoToml(src)
+	if m.packageName != "shop-server" || m.libName != "shop" || m.libPath != "src/shop.rs" {
+		t.Errorf("package %q lib %q path %q", m.packageName, m.libName, m.libPath)
+	}
+	if !m.workspace {
+		t.Error("expected a [workspace] table")
+	}
+	want := map[string]cargoDep{
+		"serde":         {},
+		"shop-settings": {path: "../settings"},
+		"shop-db":       {workspace: true},
+		"tokio":         {},
+		"shop-testing":  {path: "../testing"},
+	}
+	if len(m.deps) != len(want) {
+		t.Errorf("deps = %+v, want %+v", m.deps, want)
+	}
+	for name, dep := range want {
+		if m.deps[name] != dep {
+			t.Errorf("dep %s = %+v, want %+v", name, m.deps[name], dep)
+		}
+	}
+	if m.workspaceDeps["shop-db"].path != "crates/db" {
+		t.Errorf("workspace deps = %+v", m.workspaceDeps)
+	}
+}
+
+// rustwsRoot is the source root of the rustws fixture.
+const rustwsRoot = "rustws/crates"
+
+func TestRustRefsResolveThroughModules(t *testing.T) {
+	mainFile := fixturePath(rustwsRoot, "server", "src", "main.rs")
+	routerFile := fixturePath(rustwsRoot, "server", "src", "router.rs")
+	tests := []struct {
+		file       string
+		line, col  int
+		name       string
+		importPath string
+		resolved   string
+	}{
+		// pub use re-export in a workspace dependency, imported under an alias
+		{mainFile, 8, 35, "new", "shop_settings::config::Config", fixturePath(rustwsRoot, "settings", "src", "config.rs")},
+		// child module in http/mod.rs
+		{mainFile, 9, 31, "new", "server::http::Config", fixturePath(rustwsRoot, "server", "src", "http", "mod.rs")},
+		{mainFile, 10, 33, "new", "server::router::Router", routerFile},
+		// std is outside the repo
+		{mainFile, 11, 52, "new", "std::collections::HashMap", ""},
+		// self:: and a use of the module itself (use crate::http::{self})
+		{routerFile, 14, 18, "new", "server::router::Router", routerFile},
+		{routerFile, 14, 37, "new", "server::http::Config", fixturePath(rustwsRoot, "server", "src", "http", "mod.rs")},
+		// super:: import of a type
+		{fixturePath(rustwsRoot, "server", "src", "http", "handlers.rs"), 4, 23, "Config", "server::http", fixturePath(rustwsRoot, "server", "src", "http", "mod.rs")},
+	}
+	for _, tt := range tests {
+		usages, err := sharedNav.FindUsagesByName(tt.name, "", "rust")
+		if err != nil {
+			t.Fatalf("FindUsagesByName(%s): %v", tt.name, err)
+		}
+		if u := usageAt(t, usages, tt.file, tt.line, tt.col); u != nil && (u.ImportPath != tt.importPath || u.ResolvedPath != tt.resolved) {
+			t.Errorf("%s at %s:%d:%d: import %q resolved %q, want %q %q",
+				tt.name, tt.file, tt.line, tt.col, u.ImportPath, u.ResolvedPath, tt.importPath, tt.resolved)
+		}
+	}
+}
+
+func TestRustGoToDefinitionFollowsModulePaths(t *testing.T) {
+	mainFile := fixturePath(rustwsRoot, "server", "src", "main.rs")
+	tests := []struct {
+		line, col int
+		wantPath  string
+	}{
+		{8, 35, fixturePath(rustwsRoot, "settings", "src", "config.rs")},
+		{9, 31, fixturePath(rustwsRoot, "server", "src", "http", "mod.rs")},
+		{10, 33, fixturePath(rustwsRoot, "server", "src", "router.rs")},
+	}
+	for _, tt := range tests {
+		defs, err := sharedNav.GoToDefinitionByPosition(mainFile, tt.line, tt.col, "rust")
+		if err != nil {
+			t.Fatalf("GoToDefinitionByPosition(%d:%d): %v", tt.line, tt.col, err)
+		}
+		if len(defs) != 1 || defs[0].Location.Path != tt.wantPath {
+			t.Errorf("%d:%d resolved to %+v, want exactly %s", tt.line, tt.col, defs, tt.wantPath)
+		}
+	}
+}
+
+func TestRustFindUsagesDropsOtherModules(t *testing.T) {
+	mainFile := fixturePath(rustwsRoot, "server", "src", "main.rs")
+	defs, err := sharedNav.GoToDefinitionByName("new", "", "rust")
+	if err != nil {
+		t.Fatal(err)
+	}
+	usages, err := sharedNav.FindUsagesByName("new", "", "rust")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	check := func(defPath string, keepLine int, dropLines ...int) {
+		t.Helper()
+		var primary *DefinitionResult
+		for i := range defs {
+			if defs[i].Location.Path == defPath {
+				primary = &defs[i]
+			}
+		}
+		if primary == nil {
+			t.Fatalf("no new in %s", defPath)
+		}
+
+		kept := false
+		for _, r := range ResolveAndFilterUsages(usages, defs, primary, sharedRepoRoot, NoiseFilterOptions{}) {
+			if r.Location.Path != mainFile {
+				continue
+			}
+			if r.Location.StartLine == keepLine {
+				kept = true
+				if r.ResolutionConfidence != 1.0 {
+					t.Errorf("line %d: resolution confidence %v, want 1.0", keepLine, r.ResolutionConfidence)
+				}
+			}
+			for _, line := range dropLines {
+				if r.Location.StartLine == line {
+					t.Errorf("usage of %s kept at line %d, which resolves elsewhere", defPath, line)
+				}
+			}
+		}
+		if !kept {
+			t.Errorf("usage of %s at line %d was dropped", defPath, keepLine)
+		}
+	}
+
+	check(fixturePath(rustwsRoot, "settings", "src", "config.rs"), 8, 9, 10, 11)
+	check(fixturePath(rustwsRoot, "server", "src", "http", "mod.rs"), 9, 8, 10, 11)
+}
+
+func TestRustStaleResolutionIsUndecided(t *testing.T) {
+	usage := UsageResult{
+		Name:         "new",
+		ImportPath:   "server::router::Router",
+		ResolvedPath: "src/router.rs",
+		Location:     Location{Path: "src/main.rs", StartLine: 3},
+	}
+	moved := []DefinitionResult{
+		{Name: "new", Kind: "method", Container: "Router", Location: Location{Path: "src/routing/router.rs"}},
+	}
+	if matches, ok := importResolution(usage, moved); ok {
+		t.Errorf("resolution into a module without new decided %v, want undecided", matches)
+	}
+
+	current := append(moved, DefinitionResult{
+		Name: "new", Kind: "method", Container: "Router", Location: Location{Path: "src/router.rs"},
+	})
+	if matches, ok := importResolution(usage, current); !ok || len(matches) != 1 || matches[0] != 1 {
+		t.Errorf("importResolution = %v, %v, want [1] true", matches, ok)
+	}
+}
@@ -0,0 +1,892 @@
+package indexer
+
+import (
+	"path"
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// Protocol Buffers and GraphQL schemas are parsed with a small token scanner
+// instead of a tree-sitter grammar: their declaration syntax is regular, and
+// we only need definitions and the type names they reference.
+
+// protoScalars are the built-in proto field types; they produce no refs.
+var protoScalars = map[string]bool{
+	"double": true, "float": true, "bool": true, "string": true, "bytes": true,
+	"int32": true, "int64": true, "uint32": true, "uint64": true,
+	"sint32": true, "sint64": true, "fixed32": true, "fixed64": true,
+	"sfixed32": true, "sfixed64": true,
+}
+
+// graphqlScalars are the built-in GraphQL scalar types; they produce no refs.
+var graphqlScalars = map[string]bool{
+	"ID": true, "String": true, "Int": true, "Float": true, "Boolean": true,
+}
+
+type schemaTokKind int
+
+const (
+	schemaIdent schemaTokKind = iota
+	schemaPunct
+	schemaString
+	schemaNumber
+)
+
+type schemaToken struct {
+	kind schemaTokKind
+	text string
+	line int // 1-based
+	col  int // 0-based byte column
+}
+
+// tokenizeSchema splits a schema into tokens, dropping whitespace and
+// comments. Proto uses C-style comments, GraphQL uses '#' and treats commas
+// as whitespace. GraphQL block strings ("""...""") become one string token.
+func tokenizeSchema(src []byte, lang Lang) []schemaToken {
+	var toks []schemaToken
+	line, lineStart := 1, 0
+	i, n := 0, len(src)
+
+	// skipTo moves i forward to end, keeping line bookkeeping in sync.
+	skipTo := func(end int) {
+		if end > n {
+			end = n
+		}
+		for ; i < end; i++ {
+			if src[i] == '\n' {
+				line++
+				lineStart = i + 1
+			}
+		}
+	}
+	isIdentStart := func(c byte) bool {
+		return c == '_' || (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z')
+	}
+	isIdentPart := func(c byte) bool {
+		return isIdentStart(c) || (c >= '0' && c <= '9')
+	}
+
+	for i < n {
+		c := src[i]
+		start, startLine, startCol := i, line, i-lineStart
+		switch {
+		case c == ' ' || c == '\t' || c == '\r' || c == '\n' || (c == ',' && lang == LangGraphQL):
+			skipTo(i + 1)
+
+		case lang == LangProto && c == '/' && i+1 < n && src[i+1] == '/',
+			lang == LangGraphQL && c == '#':
+			for i < n && src[i] != '\n' {
+				i++
+			}
+
+		case lang == LangProto && c == '/' && i+1 < n && src[i+1] == '*':
+			end := strings.Index(string(src[i+2:]), "*/")
+			if end < 0 {
+				skipTo(n)
+			} else {
+				skipTo(i + 2 + end + 2)
+			}
+
+		case lang == LangGraphQL && strings.HasPrefix(string(src[i:min(i+3, n)]), `"""`):
+			end := strings.Index(string(src[i+3:]), `"""`)
+			if end < 0 {
+				skipTo(n)
+			} else {
+				skipTo(i + 3 + end + 3)
+			}
+			toks = append(toks, schemaToken{schemaString, string(src[start:i]), startLine, startCol})
+
+		case c == '"' || (c == '\'' && lang == LangProto):
+			i++
+			for i < n && src[i] != c && src[i] != '\n' {
+				if src[i] == '\\' {
+					skipTo(i + 2)
+					continue
+				}
+				i++
+			}
+			if i < n && src[i] == c {
+				i++
+			}
+			toks = append(toks, schemaToken{schemaString, string(src[start:min(i, n)]), startLine, startCol})
+
+		case isIdentStart(c):
+			for i < n && isIdentPart(src[i]) {
+				i++
+			}
+			toks = append(toks, schemaToken{schemaIdent, string(src[start:i]), startLine, startCol})
+
+		case c >= '0' && c <= '9':
+			for i < n && (isIdentPart(src[i]) || src[i] == '.') {
+				i++
+			}
+			toks = append(toks, schemaToken{schemaNumber, string(src[start:i]), startLine, startCol})
+
+		case c == '.' && strings.HasPrefix(string(src[i:min(i+3, n)]), "..."):
+			i += 3
+			toks = append(toks, schemaToken{schemaPunct, "...", startLine, startCol})
+
+		default:
+			i++
+			toks = append(toks, schemaToken{schemaPunct, string(c), startLine, startCol})
+		}
+	}
+	return toks
+}
+
+// schemaParser walks the token stream of one schema file.
+type schemaParser struct {
+	toks []schemaToken
+	pos  int
+	res  *symbols.FileResult
+}
+
+func newSchemaParser(src []byte, lang Lang) *schemaParser {
+	return &schemaParser{toks: tokenizeSchema(src, lang), res: &symbols.FileResult{}}
+}
+
+func (p *schemaParser) atEnd() bool { return p.pos >= len(p.toks) }
+
+func (p *schemaParser) peekN(k int) schemaToken {
+	if p.pos+k >= len(p.toks) {
+		return schemaToken{kind: schemaPunct}
+	}
+	return p.toks[p.pos+k]
+}
+
+func (p *schemaParser) peek() schemaToken { return p.peekN(0) }
+
+func (p *schemaParser) isPunct(text string) bool {
+	t := p.peek()
+	return !p.atEnd() && t.kind == schemaPunct && t.text == text
+}
+
+func (p *schemaParser) isIdent(text string) bool {
+	t := p.peek()
+	return !p.atEnd() && t.kind == schemaIdent && t.text == text
+}
+
+// lastLine returns the line of the last token, used to close unterminated blocks.
+func (p *schemaParser) lastLine() int {
+	if len(p.toks) == 0 {
+		return 1
+	}
+	return p.toks[len(p.toks)-1].line
+}
+
+// addSymbol records a definition and returns its index so the caller can
+// set EndLine once the body has been parsed.
+func (p *schemaParser) addSymbol(t schemaToken, name string, kind symbols.SymbolKind, container, signature string) int {
+	p.res.Symbols = append(p.res.Symbols, symbols.Symbol{
+		Name:          name,
+		Kind:          kind,
+		ContainerName: container,
+		Signature:     signature,
+		StartLine:     t.line,
+		StartCol:      t.col,
+		EndLine:       t.line,
+		EndCol:        t.col + len(name),
+	})
+	return len(p.res.Symbols) - 1
+}
+
+func (p *schemaParser) addRef(t schemaToken, kind symbols.RefKind, container, relation string) {
+	p.res.Refs = append(p.res.Refs, symbols.Ref{
+		Name:             t.text,
+		Kind:             kind,
+		Relation:         relation,
+		StartLine:        t.line,
+		StartCol:         t.col,
+		EndLine:          t.line,
+		EndCol:           t.col + len(t.text),
+		ContextContainer: container,
+	})
+}
+
+// skipBalanced skips a bracketed group starting at the current opening
+// token and returns the line of its closing token.
+func (p *schemaParser) skipBalanced() int {
+	depth := 0
+	for !p.atEnd() {
+		t := p.toks[p.pos]
+		p.pos++
+		if t.kind != schemaPunct {
+			continue
+		}
+		switch t.text {
+		case "{", "(", "[":
+			depth++
+		case "}", ")", "]":
+			depth--
+			if depth <= 0 {
+				return t.line
+			}
+		}
+	}
+	return p.lastLine()
+}
+
+// skipStatement skips to the end of a ';'-terminated statement (or a
+// statement ending in a {...} block) and returns the line of its last token.
+// A closing brace belonging to the enclosing block is left unconsumed.
+func (p *schemaParser) skipStatement() int {
+	depth := 0
+	for !p.atEnd() {
+		t := p.toks[p.pos]
+		if t.kind != schemaPunct {
+			p.pos++
+			continue
+		}
+		switch t.text {
+		case "{", "(", "[":
+			depth++
+		case "}", ")", "]":
+			if depth == 0 {
+				return t.line
+			}
+			depth--
+			if depth == 0 && t.text == "}" {
+				p.pos++
+				return t.line
+			}
+		case ";":
+			if depth == 0 {
+				p.pos++
+				return t.line
+			}
+		}
+		p.pos++
+	}
+	return p.lastLine()
+}
+
+// ---------------------------------------------------------------------------
+// Protocol Buffers
+// ---------------------------------------------------------------------------
+
+// ProtoParser extracts messages, enums, services, RPCs and fields from
+// .proto files. Nested declarations carry the dotted path of their enclosing
+// messages (Outer.Inner) as container.
+type ProtoParser struct{}
+
+// Parse implements Parser.
+func (ProtoParser) Parse(filename string, src []byte) (*symbols.FileResult, error) {
+	p := newSchemaParser(src, LangProto)
+	p.protoBody("")
+	return p.res, nil
+}
+
+// protoBody parses declarations up to the '}' closing the current block (or
+// end of file at top level) and returns the line of that brace.
+func (p *schemaParser) protoBody(container string) int {
+	for !p.atEnd() {
+		t := p.peek()
+		if t.kind == schemaPunct && t.text == "}" {
+			p.pos++
+			return t.line
+		}
+		if t.kind != schemaIdent {
+			p.pos++
+			continue
+		}
+		switch t.text {
+		case "syntax", "edition", "option", "reserved", "extensions":
+			p.skipStatement()
+		case "package":
+			p.pos++
+			if first, name := p.protoDottedName(); name != "" {
+				p.addSymbol(first, name, symbols.KindPackage, "", "")
+			}
+			p.skipStatement()
+		case "import":
+			p.pos++
+			for p.isIdent("public") || p.isIdent("weak") {
+				p.pos++
+			}
+			if s := p.peek(); s.kind == schemaString && len(s.text) >= 2 {
+				// Keep the file's base name, like Ruby require paths.
+				imp := s
+				imp.text = strings.TrimSuffix(path.Base(s.text[1:len(s.text)-1]), ".proto")
+				imp.col++
+				p.addRef(imp, symbols.RefImport, container, "")
+			}
+			p.skipStatement()
+		case "message":
+			p.protoBlockDecl(symbols.KindStruct, container, p.protoBody)
+		case "enum":
+			p.protoBlockDecl(symbols.KindEnum, container, p.protoEnumBody)
+		case "service":
+			p.protoBlockDecl(symbols.KindInterface, container, p.protoServiceBody)
+		case "extend":
+			p.pos++
+			target := p.protoTypeRef(container)
+			if p.isPunct("{") {
+				p.pos++
+				p.protoBody(target)
+			}
+		case "oneof":
+			// Oneof members are fields of the enclosing message.
+			p.pos++
+			if p.peek().kind == schemaIdent {
+				p.pos++
+			}
+			if p.isPunct("{") {
+				p.pos++
+				p.protoBody(container)
+			}
+		default:
+			if container == "" {
+				p.pos++
+				continue
+			}
+			p.protoField(container)
+		}
+	}
+	return p.lastLine()
+}
+
+// protoBlockDecl parses "keyword Name { ... }" and records Name as a
+// definition of the given kind spanning the whole block.
+func (p *schemaParser) protoBlockDecl(kind symbols.SymbolKind, container string, body func(string) int) {
+	p.pos++ // keyword
+	name := p.peek()
+	if name.kind != schemaIdent {
+		return
+	}
+	p.pos++
+	if !p.isPunct("{") {
+		return
+	}
+	p.pos++
+	idx := p.addSymbol(name, name.text, kind, container, "")
+	inner := name.text
+	if container != "" {
+		inner = container + "." + name.text
+	}
+	p.res.Symbols[idx].EndLine = body(inner)
+}
+
+// protoDottedName reads a (possibly fully-qualified) name such as
+// .acme.shipping.Parcel and returns its last identifier token and the
+// full dotted text.
+func (p *schemaParser) protoDottedName() (schemaToken, string) {
+	var last schemaToken
+	var b strings.Builder
+	if p.isPunct(".") {
+		p.pos++
+	}
+	for p.peek().kind == schemaIdent && !p.atEnd() {
+		last = p.peek()
+		b.WriteString(last.text)
+		p.pos++
+		if !p.isPunct(".") || p.peekN(1).kind != schemaIdent {
+			break
+		}
+		b.WriteByte('.')
+		p.pos++
+	}
+	return last, b.String()
+}
+
+// protoTypeRef reads a field or RPC type and records a type ref to its last
+// segment unless it is a scalar. It returns the dotted type name.
+func (p *schemaParser) protoTypeRef(container string) string {
+	last, name := p.protoDottedName()
+	if name != "" && !protoScalars[name] {
+		p.addRef(last, symbols.RefTypeRef, container, "")
+	}
+	return name
+}
+
+// protoField parses "[repeated|optional|required] Type name = N [...];" and
+// "map<K, V> name = N;".
+func (p *schemaParser) protoField(container string) {
+	for p.isIdent("repeated") || p.isIdent("optional") || p.isIdent("required") {
+		p.pos++
+	}
+	switch {
+	case p.isIdent("group"):
+		p.skipStatement()
+		return
+	case p.isIdent("map") && p.peekN(1).text == "<":
+		p.pos += 2
+		p.protoTypeRef(container)
+		if p.isPunct(",") {
+			p.pos++
+		}
+		p.protoTypeRef(container)
+		if p.isPunct(">") {
+			p.pos++
+		}
+	default:
+		p.protoTypeRef(container)
+	}
+	if name := p.peek(); name.kind == schemaIdent && p.peekN(1).text == "=" {
+		p.addSymbol(name, name.text, symbols.KindField, container, "")
+	}
+	p.skipStatement()
+}
+
+// protoEnumBody parses enum values up to the closing '}'.
+func (p *schemaParser) protoEnumBody(container string) int {
+	for !p.atEnd() {
+		t := p.peek()
+		switch {
+		case t.kind == schemaPunct && t.text == "}":
+			p.pos++
+			return t.line
+		case t.kind == schemaIdent && (t.text == "option" || t.text == "reserved"):
+			p.skipStatement()
+		case t.kind == schemaIdent && p.peekN(1).text == "=":
+			p.addSymbol(t, t.text, symbols.KindConstant, container, "")
+			p.skipStatement()
+		default:
+			p.pos++
+		}
+	}
+	return p.lastLine()
+}
+
+// protoServiceBody parses RPC declarations up to the closing '}'.
+func (p *schemaParser) protoServiceBody(container string) int {
+	for !p.atEnd() {
+		t := p.peek()
+		switch {
+		case t.kind == schemaPunct && t.text == "}":
+			p.pos++
+			return t.line
+		case t.kind == schemaIdent && t.text == "rpc":
+			p.pos++
+			name := p.peek()
+			if name.kind != schemaIdent {
+				continue
+			}
+			p.pos++
+			idx := p.addSymbol(name, name.text, symbols.KindMethod, container, "")
+			p.protoRPCType(container)
+			if p.isIdent("returns") {
+				p.pos++
+				p.protoRPCType(container)
+			}
+			end := name.line
+			if p.isPunct("{") {
+				end = p.skipBalanced()
+			} else if p.isPunct(";") {
+				end = p.peek().line
+				p.pos++
+			}
+			p.res.Symbols[idx].EndLine = end
+		case t.kind == schemaIdent && t.text == "option":
+			p.skipStatement()
+		default:
+			p.pos++
+		}
+	}
+	return p.lastLine()
+}
+
+// protoRPCType parses "(stream Type)".
+func (p *schemaParser) protoRPCType(container string) {
+	if !p.isPunct("(") {
+		return
+	}
+	p.pos++
+	if p.isIdent("stream") && p.peekN(1).kind == schemaIdent {
+		p.pos++
+	}
+	p.protoTypeRef(container)
+	if p.isPunct(")") {
+		p.pos++
+	}
+}
+
+// ---------------------------------------------------------------------------
+// GraphQL
+// ---------------------------------------------------------------------------
+
+// GraphQLParser extracts types, interfaces, inputs, enums, unions, scalars,
+// fields (including Query and Mutation fields), named operations and
+// fragments from .graphql files.
+type GraphQLParser struct{}
+
+// Parse implements Parser.
+func (GraphQLParser) Parse(filename string, src []byte) (*symbols.FileResult, error) {
+	p := newSchemaParser(src, LangGraphQL)
+	p.graphqlDocument()
+	return p.res, nil
+}
+
+func (p *schemaParser) graphqlDocument() {
+	for !p.atEnd() {
+		t := p.peek()
+		if t.kind == schemaPunct && t.text == "{" {
+			// Anonymous query shorthand.
+			p.graphqlSelection("")
+			continue
+		}
+		if t.kind != schemaIdent {
+			p.pos++
+			continue
+		}
+		switch t.text {
+		case "extend":
+			p.pos++
+			p.graphqlTypeDef(true)
+		case "type", "interface", "input", "enum", "union", "scalar":
+			p.graphqlTypeDef(false)
+		case "schema":
+			p.pos++
+			p.graphqlDirectives("")
+			if p.isPunct("{") {
+				p.graphqlSchemaBody()
+			}
+		case "directive":
+			p.graphqlDirectiveDef()
+		case "query", "mutation", "subscription":
+			p.graphqlOperation()
+		case "fragment":
+			p.graphqlFragment()
+		default:
+			p.pos++
+		}
+	}
+}
+
+// graphqlTypeDef parses a type system definition (or extension) starting at
+// its keyword.
+func (p *schemaParser) graphqlTypeDef(extend bool) {
+	kw := p.peek().text
+	p.pos++
+	name := p.peek()
+	if name.kind != schemaIdent {
+		return
+	}
+	p.pos++
+
+	kind := symbols.KindClass
+	switch kw {
+	case "interface":
+		kind = symbols.KindInterface
+	case "input":
+		kind = symbols.KindStruct
+	case "enum":
+		kind = symbols.KindEnum
+	case "union", "scalar":
+		kind = symbols.KindTypeAlias
+	}
+
+	idx := -1
+	if extend {
+		p.addRef(name, symbols.RefTypeRef, "", "")
+	} else {
+		idx = p.addSymbol(name, name.text, kind, "", "")
+	}
+	end := name.line
+
+	if p.isIdent("implements") {
+		p.pos++
+		for {
+			if p.isPunct("&") {
+				p.pos++
+			}
+			iface := p.peek()
+			if iface.kind != schemaIdent || p.atEnd() {
+				break
+			}
+			p.addRef(iface, symbols.RefInherit, name.text, "implements")
+			end = iface.line
+			p.pos++
+			if !p.isPunct("&") {
+				break
+			}
+		}
+	}
+	p.graphqlDirectives(name.text)
+
+	switch kw {
+	case "union":
+		if p.isPunct("=") {
+			p.pos++
+			for {
+				if p.isPunct("|") {
+					p.pos++
+				}
+				member := p.peek()
+				if member.kind != schemaIdent || p.atEnd() {
+					break
+				}
+				p.addRef(member, symbols.RefTypeRef, name.text, "")
+				end = member.line
+				p.pos++
+				if !p.isPunct("|") {
+					break
+				}
+			}
+		}
+	case "enum":
+		if p.isPunct("{") {
+			end = p.graphqlEnumBody(name.text)
+		}
+	case "scalar":
+	default:
+		if p.isPunct("{") {
+			end = p.graphqlFieldsBody(name.text)
+		}
+	}
+	if idx >= 0 {
+		p.res.Symbols[idx].EndLine = end
+	}
+}
+
+// graphqlFieldsBody parses field definitions up to the closing '}'.
+func (p *schemaParser) graphqlFieldsBody(container string) int {
+	p.pos++ // '{'
+	for !p.atEnd() {
+		t := p.peek()
+		switch {
+		case t.kind == schemaPunct && t.text == "}":
+			p.pos++
+			return t.line
+		case t.kind == schemaIdent && (p.peekN(1).text == ":" || p.peekN(1).text == "("):
+			idx := p.addSymbol(t, t.text, symbols.KindField, container, "")
+			p.pos++
+			if p.isPunct("(") {
+				p.graphqlArgumentDefs(container)
+			}
+			if p.isPunct(":") {
+				p.pos++
+				p.res.Symbols[idx].EndLine = p.graphqlTypeRef(container)
+			}
+			if p.isPunct("=") {
+				p.pos++
+				p.graphqlSkipValue()
+			}
+			p.graphqlDirectives(container)
+		default:
+			p.pos++
+		}
+	}
+	return p.lastLine()
+}
+
+// graphqlArgumentDefs parses "(name: Type = default @dir, ...)" in field
+// definitions and operation variable lists, recording refs to argument types.
+func (p *schemaParser) graphqlArgumentDefs(container string) {
+	p.pos++ // '('
+	for !p.atEnd() {
+		switch {
+		case p.isPunct(")"):
+			p.pos++
+			return
+		case p.isPunct(":"):
+			p.pos++
+			p.graphqlTypeRef(container)
+		case p.isPunct("="):
+			p.pos++
+			p.graphqlSkipValue()
+		case p.isPunct("@"):
+			p.graphqlDirectives(container)
+		default:
+			p.pos++
+		}
+	}
+}
+
+// graphqlTypeRef parses a type such as [Parcel!]! and returns the line of
+// its last token.
+func (p *schemaParser) graphqlTypeRef(container string) int {
+	line := p.peek().line
+	for p.isPunct("[") {
+		p.pos++
+	}
+	if t := p.peek(); t.kind == schemaIdent && !p.atEnd() {
+		if !graphqlScalars[t.text] {
+			p.addRef(t, symbols.RefTypeRef, container, "")
+		}
+		line = t.line
+		p.pos++
+	}
+	for p.isPunct("]") || p.isPunct("!") {
+		line = p.peek().line
+		p.pos++
+	}
+	return line
+}
+
+// graphqlSkipValue skips a default or argument value.
+func (p *schemaParser) graphqlSkipValue() {
+	switch {
+	case p.isPunct("[") || p.isPunct("{"):
+		p.skipBalanced()
+	case p.isPunct("$") || p.isPunct("-"):
+		p.pos += 2
+	default:
+		p.pos++
+	}
+}
+
+// graphqlDirectives records refs for "@name(args)" directive usages.
+func (p *schemaParser) graphqlDirectives(container string) {
+	for p.isPunct("@") && p.peekN(1).kind == schemaIdent {
+		p.addRef(p.peekN(1), symbols.RefAnnotation, container, "")
+		p.pos += 2
+		if p.isPunct("(") {
+			p.skipBalanced()
+		}
+	}
+}
+
+// graphqlEnumBody parses enum values up to the closing '}'.
+func (p *schemaParser) graphqlEnumBody(container string) int {
+	p.pos++ // '{'
+	for !p.atEnd() {
+		t := p.peek()
+		switch {
+		case t.kind == schemaPunct && t.text == "}":
+			p.pos++
+			return t.line
+		case t.kind == schemaIdent:
+			p.addSymbol(t, t.text, symbols.KindConstant, container, "")
+			p.pos++
+			p.graphqlDirectives(container)
+		default:
+			p.pos++
+		}
+	}
+	return p.lastLine()
+}
+
+// graphqlSchemaBody parses "schema { query: Query ... }".
+func (p *schemaParser) graphqlSchemaBody() {
+	p.pos++ // '{'
+	for !p.atEnd() {
+		switch {
+		case p.isPunct("}"):
+			p.pos++
+			return
+		case p.isPunct(":"):
+			p.pos++
+			p.graphqlTypeRef("")
+		default:
+			p.pos++
+		}
+	}
+}
+
+// graphqlDirectiveDef skips "directive @name(args) repeatable on A | B".
+func (p *schemaParser) graphqlDirectiveDef() {
+	p.pos++ // directive
+	if p.isPunct("@") {
+		p.pos += 2
+	}
+	if p.isPunct("(") {
+		p.graphqlArgumentDefs("")
+	}
+	if p.isIdent("repeatable") {
+		p.pos++
+	}
+	if !p.isIdent("on") {
+		return
+	}
+	p.pos++
+	for {
+		if p.isPunct("|") {
+			p.pos++
+		}
+		if p.peek().kind != schemaIdent || p.atEnd() {
+			return
+		}
+		p.pos++
+		if !p.isPunct("|") {
+			return
+		}
+	}
+}
+
+// graphqlOperation parses a named or anonymous query, mutation or
+// subscription. Named operations are recorded as functions whose signature
+// starts with the operation type.
+func (p *schemaParser) graphqlOperation() {
+	op := p.peek().text
+	p.pos++
+	idx := -1
+	container := ""
+	if name := p.peek(); name.kind == schemaIdent && !p.atEnd() {
+		idx = p.addSymbol(name, name.text, symbols.KindFunction, "", op+" "+name.text)
+		container = name.text
+		p.pos++
+	}
+	if p.isPunct("(") {
+		p.graphqlArgumentDefs(container)
+	}
+	p.graphqlDirectives(container)
+	if p.isPunct("{") {
+		end := p.graphqlSelection(container)
+		if idx >= 0 {
+			p.res.Symbols[idx].EndLine = end
+		}
+	}
+}
+
+// graphqlFragment parses "fragment Name on Type { ... }".
+func (p *schemaParser) graphqlFragment() {
+	p.pos++ // fragment
+	name := p.peek()
+	if name.kind != schemaIdent {
+		return
+	}
+	p.pos++
+	idx := p.addSymbol(name, name.text, symbols.KindTypeAlias, "", "fragment "+name.text)
+	if p.isIdent("on") {
+		p.pos++
+		p.graphqlTypeRef(name.text)
+	}
+	p.graphqlDirectives(name.text)
+	if p.isPunct("{") {
+		p.res.Symbols[idx].EndLine = p.graphqlSelection(name.text)
+	}
+}
+
+// graphqlSelection parses a selection set, recording selected fields as read
+// refs and fragment spreads and type conditions as type refs. It returns the
+// line of the closing '}'.
+func (p *schemaParser) graphqlSelection(container string) int {
+	p.pos++ // '{'
+	for !p.atEnd() {
+		t := p.peek()
+		switch {
+		case t.kind == schemaPunct && t.text == "}":
+			p.pos++
+			return t.line
+		case t.kind == schemaPunct && t.text == "{":
+			p.graphqlSelection(container)
+		case t.kind == schemaPunct && t.text == "(":
+			p.skipBalanced()
+		case t.kind == schemaPunct && t.text == "@":
+			p.graphqlDirectives(container)
+		case t.kind == schemaPunct && t.text == "...":
+			p.pos++
+			if p.isIdent("on") {
+				p.pos++
+				p.graphqlTypeRef(container)
+			} else if s := p.peek(); s.kind == schemaIdent && !p.atEnd() {
+				p.addRef(s, symbols.RefTypeRef, container, "")
+				p.pos++
+			}
+		case t.kind == schemaIdent:
+			p.pos++
+			if p.isPunct(":") {
+				// Alias: the selected field follows the colon.
+				p.pos++
+				continue
+			}
+			if !strings.HasPrefix(t.text, "__") {
+				p.addRef(t, symbols.RefRead, container, "")
+			}
+		default:
+			p.pos++
+		}
+	}
+	return p.lastLine()
+}
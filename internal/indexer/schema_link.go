@@ -0,0 +1,404 @@
+package indexer
+
+import (
+	"fmt"
+	"math"
+	"path/filepath"
+	"strings"
+	"unicode"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// Code generators (protoc-gen-go, grpc, ts-proto, gqlgen, graphql-codegen)
+// name the symbols they emit after schema declarations by fixed conventions:
+// message Parcel becomes type Parcel, field tracking_id becomes TrackingId
+// and GetTrackingId, service Shipping becomes ShippingClient and
+// ShippingServer, and so on. generatedNames applies those conventions; the
+// Navigator helpers below use them to hop between generated code and the
+// schema it came from.
+
+// IsSchemaLang reports whether lang is a schema language (proto, graphql)
+// whose definitions are consumed through generated code.
+func IsSchemaLang(lang string) bool {
+	return lang == string(LangProto) || lang == string(LangGraphQL)
+}
+
+// generatedFileSuffixes match the file names protoc plugins and GraphQL
+// code generators write.
+var generatedFileSuffixes = []string{
+	".pb.go", ".pb.gw.go", ".pb.ts", "_pb.ts", "_pb.js", "_pb.d.ts",
+	"_grpc_pb.js", "_grpc_pb.d.ts", "_pb2.py", "_pb2.pyi", "_pb2_grpc.py",
+	".pb.cc", ".pb.h", "_gen.go", ".generated.go", ".generated.ts", ".graphql.ts",
+}
+
+// generatedFileNames are well-known outputs of gqlgen and graphql-codegen.
+var generatedFileNames = map[string]bool{
+	"generated.go":  true,
+	"models_gen.go": true,
+	"graphql.ts":    true,
+	"gql.ts":        true,
+}
+
+// generatedDirs are directory names that conventionally hold generated code.
+var generatedDirs = map[string]bool{
+	"generated":     true,
+	"__generated__": true,
+	"gen":           true,
+}
+
+// isGeneratedPath reports whether relPath looks like code generated from a
+// schema.
+func isGeneratedPath(relPath string) bool {
+	lower := strings.ToLower(filepath.ToSlash(relPath))
+	base := filepath.Base(lower)
+	if generatedFileNames[base] {
+		return true
+	}
+	for _, suf := range generatedFileSuffixes {
+		if strings.HasSuffix(base, suf) {
+			return true
+		}
+	}
+	for _, seg := range strings.Split(filepath.Dir(lower), "/") {
+		if generatedDirs[seg] {
+			return true
+		}
+	}
+	return false
+}
+
+// isSchemaFile reports whether relPath is a .proto or .graphql source.
+func isSchemaFile(relPath string) bool {
+	return IsSchemaLang(string(DetectLang(relPath)))
+}
+
+// generatedNames returns the names code generators give to the symbols they
+// emit for the schema definition def.
+func generatedNames(def DefinitionResult) []string {
+	var names []string
+	add := func(s string) {
+		if s == "" {
+			return
+		}
+		for _, n := range names {
+			if n == s {
+				return
+			}
+		}
+		names = append(names, s)
+	}
+	flat := strings.ReplaceAll(def.Container, ".", "_")
+
+	switch DetectLang(def.Location.Path) {
+	case LangProto:
+		switch def.Kind {
+		case "struct", "enum":
+			add(def.Name)
+			if flat != "" {
+				add(flat + "_" + def.Name) // Outer_Inner
+			}
+		case "interface":
+			for _, f := range []string{"%sClient", "%sServer", "New%sClient", "Register%sServer",
+				"Unimplemented%sServer", "%sClientImpl", "%sStub", "%sServicer"} {
+				add(fmt.Sprintf(f, def.Name))
+			}
+		case "method":
+			add(def.Name)
+			add(lowerFirst(def.Name))
+		case "field":
+			goName := schemaPascal(def.Name)
+			add(goName)
+			add("Get" + goName)
+			add(lowerFirst(goName))
+		case "constant":
+			// Enum values are prefixed with the enum's name, or with the
+			// enclosing message's name for enums nested in a message.
+			prefix := flat
+			if i := strings.LastIndex(def.Container, "."); i >= 0 {
+				prefix = strings.ReplaceAll(def.Container[:i], ".", "_")
+			}
+			add(prefix + "_" + def.Name)
+			add(def.Name)
+		}
+
+	case LangGraphQL:
+		switch def.Kind {
+		case "class", "interface", "struct", "enum":
+			add(def.Name)
+		case "type_alias":
+			add(def.Name)
+			if strings.HasPrefix(def.Signature, "fragment ") {
+				add(def.Name + "Fragment")
+				add(def.Name + "FragmentDoc")
+			}
+		case "function":
+			op, _, _ := strings.Cut(def.Signature, " ")
+			if op == "" {
+				break
+			}
+			base := upperFirst(def.Name)
+			opType := upperFirst(op)
+			add(base + opType)
+			add(base + opType + "Variables")
+			add(base + "Document")
+			add("use" + base + opType)
+			if op == "query" {
+				add("use" + base + "LazyQuery")
+			}
+		case "field":
+			add(schemaPascal(def.Name))
+			switch def.Container {
+			case "Query", "Mutation", "Subscription":
+				add(def.Container + schemaPascal(def.Name) + "Args")
+			}
+		case "constant":
+			add(def.Container + schemaPascal(strings.ToLower(def.Name)))
+			add(schemaPascal(strings.ToLower(def.Name)))
+		}
+	}
+	return names
+}
+
+// schemaNameCandidates undoes the generator conventions for a generated
+// symbol name, returning the schema names it may have come from. The
+// guesses are loose; SchemaDefinitionsFor confirms each one by mapping the
+// schema definition forward with generatedNames.
+func schemaNameCandidates(name string) []string {
+	cands := []string{name}
+	add := func(s string) {
+		if s == "" {
+			return
+		}
+		for _, c := range cands {
+			if c == s {
+				return
+			}
+		}
+		cands = append(cands, s)
+	}
+
+	trimmed := name
+	for _, pre := range []string{"New", "Register", "Unimplemented", "use"} {
+		if strings.HasPrefix(trimmed, pre) && len(trimmed) > len(pre) {
+			trimmed = trimmed[len(pre):]
+			break
+		}
+	}
+	add(trimmed)
+	for _, suf := range []string{"ClientImpl", "Client", "Server", "Stub", "Servicer",
+		"QueryVariables", "MutationVariables", "SubscriptionVariables", "LazyQuery",
+		"Query", "Mutation", "Subscription", "Document", "FragmentDoc", "Fragment", "Args"} {
+		if strings.HasSuffix(trimmed, suf) && len(trimmed) > len(suf) {
+			add(strings.TrimSuffix(trimmed, suf))
+		}
+	}
+	if strings.HasPrefix(name, "Get") && len(name) > 3 {
+		add(name[3:])
+	}
+	// Nested messages and enum values: Outer_Inner, Status_STATUS_ACTIVE.
+	for i, r := range name {
+		if r == '_' && i > 0 && i < len(name)-1 {
+			add(name[i+1:])
+		}
+	}
+
+	// Resolver argument types: QueryParcelArgs -> Parcel.
+	for _, c := range append([]string(nil), cands...) {
+		for _, pre := range []string{"Query", "Mutation", "Subscription"} {
+			if strings.HasPrefix(c, pre) && len(c) > len(pre) {
+				add(c[len(pre):])
+			}
+		}
+	}
+
+	// Field and enum value spellings: TrackingId -> tracking_id, trackingId;
+	// Pending -> PENDING.
+	for _, c := range append([]string(nil), cands...) {
+		snake := schemaSnake(c)
+		add(snake)
+		add(lowerFirst(c))
+		add(strings.ToUpper(snake))
+	}
+	return cands
+}
+
+// SchemaDefinitionsFor returns the .proto and .graphql definitions from
+// which a generated symbol called name was produced.
+func (n *Navigator) SchemaDefinitionsFor(name string) ([]DefinitionResult, error) {
+	cands := schemaNameCandidates(name)
+	placeholders := strings.TrimSuffix(strings.Repeat("?,", len(cands)), ",")
+	query := `
+		SELECT s.name, s.kind, s.container_name, s.signature,
+		       f.path, s.start_line, s.start_col, s.end_line, s.end_col
+		FROM symbols s
+		JOIN files f ON s.file_id = f.id
+		WHERE f.project_id = ? AND f.lang IN (?, ?) AND s.name IN (` + placeholders + `)
+		ORDER BY f.path ASC, s.start_line ASC
+	`
+	args := []interface{}{n.ProjectID, string(LangProto), string(LangGraphQL)}
+	for _, c := range cands {
+		args = append(args, c)
+	}
+	rows, err := n.DB.Query(query, args...)
+	if err != nil {
+		return nil, fmt.Errorf("query schema definitions: %w", err)
+	}
+	defer func() { _ = rows.Close() }()
+
+	results := []DefinitionResult{}
+	for rows.Next() {
+		var r DefinitionResult
+		var kindInt int
+		if err := rows.Scan(&r.Name, &kindInt, &r.Container, &r.Signature,
+			&r.Location.Path, &r.Location.StartLine, &r.Location.StartCol,
+			&r.Location.EndLine, &r.Location.EndCol); err != nil {
+			return nil, err
+		}
+		r.Kind = symbols.SymbolKind(kindInt).String()
+		for _, g := range generatedNames(r) {
+			if g == name {
+				results = append(results, r)
+				break
+			}
+		}
+	}
+	return results, rows.Err()
+}
+
+// GeneratedCodeUsages returns references, outside generated files, to the
+// symbols generated from the schema definition def.
+func (n *Navigator) GeneratedCodeUsages(def DefinitionResult) ([]UsageResult, error) {
+	names := generatedNames(def)
+	if len(names) == 0 {
+		return []UsageResult{}, nil
+	}
+	placeholders := strings.TrimSuffix(strings.Repeat("?,", len(names)), ",")
+	query := `
+		SELECT r.name, r.kind, r.context_container, r.relation, r.receiver_type, r.target_type,
+		       f.path, r.start_line, r.start_col, r.end_line, r.end_col
+		FROM refs r
+		JOIN files f ON r.file_id = f.id
+		WHERE f.project_id = ? AND f.lang NOT IN (?, ?) AND r.name IN (` + placeholders + `)
+		ORDER BY f.path ASC, r.start_line ASC
+	`
+	args := []interface{}{n.ProjectID, string(LangProto), string(LangGraphQL)}
+	for _, g := range names {
+		args = append(args, g)
+	}
+	rows, err := n.DB.Query(query, args...)
+	if err != nil {
+		return nil, fmt.Errorf("query generated code usages: %w", err)
+	}
+	defer func() { _ = rows.Close() }()
+
+	results := []UsageResult{}
+	cells := newNotebookLocator(n.RepoRoot)
+	for rows.Next() {
+		var r UsageResult
+		var kindInt int
+		if err := rows.Scan(&r.Name, &kindInt, &r.ContextContainer,
+			&r.Relation, &r.ReceiverType, &r.TargetType,
+			&r.Location.Path, &r.Location.StartLine, &r.Location.StartCol,
+			&r.Location.EndLine, &r.Location.EndCol); err != nil {
+			return nil, err
+		}
+		if isGeneratedPath(r.Location.Path) {
+			continue
+		}
+		r.Kind = symbols.RefKind(kindInt).String()
+		cells.annotate(&r.Location)
+		results = append(results, r)
+	}
+	return results, rows.Err()
+}
+
+// hasGeneratedDefinition reports whether any definition lives in generated code.
+func hasGeneratedDefinition(defs []DefinitionResult) bool {
+	for _, d := range defs {
+		if isGeneratedPath(d.Location.Path) {
+			return true
+		}
+	}
+	return false
+}
+
+// schemaOutbound links a generated primary definition to the schema
+// definitions it was generated from ("generated_from" edges).
+func schemaOutbound(nav *Navigator, def *DefinitionResult, defNodeID string) *outboundResult {
+	result := &outboundResult{}
+	if !isGeneratedPath(def.Location.Path) {
+		return result
+	}
+	schemaDefs, err := nav.SchemaDefinitionsFor(def.Name)
+	if err != nil {
+		return result
+	}
+	for _, sd := range schemaDefs {
+		targetNodeID := nodeID(sd.Location.Path, sd.Name, sd.Location.StartLine)
+		result.nodes = append(result.nodes, DepGraphNode{
+			ID:        targetNodeID,
+			Name:      sd.Name,
+			Kind:      sd.Kind,
+			Path:      sd.Location.Path,
+			StartLine: sd.Location.StartLine,
+			EndLine:   sd.Location.EndLine,
+			Container: sd.Container,
+			Signature: sd.Signature,
+		})
+		result.edges = append(result.edges, DepGraphEdge{
+			From:     defNodeID,
+			To:       targetNodeID,
+			Score:    round4(1.0 / math.Sqrt(float64(len(schemaDefs)))),
+			Count:    1,
+			FilePath: sd.Location.Path,
+			Relation: "generated_from",
+		})
+	}
+	return result
+}
+
+// schemaPascal converts a schema name to the exported Go spelling used by
+// protoc-gen-go and gqlgen: tracking_id -> TrackingId, trackingId -> TrackingId.
+func schemaPascal(s string) string {
+	var b strings.Builder
+	for _, part := range strings.Split(s, "_") {
+		b.WriteString(upperFirst(part))
+	}
+	return b.String()
+}
+
+// schemaSnake converts TrackingId or trackingId to tracking_id.
+func schemaSnake(s string) string {
+	runes := []rune(s)
+	var b strings.Builder
+	for i, r := range runes {
+		if unicode.IsUpper(r) && i > 0 {
+			prev := runes[i-1]
+			nextLower := i+1 < len(runes) && unicode.IsLower(runes[i+1])
+			if unicode.IsLower(prev) || unicode.IsDigit(prev) || (unicode.IsUpper(prev) && nextLower) {
+				b.WriteByte('_')
+			}
+		}
+		b.WriteRune(unicode.ToLower(r))
+	}
+	return b.String()
+}
+
+func upperFirst(s string) string {
+	if s == "" {
+		return s
+	}
+	r := []rune(s)
+	r[0] = unicode.ToUpper(r[0])
+	return string(r)
+}
+
+func lowerFirst(s string) string {
+	if s == "" {
+		return s
+	}
+	r := []rune(s)
+	r[0] = unicode.ToLower(r[0])
+	return string(r)
+}
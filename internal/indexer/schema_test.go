@@ -0,0 +1,265 @@
+package indexer
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+func TestProtoParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "schema", "proto", "shipping.proto"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	result, err := ProtoParser{}.Parse("shipping.proto", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "acme.shipping.v1", symbols.KindPackage)
+	expectSymbol(t, result, "ShipmentRequest", symbols.KindStruct)
+	expectSymbol(t, result, "Address", symbols.KindStruct)
+	expectSymbol(t, result, "tracking_id", symbols.KindField)
+	expectSymbol(t, result, "stops", symbols.KindField)
+	expectSymbol(t, result, "express", symbols.KindField)
+	expectSymbol(t, result, "ShipmentStatus", symbols.KindEnum)
+	expectSymbol(t, result, "SHIPMENT_STATUS_PENDING", symbols.KindConstant)
+	expectSymbol(t, result, "Shipping", symbols.KindInterface)
+	expectSymbol(t, result, "CreateShipment", symbols.KindMethod)
+	expectSymbol(t, result, "WatchShipment", symbols.KindMethod)
+
+	expectRef(t, result, "Address")
+	expectRef(t, result, "ShipmentReply")
+	expectRef(t, result, "Timestamp")
+	expectRef(t, result, "timestamp")
+
+	for _, s := range result.Symbols {
+		switch {
+		case s.Name == "ShipmentRequest":
+			if s.StartLine != 10 || s.EndLine != 26 {
+				t.Errorf("ShipmentRequest spans %d-%d, want 10-26", s.StartLine, s.EndLine)
+			}
+		case s.Name == "Address":
+			if s.ContainerName != "ShipmentRequest" {
+				t.Errorf("Address container = %q, want ShipmentRequest", s.ContainerName)
+			}
+		case s.Name == "postal_code":
+			if s.ContainerName != "ShipmentRequest.Address" {
+				t.Errorf("postal_code container = %q, want ShipmentRequest.Address", s.ContainerName)
+			}
+		case s.Name == "CreateShipment":
+			if s.ContainerName != "Shipping" {
+				t.Errorf("CreateShipment container = %q, want Shipping", s.ContainerName)
+			}
+		}
+	}
+	for _, r := range result.Refs {
+		if protoScalars[r.Name] {
+			t.Errorf("unexpected ref to scalar type %q", r.Name)
+		}
+	}
+}
+
+func TestGraphQLParser(t *testing.T) {
+	src, err := os.ReadFile(filepath.Join(testdataDir(t), "schema", "parcels.graphql"))
+	if err != nil {
+		t.Fatalf("read fixture: %v", err)
+	}
+
+	result, err := GraphQLParser{}.Parse("parcels.graphql", src)
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	expectSymbol(t, result, "Parcel", symbols.KindClass)
+	expectSymbol(t, result, "Node", symbols.KindInterface)
+	expectSymbol(t, result, "ParcelStatus", symbols.KindEnum)
+	expectSymbol(t, result, "DELIVERED", symbols.KindConstant)
+	expectSymbol(t, result, "ParcelFilter", symbols.KindStruct)
+	expectSymbol(t, result, "SearchHit", symbols.KindTypeAlias)
+	expectSymbol(t, result, "DateTime", symbols.KindTypeAlias)
+	expectSymbol(t, result, "weightGrams", symbols.KindField)
+	expectSymbol(t, result, "parcels", symbols.KindField)
+	expectSymbol(t, result, "markDelivered", symbols.KindField)
+	expectSymbol(t, result, "TrackParcel", symbols.KindFunction)
+	expectSymbol(t, result, "ParcelScans", symbols.KindTypeAlias)
+
+	expectRef(t, result, "Scan")
+	expectRef(t, result, "ParcelFilter")
+	expectRef(t, result, "key")
+	expectRef(t, result, "ParcelScans")
+
+	implementsNode := false
+	for _, r := range result.Refs {
+		if graphqlScalars[r.Name] {
+			t.Errorf("unexpected ref to scalar type %q", r.Name)
+		}
+		if r.Name == "Node" && r.Kind == symbols.RefInherit && r.Relation == "implements" {
+			implementsNode = true
+		}
+	}
+	if !implementsNode {
+		t.Error("expected Parcel to record an implements ref to Node")
+	}
+
+	for _, s := range result.Symbols {
+		switch s.Name {
+		case "parcel":
+			if s.ContainerName != "Query" {
+				t.Errorf("parcel container = %q, want Query", s.ContainerName)
+			}
+		case "TrackParcel":
+			if s.Signature != "query TrackParcel" {
+				t.Errorf("TrackParcel signature = %q, want %q", s.Signature, "query TrackParcel")
+			}
+		case "Parcel":
+			if s.StartLine != 4 || s.EndLine != 10 {
+				t.Errorf("Parcel spans %d-%d, want 4-10", s.StartLine, s.EndLine)
+			}
+		}
+	}
+}
+
+func TestSchemaNameConversions(t *testing.T) {
+	if got := schemaPascal("tracking_id"); got != "TrackingId" {
+		t.Errorf("schemaPascal(tracking_id) = %q", got)
+	}
+	if got := schemaSnake("TrackingId"); got != "tracking_id" {
+		t.Errorf("schemaSnake(TrackingId) = %q", got)
+	}
+	if got := schemaSnake("HTTPServer"); got != "http_server" {
+		t.Errorf("schemaSnake(HTTPServer) = %q", got)
+	}
+
+	for _, path := range []string{"gen/shippingpb/shipping.pb.go", "web/src/__generated__/graphql.ts", "api/shipping_pb2.py"} {
+		if !isGeneratedPath(path) {
+			t.Errorf("isGeneratedPath(%q) = false, want true", path)
+		}
+	}
+	if isGeneratedPath("schema/dispatch/dispatch.go") {
+		t.Error("isGeneratedPath(dispatch.go) = true, want false")
+	}
+}
+
+func TestTokenizeSchemaEscapedNewline(t *testing.T) {
+	src := []byte("option note = \"first\\\nsecond\";\nmessage Reply {}\n")
+	for _, tok := range tokenizeSchema(src, LangProto) {
+		if tok.text == "Reply" {
+			if tok.line != 3 || tok.col != 8 {
+				t.Errorf("Reply at %d:%d, want 3:8", tok.line, tok.col)
+			}
+			return
+		}
+	}
+	t.Error("Reply token not found")
+}
+
+func TestGoToDefinitionFromGeneratedCode(t *testing.T) {
+	tests := []struct {
+		name, lang           string
+		wantName, wantKind   string
+		wantPath, wantParent string
+	}{
+		{"GetTrackingId", "go", "tracking_id", "field", "schema/proto/shipping.proto", "ShipmentRequest"},
+		{"ShipmentRequest_Address", "go", "Address", "struct", "schema/proto/shipping.proto", "ShipmentRequest"},
+		{"ShippingClient", "go", "Shipping", "interface", "schema/proto/shipping.proto", ""},
+		{"ShipmentStatus_SHIPMENT_STATUS_PENDING", "go", "SHIPMENT_STATUS_PENDING", "constant", "schema/proto/shipping.proto", "ShipmentStatus"},
+		{"Parcel", "typescript", "Parcel", "class", "schema/parcels.graphql", ""},
+		{"QueryParcelArgs", "typescript", "parcel", "field", "schema/parcels.graphql", "Query"},
+		{"TrackParcelQuery", "typescript", "TrackParcel", "function", "schema/parcels.graphql", ""},
+	}
+	for _, tt := range tests {
+		defs, err := sharedNav.GoToDefinitionByName(tt.name, "", tt.lang)
+		if err != nil {
+			t.Fatalf("GoToDefinitionByName(%s): %v", tt.name, err)
+		}
+		found := false
+		for _, d := range defs {
+			if d.Name == tt.wantName && d.Kind == tt.wantKind &&
+				d.Location.Path == filepath.FromSlash(tt.wantPath) && d.Container == tt.wantParent {
+				found = true
+			}
+		}
+		if !found {
+			t.Errorf("%s: expected schema definition %s (%s) in %s; got %+v",
+				tt.name, tt.wantName, tt.wantKind, tt.wantPath, defs)
+		}
+	}
+
+	// The schema declaration outranks the generated stub.
+	defs, err := sharedNav.GoToDefinitionByName("ShipmentRequest", "", "go")
+	if err != nil {
+		t.Fatalf("GoToDefinitionByName: %v", err)
+	}
+	ranked := RankDefinitions(defs, "", NoiseFilterOptions{})
+	if len(ranked) == 0 || filepath.Ext(ranked[0].Location.Path) != ".proto" {
+		t.Errorf("expected shipping.proto to rank first for ShipmentRequest; got %+v", ranked)
+	}
+
+	// Hand-written code is not linked to the schema.
+	defs, err = sharedNav.GoToDefinitionByName("DispatchParcel", "", "go")
+	if err != nil {
+		t.Fatalf("GoToDefinitionByName: %v", err)
+	}
+	for _, d := range defs {
+		if isSchemaFile(d.Location.Path) {
+			t.Errorf("DispatchParcel unexpectedly resolved to %s", d.Location.Path)
+		}
+	}
+}
+
+func TestDependencyGraphCrossesSchemaBoundary(t *testing.T) {
+	defs, err := sharedNav.GoToDefinitionByName("ShipmentRequest", "", "proto")
+	if err != nil || len(defs) == 0 {
+		t.Fatalf("expected ShipmentRequest in shipping.proto: %v", err)
+	}
+
+	graph, err := BuildDependencyGraph(sharedNav, &defs[0], defs, "proto", sharedRepoRoot, 1, 0.0, 500)
+	if err != nil {
+		t.Fatalf("BuildDependencyGraph: %v", err)
+	}
+	dispatch := filepath.Join("schema", "dispatch", "dispatch.go")
+	found := false
+	for _, e := range graph.Inbound.Edges {
+		if e.FilePath == dispatch {
+			found = true
+		}
+		if isGeneratedPath(e.FilePath) {
+			t.Errorf("unexpected inbound edge from generated file %s", e.FilePath)
+		}
+	}
+	if !found {
+		t.Errorf("expected an inbound edge from %s; got %+v", dispatch, graph.Inbound.Edges)
+	}
+
+	// From the generated side, the graph points back to the schema.
+	genDefs, err := sharedNav.GoToDefinitionByName("ShippingClient", "", "go")
+	if err != nil {
+		t.Fatalf("GoToDefinitionByName: %v", err)
+	}
+	var stub *DefinitionResult
+	for i := range genDefs {
+		if isGeneratedPath(genDefs[i].Location.Path) {
+			stub = &genDefs[i]
+		}
+	}
+	if stub == nil {
+		t.Fatal("expected ShippingClient in shipping.pb.go")
+	}
+	graph, err = BuildDependencyGraph(sharedNav, stub, genDefs, "go", sharedRepoRoot, 1, 0.0, 500)
+	if err != nil {
+		t.Fatalf("BuildDependencyGraph: %v", err)
+	}
+	linked := false
+	for _, e := range graph.Outbound.Edges {
+		if e.Relation == "generated_from" && filepath.Ext(e.FilePath) == ".proto" {
+			linked = true
+		}
+	}
+	if !linked {
+		t.Errorf("expected a generated_from edge to shipping.proto; got %+v", graph.Outbound.Edges)
+	}
+}
@@ -0,0 +1,81 @@
+package indexer
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// encodeSignatureList returns the JSON stored for the type parameters or
+// parameters of a symbol, or "" when it has none.
+func encodeSignatureList[T any](list []T) (string, error) {
+	if list == nil {
+		return "", nil
+	}
+	b, err := json.Marshal(list)
+	return string(b), err
+}
+
+// decodeSignatureLists sets the type parameters and parameters of a
+// definition from their stored JSON.
+func decodeSignatureLists(r *DefinitionResult, typeParams, params string) error {
+	if typeParams != "" {
+		if err := json.Unmarshal([]byte(typeParams), &r.TypeParams); err != nil {
+			return fmt.Errorf("decode type parameters of %s: %w", r.Name, err)
+		}
+	}
+	if params != "" {
+		if err := json.Unmarshal([]byte(params), &r.Params); err != nil {
+			return fmt.Errorf("decode parameters of %s: %w", r.Name, err)
+		}
+	}
+	return nil
+}
+
+// ParseVisibilities parses a comma-separated list of visibilities (public,
+// protected, internal, private) as accepted by the MCP tools. It returns
+// nil for an empty list, which accepts every visibility.
+func ParseVisibilities(list string) ([]symbols.Visibility, error) {
+	var vs []symbols.Visibility
+	for _, s := range strings.Split(list, ",") {
+		switch v := symbols.Visibility(strings.ToLower(strings.TrimSpace(s))); v {
+		case symbols.VisibilityUnknown:
+		case symbols.VisibilityPublic, symbols.VisibilityProtected,
+			symbols.VisibilityInternal, symbols.VisibilityPrivate:
+			vs = append(vs, v)
+		default:
+			return nil, fmt.Errorf("unknown visibility %q (want public, protected, internal or private)", s)
+		}
+	}
+	return vs, nil
+}
+
+// FilterVisibility returns the definitions whose visibility is one of
+// visibilities, in their original order. Definitions of unknown visibility
+// (schema declarations) are left out. An empty list returns defs as is.
+func FilterVisibility(defs []DefinitionResult, visibilities []symbols.Visibility) []DefinitionResult {
+	if len(visibilities) == 0 {
+		return defs
+	}
+	filtered := []DefinitionResult{}
+	for _, d := range defs {
+		for _, v := range visibilities {
+			if d.Visibility == string(v) {
+				filtered = append(filtered, d)
+				break
+			}
+		}
+	}
+	return filtered
+}
+
+// kindAndVisibility returns the kind of a definition followed by its
+// visibility when known (method, public).
+func kindAndVisibility(r DefinitionResult) string {
+	if r.Visibility == "" {
+		return r.Kind
+	}
+	return r.Kind + ", " + r.Visibility
+}
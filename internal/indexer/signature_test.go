@@ -0,0 +1,86 @@
+package indexer
+
+import (
+	"path/filepath"
+	"slices"
+	"testing"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+func TestGoToDefinitionReturnsStructuredSignature(t *testing.T) {
+	defs, err := sharedNav.GoToDefinitionByName("Ledger.Record", "", "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(defs) != 1 {
+		t.Fatalf("got %d definitions of Ledger.Record, want 1: %+v", len(defs), defs)
+	}
+	d := defs[0]
+	wantParams := []symbols.Parameter{{Name: "entry", Type: "T"}, {Name: "memo", Type: "string"}}
+	if d.Visibility != "public" || d.Receiver != "*Ledger[T]" || d.ReturnType != "(int, error)" ||
+		!slices.Equal(d.Params, wantParams) {
+		t.Errorf("Ledger.Record: visibility %q, receiver %q, params %+v, return type %q",
+			d.Visibility, d.Receiver, d.Params, d.ReturnType)
+	}
+	if want := "func (l *Ledger[T]) Record(entry T, memo string) (int, error)"; d.Signature != want {
+		t.Errorf("signature = %q, want %q", d.Signature, want)
+	}
+
+	types, err := sharedNav.GoToDefinitionByName("Ledger", "", "go")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(types) != 1 || !slices.Equal(types[0].TypeParams, []string{"T any"}) {
+		t.Errorf("Ledger: got %+v, want one definition with type parameters [T any]", types)
+	}
+}
+
+func TestVisibilityFilterAndRanking(t *testing.T) {
+	file := filepath.Join("signatures", "ledger.rs")
+	defs, err := sharedNav.GoToDefinitionByName("settle", "", "rust")
+	if err != nil {
+		t.Fatal(err)
+	}
+	var fixture []DefinitionResult
+	for _, d := range defs {
+		if d.Location.Path == file {
+			fixture = append(fixture, d)
+		}
+	}
+	if len(fixture) != 2 {
+		t.Fatalf("got %d definitions of settle in %s, want 2: %+v", len(fixture), file, defs)
+	}
+
+	public := FilterVisibility(fixture, []symbols.Visibility{symbols.VisibilityPublic})
+	if len(public) != 1 || public[0].Location.StartLine != 1 {
+		t.Errorf("public settle: got %+v, want only the one on line 1", public)
+	}
+	private := FilterVisibility(fixture, []symbols.Visibility{symbols.VisibilityPrivate})
+	if len(private) != 1 || private[0].Location.StartLine != 6 {
+		t.Errorf("private settle: got %+v, want only the one on line 6", private)
+	}
+
+	// The exported definition outranks the private one, whichever is listed first.
+	slices.Reverse(fixture)
+	ranked := RankDefinitions(fixture, "", NoiseFilterOptions{IncludeNoise: true})
+	if ranked[0].Location.StartLine != 1 || ranked[0].Confidence <= ranked[1].Confidence {
+		t.Errorf("ranked %+v, want the public settle on line 1 first", ranked)
+	}
+}
+
+func TestParseVisibilities(t *testing.T) {
+	got, err := ParseVisibilities("public, Protected")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !slices.Equal(got, []symbols.Visibility{symbols.VisibilityPublic, symbols.VisibilityProtected}) {
+		t.Errorf("ParseVisibilities = %v", got)
+	}
+	if got, err := ParseVisibilities(""); err != nil || got != nil {
+		t.Errorf("ParseVisibilities(\"\") = %v, %v; want nil, nil", got, err)
+	}
+	if _, err := ParseVisibilities("exported"); err == nil {
+		t.Error("ParseVisibilities(\"exported\") succeeded, want an error")
+	}
+}
@@ -127,7 +127,13 @@ func (s *Store) UpsertFile(path string, lang Lang, sha string, sizeBytes int64,
 		); err != nil {
 			return fmt.Errorf("update file: %w", err)
 		}
-		// Delete old symbols and refs for this file
+		// Delete old calls, symbols, their name grams and refs for this file
+		if _, err := tx.Exec(`DELETE FROM calls WHERE file_id = ?`, fileID); err != nil {
+			return err
+		}
+		if _, err := tx.Exec(`DELETE FROM symbol_name_grams WHERE file_id = ?`, fileID); err != nil {
+			return err
+		}
 		if _, err := tx.Exec(`DELETE FROM symbols WHERE file_id = ?`, fileID); err != nil {
 			return err
 		}
@@ -137,23 +143,45 @@ func (s *Store) UpsertFile(path string, lang Lang, sha string, sizeBytes int64,
 	}
 
 	// Insert symbols
-	for _, sym := range fr.Symbols {
+	symbolIDs := make([]int64, len(fr.Symbols))
+	for i, sym := range fr.Symbols {
 		isExt := 0
 		if sym.IsExternal {
 			isExt = 1
 		}
-		if _, err := tx.Exec(
-			`INSERT INTO symbols (file_id, name, kind, container_name, signature, start_line, start_col, end_line, end_col, is_external)
-			 VALUES (?,?,?,?,?,?,?,?,?,?)`,
+		typeParams, err := encodeSignatureList(sym.TypeParams)
+		if err != nil {
+			return fmt.Errorf("encode type parameters of %q: %w", sym.Name, err)
+		}
+		params, err := encodeSignatureList(sym.Params)
+		if err != nil {
+			return fmt.Errorf("encode parameters of %q: %w", sym.Name, err)
+		}
+		res, err := tx.Exec(
+			`INSERT INTO symbols (file_id, name, kind, container_name, signature, start_line, start_col, end_line, end_col, is_external, qualified_name, visibility, receiver, type_params, params, return_type, doc)
+			 VALUES (?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?)`,
 			fileID, sym.Name, int(sym.Kind), sym.ContainerName, sym.Signature,
 			sym.StartLine, sym.StartCol, sym.EndLine, sym.EndCol, isExt,
-		); err != nil {
+			sym.QualifiedName, string(sym.Visibility), sym.Receiver,
+			typeParams, params, sym.ReturnType, sym.Doc,
+		)
+		if err != nil {
 			return fmt.Errorf("insert symbol %q: %w", sym.Name, err)
 		}
+		symbolIDs[i], _ = res.LastInsertId()
+		for _, gram := range nameGrams(sym.Name) {
+			if _, err := tx.Exec(
+				`INSERT OR IGNORE INTO symbol_name_grams (gram, symbol_id, file_id) VALUES (?,?,?)`,
+				gram, symbolIDs[i], fileID,
+			); err != nil {
+				return fmt.Errorf("insert name grams of %q: %w", sym.Name, err)
+			}
+		}
 	}
 
-	// Insert refs
-	for _, ref := range fr.Refs {
+	// Insert refs, and the calls made from the file's functions
+	callers := callerSymbols(fr)
+	for i, ref := range fr.Refs {
 		isExt := 0
 		if ref.IsExternal {
 			isExt = 1
@@ -162,22 +190,35 @@ func (s *Store) UpsertFile(path string, lang Lang, sha string, sizeBytes int64,
 		if ref.IsBuiltin {
 			isBuiltin = 1
 		}
-		if _, err := tx.Exec(
-			`INSERT INTO refs (file_id, name, kind, start_line, start_col, end_line, end_col, context_container, is_external, is_builtin, relation, receiver_type, target_type)
-			 VALUES (?,?,?,?,?,?,?,?,?,?,?,?,?)`,
+		res, err := tx.Exec(
+			`INSERT INTO refs (file_id, name, kind, start_line, start_col, end_line, end_col, context_container, is_external, is_builtin, relation, receiver_type, target_type, qualifier, import_path, resolved_path, binding_line, binding_col)
+			 VALUES (?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?)`,
 			fileID, ref.Name, int(ref.Kind),
 			ref.StartLine, ref.StartCol, ref.EndLine, ref.EndCol,
 			ref.ContextContainer, isExt, isBuiltin,
 			ref.Relation, ref.ReceiverType, ref.TargetType,
-		); err != nil {
+			ref.Qualifier, ref.ImportPath, ref.ResolvedPath,
+			ref.BindingLine, ref.BindingCol,
+		)
+		if err != nil {
 			return fmt.Errorf("insert ref %q: %w", ref.Name, err)
 		}
+		if callers[i] < 0 {
+			continue
+		}
+		refID, _ := res.LastInsertId()
+		if _, err := tx.Exec(
+			`INSERT INTO calls (file_id, caller_id, ref_id) VALUES (?,?,?)`,
+			fileID, symbolIDs[callers[i]], refID,
+		); err != nil {
+			return fmt.Errorf("insert call %q: %w", ref.Name, err)
+		}
 	}
 
 	return tx.Commit()
 }
 
-// DeleteFile removes a file and its associated symbols/refs.
+// DeleteFile removes a file and its associated calls/symbols/name grams/refs.
 func (s *Store) DeleteFile(path string) error {
 	var fileID int64
 	err := s.DB.QueryRow(
@@ -190,7 +231,13 @@ func (s *Store) DeleteFile(path string) error {
 	if err != nil {
 		return err
 	}
-	// CASCADE should handle symbols/refs, but be explicit.
+	// CASCADE should handle calls/symbols/name grams/refs, but be explicit.
+	if _, err := s.DB.Exec(`DELETE FROM calls WHERE file_id = ?`, fileID); err != nil {
+		return err
+	}
+	if _, err := s.DB.Exec(`DELETE FROM symbol_name_grams WHERE file_id = ?`, fileID); err != nil {
+		return err
+	}
 	if _, err := s.DB.Exec(`DELETE FROM symbols WHERE file_id = ?`, fileID); err != nil {
 		return err
 	}
@@ -201,7 +248,7 @@ func (s *Store) DeleteFile(path string) error {
 	return err
 }
 
-// DeleteAllFiles removes all files (and cascaded symbols/refs) for the project.
+// DeleteAllFiles removes all files (and cascaded calls/symbols/refs) for the project.
 func (s *Store) DeleteAllFiles() error {
 	rows, err := s.DB.Query(`SELECT id FROM files WHERE project_id = ?`, s.ProjectID)
 	if err != nil {
@@ -220,6 +267,12 @@ func (s *Store) DeleteAllFiles() error {
 		return err
 	}
 	for _, id := range ids {
+		if _, err := s.DB.Exec(`DELETE FROM calls WHERE file_id = ?`, id); err != nil {
+			return err
+		}
+		if _, err := s.DB.Exec(`DELETE FROM symbol_name_grams WHERE file_id = ?`, id); err != nil {
+			return err
+		}
 		if _, err := s.DB.Exec(`DELETE FROM symbols WHERE file_id = ?`, id); err != nil {
 			return err
 		}
@@ -232,3 +285,51 @@ func (s *Store) DeleteAllFiles() error {
 	}
 	return nil
 }
+
+// GetMeta returns the value stored under key in the meta table, or "" if unset.
+func (s *Store) GetMeta(key string) (string, error) {
+	var v string
+	err := s.DB.QueryRow(`SELECT value FROM meta WHERE key = ?`, key).Scan(&v)
+	if err == sql.ErrNoRows {
+		return "", nil
+	}
+	return v, err
+}
+
+// SetMeta stores value under key in the meta table.
+func (s *Store) SetMeta(key, value string) error {
+	_, err := s.DB.Exec(
+		`INSERT INTO meta (key, value) VALUES (?, ?)
+		 ON CONFLICT(key) DO UPDATE SET value = excluded.value`,
+		key, value,
+	)
+	return err
+}
+
+// DeleteFilesByLang removes all files of the given language (and their
+// symbols/refs) so the next Reconcile re-indexes them.
+func (s *Store) DeleteFilesByLang(lang Lang) error {
+	rows, err := s.DB.Query(`SELECT path FROM files WHERE project_id = ? AND lang = ?`, s.ProjectID, string(lang))
+	if err != nil {
+		return err
+	}
+	var paths []string
+	for rows.Next() {
+		var p string
+		if err := rows.Scan(&p); err != nil {
+			_ = rows.Close()
+			return err
+		}
+		paths = append(paths, p)
+	}
+	_ = rows.Close()
+	if err := rows.Err(); err != nil {
+		return err
+	}
+	for _, p := range paths {
+		if err := s.DeleteFile(p); err != nil {
+			return err
+		}
+	}
+	return nil
+}
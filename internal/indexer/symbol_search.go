@@ -0,0 +1,534 @@
+package indexer
+
+import (
+	"fmt"
+	"path"
+	"path/filepath"
+	"sort"
+	"strings"
+	"unicode"
+	"unicode/utf8"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// maxSymbolSearchCandidates bounds the symbols scored for a search, taken
+// in the order of the most grams shared with the query.
+const maxSymbolSearchCandidates = 2000
+
+// maxSymbolSearchGramRows bounds the symbols a single gram of the query
+// contributes to the candidates.
+const maxSymbolSearchGramRows = 5000
+
+// Name gram markers: the first two characters of a name, and the initials
+// of its words (UserRepository: ~ur).
+const (
+	prefixGram   = "^"
+	initialsGram = "~"
+)
+
+// SymbolSearchOptions filters the symbols returned by SearchSymbols.
+type SymbolSearchOptions struct {
+	// Lang limits the search to a language and those it interoperates with;
+	// empty searches every language.
+	Lang string
+
+	// Kinds and Visibilities, when not empty, list the kinds and
+	// visibilities of the symbols returned.
+	Kinds        []symbols.SymbolKind
+	Visibilities []symbols.Visibility
+
+	// PathGlob, when set, limits the search to the files whose repo-relative
+	// path (or, for a pattern without a slash, base name) matches it; **
+	// matches any number of directories.
+	PathGlob string
+
+	// Limit is the maximum number of symbols returned (all when zero).
+	Limit int
+}
+
+// SymbolMatch is a symbol found by SearchSymbols, with the confidence
+// RankDefinitions gives it.
+type SymbolMatch struct {
+	RankedDefinition
+
+	// Match is how the name matches the query: exact, prefix, camelCase
+	// (the query abbreviates its words, URepo for UserRepository),
+	// substring or fuzzy (the query's characters appear in order).
+	Match string `json:"match"`
+
+	// Score ranks the matches: the quality of the name match times the
+	// confidence.
+	Score float64 `json:"score"`
+}
+
+// ParseKinds parses a comma-separated list of symbol kinds (class, method,
+// ...). An empty list parses to no kinds.
+func ParseKinds(list string) ([]symbols.SymbolKind, error) {
+	var kinds []symbols.SymbolKind
+	for _, s := range strings.Split(list, ",") {
+		name := strings.ToLower(strings.TrimSpace(s))
+		if name == "" {
+			continue
+		}
+		k := symbols.ParseKind(name)
+		if k == symbols.KindUnknown {
+			return nil, fmt.Errorf("unknown symbol kind %q", s)
+		}
+		kinds = append(kinds, k)
+	}
+	return kinds, nil
+}
+
+// nameGrams returns the keys under which the symbol search index stores a
+// name: the trigrams of the lowercased name, a prefix gram for its first
+// two characters and an initials gram for its words.
+func nameGrams(name string) []string {
+	lower := []rune(strings.ToLower(name))
+	if len(lower) == 0 {
+		return nil
+	}
+	seen := map[string]bool{}
+	var grams []string
+	add := func(g string) {
+		if !seen[g] {
+			seen[g] = true
+			grams = append(grams, g)
+		}
+	}
+	add(prefixGram + string(lower[:min(2, len(lower))]))
+	for i := 0; i+3 <= len(lower); i++ {
+		add(string(lower[i : i+3]))
+	}
+	if initials := wordInitials(nameWords(name)); utf8.RuneCountInString(initials) >= 2 {
+		add(initialsGram + initials)
+	}
+	return grams
+}
+
+// nameWords splits a name into its words at underscores, dashes and other
+// separators and at camelCase humps (HTTPServer: HTTP, Server).
+func nameWords(name string) []string {
+	runes := []rune(name)
+	var words []string
+	start := -1
+	for i, r := range runes {
+		if !unicode.IsLetter(r) && !unicode.IsDigit(r) {
+			if start >= 0 {
+				words = append(words, string(runes[start:i]))
+				start = -1
+			}
+			continue
+		}
+		if start < 0 {
+			start = i
+			continue
+		}
+		prev := runes[i-1]
+		if unicode.IsUpper(r) && (unicode.IsLower(prev) || unicode.IsDigit(prev) ||
+			(unicode.IsUpper(prev) && i+1 < len(runes) && unicode.IsLower(runes[i+1]))) {
+			words = append(words, string(runes[start:i]))
+			start = i
+		}
+	}
+	if start >= 0 {
+		words = append(words, string(runes[start:]))
+	}
+	return words
+}
+
+// wordInitials returns the lowercased first characters of words.
+func wordInitials(words []string) string {
+	var b strings.Builder
+	for _, w := range words {
+		r, _ := utf8.DecodeRuneInString(w)
+		b.WriteRune(unicode.ToLower(r))
+	}
+	return b.String()
+}
+
+// SearchSymbols returns the symbols whose name matches query exactly, by
+// prefix, as a camelCase abbreviation, as a substring or fuzzily, best
+// first. Candidates come from the name grams of the index, or from a scan
+// of the names when the grams find none the query matches; each is scored
+// on how well its name matches, weighted by the kind, visibility and noisy
+// path confidence of RankDefinitions.
+func (n *Navigator) SearchSymbols(query string, opts SymbolSearchOptions) ([]SymbolMatch, error) {
+	query = strings.TrimSpace(query)
+	if query == "" {
+		return nil, fmt.Errorf("empty symbol search query")
+	}
+	defs, err := n.symbolSearchCandidates(query, opts)
+	if err != nil {
+		return nil, err
+	}
+
+	var matches []SymbolMatch
+	for _, d := range defs {
+		if opts.PathGlob != "" && !matchPathGlob(opts.PathGlob, d.Location.Path) {
+			continue
+		}
+		match, quality := matchSymbolName(query, d.Name)
+		if match == "" {
+			continue
+		}
+		conf := definitionConfidence(d, "")
+		matches = append(matches, SymbolMatch{
+			RankedDefinition: RankedDefinition{DefinitionResult: d, Confidence: conf},
+			Match:            match,
+			Score:            round4(quality * conf),
+		})
+	}
+	sort.SliceStable(matches, func(i, j int) bool {
+		if matches[i].Score != matches[j].Score {
+			return matches[i].Score > matches[j].Score
+		}
+		return len(matches[i].Name) < len(matches[j].Name)
+	})
+	if opts.Limit > 0 && len(matches) > opts.Limit {
+		matches = matches[:opts.Limit]
+	}
+	cells := newNotebookLocator(n.RepoRoot)
+	for i := range matches {
+		cells.annotate(&matches[i].Location)
+	}
+	return matches, nil
+}
+
+// symbolSearchCandidates returns the symbols passing the filters of opts
+// that share a trigram, their prefix gram or their initials with query, or
+// are named query, those sharing the most grams first. Each gram adds at
+// most maxSymbolSearchGramRows symbols, so that grams common to many names
+// (get, ^ge) do not read the whole index. A query too short to have a
+// trigram, or whose grams find no name it matches (qvst for QuiverStore),
+// falls back to a scan of the names containing its characters in order,
+// bounded by maxSymbolSearchCandidates.
+func (n *Navigator) symbolSearchCandidates(query string, opts SymbolSearchOptions) ([]DefinitionResult, error) {
+	lower := []rune(strings.ToLower(query))
+
+	// The filters are applied to the rows of each gram, before they are
+	// capped.
+	where := []string{"f.project_id = ?"}
+	whereArgs := []interface{}{n.ProjectID}
+	if opts.Lang != "" {
+		langs := InteropLangs(opts.Lang)
+		where = append(where, `f.lang IN (`+strings.TrimSuffix(strings.Repeat("?,", len(langs)), ",")+`)`)
+		for _, l := range langs {
+			whereArgs = append(whereArgs, l)
+		}
+	}
+	if len(opts.Kinds) > 0 {
+		where = append(where, `s.kind IN (`+strings.TrimSuffix(strings.Repeat("?,", len(opts.Kinds)), ",")+`)`)
+		for _, k := range opts.Kinds {
+			whereArgs = append(whereArgs, int(k))
+		}
+	}
+	if len(opts.Visibilities) > 0 {
+		where = append(where, `s.visibility IN (`+strings.TrimSuffix(strings.Repeat("?,", len(opts.Visibilities)), ",")+`)`)
+		for _, v := range opts.Visibilities {
+			whereArgs = append(whereArgs, string(v))
+		}
+	}
+	if opts.PathGlob != "" {
+		// SQLite's * also matches slashes: a superset of matchPathGlob,
+		// which filters the rows exactly.
+		glob := strings.ReplaceAll(strings.ReplaceAll(opts.PathGlob, "**/", "*"), "**", "*")
+		where = append(where, `(f.path GLOB ? OR f.path GLOB ?)`)
+		whereArgs = append(whereArgs, glob, "*/"+glob)
+	}
+	filter := strings.Join(where, " AND ")
+
+	var grams []string
+	var args []interface{}
+	addGram := func(cond string, condArgs ...interface{}) {
+		grams = append(grams, `SELECT * FROM (
+			SELECT g.symbol_id, 1 AS hits
+			FROM symbol_name_grams g
+			JOIN symbols s ON s.id = g.symbol_id
+			JOIN files f ON s.file_id = f.id
+			WHERE `+cond+` AND `+filter+`
+			LIMIT ?
+		)`)
+		args = append(args, condArgs...)
+		args = append(args, whereArgs...)
+		args = append(args, maxSymbolSearchGramRows)
+	}
+
+	// A name sharing a trigram with the query.
+	seen := map[string]bool{}
+	for i := 0; i+3 <= len(lower); i++ {
+		if g := string(lower[i : i+3]); !seen[g] {
+			seen[g] = true
+			addGram(`g.gram = ?`, g)
+		}
+	}
+	trigrams := len(grams)
+
+	// A name starting like the query, and one whose words the query
+	// abbreviates (URepo, or ur for UserRepository).
+	prefixes := []string{prefixGram + string(lower[:min(2, len(lower))])}
+	if words := nameWords(query); len(words) >= 2 {
+		prefixes = append(prefixes, initialsGram+wordInitials(words))
+	} else if isLowerLetters(query) {
+		prefixes = append(prefixes, initialsGram+string(lower))
+	}
+	for _, p := range prefixes {
+		addGram(`g.gram >= ? AND g.gram < ?`, p, p+string(utf8.MaxRune))
+	}
+
+	// A name equal to the query, which the caps may have left out, ranks
+	// as sharing every gram.
+	exact := `SELECT s.id AS symbol_id, ? AS hits
+		FROM symbols s
+		JOIN files f ON s.file_id = f.id
+		WHERE s.name = ? AND ` + filter
+	args = append(args, len(grams), query)
+	args = append(args, whereArgs...)
+	args = append(args, maxSymbolSearchCandidates)
+
+	defs, err := n.queryDefinitions(`
+		SELECT `+definitionColumns+`
+		FROM (
+			SELECT symbol_id, SUM(hits) AS hits
+			FROM (`+strings.Join(append(grams, exact), " UNION ALL ")+`)
+			GROUP BY symbol_id
+		) g
+		JOIN symbols s ON s.id = g.symbol_id
+		JOIN files f ON s.file_id = f.id
+		ORDER BY g.hits DESC, length(s.name) ASC, f.path ASC, s.start_line ASC
+		LIMIT ?
+	`, args...)
+	if err != nil {
+		return nil, fmt.Errorf("query symbol search candidates: %w", err)
+	}
+	matched := definitionsMatching(query, defs)
+	if trigrams > 0 && len(matched) > 0 {
+		return defs, nil
+	}
+	defs = matched
+
+	// Every name query matches contains its characters in order, which
+	// LIKE compares case-insensitively.
+	scanArgs := append([]interface{}{subsequencePattern(query)}, whereArgs...)
+	scanArgs = append(scanArgs, maxSymbolSearchCandidates)
+	scanned, err := n.queryDefinitions(`
+		SELECT `+definitionColumns+`
+		FROM symbols s
+		JOIN files f ON s.file_id = f.id
+		WHERE s.name LIKE ? ESCAPE '\' AND `+filter+`
+		ORDER BY length(s.name) ASC, f.path ASC, s.start_line ASC
+		LIMIT ?
+	`, scanArgs...)
+	if err != nil {
+		return nil, fmt.Errorf("scan symbol search candidates: %w", err)
+	}
+	found := make(map[Location]bool, len(defs))
+	for _, d := range defs {
+		found[d.Location] = true
+	}
+	for _, d := range scanned {
+		if len(defs) == maxSymbolSearchCandidates {
+			break
+		}
+		if !found[d.Location] {
+			defs = append(defs, d)
+		}
+	}
+	return defs, nil
+}
+
+// queryDefinitions runs a query selecting definitionColumns.
+func (n *Navigator) queryDefinitions(query string, args ...interface{}) ([]DefinitionResult, error) {
+	rows, err := n.DB.Query(query, args...)
+	if err != nil {
+		return nil, err
+	}
+	defer func() { _ = rows.Close() }()
+
+	var defs []DefinitionResult
+	for rows.Next() {
+		d, err := scanDefinition(rows)
+		if err != nil {
+			return nil, err
+		}
+		defs = append(defs, d)
+	}
+	return defs, rows.Err()
+}
+
+// definitionsMatching returns the definitions of defs whose name query
+// matches.
+func definitionsMatching(query string, defs []DefinitionResult) []DefinitionResult {
+	var matched []DefinitionResult
+	for _, d := range defs {
+		if match, _ := matchSymbolName(query, d.Name); match != "" {
+			matched = append(matched, d)
+		}
+	}
+	return matched
+}
+
+// subsequencePattern returns the LIKE pattern matching the strings that
+// contain the characters of query in order, escaped with \.
+func subsequencePattern(query string) string {
+	var b strings.Builder
+	b.WriteByte('%')
+	for _, r := range query {
+		if r == '%' || r == '_' || r == '\\' {
+			b.WriteByte('\\')
+		}
+		b.WriteRune(r)
+		b.WriteByte('%')
+	}
+	return b.String()
+}
+
+// isLowerLetters reports whether s is made of lowercase letters only.
+func isLowerLetters(s string) bool {
+	for _, r := range s {
+		if !unicode.IsLower(r) {
+			return false
+		}
+	}
+	return s != ""
+}
+
+// matchSymbolName returns how name matches query, or "" when it does not,
+// and the quality of the match in [0, 1]: exact, then prefix, camelCase,
+// substring and fuzzy matches, each better the more of the name the query
+// covers.
+func matchSymbolName(query, name string) (string, float64) {
+	lowerQuery, lowerName := strings.ToLower(query), strings.ToLower(name)
+	coverage := 0.1 * float64(utf8.RuneCountInString(query)) / float64(max(1, utf8.RuneCountInString(name)))
+	switch {
+	case query == name:
+		return "exact", 1.0
+	case lowerQuery == lowerName:
+		return "exact", 0.95
+	case strings.HasPrefix(lowerName, lowerQuery):
+		return "prefix", 0.75 + coverage
+	case matchesWordAbbreviation(query, name):
+		return "camelCase", 0.65 + coverage
+	}
+	if i := strings.Index(lowerName, lowerQuery); i >= 0 {
+		quality := 0.5 + coverage
+		if startsWord(name, i) {
+			quality += 0.05
+		}
+		return "substring", quality
+	}
+	if isSubsequence(lowerQuery, lowerName) {
+		return "fuzzy", 0.3 + coverage
+	}
+	return "", 0
+}
+
+// matchesWordAbbreviation reports whether query abbreviates the words of
+// name: each of its words (or, for an all-lowercase query, each of its
+// letters) begins one of the words of name, in order. URepo and ur
+// abbreviate UserRepository.
+func matchesWordAbbreviation(query, name string) bool {
+	parts := nameWords(query)
+	if len(parts) < 2 && isLowerLetters(query) {
+		parts = strings.Split(query, "")
+	}
+	if len(parts) < 2 {
+		return false
+	}
+	words := nameWords(name)
+	w := 0
+	for _, p := range parts {
+		p = strings.ToLower(p)
+		for w < len(words) && !strings.HasPrefix(strings.ToLower(words[w]), p) {
+			w++
+		}
+		if w == len(words) {
+			return false
+		}
+		w++
+	}
+	return true
+}
+
+// startsWord reports whether the byte offset i of name begins one of its
+// words.
+func startsWord(name string, i int) bool {
+	if i == 0 {
+		return true
+	}
+	prev, _ := utf8.DecodeLastRuneInString(name[:i])
+	r, _ := utf8.DecodeRuneInString(name[i:])
+	if !unicode.IsLetter(prev) && !unicode.IsDigit(prev) {
+		return true
+	}
+	return unicode.IsUpper(r) && !unicode.IsUpper(prev)
+}
+
+// isSubsequence reports whether the characters of sub appear in s in order.
+func isSubsequence(sub, s string) bool {
+	rest := []rune(sub)
+	for _, r := range s {
+		if len(rest) == 0 {
+			break
+		}
+		if r == rest[0] {
+			rest = rest[1:]
+		}
+	}
+	return len(rest) == 0
+}
+
+// matchPathGlob reports whether a repo-relative path matches pattern, in
+// which ** matches any number of directories. A pattern without a slash
+// may also match the base name.
+func matchPathGlob(pattern, relPath string) bool {
+	relPath = filepath.ToSlash(relPath)
+	if matchGlobSegments(strings.Split(pattern, "/"), strings.Split(relPath, "/")) {
+		return true
+	}
+	if !strings.Contains(pattern, "/") {
+		ok, _ := path.Match(pattern, path.Base(relPath))
+		return ok
+	}
+	return false
+}
+
+// matchGlobSegments matches the segments of a path against those of a
+// pattern.
+func matchGlobSegments(pattern, segs []string) bool {
+	for len(pattern) > 0 {
+		if pattern[0] == "**" {
+			for i := 0; i <= len(segs); i++ {
+				if matchGlobSegments(pattern[1:], segs[i:]) {
+					return true
+				}
+			}
+			return false
+		}
+		if len(segs) == 0 {
+			return false
+		}
+		if ok, _ := path.Match(pattern[0], segs[0]); !ok {
+			return false
+		}
+		pattern, segs = pattern[1:], segs[1:]
+	}
+	return len(segs) == 0
+}
+
+// FormatSymbolMatches formats the results of a symbol search for MCP.
+func FormatSymbolMatches(query string, matches []SymbolMatch) string {
+	var b strings.Builder
+	fmt.Fprintf(&b, "Symbols matching %q\n", query)
+	if len(matches) == 0 {
+		b.WriteString("(none)\n")
+	}
+	for _, m := range matches {
+		name := m.Name
+		if m.QualifiedName != "" {
+			name = m.QualifiedName
+		}
+		fmt.Fprintf(&b, "- %s (%s) at %s [%s, score %.2f]\n", name, kindAndVisibility(m.DefinitionResult), m.Location, m.Match, m.Score)
+	}
+	return strings.TrimSuffix(b.String(), "\n")
+}
@@ -0,0 +1,156 @@
+package indexer
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// symbolMatchNames renders matches as their names.
+func symbolMatchNames(matches []SymbolMatch) string {
+	var names []string
+	for _, m := range matches {
+		names = append(names, m.Name)
+	}
+	return strings.Join(names, " ")
+}
+
+func TestSearchSymbols(t *testing.T) {
+	tests := []struct {
+		query string
+		lang  string
+		first string
+		match string
+	}{
+		{"QuiverRepo", "go", "QuiverRepo", "exact"},
+		{"quiverrepos", "go", "QuiverRepository", "prefix"},
+		{"QRepo", "go", "QuiverRepo", "camelCase"},
+		{"fqa", "go", "FetchQuiverArrow", "camelCase"},
+		{"Arrow", "go", "FetchQuiverArrow", "substring"},
+		{"qvrstr", "java", "QuiverStore", "fuzzy"},
+		{"Qu", "java", "QuiverStore", "prefix"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.query, func(t *testing.T) {
+			matches, err := sharedNav.SearchSymbols(tt.query, SymbolSearchOptions{Lang: tt.lang})
+			if err != nil {
+				t.Fatal(err)
+			}
+			if len(matches) == 0 {
+				t.Fatalf("no symbols match %q", tt.query)
+			}
+			if got := matches[0]; got.Name != tt.first || got.Match != tt.match {
+				t.Errorf("first match = %s (%s), want %s (%s); all: %s",
+					got.Name, got.Match, tt.first, tt.match, symbolMatchNames(matches))
+			}
+		})
+	}
+}
+
+func TestSearchSymbolsWithoutSharedGrams(t *testing.T) {
+	tests := []struct {
+		query string
+		opts  SymbolSearchOptions
+		first string
+		match string
+	}{
+		// too short for a trigram, and not a prefix
+		{"up", SymbolSearchOptions{PathGlob: "**/symsearch/*.go"}, "Lookup", "substring"},
+		// sharing no gram with the name
+		{"qvst", SymbolSearchOptions{Lang: "java"}, "QuiverStore", "fuzzy"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.query, func(t *testing.T) {
+			matches, err := sharedNav.SearchSymbols(tt.query, tt.opts)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if len(matches) == 0 {
+				t.Fatalf("no symbols match %q", tt.query)
+			}
+			if got := matches[0]; got.Name != tt.first || got.Match != tt.match {
+				t.Errorf("first match = %s (%s), want %s (%s); all: %s",
+					got.Name, got.Match, tt.first, tt.match, symbolMatchNames(matches))
+			}
+		})
+	}
+}
+
+func TestSearchSymbolsFilters(t *testing.T) {
+	tests := []struct {
+		name string
+		opts SymbolSearchOptions
+		want string
+	}{
+		{"kind", SymbolSearchOptions{Kinds: []symbols.SymbolKind{symbols.KindMethod}}, "stockQuiver FetchQuiverArrow"},
+		{"language", SymbolSearchOptions{Lang: "java"}, "QuiverStore stockQuiver quiverCount"},
+		{"path glob", SymbolSearchOptions{PathGlob: "**/symsearch/*.java"}, "QuiverStore stockQuiver quiverCount"},
+		{"visibility", SymbolSearchOptions{Lang: "go", Visibilities: []symbols.Visibility{symbols.VisibilityPublic}}, "QuiverRepo QuiverRepository FetchQuiverArrow"},
+		{"limit", SymbolSearchOptions{Lang: "go", Limit: 1}, "QuiverRepo"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			matches, err := sharedNav.SearchSymbols("quiver", tt.opts)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if got := symbolMatchNames(matches); got != tt.want {
+				t.Errorf("matches = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestMatchPathGlob(t *testing.T) {
+	tests := []struct {
+		pattern string
+		path    string
+		want    bool
+	}{
+		{"internal/**/*.go", "internal/indexer/store.go", true},
+		{"internal/**/*.go", "internal/store.go", true},
+		{"internal/*.go", "internal/indexer/store.go", false},
+		{"*.go", "internal/indexer/store.go", true},
+		{"store.go", "internal/indexer/store.go", true},
+		{"cmd/**", "internal/indexer/store.go", false},
+	}
+	for _, tt := range tests {
+		if got := matchPathGlob(tt.pattern, tt.path); got != tt.want {
+			t.Errorf("matchPathGlob(%q, %q) = %v, want %v", tt.pattern, tt.path, got, tt.want)
+		}
+	}
+}
+
+func TestSearchSymbolsCommonGrams(t *testing.T) {
+	// Every gram of Handler is shared by more Go names than a gram reads.
+	root := t.TempDir()
+	var b strings.Builder
+	b.WriteString("package handlers\n")
+	for i := 0; i < maxSymbolSearchGramRows+100; i++ {
+		fmt.Fprintf(&b, "\nfunc HandlerNo%d() {}\n", i)
+	}
+	writeTempFile(t, root, "handlers/handlers.go", b.String())
+	writeTempFile(t, root, "Handler.java", "class Handler {\n}\n")
+	nav, _, cleanup := setupTempRepo(t, root)
+	defer cleanup()
+
+	tests := []struct {
+		query string
+		opts  SymbolSearchOptions
+		match string
+	}{
+		{"Handler", SymbolSearchOptions{}, "exact"},
+		{"andler", SymbolSearchOptions{Lang: "java"}, "substring"}, // filtered before the cap
+	}
+	for _, tt := range tests {
+		matches, err := nav.SearchSymbols(tt.query, tt.opts)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if len(matches) == 0 || matches[0].Name != "Handler" || matches[0].Match != tt.match {
+			t.Errorf("%s: first match of %d is not Handler (%s)", tt.query, len(matches), tt.match)
+		}
+	}
+}
@@ -0,0 +1,36 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include "sample.h"
+
+#define MAX_RETRIES 3
+#define SQUARE(x) ((x) * (x))
+
+typedef struct Person {
+    char *name;
+    int age;
+} Person;
+
+enum Color {
+    COLOR_RED,
+    COLOR_GREEN,
+};
+
+static const char *default_name = "World";
+
+Person *new_person(char *name, int age) {
+    Person *p = malloc(sizeof(Person));
+    p->name = name;
+    p->age = age;
+    return p;
+}
+
+void say_hello(Person *p) {
+    printf("Hello, %s (%d)\n", p->name, p->age);
+}
+
+int main(void) {
+    Person *p = new_person("Ada", MAX_RETRIES);
+    say_hello(p);
+    free(p);
+    return 0;
+}
@@ -0,0 +1,21 @@
+package calls
+
+func Checkout(cart []int) int {
+	total := SumCart(cart)
+	return ApplyFees(total)
+}
+
+func SumCart(cart []int) int {
+	if len(cart) == 0 {
+		return 0
+	}
+	return cart[0] + SumCart(cart[1:])
+}
+
+func ApplyFees(total int) int {
+	return total + 1
+}
+
+func Reorder(cart []int) int {
+	return Checkout(cart)
+}
@@ -0,0 +1,19 @@
+#pragma once
+
+namespace meters {
+
+template <typename T>
+T clamp_level(T value, T low, T high) {
+    return value < low ? low : (value > high ? high : value);
+}
+
+class Gauge {
+public:
+    explicit Gauge(int level) : level_(level) {}
+    int read() const;
+
+private:
+    int level_;
+};
+
+} // namespace meters
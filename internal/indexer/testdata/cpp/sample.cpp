@@ -0,0 +1,45 @@
+#include <iostream>
+#include <string>
+
+namespace shapes {
+
+class Shape {
+public:
+    virtual ~Shape() = default;
+    virtual double area() const = 0;
+};
+
+class Circle : public Shape {
+public:
+    Circle(double radius) : radius_(radius) {}
+    double area() const override { return 3.14159 * radius_ * radius_; }
+
+private:
+    double radius_;
+};
+
+struct Point {
+    int x;
+    int y;
+};
+
+enum class Kind { Round, Square };
+
+using Area = double;
+
+double total_area(const Shape &a, const Shape &b) {
+    return a.area() + b.area();
+}
+
+}  // namespace shapes
+
+void print_area(const shapes::Shape &s) {
+    std::cout << s.area() << std::endl;
+}
+
+int main() {
+    shapes::Circle c(2.0);
+    print_area(c);
+    double t = shapes::total_area(c, c);
+    return t > 0 ? 0 : 1;
+}
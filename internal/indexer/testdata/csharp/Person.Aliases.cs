@@ -0,0 +1,14 @@
+using System.Collections.Generic;
+
+namespace Acme.Billing
+{
+    public partial class Person
+    {
+        public IReadOnlyList<string> Aliases => _aliases;
+
+        public void AddAlias(string alias)
+        {
+            _aliases.Add(alias);
+        }
+    }
+}
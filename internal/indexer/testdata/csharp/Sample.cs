@@ -0,0 +1,59 @@
+using System;
+using System.Collections.Generic;
+
+namespace Acme.Billing
+{
+    public interface IGreeter
+    {
+        string Greet(string name);
+    }
+
+    public abstract class Entity
+    {
+        public Guid Id { get; set; }
+    }
+
+    public partial class Person : Entity, IGreeter
+    {
+        public const int MaxRetries = 3;
+        private readonly List<string> _aliases = new List<string>();
+
+        public string Name { get; set; }
+
+        public Person(string name)
+        {
+            Name = name;
+        }
+
+        public string Greet(string other)
+        {
+            return $"Hello {other}, I am {Name}";
+        }
+    }
+
+    public struct Point : IEquatable<Point>
+    {
+        public int X;
+        public int Y;
+
+        public bool Equals(Point other) => X == other.X && Y == other.Y;
+    }
+
+    public record Invoice(string Number, decimal Total);
+
+    public enum Status
+    {
+        Active,
+        Suspended,
+    }
+
+    public static class Program
+    {
+        public static void Main(string[] args)
+        {
+            var person = new Person("Ada");
+            Console.WriteLine(person.Greet("Bob"));
+            person.AddAlias("Countess");
+        }
+    }
+}
@@ -0,0 +1,11 @@
+package docs;
+
+public class Pricing {
+    /**
+     * Returns the tax owed on a price.
+     */
+    @Deprecated
+    public double computeTax(double price) {
+        return price * 0.2;
+    }
+}
@@ -0,0 +1,10 @@
+package docs
+
+// ApplyDiscount returns the price after the discount.
+//
+// The discount is a fraction between 0 and 1.
+//
+//go:noinline
+func ApplyDiscount(price, discount float64) float64 {
+	return price * (1 - discount)
+}
@@ -0,0 +1,6 @@
+def apply_surcharge(price, rate):
+    """Return the price with the surcharge added.
+
+    The rate is a fraction of the price.
+    """
+    return price * (1 + rate)
@@ -0,0 +1,5 @@
+/// Rounds a price to whole cents.
+#[inline]
+pub fn round_cents(price: f64) -> f64 {
+    (price * 100.0).round() / 100.0
+}
@@ -0,0 +1,7 @@
+/**
+ * Formats a price for display.
+ * @param price the price in euros
+ */
+export function formatPrice(price: number): string {
+  return price.toFixed(2);
+}
@@ -0,0 +1,16 @@
+package billing
+
+// Invoice is a bill for one order.
+type Invoice struct {
+	Total int
+}
+
+// New returns an invoice for the given total.
+func New(total int) *Invoice {
+	return &Invoice{Total: total}
+}
+
+// Add raises the invoice total.
+func (i *Invoice) Add(amount int) {
+	i.Total += amount
+}
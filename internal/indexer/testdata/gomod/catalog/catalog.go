@@ -0,0 +1,23 @@
+package catalog
+
+// Catalog lists the products on sale.
+type Catalog struct {
+	Items []string
+}
+
+// New returns an empty catalog.
+func New() *Catalog {
+	return &Catalog{}
+}
+
+// Println prints the catalog; it shares its name with fmt.Println.
+func Println(c *Catalog) {
+	for _, item := range c.Items {
+		println(item)
+	}
+}
+
+// Add puts an item on sale.
+func (c *Catalog) Add(item string) {
+	c.Items = append(c.Items, item)
+}
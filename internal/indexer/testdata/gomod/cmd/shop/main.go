@@ -0,0 +1,17 @@
+package main
+
+import (
+	"fmt"
+
+	bill "example.com/shop/billing"
+	"example.com/shop/catalog"
+	"example.com/shop/internal/go-pricing"
+)
+
+func main() {
+	c := catalog.New()
+	c.Items = append(c.Items, "lamp")
+	inv := bill.New(pricing.Quote("lamp"))
+	fmt.Println(inv.Total)
+	catalog.Println(c)
+}
@@ -0,0 +1,6 @@
+package pricing
+
+// Quote returns the price of an item in cents.
+func Quote(item string) int {
+	return len(item) * 100
+}
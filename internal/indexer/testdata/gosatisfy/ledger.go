@@ -0,0 +1,91 @@
+package gosatisfy
+
+import "io"
+
+// Poster records entries.
+type Poster interface {
+	Post(amount int, memo string) error
+	Balance() int
+}
+
+// Auditor is a Poster that can also be audited.
+type Auditor interface {
+	Poster
+	Audit() []string
+}
+
+// Faulter embeds the predeclared error interface.
+type Faulter interface {
+	error
+	Code() int
+}
+
+// Streamer embeds an interface from the standard library.
+type Streamer interface {
+	io.Reader
+	Stream() int
+}
+
+// Journal keeps entries in memory.
+type Journal struct {
+	entries []int
+}
+
+// Balance sums the entries.
+func (j Journal) Balance() int {
+	total := 0
+	for _, e := range j.entries {
+		total += e
+	}
+	return total
+}
+
+// Post appends an entry.
+func (j *Journal) Post(amount int, memo string) error {
+	j.entries = append(j.entries, amount)
+	return nil
+}
+
+// AuditedJournal promotes the methods of its embedded Journal.
+type AuditedJournal struct {
+	*Journal
+	trail []string
+}
+
+// Audit returns the audit trail.
+func (a AuditedJournal) Audit() []string {
+	return a.trail
+}
+
+// Tally has a Post method taking another number of parameters.
+type Tally struct{}
+
+// Post ignores the amount.
+func (Tally) Post(amount int) error {
+	return nil
+}
+
+// Balance is always zero.
+func (Tally) Balance() int {
+	return 0
+}
+
+// Fault is an error with a code.
+type Fault struct {
+	code int
+}
+
+// Error describes the fault.
+func (f Fault) Error() string {
+	return "fault"
+}
+
+// Code returns the fault code.
+func (f Fault) Code() int {
+	return f.code
+}
+
+// Settle posts a balance through any Poster.
+func Settle(p Poster) error {
+	return p.Post(p.Balance(), "settle")
+}
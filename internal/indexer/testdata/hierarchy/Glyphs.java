@@ -0,0 +1,9 @@
+package hierarchy;
+
+interface Glyph {}
+
+interface Outline extends Glyph {}
+
+abstract class Polygon implements Glyph {}
+
+class Square extends Polygon implements Outline {}
@@ -0,0 +1,9 @@
+pub trait Chime {}
+
+pub trait Peal: Chime {}
+
+pub struct Bellow;
+
+impl Chime for Bellow {}
+
+impl Peal for Bellow {}
@@ -0,0 +1,13 @@
+import abc
+
+
+class Craft:
+    pass
+
+
+class Barge(Craft):
+    pass
+
+
+class Ferry(Barge, abc.ABC):
+    pass
@@ -0,0 +1,5 @@
+export interface Herald {}
+
+export class BaseHerald implements Herald {}
+
+export class LoudHerald extends BaseHerald {}
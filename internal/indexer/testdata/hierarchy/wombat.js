@@ -0,0 +1,7 @@
+function Marsupial() {}
+
+function Wombat() {
+  Marsupial.call(this);
+}
+
+Wombat.prototype = Object.create(Marsupial.prototype);
@@ -0,0 +1,14 @@
+package com.shop.app;
+
+import com.shop.http.Request;
+
+class Handler {
+    private final Request req;
+
+    Handler(Request req) {
+        this.req = req;
+    }
+
+    void handle() {
+    }
+}
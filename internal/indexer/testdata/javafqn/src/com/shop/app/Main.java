@@ -0,0 +1,13 @@
+package com.shop.app;
+
+import com.shop.http.Request;
+import static com.shop.queue.Request.of;
+
+public class Main {
+    public static void main(String[] args) {
+        Request req = Request.of("/cart");
+        com.shop.queue.Request job = of(42);
+        Handler handler = new Handler(req);
+        handler.handle();
+    }
+}
@@ -0,0 +1,13 @@
+package com.shop.http;
+
+public class Request {
+    private final String path;
+
+    public Request(String path) {
+        this.path = path;
+    }
+
+    public static Request of(String path) {
+        return new Request(path);
+    }
+}
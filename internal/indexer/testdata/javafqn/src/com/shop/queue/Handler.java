@@ -0,0 +1,6 @@
+package com.shop.queue;
+
+public class Handler {
+    public void handle(Request job) {
+    }
+}
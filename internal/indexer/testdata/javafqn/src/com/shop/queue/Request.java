@@ -0,0 +1,13 @@
+package com.shop.queue;
+
+public class Request {
+    private final int id;
+
+    public Request(int id) {
+        this.id = id;
+    }
+
+    public static Request of(int id) {
+        return new Request(id);
+    }
+}
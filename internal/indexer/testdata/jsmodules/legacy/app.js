@@ -0,0 +1,9 @@
+const factory = require("./factory");
+const { destroy } = require("./factory.js");
+
+function run() {
+  const thing = factory.build("crate");
+  destroy(thing);
+}
+
+module.exports = { run };
@@ -0,0 +1,9 @@
+function build(name) {
+  return { name };
+}
+
+exports.destroy = function (thing) {
+  return null;
+};
+
+module.exports.build = build;
@@ -0,0 +1,9 @@
+import { create, render } from "@lib/widgets";
+import * as shapes from "../lib/shapes/create.js";
+import { join } from "node:path";
+
+export function main(): string {
+  const widget = create("button");
+  shapes.create(4);
+  return join(render(widget), "out");
+}
@@ -0,0 +1,3 @@
+export function create(sides: number): number[] {
+  return new Array(sides).fill(0);
+}
@@ -0,0 +1,7 @@
+export interface Widget {
+  name: string;
+}
+
+export function create(name: string): Widget {
+  return { name };
+}
@@ -0,0 +1,2 @@
+export { create } from "./create";
+export * from "./render";
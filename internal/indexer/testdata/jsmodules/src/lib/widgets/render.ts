@@ -0,0 +1,5 @@
+import type { Widget } from "./create";
+
+export function render(widget: Widget): string {
+  return `<${widget.name}>`;
+}
@@ -0,0 +1,46 @@
+package com.example.shop
+
+import com.example.Sample
+import kotlin.math.max
+
+typealias Sku = String
+
+interface Priced {
+    fun price(): Long
+}
+
+open class Product(val sku: Sku, val cents: Long) : Priced {
+    override fun price(): Long = cents
+
+    companion object {
+        const val FREE_SHIPPING_CENTS = 5000L
+
+        fun free(sku: Sku): Product = Product(sku, 0)
+    }
+}
+
+data class CartLine(val product: Product, val quantity: Int)
+
+class DiscountedProduct(sku: Sku, cents: Long, private val percent: Int) : Product(sku, cents) {
+    override fun price(): Long = max(0, cents - cents * percent / 100)
+}
+
+enum class Currency {
+    EUR,
+    USD,
+}
+
+object CartRegistry {
+    val carts = mutableListOf<CartLine>()
+
+    fun register(line: CartLine) {
+        carts.add(line)
+    }
+}
+
+fun CartLine.subtotal(): Long = product.price() * quantity
+
+val CartLine.label: String
+    get() = "${product.sku} x$quantity"
+
+fun describe(sample: Sample): String = sample.getName()
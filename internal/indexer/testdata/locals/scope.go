@@ -0,0 +1,15 @@
+package locals
+
+// limit is shadowed by the parameter of ClampScore.
+var limit = 10
+
+func ClampScore(value, limit int) int {
+	if value > limit {
+		return limit
+	}
+	return value
+}
+
+func DefaultLimit() int {
+	return limit
+}
@@ -0,0 +1,5 @@
+def tally_entries(entries):
+    count = 0
+    for entry in entries:
+        count += entry
+    return count
@@ -0,0 +1,34 @@
+package overrides;
+
+interface Tile {
+    double area();
+
+    String label(String prefix);
+}
+
+abstract class BaseTile implements Tile {
+    public String label(String prefix) {
+        return prefix + area();
+    }
+}
+
+class SquareTile extends BaseTile {
+    public double area() {
+        return 4.0;
+    }
+
+    @Override
+    public String label(String prefix) {
+        return "square";
+    }
+
+    public String label(String prefix, int width) {
+        return "wide";
+    }
+}
+
+class RoundTile extends BaseTile {
+    public double area() {
+        return 3.14;
+    }
+}
@@ -0,0 +1,15 @@
+pub trait Toll {
+    fn toll(&self) -> u32;
+
+    fn echo(&self) -> u32 {
+        self.toll()
+    }
+}
+
+pub struct Carillon;
+
+impl Toll for Carillon {
+    fn toll(&self) -> u32 {
+        3
+    }
+}
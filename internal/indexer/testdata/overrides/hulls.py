@@ -0,0 +1,17 @@
+class Hull:
+    def displace(self, tons):
+        raise NotImplementedError
+
+
+class Dinghy(Hull):
+    def displace(self, tons):
+        return tons / 2
+
+
+class Skiff(Dinghy):
+    pass
+
+
+class Punt(Skiff):
+    def displace(self, tons):
+        return tons
@@ -0,0 +1,78 @@
+<?php
+
+namespace App\Billing;
+
+use App\Support\Money;
+use JsonSerializable;
+
+interface Payable
+{
+    const CURRENCY = 'EUR';
+
+    public function amountDue(): int;
+}
+
+trait Timestamps
+{
+    protected $createdAt;
+
+    public function touch(): void
+    {
+        $this->createdAt = time();
+    }
+}
+
+enum InvoiceStatus: string
+{
+    case Draft = 'draft';
+    case Paid = 'paid';
+
+    public function label(): string
+    {
+        return ucfirst($this->value);
+    }
+}
+
+abstract class Document
+{
+    abstract public function number(): string;
+}
+
+class Invoice extends Document implements Payable, JsonSerializable
+{
+    use Timestamps;
+
+    private $lines = [];
+
+    public function __construct(private string $invoiceNumber)
+    {
+    }
+
+    public function number(): string
+    {
+        return $this->invoiceNumber;
+    }
+
+    public function addLine(Money $price, int $quantity): void
+    {
+        $this->lines[] = $price->times($quantity);
+        $this->touch();
+    }
+
+    public function amountDue(): int
+    {
+        return array_sum(array_map(fn ($line) => $line->cents(), $this->lines));
+    }
+
+    public function jsonSerialize(): mixed
+    {
+        return ['number' => $this->number(), 'due' => $this->amountDue()];
+    }
+}
+
+function create_invoice(string $number): Invoice
+{
+    $invoice = new Invoice($number);
+    $invoice->addLine(Money::fromCents(1000), 2);
+    return $invoice;
+}
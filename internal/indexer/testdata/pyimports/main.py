@@ -0,0 +1,13 @@
+import shop.catalog as cat
+from shop import search, Cart
+from shop.util import helpers
+from shop.util.helpers import *
+
+
+def main():
+    cat.render("sku")
+    helpers.render("hello")
+    search("boots")
+    Cart().render()
+    shout("hi")
+    whisper("hi")
@@ -0,0 +1,4 @@
+from .catalog import search
+from .cart import Cart
+
+__all__ = ["search", "Cart"]
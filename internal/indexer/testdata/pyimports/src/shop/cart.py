@@ -0,0 +1,6 @@
+class Cart:
+    def __init__(self):
+        self.items = []
+
+    def render(self):
+        return ", ".join(self.items)
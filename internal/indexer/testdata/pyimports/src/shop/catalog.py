@@ -0,0 +1,6 @@
+def search(query):
+    return [query]
+
+
+def render(sku):
+    return f"<{sku}>"
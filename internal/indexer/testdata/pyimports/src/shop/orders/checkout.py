@@ -0,0 +1,11 @@
+from ..cart import Cart
+from ..util import helpers
+
+
+def checkout(cart: Cart):
+    helpers.render(cart)
+    return render(cart)
+
+
+def render(cart):
+    return str(cart)
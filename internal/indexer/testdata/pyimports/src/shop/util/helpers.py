@@ -0,0 +1,13 @@
+__all__ = ["shout"]
+
+
+def render(text):
+    return text
+
+
+def shout(text):
+    return text.upper()
+
+
+def whisper(text):
+    return text.lower()
@@ -0,0 +1,26 @@
+package receivers
+
+// UserRepo and AuditRepo both have an Archive method.
+type UserRepo struct{}
+
+func (r *UserRepo) Archive(id int) error { return nil }
+
+type AuditRepo struct{}
+
+func (a *AuditRepo) Archive(id int) error { return nil }
+
+type Service struct {
+	users *UserRepo
+}
+
+func (s *Service) Close(id int) error {
+	audit := &AuditRepo{}
+	if err := audit.Archive(id); err != nil {
+		return err
+	}
+	return s.users.Archive(id)
+}
+
+func ArchiveUser(repo *UserRepo, id int) error {
+	return repo.Archive(id)
+}
@@ -0,0 +1,18 @@
+class UserRepo:
+    def archive(self, record_id):
+        return record_id
+
+
+class AuditRepo:
+    def archive(self, record_id):
+        return record_id
+
+
+class Service:
+    def __init__(self, users: UserRepo):
+        self.users = users
+
+    def close(self, record_id):
+        audit = AuditRepo()
+        audit.archive(record_id)
+        return self.users.archive(record_id)
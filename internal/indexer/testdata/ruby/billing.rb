@@ -0,0 +1,47 @@
+require 'json'
+require_relative 'support/money_format'
+
+module Billing
+  TAX_RATE = 0.2
+
+  class Account
+    attr_accessor :owner, :balance
+    attr_reader :currency
+
+    def initialize(owner, balance = 0)
+      @owner = owner
+      @balance = balance
+      @currency = 'EUR'
+    end
+
+    def deposit(amount)
+      raise ArgumentError, 'amount must be positive' if amount <= 0
+
+      @balance += amount
+    end
+
+    def self.open_for(owner)
+      new(owner)
+    end
+  end
+
+  class SavingsAccount < Account
+    include Comparable
+
+    def <=>(other)
+      balance <=> other.balance
+    end
+
+    def accrue_interest
+      deposit(balance * TAX_RATE)
+    end
+  end
+end
+
+def format_invoice(account)
+  puts JSON.generate(owner: account.owner, balance: account.balance)
+end
+
+account = Billing::Account.open_for('ada')
+account.deposit(100)
+format_invoice(account)
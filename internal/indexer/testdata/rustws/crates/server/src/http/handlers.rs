@@ -0,0 +1,6 @@
+use super::Config;
+use crate::router::Router;
+
+pub fn health(config: &Config, router: &Router) -> u16 {
+    router.port + config.port
+}
@@ -0,0 +1,12 @@
+pub mod handlers;
+
+/// Config holds the HTTP listener settings.
+pub struct Config {
+    pub port: u16,
+}
+
+impl Config {
+    pub fn new(port: u16) -> Config {
+        Config { port }
+    }
+}
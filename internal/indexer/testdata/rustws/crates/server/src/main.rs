@@ -0,0 +1,13 @@
+mod http;
+mod router;
+
+use shop_settings::Config as SettingsConfig;
+use std::collections::HashMap;
+
+fn main() {
+    let settings = SettingsConfig::new("shop.toml");
+    let config = http::Config::new(8080);
+    let router = router::Router::new(&config);
+    let mut routes: HashMap<String, u16> = HashMap::new();
+    routes.insert(settings.path, router.port);
+}
@@ -0,0 +1,15 @@
+use crate::http::{self, Config};
+
+pub struct Router {
+    pub port: u16,
+}
+
+impl Router {
+    pub fn new(config: &Config) -> Router {
+        Router { port: config.port }
+    }
+}
+
+pub fn fallback() -> Router {
+    self::Router::new(&http::Config::new(80))
+}
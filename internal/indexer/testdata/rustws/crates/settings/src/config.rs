@@ -0,0 +1,12 @@
+/// Config holds the settings loaded from disk.
+pub struct Config {
+    pub path: String,
+}
+
+impl Config {
+    pub fn new(path: &str) -> Config {
+        Config {
+            path: path.to_string(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// Generated by graphql-codegen. Do not edit.
+export type Parcel = {
+  id: string;
+  weightGrams: number;
+  status: ParcelStatus;
+};
+
+export enum ParcelStatus {
+  Pending = 'PENDING',
+  Delivered = 'DELIVERED',
+}
+
+export type QueryParcelArgs = {
+  id: string;
+};
+
+export type TrackParcelQuery = {
+  parcel?: Parcel | null;
+};
@@ -0,0 +1,16 @@
+package dispatch
+
+import "example.com/acme/gen/shippingpb"
+
+// DispatchParcel books a shipment and returns its tracking id.
+func DispatchParcel(street string) (string, error) {
+	client := shippingpb.NewShippingClient()
+	req := &shippingpb.ShipmentRequest{
+		Destination: &shippingpb.ShipmentRequest_Address{Street: street},
+	}
+	reply, err := client.CreateShipment(req)
+	if err != nil {
+		return req.GetTrackingId(), err
+	}
+	return reply.TrackingId, nil
+}
@@ -0,0 +1,42 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// source: shipping.proto
+
+package shippingpb
+
+type ShipmentStatus int32
+
+const (
+	ShipmentStatus_SHIPMENT_STATUS_UNSPECIFIED ShipmentStatus = 0
+	ShipmentStatus_SHIPMENT_STATUS_PENDING     ShipmentStatus = 1
+)
+
+type ShipmentRequest struct {
+	TrackingId  string
+	Destination *ShipmentRequest_Address
+	Labels      []string
+}
+
+func (x *ShipmentRequest) GetTrackingId() string {
+	if x != nil {
+		return x.TrackingId
+	}
+	return ""
+}
+
+type ShipmentRequest_Address struct {
+	Street     string
+	PostalCode string
+}
+
+type ShipmentReply struct {
+	TrackingId string
+	Status     ShipmentStatus
+}
+
+type ShippingClient interface {
+	CreateShipment(req *ShipmentRequest) (*ShipmentReply, error)
+}
+
+func NewShippingClient() ShippingClient {
+	return nil
+}
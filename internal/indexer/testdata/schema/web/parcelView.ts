@@ -0,0 +1,13 @@
+import { Parcel, QueryParcelArgs, TrackParcelQuery } from '../__generated__/graphql';
+
+export function describeParcel(p: Parcel): string {
+  return `${p.id} (${p.weightGrams}g)`;
+}
+
+export function parcelArgs(id: string): QueryParcelArgs {
+  return { id };
+}
+
+export function trackedParcel(data: TrackParcelQuery): Parcel | null {
+  return data.parcel ?? null;
+}
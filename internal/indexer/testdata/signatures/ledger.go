@@ -0,0 +1,12 @@
+package signatures
+
+// Ledger records signed amounts.
+type Ledger[T any] struct {
+	entries []T
+}
+
+// Record appends an entry and returns its position.
+func (l *Ledger[T]) Record(entry T, memo string) (int, error) {
+	l.entries = append(l.entries, entry)
+	return len(l.entries) - 1, nil
+}
@@ -0,0 +1,9 @@
+pub fn settle(amount: u64) -> bool {
+    inner::settle(amount)
+}
+
+mod inner {
+    fn settle(amount: u64) -> bool {
+        amount > 0
+    }
+}
@@ -0,0 +1,9 @@
+package symsearch;
+
+public class QuiverStore {
+    private int quiverCount;
+
+    public void stockQuiver() {
+        quiverCount++;
+    }
+}
@@ -0,0 +1,18 @@
+package symsearch
+
+// QuiverRepository keeps the arrows of each quiver.
+type QuiverRepository struct{}
+
+// FetchQuiverArrow returns an arrow of the quiver.
+func (r *QuiverRepository) FetchQuiverArrow(id string) string {
+	return id
+}
+
+// QuiverRepo looks quivers up.
+type QuiverRepo interface {
+	Lookup(id string) string
+}
+
+func quiverRegistry() int {
+	return 0
+}
@@ -57,3 +57,37 @@ func runTests(m *testing.M) int {
 
 	return m.Run()
 }
+
+// lookupOne returns the definition named name (optionally limited to file)
+// that filter keeps, failing the test unless there is exactly one.
+func lookupOne(t *testing.T, nav *Navigator, name, file, lang string, filter func([]DefinitionResult) []DefinitionResult) DefinitionResult {
+	t.Helper()
+	defs, err := nav.GoToDefinitionByName(name, file, lang)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defs = filter(defs)
+	if len(defs) != 1 {
+		t.Fatalf("got %d definitions named %s, want 1: %+v", len(defs), name, defs)
+	}
+	return defs[0]
+}
+
+// usageAt returns the usage at path:line:col, failing the test and returning
+// nil when there is none.
+func usageAt(t *testing.T, usages []UsageResult, path string, line, col int) *UsageResult {
+	t.Helper()
+	for i := range usages {
+		if loc := usages[i].Location; loc.Path == path && loc.StartLine == line && loc.StartCol == col {
+			return &usages[i]
+		}
+	}
+	t.Errorf("no usage at %s:%d:%d", path, line, col)
+	return nil
+}
+
+// fixturePath returns the index path of a testdata fixture file, given the
+// fixture's slash-separated source root and the path below it.
+func fixturePath(root string, parts ...string) string {
+	return filepath.Join(append([]string{filepath.FromSlash(root)}, parts...)...)
+}
@@ -0,0 +1,341 @@
+package indexer
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// maxTypeHierarchyNodes bounds the size of a type hierarchy.
+const maxTypeHierarchyNodes = 500
+
+// TypeHierarchy holds the supertypes and subtypes of a class, interface,
+// struct or trait, each as a tree down to a maximum depth.
+type TypeHierarchy struct {
+	Type       DefinitionResult    `json:"type"`
+	MaxDepth   int                 `json:"maxDepth"`
+	Supertypes []TypeHierarchyNode `json:"supertypes,omitempty"`
+	Subtypes   []TypeHierarchyNode `json:"subtypes,omitempty"`
+
+	// Truncated is set when the hierarchy was cut at maxTypeHierarchyNodes.
+	Truncated bool `json:"truncated,omitempty"`
+}
+
+// TypeHierarchyNode is one supertype or subtype of a type hierarchy. Its
+// children are its own supertypes (in Supertypes) or subtypes (in Subtypes).
+type TypeHierarchyNode struct {
+	Name string `json:"name"`
+
+	// Relation is how the subtype of the pair derives from the supertype:
+	// inherits, implements, prototype or embeds (Go).
+	Relation string `json:"relation"`
+
+	// Definition is nil for a type defined outside the repo (a library
+	// base class, a builtin), which is not expanded.
+	Definition *DefinitionResult `json:"definition,omitempty"`
+
+	// Cycle is set when the type is one of its own ancestors in the tree;
+	// it is not expanded again.
+	Cycle bool `json:"cycle,omitempty"`
+
+	Children []TypeHierarchyNode `json:"children,omitempty"`
+}
+
+// attachInheritingTypes records, as the context container of each
+// inheritance or overrides ref, the type declaring it: the innermost class,
+// interface, struct, enum or trait around the ref (class Foo extends Base),
+// unless the query already captured it (the Rust impl type, the JS
+// constructor whose prototype is set).
+func attachInheritingTypes(fr *symbols.FileResult) {
+	for i := range fr.Refs {
+		ref := &fr.Refs[i]
+		if ref.Kind != symbols.RefInherit || ref.ContextContainer != "" {
+			continue
+		}
+		inner := -1
+		for j, sym := range fr.Symbols {
+			if !isTypeKind(sym.Kind.String()) || !encloses(sym, *ref) {
+				continue
+			}
+			if inner < 0 || fr.Symbols[inner].StartLine < sym.StartLine ||
+				(fr.Symbols[inner].StartLine == sym.StartLine && fr.Symbols[inner].StartCol < sym.StartCol) {
+				inner = j
+			}
+		}
+		if inner >= 0 {
+			ref.ContextContainer = fr.Symbols[inner].Name
+		}
+	}
+}
+
+// FilterHierarchyTypes returns the type definitions among defs, or, when
+// there are none, the functions (JavaScript constructors linked through
+// their prototypes), in their original order.
+func FilterHierarchyTypes(defs []DefinitionResult) []DefinitionResult {
+	types, functions := []DefinitionResult{}, []DefinitionResult{}
+	for _, d := range defs {
+		switch {
+		case isTypeKind(d.Kind):
+			types = append(types, d)
+		case d.Kind == "function":
+			functions = append(functions, d)
+		}
+	}
+	if len(types) > 0 {
+		return types
+	}
+	return functions
+}
+
+// TypeHierarchy returns the transitive supertypes and subtypes of a type,
+// up to maxDepth levels each way, from the inheritance refs of the index:
+// extends and implements clauses, base class lists, Rust trait impls and
+// supertraits, JavaScript prototype chains and Go embedded types.
+// Supertypes are resolved through imports when known and else to the
+// nearest definition of the name.
+func (n *Navigator) TypeHierarchy(root DefinitionResult, maxDepth int, lang string) (*TypeHierarchy, error) {
+	w := &typeWalker{nav: n, lang: lang, maxDepth: maxDepth, types: map[string][]DefinitionResult{}}
+	h := &TypeHierarchy{Type: root, MaxDepth: maxDepth}
+	var err error
+	if h.Supertypes, err = w.expand(root, true, 0, map[string]bool{}); err != nil {
+		return nil, err
+	}
+	if h.Subtypes, err = w.expand(root, false, 0, map[string]bool{}); err != nil {
+		return nil, err
+	}
+	h.Truncated = w.truncated
+	return h, nil
+}
+
+// typeWalker builds the trees of a TypeHierarchy.
+type typeWalker struct {
+	nav       *Navigator
+	lang      string
+	maxDepth  int
+	nodes     int
+	truncated bool
+
+	// types caches the type definitions by name.
+	types map[string][]DefinitionResult
+}
+
+// inheritanceEdge is an inheritance ref: sub derives from super.
+type inheritanceEdge struct {
+	sub, super string
+	relation   string
+	ref        UsageResult
+}
+
+// expand returns the supertypes (up) or subtypes of def, with their own
+// down to maxDepth. onPath holds the types between the root and def.
+func (w *typeWalker) expand(def DefinitionResult, up bool, depth int, onPath map[string]bool) ([]TypeHierarchyNode, error) {
+	if depth >= w.maxDepth {
+		return nil, nil
+	}
+	var nodes []TypeHierarchyNode
+	var err error
+	if up {
+		nodes, err = w.supertypes(def)
+	} else {
+		nodes, err = w.subtypes(def)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	id := nodeID(def.Location.Path, def.Name, def.Location.StartLine)
+	onPath[id] = true
+	defer delete(onPath, id)
+	var kept []TypeHierarchyNode
+	for _, node := range nodes {
+		if w.nodes >= maxTypeHierarchyNodes {
+			w.truncated = true
+			break
+		}
+		w.nodes++
+		if d := node.Definition; d != nil {
+			if onPath[nodeID(d.Location.Path, d.Name, d.Location.StartLine)] {
+				node.Cycle = true
+			} else if node.Children, err = w.expand(*d, up, depth+1, onPath); err != nil {
+				return nil, err
+			}
+		}
+		kept = append(kept, node)
+	}
+	return kept, nil
+}
+
+// supertypes returns the types def derives from directly.
+func (w *typeWalker) supertypes(def DefinitionResult) ([]TypeHierarchyNode, error) {
+	edges, err := w.edges("r.context_container = ?", def.Name)
+	if err != nil {
+		return nil, err
+	}
+	subs, err := w.definitions(def.Name)
+	if err != nil {
+		return nil, err
+	}
+	var nodes []TypeHierarchyNode
+	for _, e := range edges {
+		// Another type of the same name (in another file) may be the one
+		// deriving here.
+		if sub := pickBestCandidate(subs, e.ref.Location.Path); sub == nil || !sameDefinition(*sub, def) {
+			continue
+		}
+		supers, err := w.definitions(e.super)
+		if err != nil {
+			return nil, err
+		}
+		nodes = addTypeNode(nodes, e.super, e.relation, refTarget(e.ref, supers))
+	}
+	return nodes, nil
+}
+
+// subtypes returns the types deriving from def directly.
+func (w *typeWalker) subtypes(def DefinitionResult) ([]TypeHierarchyNode, error) {
+	edges, err := w.subtypeEdges(def)
+	if err != nil {
+		return nil, err
+	}
+	var nodes []TypeHierarchyNode
+	for _, e := range edges {
+		subs, err := w.definitions(e.sub)
+		if err != nil {
+			return nil, err
+		}
+		nodes = addTypeNode(nodes, e.sub, e.relation, pickBestCandidate(subs, e.ref.Location.Path))
+	}
+	return nodes, nil
+}
+
+// subtypeEdges returns the inheritance refs of the types deriving from def
+// directly.
+func (w *typeWalker) subtypeEdges(def DefinitionResult) ([]inheritanceEdge, error) {
+	edges, err := w.edges("r.name = ? AND r.context_container != ''", def.Name)
+	if err != nil {
+		return nil, err
+	}
+	supers, err := w.definitions(def.Name)
+	if err != nil {
+		return nil, err
+	}
+	var kept []inheritanceEdge
+	for _, e := range edges {
+		if super := refTarget(e.ref, supers); super != nil && sameDefinition(*super, def) {
+			kept = append(kept, e)
+		}
+	}
+	return kept, nil
+}
+
+// edges returns the inheritance refs matching where, which compares a refs
+// r column with name. Overrides refs, on methods, are not edges.
+func (w *typeWalker) edges(where, name string) ([]inheritanceEdge, error) {
+	langs := InteropLangs(w.lang)
+	placeholders := strings.TrimSuffix(strings.Repeat("?,", len(langs)), ",")
+	args := []interface{}{w.nav.ProjectID, int(symbols.RefInherit), name}
+	for _, l := range langs {
+		args = append(args, l)
+	}
+	rows, err := w.nav.DB.Query(`
+		SELECT r.context_container, r.name, r.relation,
+		       r.receiver_type, r.qualifier, r.import_path, r.resolved_path,
+		       f.path, r.start_line, r.start_col, r.end_line, r.end_col
+		FROM refs r
+		JOIN files f ON r.file_id = f.id
+		WHERE f.project_id = ? AND r.kind = ? AND r.relation != 'overrides' AND `+where+` AND f.lang IN (`+placeholders+`)
+		ORDER BY f.path ASC, r.start_line ASC, r.start_col ASC
+	`, args...)
+	if err != nil {
+		return nil, fmt.Errorf("query inheritance refs: %w", err)
+	}
+	defer func() { _ = rows.Close() }()
+
+	var edges []inheritanceEdge
+	for rows.Next() {
+		var e inheritanceEdge
+		u := &e.ref
+		if err := rows.Scan(&e.sub, &e.super, &e.relation,
+			&u.ReceiverType, &u.Qualifier, &u.ImportPath, &u.ResolvedPath,
+			&u.Location.Path, &u.Location.StartLine, &u.Location.StartCol,
+			&u.Location.EndLine, &u.Location.EndCol); err != nil {
+			return nil, err
+		}
+		u.Name, u.Kind, u.Relation = e.super, symbols.RefInherit.String(), e.relation
+		edges = append(edges, e)
+	}
+	return edges, rows.Err()
+}
+
+// definitions returns the type definitions named name, as selected by
+// FilterHierarchyTypes.
+func (w *typeWalker) definitions(name string) ([]DefinitionResult, error) {
+	if defs, ok := w.types[name]; ok {
+		return defs, nil
+	}
+	defs, err := w.nav.definitionsByName(name, "", w.lang)
+	if err != nil {
+		return nil, err
+	}
+	defs = FilterHierarchyTypes(defs)
+	w.types[name] = defs
+	return defs, nil
+}
+
+// addTypeNode adds a supertype or subtype to nodes unless already present
+// (a class listing the same interface twice, partial classes).
+func addTypeNode(nodes []TypeHierarchyNode, name, relation string, def *DefinitionResult) []TypeHierarchyNode {
+	for _, node := range nodes {
+		if node.Name != name {
+			continue
+		}
+		if (node.Definition == nil && def == nil) ||
+			(node.Definition != nil && def != nil && sameDefinition(*node.Definition, *def)) {
+			return nodes
+		}
+	}
+	node := TypeHierarchyNode{Name: name, Relation: relation}
+	if def != nil {
+		d := *def
+		node.Definition = &d
+	}
+	return append(nodes, node)
+}
+
+// FormatTypeHierarchy formats a type hierarchy as indented trees for MCP.
+func FormatTypeHierarchy(h *TypeHierarchy) string {
+	var b strings.Builder
+	fmt.Fprintf(&b, "Type hierarchy of %s (%s) at %s, depth %d\n", h.Type.Name, h.Type.Kind, h.Type.Location, h.MaxDepth)
+	b.WriteString("\nSupertypes:\n")
+	if len(h.Supertypes) == 0 {
+		b.WriteString("(none)\n")
+	}
+	writeTypeNodes(&b, h.Supertypes, 0)
+	b.WriteString("\nSubtypes:\n")
+	if len(h.Subtypes) == 0 {
+		b.WriteString("(none)\n")
+	}
+	writeTypeNodes(&b, h.Subtypes, 0)
+	if h.Truncated {
+		fmt.Fprintf(&b, "... truncated at %d types\n", maxTypeHierarchyNodes)
+	}
+	return strings.TrimSuffix(b.String(), "\n")
+}
+
+// writeTypeNodes writes nodes and their children, indented by depth.
+func writeTypeNodes(b *strings.Builder, nodes []TypeHierarchyNode, depth int) {
+	indent := strings.Repeat("  ", depth)
+	for _, node := range nodes {
+		if d := node.Definition; d != nil {
+			fmt.Fprintf(b, "%s- %s (%s, %s) at %s", indent, node.Name, d.Kind, node.Relation, d.Location)
+		} else {
+			fmt.Fprintf(b, "%s- %s (%s, outside the repo)", indent, node.Name, node.Relation)
+		}
+		if node.Cycle {
+			b.WriteString(" (cycle)")
+		}
+		b.WriteString("\n")
+		writeTypeNodes(b, node.Children, depth+1)
+	}
+}
@@ -0,0 +1,70 @@
+package indexer
+
+import (
+	"strings"
+	"testing"
+)
+
+// typeTree renders nodes as Name(relation)[children], marking types outside
+// the repo with a trailing ?.
+func typeTree(nodes []TypeHierarchyNode) string {
+	var parts []string
+	for _, n := range nodes {
+		s := n.Name + "(" + n.Relation + ")"
+		if n.Definition == nil {
+			s += "?"
+		}
+		if len(n.Children) > 0 {
+			s += "[" + typeTree(n.Children) + "]"
+		}
+		parts = append(parts, s)
+	}
+	return strings.Join(parts, " ")
+}
+
+func TestTypeHierarchy(t *testing.T) {
+	tests := []struct {
+		name       string
+		lang       string
+		supertypes string
+		subtypes   string
+	}{
+		{"Square", "java", "Polygon(inherits)[Glyph(implements)] Outline(implements)[Glyph(inherits)]", ""},
+		{"Glyph", "java", "", "Outline(inherits)[Square(implements)] Polygon(implements)[Square(inherits)]"},
+		{"Bellow", "rust", "Chime(implements) Peal(implements)[Chime(inherits)]", ""},
+		{"Chime", "rust", "", "Peal(inherits)[Bellow(implements)] Bellow(implements)"},
+		{"Ferry", "python", "Barge(inherits)[Craft(inherits)] ABC(inherits)?", ""},
+		{"LoudHerald", "typescript", "BaseHerald(inherits)[Herald(implements)]", ""},
+		{"Herald", "typescript", "", "BaseHerald(implements)[LoudHerald(inherits)]"},
+		{"Wombat", "javascript", "Marsupial(prototype)", ""},
+		{"Marsupial", "javascript", "", "Wombat(prototype)"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			h, err := sharedNav.TypeHierarchy(lookupOne(t, sharedNav, tt.name, "", tt.lang, FilterHierarchyTypes), 5, tt.lang)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if got := typeTree(h.Supertypes); got != tt.supertypes {
+				t.Errorf("supertypes = %q, want %q", got, tt.supertypes)
+			}
+			if got := typeTree(h.Subtypes); got != tt.subtypes {
+				t.Errorf("subtypes = %q, want %q", got, tt.subtypes)
+			}
+		})
+	}
+}
+
+func TestTypeHierarchyDepthAndFormat(t *testing.T) {
+	h, err := sharedNav.TypeHierarchy(lookupOne(t, sharedNav, "Square", "", "java", FilterHierarchyTypes), 1, "java")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got, want := typeTree(h.Supertypes), "Polygon(inherits) Outline(implements)"; got != want {
+		t.Errorf("depth 1 supertypes = %q, want %q", got, want)
+	}
+	out := FormatTypeHierarchy(h)
+	if !strings.Contains(out, "- Polygon (class, inherits) at hierarchy/Glyphs.java:7:15") {
+		t.Errorf("unexpected output:\n%s", out)
+	}
+}
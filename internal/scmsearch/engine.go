@@ -13,6 +13,7 @@ import (
 	"time"
 
 	"github.com/mesdx/cli/internal/indexer"
+	"github.com/mesdx/cli/internal/sourcefile"
 	"github.com/mesdx/cli/internal/treesitter"
 )
 
@@ -284,6 +285,23 @@ func (e *Engine) searchFile(
 		return nil, err
 	}
 
+	// Vue/Svelte components: parse only the <script> blocks. Masking keeps
+	// offsets intact, so match positions and lines refer to the original file.
+	parseSrc := src
+	if sourcefile.IsComponentFile(filePath) {
+		parseSrc = sourcefile.MaskNonScript(src)
+	}
+
+	// Notebooks: search the concatenated code cells, as the indexer does.
+	var cells []sourcefile.NotebookCell
+	if sourcefile.IsNotebookFile(filePath) {
+		parseSrc, cells, err = sourcefile.NotebookSource(src)
+		if err != nil {
+			return nil, err
+		}
+		src = parseSrc
+	}
+
 	lang, err := treesitter.LoadLanguage(language)
 	if err != nil {
 		return nil, err
@@ -293,7 +311,7 @@ func (e *Engine) searchFile(
 		return nil, err
 	}
 
-	tree := parser.ParseString(nil, src)
+	tree := parser.ParseString(nil, parseSrc)
 	if tree == nil {
 		return nil, fmt.Errorf("parse failed")
 	}
@@ -313,7 +331,7 @@ func (e *Engine) searchFile(
 	defer cursor.Close()
 
 	rootNode := tree.RootNode()
-	cursor.ExecWithText(q, rootNode, src)
+	cursor.ExecWithText(q, rootNode, parseSrc)
 
 	captureNames := make(map[uint32]string)
 	for i := uint32(0); i < q.CaptureCount(); i++ {
@@ -342,7 +360,8 @@ func (e *Engine) searchFile(
 
 			capName := captureNames[cap.Index]
 			node := cap.Node
-			if node.IsNull() {
+			if node.IsNull() || strings.HasPrefix(capName, "_") {
+				// _-prefixed captures only feed predicates.
 				continue
 			}
 
@@ -369,6 +388,10 @@ func (e *Engine) searchFile(
 				Line:        getLine(lines, int(startPoint.Row)),
 			}
 
+			if cells != nil {
+				m.Cell = sourcefile.NotebookCellAt(cells, startLine)
+			}
+
 			if req.ContextLines > 0 {
 				m.ContextBefore = getLineRange(lines, int(startPoint.Row)-req.ContextLines, int(startPoint.Row)-1)
 				m.ContextAfter = getLineRange(lines, int(endPoint.Row)+1, int(endPoint.Row)+req.ContextLines)
@@ -342,6 +342,98 @@ func TestStubRefsCallNamed_Go(t *testing.T) {
 	}
 }
 
+func TestStubRefsCallNamed_PHP(t *testing.T) {
+	e := newTestEngine(t)
+	res, err := e.Search(context.Background(), SearchRequest{
+		Language:     "php",
+		StubName:     "refs.call.named",
+		StubArgs:     map[string]string{"name": "addLine"},
+		IncludeGlobs: []string{"*.php"},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	found := false
+	for _, m := range res.Matches {
+		if m.TextSnippet == "addLine" && m.CaptureName == "ref.call" {
+			found = true
+			break
+		}
+	}
+	if !found {
+		t.Errorf("expected to find call to addLine in php/Invoice.php; matches: %+v", res.Matches)
+	}
+}
+
+func TestStubDefsClassNamed_PHP(t *testing.T) {
+	e := newTestEngine(t)
+	res, err := e.Search(context.Background(), SearchRequest{
+		Language:     "php",
+		StubName:     "defs.class.named",
+		StubArgs:     map[string]string{"name": "Invoice"},
+		IncludeGlobs: []string{"*.php"},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	found := false
+	for _, m := range res.Matches {
+		if m.TextSnippet == "Invoice" && m.CaptureName == "def.class" {
+			found = true
+			break
+		}
+	}
+	if !found {
+		t.Errorf("expected to find class Invoice in php/Invoice.php; matches: %+v", res.Matches)
+	}
+}
+
+func TestStubDefsFunctionNamed_VueComponent(t *testing.T) {
+	e := newTestEngine(t)
+	res, err := e.Search(context.Background(), SearchRequest{
+		Language:     "typescript",
+		StubName:     "defs.function.named",
+		StubArgs:     map[string]string{"name": "toggleTodo"},
+		IncludeGlobs: []string{"*.vue"},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(res.Matches) != 1 {
+		t.Fatalf("expected one match for toggleTodo in vue/TodoList.vue, got %+v", res.Matches)
+	}
+	m := res.Matches[0]
+	if m.StartLine != 20 || m.StartCol != 9 {
+		t.Errorf("expected toggleTodo at 20:9 in the component, got %d:%d", m.StartLine, m.StartCol)
+	}
+	if !strings.Contains(m.Line, "function toggleTodo") {
+		t.Errorf("expected original source line, got %q", m.Line)
+	}
+}
+
+func TestStubDefsFunctionNamed_Notebook(t *testing.T) {
+	e := newTestEngine(t)
+	res, err := e.Search(context.Background(), SearchRequest{
+		Language:     "python",
+		StubName:     "defs.function.named",
+		StubArgs:     map[string]string{"name": "shout_names"},
+		IncludeGlobs: []string{"*.ipynb"},
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(res.Matches) != 1 {
+		t.Fatalf("expected one match for shout_names in notebooks/exploration.ipynb, got %+v", res.Matches)
+	}
+	m := res.Matches[0]
+	if m.Cell == nil || m.Cell.Index != 2 || m.Cell.Line != 1 {
+		t.Errorf("expected shout_names in cell 2, line 1, got %+v", m.Cell)
+	}
+	if !strings.Contains(m.Line, "def shout_names") {
+		t.Errorf("expected the cell's source line, got %q", m.Line)
+	}
+}
+
 func TestStubDefsMethodNamed_TypeScript(t *testing.T) {
 	e := newTestEngine(t)
 	res, err := e.Search(context.Background(), SearchRequest{
@@ -365,6 +457,56 @@ func TestStubDefsMethodNamed_TypeScript(t *testing.T) {
 	}
 }
 
+func TestStubDefsMethodNamed_Cpp(t *testing.T) {
+	e := newTestEngine(t)
+	search := func(name string) []Match {
+		t.Helper()
+		res, err := e.Search(context.Background(), SearchRequest{
+			Language:     "cpp",
+			StubName:     "defs.method.named",
+			StubArgs:     map[string]string{"name": name},
+			IncludeGlobs: []string{"sample.cpp"},
+		})
+		if err != nil {
+			t.Fatal(err)
+		}
+		return res.Matches
+	}
+	matches := search("area")
+	if len(matches) != 2 || matches[0].StartLine != 9 || matches[1].StartLine != 15 {
+		t.Errorf("expected area declared at lines 9 and 15 of cpp/sample.cpp, got %+v", matches)
+	}
+	// The name is matched whole, not as a suffix.
+	if matches := search("rea"); len(matches) != 0 {
+		t.Errorf("expected no method named rea, got %+v", matches)
+	}
+}
+
+func TestStubRefsImportNamed_Ruby(t *testing.T) {
+	e := newTestEngine(t)
+	search := func(name string) []Match {
+		t.Helper()
+		res, err := e.Search(context.Background(), SearchRequest{
+			Language:     "ruby",
+			StubName:     "refs.import.named",
+			StubArgs:     map[string]string{"name": name},
+			IncludeGlobs: []string{"billing.rb"},
+		})
+		if err != nil {
+			t.Fatal(err)
+		}
+		return res.Matches
+	}
+	matches := search("json")
+	if len(matches) != 1 || matches[0].StartLine != 1 || matches[0].CaptureName != "ref.import" {
+		t.Errorf("expected require 'json' at line 1 of ruby/billing.rb, got %+v", matches)
+	}
+	// raise ArgumentError, 'amount must be positive' is not an import.
+	if matches := search("amount"); len(matches) != 0 {
+		t.Errorf("expected no import of amount, got %+v", matches)
+	}
+}
+
 // ---------------------------------------------------------------------------
 // Raw query tests
 // ---------------------------------------------------------------------------
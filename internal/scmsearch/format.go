@@ -33,6 +33,9 @@ func FormatResults(r *SearchResult) string {
 		if m.EndLine != m.StartLine {
 			fmt.Fprintf(&b, "–L%d:%d", m.EndLine, m.EndCol)
 		}
+		if m.Cell != nil {
+			fmt.Fprintf(&b, " (cell %d, line %d)", m.Cell.Index, m.Cell.Line)
+		}
 		b.WriteString("\n")
 
 		if m.TextSnippet != "" {
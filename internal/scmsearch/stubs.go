@@ -2,6 +2,7 @@ package scmsearch
 
 import (
 	"fmt"
+	"regexp"
 	"strings"
 )
 
@@ -13,6 +14,9 @@ type Stub struct {
 	Templates   map[string]string // language -> SCM query with {{placeholders}}
 }
 
+// queryStringEscaper escapes a value for a double-quoted SCM string.
+var queryStringEscaper = strings.NewReplacer(`\`, `\\`, `"`, `\"`)
+
 var stubRegistry = map[string]*Stub{}
 
 func init() {
@@ -41,6 +45,9 @@ func ListStubs() []*Stub {
 }
 
 // Render resolves placeholders in the template for the given language.
+// {{arg}} is replaced by the value as is, for #eq? and for #match? patterns
+// the caller writes as a regex; {{arg|regex}} by the value escaped to match
+// literally.
 func (s *Stub) Render(language string, args map[string]string) (string, error) {
 	tpl, ok := s.Templates[language]
 	if !ok {
@@ -51,7 +58,8 @@ func (s *Stub) Render(language string, args map[string]string) (string, error) {
 		if !ok || val == "" {
 			return "", fmt.Errorf("stub %q requires arg %q", s.ID, a)
 		}
-		tpl = strings.ReplaceAll(tpl, "{{"+a+"}}", val)
+		tpl = strings.ReplaceAll(tpl, "{{"+a+"|regex}}", queryStringEscaper.Replace(regexp.QuoteMeta(val)))
+		tpl = strings.ReplaceAll(tpl, "{{"+a+"}}", queryStringEscaper.Replace(val))
 	}
 	return tpl, nil
 }
@@ -71,6 +79,12 @@ var defsFunctionNamed = &Stub{
 		"python":     `(function_definition name: (identifier) @def.function (#eq? @def.function "{{name}}"))`,
 		"typescript": `(function_declaration name: (identifier) @def.function (#eq? @def.function "{{name}}"))`,
 		"javascript": `(function_declaration name: (identifier) @def.function (#eq? @def.function "{{name}}"))`,
+		"c":          `(function_definition declarator: (function_declarator declarator: (identifier) @def.function (#eq? @def.function "{{name}}")))`,
+		"cpp":        `(function_definition declarator: (function_declarator declarator: (identifier) @def.function (#eq? @def.function "{{name}}")))`,
+		"csharp":     `(local_function_statement name: (identifier) @def.function (#eq? @def.function "{{name}}"))`,
+		"kotlin":     `(function_declaration (simple_identifier) @def.function (#eq? @def.function "{{name}}"))`,
+		"ruby":       `(method name: (identifier) @def.function (#eq? @def.function "{{name}}"))`,
+		"php":        `(function_definition name: (name) @def.function (#eq? @def.function "{{name}}"))`,
 	},
 }
 
@@ -85,6 +99,12 @@ var defsClassNamed = &Stub{
 		"python":     `(class_definition name: (identifier) @def.class (#eq? @def.class "{{name}}"))`,
 		"typescript": `(class_declaration name: (type_identifier) @def.class (#eq? @def.class "{{name}}"))`,
 		"javascript": `(class_declaration name: (identifier) @def.class (#eq? @def.class "{{name}}"))`,
+		"c":          `(struct_specifier name: (type_identifier) @def.class (#eq? @def.class "{{name}}") body: (field_declaration_list))`,
+		"cpp":        `(class_specifier name: (type_identifier) @def.class (#eq? @def.class "{{name}}") body: (field_declaration_list))`,
+		"csharp":     `(class_declaration name: (identifier) @def.class (#eq? @def.class "{{name}}"))`,
+		"kotlin":     `(class_declaration (type_identifier) @def.class (#eq? @def.class "{{name}}"))`,
+		"ruby":       `(class name: (constant) @def.class (#eq? @def.class "{{name}}"))`,
+		"php":        `(class_declaration name: (name) @def.class (#eq? @def.class "{{name}}"))`,
 	},
 }
 
@@ -99,6 +119,12 @@ var defsInterfaceNamed = &Stub{
 		"python":     `(class_definition name: (identifier) @def.interface (#eq? @def.interface "{{name}}"))`,
 		"typescript": `(interface_declaration name: (type_identifier) @def.interface (#eq? @def.interface "{{name}}"))`,
 		"javascript": `(class_declaration name: (identifier) @def.interface (#eq? @def.interface "{{name}}"))`,
+		"c":          `(struct_specifier name: (type_identifier) @def.interface (#eq? @def.interface "{{name}}") body: (field_declaration_list))`,
+		"cpp":        `(class_specifier name: (type_identifier) @def.interface (#eq? @def.interface "{{name}}") body: (field_declaration_list))`,
+		"csharp":     `(interface_declaration name: (identifier) @def.interface (#eq? @def.interface "{{name}}"))`,
+		"kotlin":     `(class_declaration "interface" (type_identifier) @def.interface (#eq? @def.interface "{{name}}"))`,
+		"ruby":       `(module name: (constant) @def.interface (#eq? @def.interface "{{name}}"))`,
+		"php":        `(interface_declaration name: (name) @def.interface (#eq? @def.interface "{{name}}"))`,
 	},
 }
 
@@ -113,6 +139,12 @@ var refsTypeNamed = &Stub{
 		"python":     `(identifier) @ref.type (#eq? @ref.type "{{name}}")`,
 		"typescript": `(type_identifier) @ref.type (#eq? @ref.type "{{name}}")`,
 		"javascript": `(identifier) @ref.type (#eq? @ref.type "{{name}}")`,
+		"c":          `(type_identifier) @ref.type (#eq? @ref.type "{{name}}")`,
+		"cpp":        `(type_identifier) @ref.type (#eq? @ref.type "{{name}}")`,
+		"csharp":     `(identifier) @ref.type (#eq? @ref.type "{{name}}")`,
+		"kotlin":     `(type_identifier) @ref.type (#eq? @ref.type "{{name}}")`,
+		"ruby":       `(constant) @ref.type (#eq? @ref.type "{{name}}")`,
+		"php":        `(named_type (name) @ref.type (#eq? @ref.type "{{name}}"))`,
 	},
 }
 
@@ -127,6 +159,12 @@ var refsCallNamed = &Stub{
 		"python":     `(call function: (identifier) @ref.call (#eq? @ref.call "{{name}}"))`,
 		"typescript": `(call_expression function: (identifier) @ref.call (#eq? @ref.call "{{name}}"))`,
 		"javascript": `(call_expression function: (identifier) @ref.call (#eq? @ref.call "{{name}}"))`,
+		"c":          `(call_expression function: (identifier) @ref.call (#eq? @ref.call "{{name}}"))`,
+		"cpp":        `(call_expression function: (identifier) @ref.call (#eq? @ref.call "{{name}}"))`,
+		"csharp":     `(invocation_expression function: [(identifier) @ref.call (member_access_expression name: (identifier) @ref.call)] (#eq? @ref.call "{{name}}"))`,
+		"kotlin":     `(call_expression (simple_identifier) @ref.call (#eq? @ref.call "{{name}}"))`,
+		"ruby":       `(call method: (identifier) @ref.call (#eq? @ref.call "{{name}}"))`,
+		"php":        `([(function_call_expression function: (name) @ref.call) (function_call_expression function: (qualified_name (name) @ref.call)) (member_call_expression name: (name) @ref.call) (nullsafe_member_call_expression name: (name) @ref.call) (scoped_call_expression name: (name) @ref.call)] (#eq? @ref.call "{{name}}"))`,
 	},
 }
 
@@ -141,6 +179,12 @@ var defsMethodNamed = &Stub{
 		"python":     `(class_definition body: (block (function_definition name: (identifier) @def.method (#eq? @def.method "{{name}}"))))`,
 		"typescript": `(method_definition name: (property_identifier) @def.method (#eq? @def.method "{{name}}"))`,
 		"javascript": `(method_definition name: (property_identifier) @def.method (#eq? @def.method "{{name}}"))`,
+		"c":          `(function_definition declarator: (function_declarator declarator: (identifier) @def.method (#eq? @def.method "{{name}}")))`,
+		"cpp":        `(function_declarator declarator: [(field_identifier) @def.method (operator_name) @def.method (destructor_name) @def.method (qualified_identifier name: [(identifier) (operator_name) (destructor_name)] @def.method) (qualified_identifier name: (qualified_identifier name: [(identifier) (operator_name) (destructor_name)] @def.method))] (#eq? @def.method "{{name}}"))`,
+		"csharp":     `(method_declaration name: (identifier) @def.method (#eq? @def.method "{{name}}"))`,
+		"kotlin":     `(class_body (function_declaration (simple_identifier) @def.method (#eq? @def.method "{{name}}")))`,
+		"ruby":       `(method name: (identifier) @def.method (#eq? @def.method "{{name}}"))`,
+		"php":        `(method_declaration name: (name) @def.method (#eq? @def.method "{{name}}"))`,
 	},
 }
 
@@ -155,6 +199,12 @@ var refsWriteNamed = &Stub{
 		"python":     `(assignment left: (identifier) @ref.write (#eq? @ref.write "{{name}}"))`,
 		"typescript": `(assignment_expression left: (identifier) @ref.write (#eq? @ref.write "{{name}}"))`,
 		"javascript": `(assignment_expression left: (identifier) @ref.write (#eq? @ref.write "{{name}}"))`,
+		"c":          `(assignment_expression left: (identifier) @ref.write (#eq? @ref.write "{{name}}"))`,
+		"cpp":        `(assignment_expression left: (identifier) @ref.write (#eq? @ref.write "{{name}}"))`,
+		"csharp":     `(assignment_expression left: (identifier) @ref.write (#eq? @ref.write "{{name}}"))`,
+		"kotlin":     `(assignment (directly_assignable_expression (simple_identifier) @ref.write (#eq? @ref.write "{{name}}")))`,
+		"ruby":       `(assignment left: (identifier) @ref.write (#eq? @ref.write "{{name}}"))`,
+		"php":        `(assignment_expression left: (variable_name (name) @ref.write (#eq? @ref.write "{{name}}")))`,
 	},
 }
 
@@ -169,5 +219,11 @@ var refsImportNamed = &Stub{
 		"python":     `(import_from_statement name: (dotted_name (identifier) @ref.import (#eq? @ref.import "{{name}}")))`,
 		"typescript": `(import_statement (import_clause (named_imports (import_specifier name: (identifier) @ref.import (#eq? @ref.import "{{name}}")))))`,
 		"javascript": `(import_statement (import_clause (named_imports (import_specifier name: (identifier) @ref.import (#eq? @ref.import "{{name}}")))))`,
+		"c":          `(preproc_include path: (_) @ref.import (#match? @ref.import "{{name|regex}}"))`,
+		"cpp":        `(preproc_include path: (_) @ref.import (#match? @ref.import "{{name|regex}}"))`,
+		"csharp":     `(using_directive (qualified_name name: (identifier) @ref.import (#eq? @ref.import "{{name}}")))`,
+		"kotlin":     `(import_header (identifier (simple_identifier) @ref.import (#eq? @ref.import "{{name}}")))`,
+		"ruby":       `(call method: (identifier) @_method arguments: (argument_list (string (string_content) @ref.import (#match? @ref.import "{{name|regex}}"))) (#match? @_method "^(require|require_relative|load)$"))`,
+		"php":        `(namespace_use_clause (qualified_name (name) @ref.import (#eq? @ref.import "{{name}}")))`,
 	},
 }
@@ -1,6 +1,7 @@
 package scmsearch
 
 import (
+	"strings"
 	"testing"
 )
 
@@ -38,7 +39,7 @@ func TestListStubs(t *testing.T) {
 }
 
 func TestStubRender_AllLanguages(t *testing.T) {
-	languages := []string{"go", "java", "rust", "python", "typescript", "javascript"}
+	languages := []string{"go", "java", "rust", "python", "typescript", "javascript", "c", "cpp", "csharp", "kotlin", "ruby", "php"}
 	for _, s := range ListStubs() {
 		for _, lang := range languages {
 			args := map[string]string{}
@@ -84,3 +85,23 @@ func TestStubRender_UnsupportedLanguage(t *testing.T) {
 		t.Error("expected error for unsupported language")
 	}
 }
+
+func TestStubRender_EscapesValues(t *testing.T) {
+	s := LookupStub("refs.import.named")
+	got, err := s.Render("cpp", map[string]string{"name": "vector.h"})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !strings.Contains(got, `"vector\\.h"`) {
+		t.Errorf("expected the include name escaped for a literal regex match, got %s", got)
+	}
+
+	s = LookupStub("defs.method.named")
+	got, err = s.Render("cpp", map[string]string{"name": `operator"()`})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !strings.Contains(got, `(#eq? @def.method "operator\"()")`) {
+		t.Errorf("expected the method name quoted for an SCM string, got %s", got)
+	}
+}
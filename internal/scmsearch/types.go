@@ -1,5 +1,7 @@
 package scmsearch
 
+import "github.com/mesdx/cli/internal/sourcefile"
+
 // SearchRequest describes a Tree-sitter SCM query search over source files.
 type SearchRequest struct {
 	Language string `json:"language"`
@@ -47,6 +49,9 @@ type Match struct {
 	ContextBefore []string `json:"contextBefore,omitempty"`
 	ContextAfter  []string `json:"contextAfter,omitempty"`
 	ASTParents    []string `json:"astParents,omitempty"`
+
+	// Cell locates matches inside Jupyter notebooks.
+	Cell *sourcefile.CellLocation `json:"cell,omitempty"`
 }
 
 // Summary provides execution metadata.
@@ -0,0 +1,43 @@
+package sourcefile
+
+import (
+	"path/filepath"
+	"regexp"
+	"strings"
+)
+
+// componentExts lists single-file component formats whose <script> blocks
+// are indexed. They are detected as TypeScript: the TS grammar also parses
+// plain JavaScript, so both <script> and <script lang="ts"> work.
+var componentExts = map[string]bool{
+	".vue":    true,
+	".svelte": true,
+}
+
+// scriptBlockRe matches a <script ...>...</script> element; group 1 is the
+// script body. Attributes (setup, lang="ts", context="module") are ignored.
+var scriptBlockRe = regexp.MustCompile(`(?is)<script\b[^>]*>(.*?)</script\s*>`)
+
+// IsComponentFile reports whether path is a Vue or Svelte single-file component.
+func IsComponentFile(path string) bool {
+	return componentExts[strings.ToLower(filepath.Ext(path))]
+}
+
+// MaskNonScript returns a copy of a component's source in which everything
+// outside its <script> blocks is replaced by spaces. Newlines are kept, so
+// every byte offset, line and column in the result refers to the same place
+// in the original file and extracted positions need no remapping.
+func MaskNonScript(src []byte) []byte {
+	out := make([]byte, len(src))
+	for i, b := range src {
+		if b == '\n' {
+			out[i] = '\n'
+		} else {
+			out[i] = ' '
+		}
+	}
+	for _, m := range scriptBlockRe.FindAllSubmatchIndex(src, -1) {
+		copy(out[m[2]:m[3]], src[m[2]:m[3]])
+	}
+	return out
+}
@@ -0,0 +1,38 @@
+package sourcefile
+
+import (
+	"bytes"
+	"testing"
+)
+
+func TestMaskNonScript(t *testing.T) {
+	src := []byte("<template>\n  <p>{{ x }}</p>\n</template>\n<script setup lang=\"ts\">\nconst x = 1\n</script>\n")
+	masked := MaskNonScript(src)
+
+	if len(masked) != len(src) {
+		t.Fatalf("masked length = %d, want %d", len(masked), len(src))
+	}
+	if bytes.Count(masked, []byte("\n")) != bytes.Count(src, []byte("\n")) {
+		t.Error("masking must preserve newlines")
+	}
+	if !bytes.Contains(masked, []byte("const x = 1")) {
+		t.Errorf("script body missing from masked source: %q", masked)
+	}
+	if bytes.Contains(masked, []byte("template")) || bytes.Contains(masked, []byte("<script")) {
+		t.Errorf("markup leaked into masked source: %q", masked)
+	}
+	if i := bytes.Index(masked, []byte("const")); i != bytes.Index(src, []byte("const")) {
+		t.Errorf("script body moved: offset %d", i)
+	}
+}
+
+func TestIsComponentFile(t *testing.T) {
+	for _, path := range []string{"src/App.vue", "lib/Button.svelte"} {
+		if !IsComponentFile(path) {
+			t.Errorf("IsComponentFile(%q) = false, want true", path)
+		}
+	}
+	if IsComponentFile("src/app.ts") {
+		t.Error("IsComponentFile(app.ts) = true, want false")
+	}
+}
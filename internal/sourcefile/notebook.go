@@ -0,0 +1,105 @@
+package sourcefile
+
+import (
+	"encoding/json"
+	"fmt"
+	"path/filepath"
+	"strings"
+)
+
+// Jupyter notebooks are indexed by concatenating their code cells into one
+// synthetic Python source. Stored positions refer to that source; the cell
+// map built alongside it translates a synthetic line back to the cell and
+// the line within the cell.
+
+// NotebookCell records where one code cell sits in the synthetic source.
+type NotebookCell struct {
+	Index     int // 0-based position of the cell in the notebook (all cell types)
+	StartLine int // 1-based first line of the cell in the synthetic source
+	Lines     int // number of source lines in the cell
+}
+
+// CellLocation identifies a line inside a notebook cell.
+type CellLocation struct {
+	Index int `json:"index"` // 0-based cell index in the notebook
+	Line  int `json:"line"`  // 1-based line within the cell
+}
+
+// IsNotebookFile reports whether path is a Jupyter notebook.
+func IsNotebookFile(path string) bool {
+	return strings.EqualFold(filepath.Ext(path), ".ipynb")
+}
+
+// notebookJSON is the subset of the nbformat schema we read.
+type notebookJSON struct {
+	Cells []struct {
+		CellType string          `json:"cell_type"`
+		Source   json.RawMessage `json:"source"`
+	} `json:"cells"`
+}
+
+// NotebookSource builds the synthetic Python source for a notebook and the
+// map of its code cells. Cells are separated by a blank line so statements
+// never run together. IPython magics and shell escapes (%time, !pip) are
+// blanked so they do not confuse the Python grammar.
+func NotebookSource(src []byte) ([]byte, []NotebookCell, error) {
+	var nb notebookJSON
+	if err := json.Unmarshal(src, &nb); err != nil {
+		return nil, nil, fmt.Errorf("parse notebook: %w", err)
+	}
+
+	var b strings.Builder
+	var cells []NotebookCell
+	line := 1
+	for i, c := range nb.Cells {
+		if c.CellType != "code" {
+			continue
+		}
+		text, err := cellSourceText(c.Source)
+		if err != nil {
+			return nil, nil, fmt.Errorf("parse notebook cell %d: %w", i, err)
+		}
+		lines := strings.Split(strings.TrimSuffix(text, "\n"), "\n")
+		for _, l := range lines {
+			trimmed := strings.TrimSpace(l)
+			if strings.HasPrefix(trimmed, "%") || strings.HasPrefix(trimmed, "!") {
+				l = ""
+			}
+			b.WriteString(l)
+			b.WriteByte('\n')
+		}
+		b.WriteByte('\n')
+
+		cells = append(cells, NotebookCell{Index: i, StartLine: line, Lines: len(lines)})
+		line += len(lines) + 1
+	}
+	return []byte(b.String()), cells, nil
+}
+
+// cellSourceText decodes a cell's "source", which nbformat allows to be
+// either a single string or a list of line strings.
+func cellSourceText(raw json.RawMessage) (string, error) {
+	if len(raw) == 0 {
+		return "", nil
+	}
+	var s string
+	if err := json.Unmarshal(raw, &s); err == nil {
+		return s, nil
+	}
+	var parts []string
+	if err := json.Unmarshal(raw, &parts); err != nil {
+		return "", err
+	}
+	return strings.Join(parts, ""), nil
+}
+
+// NotebookCellAt maps a 1-based synthetic line to its cell, or returns nil
+// if the line is a cell separator or out of range.
+func NotebookCellAt(cells []NotebookCell, line int) *CellLocation {
+	for _, c := range cells {
+		if line >= c.StartLine && line < c.StartLine+c.Lines {
+			return &CellLocation{Index: c.Index, Line: line - c.StartLine + 1}
+		}
+	}
+	return nil
+}
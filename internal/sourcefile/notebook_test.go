@@ -0,0 +1,36 @@
+package sourcefile
+
+import "testing"
+
+func TestNotebookSource(t *testing.T) {
+	nb := []byte(`{"cells": [
+		{"cell_type": "markdown", "source": ["# Title\n"]},
+		{"cell_type": "code", "source": ["%time\n", "x = 1\n", "!pip install foo"]},
+		{"cell_type": "code", "source": "def f():\n    return x\n"}
+	]}`)
+	src, cells, err := NotebookSource(nb)
+	if err != nil {
+		t.Fatalf("NotebookSource: %v", err)
+	}
+
+	want := "\nx = 1\n\n\ndef f():\n    return x\n\n"
+	if string(src) != want {
+		t.Errorf("synthetic source = %q, want %q", src, want)
+	}
+	if len(cells) != 2 {
+		t.Fatalf("got %d code cells, want 2", len(cells))
+	}
+	if cells[0].Index != 1 || cells[0].StartLine != 1 || cells[0].Lines != 3 {
+		t.Errorf("cell 0 = %+v, want index 1 at line 1 with 3 lines", cells[0])
+	}
+	if cells[1].Index != 2 || cells[1].StartLine != 5 || cells[1].Lines != 2 {
+		t.Errorf("cell 1 = %+v, want index 2 at line 5 with 2 lines", cells[1])
+	}
+
+	if loc := NotebookCellAt(cells, 6); loc == nil || loc.Index != 2 || loc.Line != 2 {
+		t.Errorf("NotebookCellAt(6) = %+v, want cell 2 line 2", loc)
+	}
+	if loc := NotebookCellAt(cells, 4); loc != nil {
+		t.Errorf("NotebookCellAt(4) = %+v, want nil for separator line", loc)
+	}
+}
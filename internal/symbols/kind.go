@@ -83,17 +83,57 @@ const (
 	RefOther      RefKind = 0
 )
 
+// Visibility describes where a symbol can be used from.
+type Visibility string
+
+const (
+	VisibilityUnknown   Visibility = ""
+	VisibilityPublic    Visibility = "public"    // exported: pub, public, export, a capitalised Go name
+	VisibilityProtected Visibility = "protected" // the declaring type and its subtypes
+	VisibilityInternal  Visibility = "internal"  // the declaring package, crate, assembly or module
+	VisibilityPrivate   Visibility = "private"   // the declaring type or file
+)
+
+// Parameter is one parameter of a function or method signature. Either
+// part may be empty: Python and JavaScript parameters have no declared
+// type, and Go allows unnamed parameters.
+type Parameter struct {
+	Name string `json:"name,omitempty"`
+	Type string `json:"type,omitempty"`
+}
+
 // Symbol is the in-memory representation of an extracted definition.
 type Symbol struct {
 	Name          string
 	Kind          SymbolKind
 	ContainerName string
-	Signature     string
-	IsExternal    bool // true when the symbol originates from an external package/module
-	StartLine     int  // 1-based
-	StartCol      int  // 0-based
-	EndLine       int  // 1-based
-	EndCol        int  // 0-based
+	QualifiedName string // package or module and enclosing types (com.shop.Request.build, shop::http::Config::new)
+	Signature     string // declaration header as written (func (s *Store) Save(u User) error)
+	IsExternal    bool   // true when the symbol originates from an external package/module
+	StartLine     int    // 1-based
+	StartCol      int    // 0-based
+	EndLine       int    // 1-based
+	EndCol        int    // 0-based
+
+	// Visibility is the declared or conventional visibility of the symbol.
+	Visibility Visibility
+	// Receiver is the type a method is declared on, as written, for the
+	// languages that declare it with the method: the Go receiver
+	// (*Store[T]), the Kotlin extension receiver, or the impl type of a Rust
+	// method taking self (&mut Stack<T>).
+	Receiver string
+	// TypeParams lists the type parameters of a generic function or type
+	// as written, with their constraints (T any, K: Hash + Eq).
+	TypeParams []string
+	// Params lists the parameters of a function, method or constructor,
+	// or is nil for other symbols.
+	Params []Parameter
+	// ReturnType is the declared return type as written ((int, error),
+	// Optional[str]), or "" when none is declared.
+	ReturnType string
+	// Doc is the documentation of the symbol without comment markers: its
+	// doc comment, Javadoc, rustdoc or JSDoc, or its Python docstring.
+	Doc string
 }
 
 var refKindNames = map[RefKind]string{
@@ -129,10 +169,42 @@ type Ref struct {
 	EndLine          int    // 1-based
 	EndCol           int    // 0-based
 	ContextContainer string // enclosing function/class name if available
+
+	// Qualifier is the identifier or path written before the name in a
+	// qualified reference (pkg in pkg.Func, a::b in a::b::name), or "" for
+	// a bare name.
+	Qualifier string
+	// ImportPath is the package or module path the ref resolves through:
+	// the Go import path, the Rust module path (followed by the type name
+	// for a Type::member ref), the Java package (or qualified type for a
+	// Type.member ref), the dotted Python module, or the JS/TS module
+	// specifier as written.
+	ImportPath string
+	// ResolvedPath is the repo-relative package directory or file defining
+	// the target, or "" when the target could not be resolved inside the repo.
+	ResolvedPath string
+	// BindingLine and BindingCol locate the declaration of the local
+	// variable or parameter the ref names (1-based line, 0-based column).
+	// Both are 0 when the ref does not name a local.
+	BindingLine int
+	BindingCol  int
+}
+
+// Import is one import declaration of a file.
+type Import struct {
+	Path     string // package or module path as written
+	Name     string // name imported from Path (Python from-import, JS/TS), or * for all of them
+	Alias    string // local name given by the import, or "" for the default name
+	Static   bool   // Java import static: Path names a member of a class
+	Reexport bool   // JS/TS export ... from: re-exported under Alias (or Name), not bound locally
+	Line     int    // 1-based
 }
 
 // FileResult holds the parsing output for a single file.
 type FileResult struct {
 	Symbols []Symbol
 	Refs    []Ref
+	Imports []Import
+	Package string   // package declared by the file (Java, Kotlin), or ""
+	Exports []string // Python __all__, nil when the module declares none
 }
@@ -11,6 +11,12 @@ var builtinsByLang = map[string]map[string]bool{
 	"typescript": jstsBuiltins,
 	"javascript": jstsBuiltins,
 	"rust": rustBuiltins,
+	"c": cBuiltins,
+	"cpp": cppBuiltins,
+	"csharp": csharpBuiltins,
+	"kotlin": kotlinBuiltins,
+	"ruby": rubyBuiltins,
+	"php": phpBuiltins,
 }
 
 // IsBuiltin returns true if name is a known builtin for the given language.
@@ -424,3 +430,472 @@ var rustBuiltins = map[string]bool{
 	"usize":   true,
 	"str":     true,
 }
+
+// ---------- C builtins (libc + common macros) ----------
+
+var cBuiltins = map[string]bool{
+	// stdio.h
+	"printf":   true,
+	"fprintf":  true,
+	"sprintf":  true,
+	"snprintf": true,
+	"scanf":    true,
+	"sscanf":   true,
+	"puts":     true,
+	"putchar":  true,
+	"getchar":  true,
+	"fopen":    true,
+	"fclose":   true,
+	"fread":    true,
+	"fwrite":   true,
+	"fgets":    true,
+	"fputs":    true,
+	"fflush":   true,
+	"perror":   true,
+	"stdin":    true,
+	"stdout":   true,
+	"stderr":   true,
+	"FILE":     true,
+	"EOF":      true,
+	// stdlib.h
+	"malloc":  true,
+	"calloc":  true,
+	"realloc": true,
+	"free":    true,
+	"exit":    true,
+	"abort":   true,
+	"atoi":    true,
+	"atol":    true,
+	"strtol":  true,
+	"strtoul": true,
+	"strtod":  true,
+	"qsort":   true,
+	"bsearch": true,
+	"getenv":  true,
+	// string.h
+	"memcpy":  true,
+	"memmove": true,
+	"memset":  true,
+	"memcmp":  true,
+	"strlen":  true,
+	"strcpy":  true,
+	"strncpy": true,
+	"strcat":  true,
+	"strncat": true,
+	"strcmp":  true,
+	"strncmp": true,
+	"strchr":  true,
+	"strrchr": true,
+	"strstr":  true,
+	"strdup":  true,
+	// assert.h / errno.h
+	"assert": true,
+	"errno":  true,
+	// stddef.h / stdint.h / stdbool.h
+	"NULL":      true,
+	"size_t":    true,
+	"ssize_t":   true,
+	"ptrdiff_t": true,
+	"int8_t":    true,
+	"int16_t":   true,
+	"int32_t":   true,
+	"int64_t":   true,
+	"uint8_t":   true,
+	"uint16_t":  true,
+	"uint32_t":  true,
+	"uint64_t":  true,
+	"intptr_t":  true,
+	"uintptr_t": true,
+	"bool":      true,
+	"true":      true,
+	"false":     true,
+	// Operators that parse like calls
+	"sizeof":   true,
+	"alignof":  true,
+	"offsetof": true,
+}
+
+// ---------- C++ builtins (libc + std namespace) ----------
+
+var cppBuiltins = func() map[string]bool {
+	m := map[string]bool{
+		// std namespace and common members
+		"std":              true,
+		"cout":             true,
+		"cerr":             true,
+		"cin":              true,
+		"endl":             true,
+		"string":           true,
+		"string_view":      true,
+		"vector":           true,
+		"array":            true,
+		"map":              true,
+		"unordered_map":    true,
+		"set":              true,
+		"unordered_set":    true,
+		"list":             true,
+		"deque":            true,
+		"pair":             true,
+		"tuple":            true,
+		"optional":         true,
+		"variant":          true,
+		"function":         true,
+		"unique_ptr":       true,
+		"shared_ptr":       true,
+		"weak_ptr":         true,
+		"make_unique":      true,
+		"make_shared":      true,
+		"make_pair":        true,
+		"make_tuple":       true,
+		"move":             true,
+		"forward":          true,
+		"swap":             true,
+		"begin":            true,
+		"end":              true,
+		"size":             true,
+		"sort":             true,
+		"find":             true,
+		"min":              true,
+		"max":              true,
+		"to_string":        true,
+		"exception":        true,
+		"runtime_error":    true,
+		"logic_error":      true,
+		"invalid_argument": true,
+		"out_of_range":     true,
+		"nullptr":          true,
+		"static_cast":      true,
+		"dynamic_cast":     true,
+		"const_cast":       true,
+		"reinterpret_cast": true,
+		"decltype":         true,
+		"typeid":           true,
+	}
+	for name := range cBuiltins {
+		m[name] = true
+	}
+	return m
+}()
+
+// ---------- C# builtins (BCL + keyword types) ----------
+
+var csharpBuiltins = map[string]bool{
+	// Keyword type aliases
+	"string":  true,
+	"object":  true,
+	"int":     true,
+	"long":    true,
+	"short":   true,
+	"byte":    true,
+	"sbyte":   true,
+	"uint":    true,
+	"ulong":   true,
+	"ushort":  true,
+	"float":   true,
+	"double":  true,
+	"decimal": true,
+	"bool":    true,
+	"char":    true,
+	"dynamic": true,
+	"nameof":  true,
+	// System types
+	"String":                    true,
+	"Object":                    true,
+	"Int32":                     true,
+	"Int64":                     true,
+	"Boolean":                   true,
+	"Double":                    true,
+	"Decimal":                   true,
+	"Guid":                      true,
+	"DateTime":                  true,
+	"TimeSpan":                  true,
+	"Console":                   true,
+	"Math":                      true,
+	"Convert":                   true,
+	"Enum":                      true,
+	"Array":                     true,
+	"Nullable":                  true,
+	"Func":                      true,
+	"Action":                    true,
+	"Task":                      true,
+	"ValueTask":                 true,
+	"Exception":                 true,
+	"ArgumentException":         true,
+	"ArgumentNullException":     true,
+	"InvalidOperationException": true,
+	"NotImplementedException":   true,
+	"NotSupportedException":     true,
+	"IDisposable":               true,
+	"IEnumerable":               true,
+	"IEnumerator":               true,
+	"IComparable":               true,
+	"IEquatable":                true,
+	// System.Collections.Generic
+	"List":         true,
+	"Dictionary":   true,
+	"HashSet":      true,
+	"Queue":        true,
+	"Stack":        true,
+	"IList":        true,
+	"ICollection":  true,
+	"IDictionary":  true,
+	"KeyValuePair": true,
+	// Common members (not the System.Object and IDisposable members, which
+	// repo types override)
+	"WriteLine": true,
+	"Write":     true,
+	// LINQ
+	"Select":         true,
+	"Where":          true,
+	"First":          true,
+	"FirstOrDefault": true,
+	"Any":            true,
+	"All":            true,
+	"Count":          true,
+	"ToList":         true,
+	"ToArray":        true,
+	"OrderBy":        true,
+}
+
+// ---------- Kotlin builtins (kotlin stdlib) ----------
+
+var kotlinBuiltins = map[string]bool{
+	// Basic types
+	"Any":     true,
+	"Unit":    true,
+	"Nothing": true,
+	"Int":     true,
+	"Long":    true,
+	"Short":   true,
+	"Byte":    true,
+	"Double":  true,
+	"Float":   true,
+	"Boolean": true,
+	"Char":    true,
+	"String":  true,
+	"Array":   true,
+	// Collections
+	"List":          true,
+	"MutableList":   true,
+	"Map":           true,
+	"MutableMap":    true,
+	"Set":           true,
+	"MutableSet":    true,
+	"Pair":          true,
+	"Triple":        true,
+	"listOf":        true,
+	"mutableListOf": true,
+	"mapOf":         true,
+	"mutableMapOf":  true,
+	"setOf":         true,
+	"mutableSetOf":  true,
+	"arrayOf":       true,
+	"emptyList":     true,
+	"emptyMap":      true,
+	// Scope functions and stdlib helpers
+	"let":     true,
+	"run":     true,
+	"with":    true,
+	"apply":   true,
+	"also":    true,
+	"takeIf":  true,
+	"lazy":    true,
+	"println": true,
+	"print":   true,
+	"require": true,
+	"check":   true,
+	"error":   true,
+	"TODO":    true,
+	"repeat":  true,
+	"to":      true,
+	"it":      true,
+	// Common collection operations
+	"map":         true,
+	"filter":      true,
+	"forEach":     true,
+	"first":       true,
+	"firstOrNull": true,
+	"toList":      true,
+	"size":        true,
+	// Exceptions
+	"Exception":                true,
+	"RuntimeException":         true,
+	"IllegalArgumentException": true,
+	"IllegalStateException":    true,
+}
+
+// ---------- Ruby builtins (Kernel + core classes) ----------
+
+var rubyBuiltins = map[string]bool{
+	// Kernel methods
+	"puts":             true,
+	"print":            true,
+	"pp":               true,
+	"require":          true,
+	"require_relative": true,
+	"load":             true,
+	"raise":            true,
+	"fail":             true,
+	"loop":             true,
+	"lambda":           true,
+	"proc":             true,
+	"format":           true,
+	"sprintf":          true,
+	"gets":             true,
+	"sleep":            true,
+	"block_given?":     true,
+	"binding":          true,
+	"catch":            true,
+	"throw":            true,
+	"freeze":           true,
+	"frozen?":          true,
+	// Module / class macros
+	"attr_accessor":         true,
+	"attr_reader":           true,
+	"attr_writer":           true,
+	"include":               true,
+	"extend":                true,
+	"prepend":               true,
+	"private":               true,
+	"protected":             true,
+	"public":                true,
+	"module_function":       true,
+	"private_constant":      true,
+	"define_method":         true,
+	"alias_method":          true,
+	"respond_to?":           true,
+	"respond_to_missing?":   true,
+	"method_missing":        true,
+	"send":                  true,
+	"public_send":           true,
+	"instance_variable_get": true,
+	"instance_variable_set": true,
+	// Common object methods
+	"new":              true,
+	"class":            true,
+	"nil?":             true,
+	"is_a?":            true,
+	"kind_of?":         true,
+	"to_s":             true,
+	"to_i":             true,
+	"to_a":             true,
+	"to_h":             true,
+	"to_sym":           true,
+	"inspect":          true,
+	"each":             true,
+	"map":              true,
+	"select":           true,
+	"reject":           true,
+	"reduce":           true,
+	"each_with_object": true,
+	"empty?":           true,
+	"any?":             true,
+	"size":             true,
+	"length":           true,
+	// Core classes and modules
+	"Object":              true,
+	"BasicObject":         true,
+	"Kernel":              true,
+	"Comparable":          true,
+	"Enumerable":          true,
+	"String":              true,
+	"Symbol":              true,
+	"Integer":             true,
+	"Float":               true,
+	"Array":               true,
+	"Hash":                true,
+	"Range":               true,
+	"Proc":                true,
+	"Struct":              true,
+	"Time":                true,
+	"File":                true,
+	"IO":                  true,
+	"Dir":                 true,
+	"Math":                true,
+	"NilClass":            true,
+	"TrueClass":           true,
+	"FalseClass":          true,
+	"StandardError":       true,
+	"RuntimeError":        true,
+	"ArgumentError":       true,
+	"TypeError":           true,
+	"NameError":           true,
+	"NoMethodError":       true,
+	"NotImplementedError": true,
+	"KeyError":            true,
+	"IndexError":          true,
+}
+
+// ---------- PHP builtins (core functions + SPL) ----------
+
+var phpBuiltins = map[string]bool{
+	// Output and inspection
+	"echo":     true,
+	"print":    true,
+	"printf":   true,
+	"sprintf":  true,
+	"var_dump": true,
+	"print_r":  true,
+	"isset":    true,
+	"unset":    true,
+	"empty":    true,
+	"die":      true,
+	"exit":     true,
+	// Strings
+	"strlen":      true,
+	"str_replace": true,
+	"substr":      true,
+	"strpos":      true,
+	"trim":        true,
+	"explode":     true,
+	"implode":     true,
+	"strtolower":  true,
+	"strtoupper":  true,
+	"json_encode": true,
+	"json_decode": true,
+	// Arrays
+	"count":            true,
+	"array_map":        true,
+	"array_filter":     true,
+	"array_merge":      true,
+	"array_keys":       true,
+	"array_values":     true,
+	"array_key_exists": true,
+	"in_array":         true,
+	"sort":             true,
+	// Types
+	"is_array":  true,
+	"is_string": true,
+	"is_int":    true,
+	"is_null":   true,
+	"intval":    true,
+	"strval":    true,
+	"gettype":   true,
+	"get_class": true,
+	// Core classes and interfaces
+	"self":                     true,
+	"static":                   true,
+	"parent":                   true,
+	"stdClass":                 true,
+	"Exception":                true,
+	"Throwable":                true,
+	"Error":                    true,
+	"RuntimeException":         true,
+	"InvalidArgumentException": true,
+	"LogicException":           true,
+	"DateTime":                 true,
+	"DateTimeImmutable":        true,
+	"Closure":                  true,
+	"Countable":                true,
+	"Traversable":              true,
+	"IteratorAggregate":        true,
+	"ArrayAccess":              true,
+	"JsonSerializable":         true,
+	"Stringable":               true,
+	"string":                   true,
+	"int":                      true,
+	"float":                    true,
+	"bool":                     true,
+	"array":                    true,
+	"mixed":                    true,
+}
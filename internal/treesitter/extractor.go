@@ -3,6 +3,7 @@ package treesitter
 import (
 	_ "embed"
 	"fmt"
+	"slices"
 	"strings"
 
 	"github.com/mesdx/cli/internal/symbols"
@@ -26,37 +27,63 @@ var typescriptQuery string
 //go:embed queries/javascript.scm
 var javascriptQuery string
 
+//go:embed queries/c.scm
+var cQuery string
+
+//go:embed queries/cpp.scm
+var cppQuery string
+
+//go:embed queries/csharp.scm
+var csharpQuery string
+
+//go:embed queries/kotlin.scm
+var kotlinQuery string
+
+//go:embed queries/ruby.scm
+var rubyQuery string
+
+//go:embed queries/php.scm
+var phpQuery string
+
 // Extractor extracts symbols and references from parsed trees using queries.
 type Extractor struct {
 	lang      *Language
 	query     *Query
+	locals    *Query // nil when locals are not tracked for the language
 	langName  string
 }
 
-// NewExtractor creates a new extractor for the given language.
+// NewExtractor creates a new extractor for the given language using only
+// the embedded query.
 func NewExtractor(langName string) (*Extractor, error) {
+	return NewExtractorWithQueryDir(langName, "")
+}
+
+// NewExtractorWithQueryDir creates an extractor whose query is the embedded
+// one extended (or replaced) by <queryDir>/<lang>.scm when that file exists.
+// An empty queryDir disables overrides.
+func NewExtractorWithQueryDir(langName, queryDir string) (*Extractor, error) {
 	lang, err := LoadLanguage(langName)
 	if err != nil {
 		return nil, fmt.Errorf("load language %s: %w", langName, err)
 	}
 
-	// Select query source
-	var querySource string
-	switch langName {
-	case "go":
-		querySource = goQuery
-	case "java":
-		querySource = javaQuery
-	case "rust":
-		querySource = rustQuery
-	case "python":
-		querySource = pythonQuery
-	case "typescript":
-		querySource = typescriptQuery
-	case "javascript":
-		querySource = javascriptQuery
-	default:
-		return nil, fmt.Errorf("no query defined for language %s", langName)
+	querySource, err := embeddedQuery(langName)
+	if err != nil {
+		return nil, err
+	}
+
+	if err := ValidateUserQuery(queryDir, langName); err != nil {
+		return nil, err
+	}
+	userSource, replace, err := readUserQuery(queryDir, langName)
+	if err != nil {
+		return nil, err
+	}
+	if replace {
+		querySource = userSource
+	} else if userSource != "" {
+		querySource += "\n" + userSource
 	}
 
 	query, err := NewQuery(lang, querySource)
@@ -64,13 +91,54 @@ func NewExtractor(langName string) (*Extractor, error) {
 		return nil, fmt.Errorf("parse query for %s: %w", langName, err)
 	}
 
+	var locals *Query
+	if localsSource := embeddedLocalsQuery(langName); localsSource != "" {
+		if locals, err = NewQuery(lang, localsSource); err != nil {
+			query.Close()
+			return nil, fmt.Errorf("parse locals query for %s: %w", langName, err)
+		}
+	}
+
 	return &Extractor{
 		lang:     lang,
 		query:    query,
+		locals:   locals,
 		langName: langName,
 	}, nil
 }
 
+// embeddedQuery returns the query source compiled into the binary.
+func embeddedQuery(langName string) (string, error) {
+	switch langName {
+	case "go":
+		return goQuery, nil
+	case "java":
+		return javaQuery, nil
+	case "rust":
+		return rustQuery, nil
+	case "python":
+		return pythonQuery, nil
+	case "typescript":
+		return typescriptQuery, nil
+	case "javascript":
+		return javascriptQuery, nil
+	case "c":
+		return cQuery, nil
+	case "cpp":
+		return cppQuery, nil
+	case "csharp":
+		return csharpQuery, nil
+	case "kotlin":
+		return kotlinQuery, nil
+	case "ruby":
+		return rubyQuery, nil
+	case "php":
+		return phpQuery, nil
+	default:
+		return "", fmt.Errorf("no query defined for language %s", langName)
+	}
+}
+
 // pendingCapture holds a captured node awaiting processing in the two-pass extraction.
 type pendingCapture struct {
 	capName       string
@@ -105,6 +173,7 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 	result := &symbols.FileResult{
 		Symbols: []symbols.Symbol{},
 		Refs:    []symbols.Ref{},
+		Package: filePackage(e.langName, rootNode, source),
 	}
 
 	// Build a map of capture names to IDs
@@ -184,7 +253,17 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 		startPoint := node.StartPoint()
 		endPoint := node.EndPoint()
 
+		// Ruby-specific: attr_accessor :name names the property via a symbol;
+		// drop the leading colon.
+		if e.langName == "ruby" && strings.HasPrefix(name, ":") {
+			name = name[1:]
+			startPoint.Column++
+		}
+
 		parent := node.Parent()
+		if e.langName == "c" || e.langName == "cpp" {
+			parent = enclosingDeclaration(parent)
+		}
 		if !parent.IsNull() {
 			endPoint = parent.EndPoint()
 		}
@@ -209,6 +288,18 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 			container = containerContext[parentKey]
 		}
 
+		// Kotlin-specific: extension functions and properties are attributed
+		// to their receiver type (fun String.shout() has container String).
+		if e.langName == "kotlin" && container == "" {
+			container = kotlinReceiverType(node, source)
+		}
+
+		// PHP-specific: top-level declarations live in the namespace declared
+		// earlier in the file (namespace App\Billing;).
+		if e.langName == "php" && container == "" && isPHPNamespacedKind(kind) {
+			container = phpNamespaceAt(rootNode, node.StartByte(), source)
+		}
+
 		sym := symbols.Symbol{
 			Name:          name,
 			Kind:          kind,
@@ -219,11 +310,33 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 			EndCol:        int(startPoint.Column) + len(name),
 		}
 
+		// Java-specific: symbols are qualified by the package and the
+		// enclosing type declarations (com.shop.Order.Builder.build). Kotlin,
+		// C#, C++ and PHP symbols are prefixed with their package or
+		// namespace here; other languages are qualified by their enclosing
+		// declarations here and by their package or module when indexed.
+		if e.langName == "java" {
+			sym.QualifiedName = javaQualifiedName(node, result.Package, source)
+		} else {
+			sym.QualifiedName = scopedName(e.langName, node, container, source)
+			if ns := enclosingNamespace(e.langName, rootNode, node, result.Package, source); ns != "" && container != ns {
+				sym.QualifiedName = ns + scopeSeparator(e.langName) + sym.QualifiedName
+			}
+		}
+		describeSymbol(e.langName, &sym, node, source)
+
 		result.Symbols = append(result.Symbols, sym)
 	}
 
 	// ---------- Pass 3: process references ----------
 	// We use semantic deduplication: same position, keep higher priority capture.
+	// References to locals and parameters record the declaration they bind,
+	// and members record the type of the value they are accessed on.
+	bindings := e.resolveLocals(rootNode, source)
+	var receivers *receiverInference
+	if e.locals != nil {
+		receivers = newReceiverInference(e.langName, rootNode, source, bindings)
+	}
 	seenRefs := make(map[string]symbols.Ref) // key = "name:row:col"
 	importNames := make(map[string]bool)
 
@@ -275,9 +388,10 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 
 		startPoint := node.StartPoint()
 
-		// Skip if this position is a definition (avoid double-counting)
+		// Skip if this position is a definition (avoid double-counting),
+		// unless the definition overrides a method of a supertype.
 		posKey := fmt.Sprintf("%s:%d:%d", name, startPoint.Row, startPoint.Column)
-		if defPositions[posKey] {
+		if defPositions[posKey] && rc.capName != "ref.override" {
 			continue
 		}
 
@@ -285,6 +399,12 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 		refKind := mapCaptureToRefKind(rc.capName)
 		relation := mapCaptureToRelation(rc.capName)
 
+		// C#-specific: a class base list mixes the base class and implemented
+		// interfaces; interfaces follow the IName convention.
+		if e.langName == "csharp" && relation == "inherits" && isCSharpInterfaceName(name) {
+			relation = "implements"
+		}
+
 		// Semantic deduplication: if we've seen this ref position, keep the higher priority one
 		if existing, seen := seenRefs[refKey]; seen {
 			existingPriority := refSemanticPriority(existing.Kind, existing.Relation)
@@ -302,7 +422,30 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 			if idx := strings.LastIndex(name, "/"); idx >= 0 {
 				name = name[idx+1:]
 			}
-			if name == "" || len(name) <= 1 {
+			if len(name) <= 1 {
+				continue
+			}
+		}
+
+		// C/C++-specific: #include paths keep their quotes or angle brackets;
+		// strip them and keep the header's base name.
+		if refKind == symbols.RefImport && (e.langName == "c" || e.langName == "cpp") {
+			name = strings.Trim(name, "\"<>")
+			if idx := strings.LastIndex(name, "/"); idx >= 0 {
+				name = name[idx+1:]
+			}
+			if len(name) <= 1 {
+				continue
+			}
+		}
+
+		// Ruby-specific: require paths may be nested ("active_support/core_ext");
+		// keep the last path segment as the library name.
+		if refKind == symbols.RefImport && e.langName == "ruby" {
+			if idx := strings.LastIndex(name, "/"); idx >= 0 {
+				name = name[idx+1:]
+			}
+			if len(name) <= 1 {
 				continue
 			}
 		}
@@ -312,13 +455,15 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 			importNames[name] = true
 		}
 
-		// Classify builtin
-		isBuiltin := IsBuiltin(e.langName, name)
+		// Classify builtin; an overriding method is declared here, so it
+		// is neither builtin nor imported.
+		overrides := relation == "overrides"
+		isBuiltin := !overrides && IsBuiltin(e.langName, name)
 
 		// Classify external: import refs are always external;
 		// non-builtin refs that match a previously seen import name are external too.
 		isExternal := refKind == symbols.RefImport
-		if !isExternal && !isBuiltin && importNames[name] {
+		if !isExternal && !isBuiltin && !overrides && importNames[name] {
 			isExternal = true
 		}
 
@@ -330,13 +475,23 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 			IsExternal:       isExternal,
 			IsBuiltin:        isBuiltin,
 			Relation:         relation,
-			ReceiverType:     "", // TODO: extract from parent node context if needed
 			TargetType:       "", // TODO: for type refs, could extract target type
 			StartLine:        int(startPoint.Row) + 1,
 			StartCol:         int(startPoint.Column),
 			EndLine:          int(endPoint.Row) + 1,
 			EndCol:           int(endPoint.Column),
 			ContextContainer: rc.containerName,
+			Qualifier:        refQualifier(e.langName, node, source),
+		}
+		if binding, ok := bindings.refs[refKey]; ok && ref.Qualifier == "" {
+			ref.BindingLine = int(binding.Row) + 1
+			ref.BindingCol = int(binding.Column)
+		}
+		if receivers != nil {
+			ref.ReceiverType = receivers.receiverType(node)
+		}
+		if relation == "embeds" {
+			ref.TargetType = embeddedType(node, source)
 		}
 
 		seenRefs[refKey] = ref
@@ -346,6 +501,8 @@ func (e *Extractor) Extract(filename string, source []byte) (*symbols.FileResult
 	for _, ref := range seenRefs {
 		result.Refs = append(result.Refs, ref)
 	}
+	result.Imports = extractImports(e.langName, rootNode, source)
+	result.Exports = moduleExports(e.langName, rootNode, source)
 
 	return result, nil
 }
@@ -355,6 +512,309 @@ func (e *Extractor) Close() {
 	if e.query != nil {
 		e.query.Close()
 	}
+	if e.locals != nil {
+		e.locals.Close()
+	}
+}
+
+// declaratorNodes lists the C/C++ nodes that sit between a definition's name
+// and the declaration that owns its body, e.g. the function_declarator and
+// pointer_declarator in `char *name(void) { ... }`.
+var declaratorNodes = map[string]bool{
+	"function_declarator":      true,
+	"pointer_declarator":       true,
+	"reference_declarator":     true,
+	"array_declarator":         true,
+	"parenthesized_declarator": true,
+	"init_declarator":          true,
+	"qualified_identifier":     true,
+}
+
+// enclosingDeclaration climbs out of nested C/C++ declarators so the symbol
+// span covers the whole definition (including the body) rather than just the
+// declarator.
+func enclosingDeclaration(node Node) Node {
+	for !node.IsNull() && declaratorNodes[node.Type()] {
+		parent := node.Parent()
+		if parent.IsNull() {
+			break
+		}
+		node = parent
+	}
+	return node
+}
+
+// embeddedType returns a Go embedded field or interface as written around
+// the type name captured in it: *Base, pkg.Base, Base[T] or io.Reader.
+func embeddedType(node Node, source []byte) string {
+	for n := node.Parent(); !n.IsNull(); n = n.Parent() {
+		switch n.Type() {
+		case "field_declaration":
+			typ := n.ChildByFieldName("type").Content(source)
+			// struct { *Base }: the star is a token of the field, not a
+			// pointer type.
+			for i := uint32(0); i < n.ChildCount(); i++ {
+				if n.Child(i).Type() == "*" {
+					return "*" + typ
+				}
+			}
+			return typ
+		case "type_elem":
+			return n.Content(source)
+		}
+	}
+	return node.Content(source)
+}
+
+// kotlinReceiverType returns the receiver type of a Kotlin extension function
+// or property whose name node is given, or "" if the declaration has none.
+// The receiver is the named sibling immediately preceding the name.
+func kotlinReceiverType(node Node, source []byte) string {
+	decl := node.Parent()
+	if decl.Type() == "variable_declaration" {
+		node = decl
+		decl = decl.Parent()
+	}
+	if decl.Type() != "function_declaration" && decl.Type() != "property_declaration" {
+		return ""
+	}
+
+	var prev Node
+	for i := uint32(0); i < decl.NamedChildCount(); i++ {
+		child := decl.NamedChild(i)
+		if child.StartByte() == node.StartByte() {
+			break
+		}
+		prev = child
+	}
+	if prev.Type() != "user_type" && prev.Type() != "nullable_type" {
+		return ""
+	}
+
+	receiver := strings.TrimSuffix(prev.Content(source), "?")
+	if idx := strings.Index(receiver, "<"); idx >= 0 {
+		receiver = receiver[:idx]
+	}
+	if idx := strings.LastIndex(receiver, "."); idx >= 0 {
+		receiver = receiver[idx+1:]
+	}
+	return strings.TrimSpace(receiver)
+}
+
+// isPHPNamespacedKind reports whether a PHP symbol of this kind is scoped by
+// its file's namespace.
+func isPHPNamespacedKind(kind symbols.SymbolKind) bool {
+	switch kind {
+	case symbols.KindClass, symbols.KindInterface, symbols.KindTrait,
+		symbols.KindEnum, symbols.KindFunction, symbols.KindConstant:
+		return true
+	}
+	return false
+}
+
+// phpNamespaceAt returns the name of the PHP namespace in effect at the given
+// byte offset. It handles both the statement form (namespace Foo;), which
+// applies until the next namespace statement, and the braced form
+// (namespace Foo { ... }).
+func phpNamespaceAt(root Node, offset uint32, source []byte) string {
+	ns := ""
+	for i := uint32(0); i < root.NamedChildCount(); i++ {
+		child := root.NamedChild(i)
+		if child.StartByte() > offset {
+			break
+		}
+		if child.Type() != "namespace_definition" {
+			continue
+		}
+		body := child.ChildByFieldName("body")
+		if !body.IsNull() && offset >= body.EndByte() {
+			ns = ""
+			continue
+		}
+		ns = child.ChildByFieldName("name").Content(source)
+	}
+	return ns
+}
+
+// javaQualifiedName returns the fully qualified name of the Java
+// declaration whose name node is given. Constructors take the name of their
+// class, as java.lang.reflect reports them.
+func javaQualifiedName(node Node, pkg string, source []byte) string {
+	var parts []string
+	decl := node.Parent()
+	if decl.IsNull() || decl.Type() != "constructor_declaration" {
+		parts = append(parts, node.Content(source))
+	}
+	if !decl.IsNull() {
+		for n := decl.Parent(); !n.IsNull(); n = n.Parent() {
+			switch n.Type() {
+			case "class_declaration", "interface_declaration", "enum_declaration",
+				"record_declaration", "annotation_type_declaration":
+				if name := n.ChildByFieldName("name"); !name.IsNull() {
+					parts = append(parts, name.Content(source))
+				}
+			}
+		}
+	}
+	if pkg != "" {
+		parts = append(parts, pkg)
+	}
+	slices.Reverse(parts)
+	return strings.Join(parts, ".")
+}
+
+// enclosingNamespace returns the package or namespace a Kotlin, C#, C++ or
+// PHP declaration whose name node is given lives in, or "". A namespace's
+// own name is qualified by the namespaces around it only.
+func enclosingNamespace(langName string, root, node Node, pkg string, source []byte) string {
+	switch langName {
+	case "kotlin":
+		if node.Parent().Type() == "package_header" {
+			return ""
+		}
+		return pkg
+	case "php":
+		if node.Parent().Type() == "namespace_definition" {
+			return ""
+		}
+		return phpNamespaceAt(root, node.StartByte(), source)
+	case "csharp", "cpp":
+	default:
+		return ""
+	}
+	var parts []string
+	for n := node.Parent(); !n.IsNull(); n = n.Parent() {
+		switch n.Type() {
+		case "namespace_definition", "namespace_declaration", "file_scoped_namespace_declaration":
+			if name := n.ChildByFieldName("name"); !name.IsNull() && name.StartByte() != node.StartByte() {
+				parts = append(parts, name.Content(source))
+			}
+		}
+	}
+	slices.Reverse(parts)
+	if langName == "csharp" {
+		// namespace Shop.Orders; applies to the declarations that follow it.
+		for i := uint32(0); i < root.NamedChildCount(); i++ {
+			decl := root.NamedChild(i)
+			if decl.Type() == "file_scoped_namespace_declaration" && decl.EndByte() <= node.StartByte() {
+				if name := decl.ChildByFieldName("name"); !name.IsNull() {
+					parts = append([]string{name.Content(source)}, parts...)
+				}
+				break
+			}
+		}
+	}
+	return strings.Join(parts, scopeSeparator(langName))
+}
+
+// scopeSeparator returns the separator between the segments of a qualified
+// name in a language.
+func scopeSeparator(langName string) string {
+	switch langName {
+	case "rust", "cpp":
+		return "::"
+	case "php":
+		return "\\"
+	}
+	return "."
+}
+
+// scopedName returns the name of a declaration qualified by the type,
+// class, trait or inline module declarations enclosing it in its file
+// (Type.Method, Outer::Inner::item). Languages without a rule here fall
+// back to the captured container.
+func scopedName(langName string, node Node, container string, source []byte) string {
+	sep := scopeSeparator(langName)
+	name := node.Content(source)
+	switch langName {
+	case "go", "python", "javascript", "typescript", "rust":
+	default:
+		if container == "" {
+			return name
+		}
+		return container + sep + name
+	}
+	parts := []string{name}
+	decl := node.Parent()
+	if decl.IsNull() {
+		return name
+	}
+	if langName == "go" && decl.Type() == "method_declaration" {
+		// func (s *Store[T]) Save(): the receiver's type name
+		if recv := goReceiverType(decl.ChildByFieldName("receiver"), source); recv != "" {
+			parts = append(parts, recv)
+		}
+	}
+	for n := decl.Parent(); !n.IsNull(); n = n.Parent() {
+		var scope Node
+		switch langName {
+		case "go":
+			if n.Type() == "type_spec" {
+				scope = n.ChildByFieldName("name")
+			}
+		case "python":
+			if n.Type() == "class_definition" {
+				scope = n.ChildByFieldName("name")
+			}
+		case "javascript", "typescript":
+			switch n.Type() {
+			case "class_declaration", "class", "abstract_class_declaration",
+				"interface_declaration", "enum_declaration", "internal_module":
+				scope = n.ChildByFieldName("name")
+			}
+		case "rust":
+			switch n.Type() {
+			case "struct_item", "enum_item", "union_item", "trait_item", "mod_item":
+				scope = n.ChildByFieldName("name")
+			case "impl_item":
+				scope = n.ChildByFieldName("type")
+				if !scope.IsNull() && scope.Type() == "generic_type" {
+					scope = scope.ChildByFieldName("type") // impl<T> Stack<T>
+				}
+			}
+		}
+		if !scope.IsNull() {
+			parts = append(parts, scope.Content(source))
+		}
+	}
+	slices.Reverse(parts)
+	return strings.Join(parts, sep)
+}
+
+// goReceiverType returns the type name of a Go method receiver list, without
+// pointer and type parameters.
+func goReceiverType(recv Node, source []byte) string {
+	if recv.IsNull() {
+		return ""
+	}
+	for i := uint32(0); i < recv.NamedChildCount(); i++ {
+		param := recv.NamedChild(i)
+		if param.Type() != "parameter_declaration" {
+			continue
+		}
+		typ := param.ChildByFieldName("type")
+		for !typ.IsNull() {
+			switch typ.Type() {
+			case "pointer_type":
+				typ = typ.NamedChild(0)
+			case "generic_type":
+				typ = typ.ChildByFieldName("type")
+			case "type_identifier":
+				return typ.Content(source)
+			default:
+				return ""
+			}
+		}
+	}
+	return ""
+}
+
+// isCSharpInterfaceName reports whether name follows the .NET interface
+// naming convention: an "I" prefix followed by a capitalised word, as in
+// IDisposable or IList.
+func isCSharpInterfaceName(name string) bool {
+	return len(name) > 2 && name[0] == 'I' && name[1] >= 'A' && name[1] <= 'Z' &&
+		name[2] >= 'a' && name[2] <= 'z'
 }
 
 // mapCaptureToKind maps a capture name to a symbol kind.
@@ -406,8 +866,12 @@ func mapCaptureToRelation(capName string) string {
 		return "implements"
 	case "ref.annotation":
 		return "annotation"
-	case "ref.prototype":
+	case "ref.prototype", "ref.inherit.prototype":
 		return "prototype"
+	case "ref.inherit.embed":
+		return "embeds"
+	case "ref.override":
+		return "overrides"
 	default:
 		return ""
 	}
@@ -452,7 +916,7 @@ func mapCaptureToRefKind(capName string) symbols.RefKind {
 		return symbols.RefImport
 	case "ref.type":
 		return symbols.RefTypeRef
-	case "ref.inherit", "ref.implements":
+	case "ref.inherit", "ref.implements", "ref.inherit.prototype", "ref.inherit.embed", "ref.override":
 		return symbols.RefInherit
 	case "ref.attribute", "ref.annotation":
 		return symbols.RefAnnotation
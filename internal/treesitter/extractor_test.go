@@ -1,6 +1,9 @@
 package treesitter
 
 import (
+	"fmt"
+	"slices"
+	"strings"
 	"testing"
 
 	"github.com/mesdx/cli/internal/symbols"
@@ -303,3 +306,623 @@ func TestPythonQuotedAnnotation_Positions(t *testing.T) {
 // RefAnnotation is a helper alias so extractor_test can reference it without
 // importing the symbols package directly (it's already in scope via extractor.go).
 const RefAnnotation = 7 // symbols.RefAnnotation
+
+func TestExtractScopedQualifiedNames(t *testing.T) {
+	tests := []struct {
+		lang, file string
+		source     string
+		want       map[string]string // symbol name -> qualified name
+	}{
+		{"go", "store.go", `package store
+
+type Store[T any] struct{}
+
+func (s *Store[T]) Save() {}
+
+func Open() {}
+`, map[string]string{"Store": "Store", "Save": "Store.Save", "Open": "Open"}},
+		{"rust", "lib.rs", `struct Stack<T> { items: Vec<T> }
+
+impl<T> Stack<T> {
+    fn push(&mut self) {}
+}
+
+mod inner {
+    pub fn helper() {}
+}
+`, map[string]string{"Stack": "Stack", "push": "Stack::push", "helper": "inner::helper"}},
+		{"python", "cart.py", `class Cart:
+    def render(self):
+        return ""
+`, map[string]string{"Cart": "Cart", "render": "Cart.render"}},
+		{"typescript", "store.ts", `class Store {
+  save(): void {}
+}
+
+namespace Shop {
+  export function open(): void {}
+}
+`, map[string]string{"Store": "Store", "save": "Store.save", "open": "Shop.open"}},
+		{"kotlin", "Cart.kt", `package com.shop.orders
+
+class Cart {
+    fun add() {}
+}
+`, map[string]string{"Cart": "com.shop.orders.Cart", "add": "com.shop.orders.Cart.add"}},
+		{"csharp", "Cart.cs", `namespace Shop.Orders
+{
+    public class Cart
+    {
+        public void Add() {}
+    }
+}
+`, map[string]string{"Cart": "Shop.Orders.Cart", "Add": "Shop.Orders.Cart.Add"}},
+		{"cpp", "cart.cpp", `namespace shop {
+class Cart {
+  void add() {}
+};
+
+void Cart::remove() {}
+}
+`, map[string]string{"shop": "shop", "Cart": "shop::Cart", "add": "shop::Cart::add", "remove": "shop::Cart::remove"}},
+		{"php", "Cart.php", `<?php
+namespace App\Billing;
+
+class Cart {
+    public function add() {}
+}
+`, map[string]string{"Cart": `App\Billing\Cart`, "add": `App\Billing\Cart\add`}},
+	}
+	for _, tt := range tests {
+		if err := VerifyLanguages([]string{tt.lang}); err != nil {
+			t.Skip("parser not available:", err)
+		}
+		extractor, err := NewExtractor(tt.lang)
+		if err != nil {
+			t.Fatal(err)
+		}
+		result, err := extractor.Extract(tt.file, []byte(tt.source))
+		extractor.Close()
+		if err != nil {
+			t.Fatal(err)
+		}
+		got := map[string]string{}
+		for _, sym := range result.Symbols {
+			got[sym.Name] = sym.QualifiedName
+		}
+		for name, want := range tt.want {
+			if got[name] != want {
+				t.Errorf("%s: %s qualified as %q, want %q", tt.lang, name, got[name], want)
+			}
+		}
+	}
+}
+
+func TestExtractLocalBindings(t *testing.T) {
+	type binding struct{ line, col, bindLine, bindCol int } // bindLine 0: not a local
+	tests := []struct {
+		lang, file string
+		source     string
+		want       []binding
+	}{
+		{"go", "run.go", `package app
+
+var err error
+
+func run(ctx context.Context) error {
+	data, err := load(ctx)
+	if err != nil {
+		return err
+	}
+	if err := save(data); err != nil {
+		return err
+	}
+	return err
+}
+
+func other() error {
+	return err
+}
+`, []binding{
+			{6, 19, 5, 9},  // parameter
+			{6, 7, 6, 7},   // declaration
+			{8, 9, 6, 7},   // in a nested block
+			{10, 4, 10, 4}, // shadowed in the if statement
+			{10, 16, 6, 1}, // outer local used in the initializer
+			{11, 9, 10, 4}, // the shadowing err
+			{13, 8, 6, 7},  // back in the function scope
+			{17, 8, 0, 0},  // package-level var
+		}},
+		{"python", "cart.py", `total = 0
+
+def add(items, total=0):
+    for item in items:
+        total += item
+    return total
+
+def report():
+    return total
+
+def reset():
+    global total
+    total = 0
+    return total
+`, []binding{
+			{4, 16, 3, 8},
+			{5, 17, 4, 8},
+			{6, 11, 3, 15}, // the parameter shadows the global
+			{9, 11, 0, 0},
+			{14, 11, 0, 0}, // declared global
+		}},
+		{"typescript", "greet.ts", `const name = "global";
+
+function greet(name: string): string {
+  const message = ` + "`hi ${name}`" + `;
+  return message;
+}
+
+function label(): string {
+  return name;
+}
+
+function scale(items: number[], factor: number): number[] {
+  return items.map((item) => item * factor);
+}
+`, []binding{
+			{4, 24, 3, 15},
+			{5, 9, 4, 8},
+			{9, 9, 0, 0},
+			{13, 9, 12, 15},
+			{13, 29, 13, 20}, // closure parameter
+			{13, 36, 12, 32}, // captured by the closure
+		}},
+		{"rust", "parse.rs", `fn parse(input: &str) -> usize {
+    let count = input.len();
+    let count = count * 2;
+    count
+}
+`, []binding{
+			{2, 16, 1, 9},
+			{3, 16, 2, 8}, // the initializer reads the previous binding
+			{4, 4, 3, 8},
+		}},
+		{"java", "Counter.java", `class Counter {
+    int total;
+
+    int add(int amount) {
+        int result = total + amount;
+        return result;
+    }
+}
+`, []binding{
+			{5, 21, 0, 0}, // field
+			{5, 29, 4, 16},
+			{6, 15, 5, 12},
+		}},
+	}
+	for _, tt := range tests {
+		if err := VerifyLanguages([]string{tt.lang}); err != nil {
+			t.Skip("parser not available:", err)
+		}
+		extractor, err := NewExtractor(tt.lang)
+		if err != nil {
+			t.Fatal(err)
+		}
+		result, err := extractor.Extract(tt.file, []byte(tt.source))
+		extractor.Close()
+		if err != nil {
+			t.Fatal(err)
+		}
+		for _, w := range tt.want {
+			found := false
+			for _, ref := range result.Refs {
+				if ref.StartLine != w.line || ref.StartCol != w.col {
+					continue
+				}
+				found = true
+				if ref.BindingLine != w.bindLine || ref.BindingCol != w.bindCol {
+					t.Errorf("%s: %s at %d:%d bound to %d:%d, want %d:%d", tt.lang, ref.Name,
+						w.line, w.col, ref.BindingLine, ref.BindingCol, w.bindLine, w.bindCol)
+				}
+			}
+			if !found {
+				t.Errorf("%s: no ref at %d:%d", tt.lang, w.line, w.col)
+			}
+		}
+	}
+}
+
+func TestExtractReceiverTypes(t *testing.T) {
+	type receiver struct {
+		line, col int
+		want      string
+	}
+	tests := []struct {
+		lang, file string
+		source     string
+		want       []receiver
+	}{
+		{"go", "server.go", `package app
+
+type Server struct {
+	repo *UserRepo
+}
+
+func (s *Server) Handle(id int) {
+	s.repo.Save(id)
+	var cache Cache
+	cache.Get(id)
+	store := NewOrderStore()
+	store.Load(id)
+	audit := &AuditLog{}
+	audit.Write(id)
+	fmt.Println(id)
+}
+`, []receiver{
+			{8, 3, "Server"},
+			{8, 8, "UserRepo"},    // a field of the receiver's type
+			{10, 7, "Cache"},      // declared type
+			{12, 7, "OrderStore"}, // NewOrderStore()
+			{14, 7, "AuditLog"},   // &AuditLog{}
+			{15, 5, ""},           // package qualifier
+		}},
+		{"java", "OrderService.java", `class OrderService {
+    private final UserRepo repo;
+
+    OrderService(UserRepo repo) {
+        this.repo = repo;
+    }
+
+    void place(Order order) {
+        order.validate();
+        this.repo.save(order);
+        var cart = new Cart();
+        cart.add(order);
+    }
+}
+`, []receiver{
+			{9, 14, "Order"},
+			{10, 18, "UserRepo"},
+			{12, 13, "Cart"}, // var initialized by a constructor call
+		}},
+		{"rust", "server.rs", `struct Server {
+    repo: Box<UserRepo>,
+}
+
+impl Server {
+    fn handle(&self, id: u32) {
+        self.repo.save(id);
+        let cache = Cache::new();
+        cache.get(id);
+        let mut log: AuditLog = open_log();
+        log.write(id);
+    }
+}
+`, []receiver{
+			{7, 18, "UserRepo"}, // through Box
+			{9, 14, "Cache"},    // Cache::new()
+			{11, 12, "AuditLog"},
+		}},
+		{"python", "server.py", `class Server:
+    def __init__(self, repo: UserRepo):
+        self.repo = repo
+
+    def handle(self, item_id):
+        self.repo.save(item_id)
+        cache = Cache()
+        cache.get(item_id)
+        self.missing.drop(item_id)
+`, []receiver{
+			{6, 18, "UserRepo"}, // assigned from a typed parameter
+			{8, 14, "Cache"},
+			{9, 21, ""},
+		}},
+		{"typescript", "server.ts", `class Server {
+  constructor(private repo: UserRepo) {}
+
+  handle(id: number): void {
+    this.repo.save(id);
+    const cache = new Cache();
+    cache.get(id);
+  }
+}
+
+function run(log: AuditLog) {
+  log.write(1);
+}
+`, []receiver{
+			{5, 14, "UserRepo"}, // parameter property
+			{7, 10, "Cache"},
+			{12, 6, "AuditLog"},
+		}},
+		{"javascript", "server.js", `class Server {
+  constructor() {
+    this.repo = new UserRepo();
+  }
+
+  handle(id) {
+    this.repo.save(id);
+  }
+}
+`, []receiver{
+			{7, 14, "UserRepo"}, // assigned in the constructor
+		}},
+	}
+	for _, tt := range tests {
+		if err := VerifyLanguages([]string{tt.lang}); err != nil {
+			t.Skip("parser not available:", err)
+		}
+		extractor, err := NewExtractor(tt.lang)
+		if err != nil {
+			t.Fatal(err)
+		}
+		result, err := extractor.Extract(tt.file, []byte(tt.source))
+		extractor.Close()
+		if err != nil {
+			t.Fatal(err)
+		}
+		for _, w := range tt.want {
+			found := false
+			for _, ref := range result.Refs {
+				if ref.StartLine != w.line || ref.StartCol != w.col {
+					continue
+				}
+				found = true
+				if ref.ReceiverType != w.want {
+					t.Errorf("%s: %s at %d:%d has receiver type %q, want %q", tt.lang, ref.Name,
+						w.line, w.col, ref.ReceiverType, w.want)
+				}
+			}
+			if !found {
+				t.Errorf("%s: no ref at %d:%d", tt.lang, w.line, w.col)
+			}
+		}
+	}
+}
+
+func TestExtractOverrides(t *testing.T) {
+	tests := []struct {
+		lang, file string
+		source     string
+		want       string // the overrides refs, as name@line:col
+	}{
+		{"java", "Square.java", `class Square extends Shape {
+    @Override
+    public double area() {
+        return 1;
+    }
+
+    public double side() {
+        return 1;
+    }
+}
+`, "area@3:18"},
+		{"rust", "square.rs", `impl Shape for Square {
+    fn area(&self) -> f64 {
+        1.0
+    }
+}
+
+impl Square {
+    fn side(&self) -> f64 {
+        1.0
+    }
+}
+`, "area@2:7"},
+		{"csharp", "Square.cs", `class Square : Shape
+{
+    public override double Area()
+    {
+        return 1;
+    }
+
+    public double Side()
+    {
+        return 1;
+    }
+}
+`, "Area@3:27"},
+		{"kotlin", "Square.kt", `class Square : Shape() {
+    override fun area(): Double = 1.0
+
+    fun side(): Double = 1.0
+}
+`, "area@2:17"},
+		{"cpp", "square.hpp", `class Square : public Shape {
+public:
+    double area() const override;
+    double side() const;
+};
+`, "area@3:11"},
+		{"typescript", "square.ts", `class Square extends Shape {
+  override area(): number {
+    return 1;
+  }
+
+  side(): number {
+    return 1;
+  }
+}
+`, "area@2:11"},
+	}
+	for _, tt := range tests {
+		if err := VerifyLanguages([]string{tt.lang}); err != nil {
+			t.Skip("parser not available:", err)
+		}
+		extractor, err := NewExtractor(tt.lang)
+		if err != nil {
+			t.Fatal(err)
+		}
+		result, err := extractor.Extract(tt.file, []byte(tt.source))
+		extractor.Close()
+		if err != nil {
+			t.Fatal(err)
+		}
+		var got []string
+		for _, ref := range result.Refs {
+			if ref.Relation != "overrides" {
+				continue
+			}
+			got = append(got, fmt.Sprintf("%s@%d:%d", ref.Name, ref.StartLine, ref.StartCol))
+			if ref.Kind != symbols.RefInherit || ref.IsExternal || ref.IsBuiltin {
+				t.Errorf("%s: override ref %+v, want an internal RefInherit", tt.lang, ref)
+			}
+		}
+		if strings.Join(got, " ") != tt.want {
+			t.Errorf("%s: overrides refs = %q, want %q", tt.lang, got, tt.want)
+		}
+	}
+}
+
+func TestExtractStructuredSignatures(t *testing.T) {
+	type signature struct {
+		name, kind string
+		visibility symbols.Visibility
+		receiver   string
+		typeParams []string
+		params     []symbols.Parameter
+		returnType string
+		signature  string // checked when set
+	}
+	tests := []struct {
+		lang, file string
+		source     string
+		want       []signature
+	}{
+		{"go", "store.go", `package store
+
+type Store[K comparable, V any] struct {
+	items map[K]V
+}
+
+func (s *Store[K, V]) Put(key K, value V) error {
+	return nil
+}
+
+func newStore(a, b int, opts ...string) (*Store[string, int], bool) {
+	return nil, false
+}
+`, []signature{
+			{"Store", "struct", "public", "", []string{"K comparable", "V any"}, nil, "",
+				"type Store[K comparable, V any] struct"},
+			{"Put", "method", "public", "*Store[K, V]", nil,
+				[]symbols.Parameter{{Name: "key", Type: "K"}, {Name: "value", Type: "V"}}, "error",
+				"func (s *Store[K, V]) Put(key K, value V) error"},
+			{"newStore", "function", "private", "", nil,
+				[]symbols.Parameter{{Name: "a", Type: "int"}, {Name: "b", Type: "int"}, {Name: "opts", Type: "...string"}},
+				"(*Store[string, int], bool)", ""},
+		}},
+		{"java", "Repo.java", `public class Repo<T extends Entity> {
+    protected int size;
+
+    public Repo(int size) {
+        this.size = size;
+    }
+
+    <R> List<R> map(Function<T, R> fn, String... tags) {
+        return null;
+    }
+}
+`, []signature{
+			{"Repo", "class", "public", "", []string{"T extends Entity"}, nil, "",
+				"public class Repo<T extends Entity>"},
+			{"size", "field", "protected", "", nil, nil, "", ""},
+			{"Repo", "constructor", "public", "", nil, []symbols.Parameter{{Name: "size", Type: "int"}}, "", ""},
+			{"map", "method", "internal", "", []string{"R"}, // package-private
+				[]symbols.Parameter{{Name: "fn", Type: "Function<T, R>"}, {Name: "tags", Type: "String..."}},
+				"List<R>", ""},
+		}},
+		{"rust", "stack.rs", `pub struct Stack<T: Clone> {
+    items: Vec<T>,
+}
+
+impl<T: Clone> Stack<T> {
+    pub fn push(&mut self, item: T) -> usize {
+        self.items.push(item);
+        self.items.len()
+    }
+
+    pub(crate) fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+}
+
+fn helper(x: i32) {}
+`, []signature{
+			{"Stack", "struct", "public", "", []string{"T: Clone"}, nil, "", "pub struct Stack<T: Clone>"},
+			{"push", "method", "public", "&mut Stack<T>", nil, []symbols.Parameter{{Name: "item", Type: "T"}}, "usize", ""},
+			{"peek", "method", "internal", "&Stack<T>", nil, []symbols.Parameter{}, "Option<&T>", ""},
+			{"helper", "function", "private", "", nil, []symbols.Parameter{{Name: "x", Type: "i32"}}, "", "fn helper(x: i32)"},
+		}},
+		{"python", "repo.py", `class Repo(Base):
+    def find(self, key: str, *args, limit: int = 10, **kwargs) -> Optional[User]:
+        pass
+
+    def _cache(self):
+        pass
+`, []signature{
+			{"Repo", "class", "public", "", nil, nil, "", "class Repo(Base)"},
+			{"find", "method", "public", "", nil, []symbols.Parameter{
+				{Name: "self"}, {Name: "key", Type: "str"}, {Name: "*args"},
+				{Name: "limit", Type: "int"}, {Name: "**kwargs"},
+			}, "Optional[User]", "def find(self, key: str, *args, limit: int = 10, **kwargs) -> Optional[User]"},
+			{"_cache", "method", "private", "", nil, []symbols.Parameter{{Name: "self"}}, "", ""},
+		}},
+		{"typescript", "cache.ts", `export class Cache<K, V> {
+  get(key: K, fallback?: V): V | undefined {
+    return undefined;
+  }
+}
+
+function build(size: number): Cache<string, number> {
+  return new Cache();
+}
+
+export const load = async (path: string): Promise<void> => {};
+`, []signature{
+			{"Cache", "class", "public", "", []string{"K", "V"}, nil, "", "class Cache<K, V>"},
+			{"get", "method", "public", "", nil,
+				[]symbols.Parameter{{Name: "key", Type: "K"}, {Name: "fallback", Type: "V"}}, "V | undefined", ""},
+			{"build", "function", "internal", "", nil,
+				[]symbols.Parameter{{Name: "size", Type: "number"}}, "Cache<string, number>", ""},
+			{"load", "function", "public", "", nil,
+				[]symbols.Parameter{{Name: "path", Type: "string"}}, "Promise<void>", ""},
+		}},
+	}
+	for _, tt := range tests {
+		if err := VerifyLanguages([]string{tt.lang}); err != nil {
+			t.Skip("parser not available:", err)
+		}
+		extractor, err := NewExtractor(tt.lang)
+		if err != nil {
+			t.Fatal(err)
+		}
+		result, err := extractor.Extract(tt.file, []byte(tt.source))
+		extractor.Close()
+		if err != nil {
+			t.Fatal(err)
+		}
+		for _, w := range tt.want {
+			var sym *symbols.Symbol
+			for i := range result.Symbols {
+				if result.Symbols[i].Name == w.name && result.Symbols[i].Kind.String() == w.kind {
+					sym = &result.Symbols[i]
+					break
+				}
+			}
+			if sym == nil {
+				t.Errorf("%s: no %s %s", tt.lang, w.kind, w.name)
+				continue
+			}
+			if sym.Visibility != w.visibility || sym.Receiver != w.receiver || sym.ReturnType != w.returnType ||
+				!slices.Equal(sym.TypeParams, w.typeParams) || !slices.Equal(sym.Params, w.params) {
+				t.Errorf("%s: %s %s has visibility %q, receiver %q, type params %q, params %+v, return type %q; want %q, %q, %q, %+v, %q",
+					tt.lang, w.kind, w.name, sym.Visibility, sym.Receiver, sym.TypeParams, sym.Params, sym.ReturnType,
+					w.visibility, w.receiver, w.typeParams, w.params, w.returnType)
+			}
+			if w.signature != "" && sym.Signature != w.signature {
+				t.Errorf("%s: %s %s has signature %q, want %q", tt.lang, w.kind, w.name, sym.Signature, w.signature)
+			}
+		}
+	}
+}
@@ -0,0 +1,549 @@
+package treesitter
+
+import (
+	"strings"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// extractImports returns the import declarations of a file for the
+// languages whose references are resolved through imports.
+func extractImports(langName string, root Node, source []byte) []symbols.Import {
+	switch langName {
+	case "go":
+		return goImports(root, source)
+	case "rust":
+		var imports []symbols.Import
+		rustUseDeclarations(root, source, &imports)
+		return imports
+	case "java":
+		return javaImports(root, source)
+	case "python":
+		var imports []symbols.Import
+		pythonImports(root, source, &imports)
+		return imports
+	case "javascript", "typescript":
+		var imports []symbols.Import
+		jsImports(root, source, &imports)
+		return imports
+	}
+	return nil
+}
+
+// moduleExports returns the names a module declares as its public
+// interface (Python __all__), or nil when it declares none.
+func moduleExports(langName string, root Node, source []byte) []string {
+	if langName != "python" {
+		return nil
+	}
+	var exports []string
+	for i := uint32(0); i < root.NamedChildCount(); i++ {
+		stmt := root.NamedChild(i)
+		if stmt.Type() != "expression_statement" || stmt.NamedChildCount() == 0 {
+			continue
+		}
+		assign := stmt.NamedChild(0)
+		if assign.Type() != "assignment" && assign.Type() != "augmented_assignment" {
+			continue
+		}
+		left := assign.ChildByFieldName("left")
+		right := assign.ChildByFieldName("right")
+		if left.IsNull() || right.IsNull() || left.Content(source) != "__all__" {
+			continue
+		}
+		if assign.Type() == "assignment" || exports == nil {
+			exports = []string{}
+		}
+		for j := uint32(0); j < right.NamedChildCount(); j++ {
+			if item := right.NamedChild(j); item.Type() == "string" {
+				exports = append(exports, strings.Trim(item.Content(source), `"'`))
+			}
+		}
+	}
+	return exports
+}
+
+// filePackage returns the package a file declares, for the languages whose
+// symbols are qualified by it (Java and Kotlin).
+func filePackage(langName string, root Node, source []byte) string {
+	if langName != "java" && langName != "kotlin" {
+		return ""
+	}
+	for i := uint32(0); i < root.NamedChildCount(); i++ {
+		decl := root.NamedChild(i)
+		if decl.Type() != "package_declaration" && decl.Type() != "package_header" {
+			continue
+		}
+		for j := uint32(0); j < decl.NamedChildCount(); j++ {
+			switch name := decl.NamedChild(j); name.Type() {
+			case "identifier", "scoped_identifier", "qualified_identifier":
+				return javaNameText(name, source)
+			}
+		}
+	}
+	return ""
+}
+
+// javaImports collects single-type, on-demand (a.b.*) and static imports.
+// An on-demand import keeps the trailing .* in its path.
+func javaImports(root Node, source []byte) []symbols.Import {
+	var imports []symbols.Import
+	for i := uint32(0); i < root.NamedChildCount(); i++ {
+		decl := root.NamedChild(i)
+		if decl.Type() != "import_declaration" {
+			continue
+		}
+		imp := symbols.Import{Line: int(decl.StartPoint().Row) + 1}
+		wildcard := false
+		for j := uint32(0); j < decl.ChildCount(); j++ {
+			switch child := decl.Child(j); child.Type() {
+			case "static":
+				imp.Static = true
+			case "asterisk":
+				wildcard = true
+			case "identifier", "scoped_identifier":
+				imp.Path = javaNameText(child, source)
+			}
+		}
+		if imp.Path == "" {
+			continue
+		}
+		if wildcard {
+			imp.Path += ".*"
+		}
+		imports = append(imports, imp)
+	}
+	return imports
+}
+
+// javaNameText returns a dotted name as written, without whitespace.
+func javaNameText(node Node, source []byte) string {
+	return strings.Join(strings.Fields(node.Content(source)), "")
+}
+
+// pythonImports collects the import statements under node, including those
+// nested in functions or try blocks. import a.b as c yields Path a.b with
+// Alias c; from .x import y as z yields Path .x, Name y and Alias z; and
+// from m import * yields Name *.
+func pythonImports(node Node, source []byte, out *[]symbols.Import) {
+	for i := uint32(0); i < node.NamedChildCount(); i++ {
+		child := node.NamedChild(i)
+		line := int(child.StartPoint().Row) + 1
+		switch child.Type() {
+		case "import_statement":
+			for j := uint32(0); j < child.NamedChildCount(); j++ {
+				if imp, ok := pythonImportName(child.NamedChild(j), source); ok {
+					imp.Line = line
+					*out = append(*out, imp)
+				}
+			}
+		case "import_from_statement":
+			module := child.ChildByFieldName("module_name")
+			if module.IsNull() {
+				continue
+			}
+			path := pythonDottedText(module, source)
+			for j := uint32(0); j < child.NamedChildCount(); j++ {
+				name := child.NamedChild(j)
+				if name.StartByte() == module.StartByte() {
+					continue
+				}
+				if name.Type() == "wildcard_import" {
+					*out = append(*out, symbols.Import{Path: path, Name: "*", Line: line})
+					continue
+				}
+				if imp, ok := pythonImportName(name, source); ok {
+					imp.Path, imp.Name, imp.Line = path, imp.Path, line
+					*out = append(*out, imp)
+				}
+			}
+		default:
+			pythonImports(child, source, out)
+		}
+	}
+}
+
+// pythonImportName reads a dotted_name or aliased_import; its dotted name
+// is returned in Path.
+func pythonImportName(node Node, source []byte) (symbols.Import, bool) {
+	switch node.Type() {
+	case "dotted_name":
+		return symbols.Import{Path: pythonDottedText(node, source)}, true
+	case "aliased_import":
+		name := node.ChildByFieldName("name")
+		alias := node.ChildByFieldName("alias")
+		if !name.IsNull() && !alias.IsNull() {
+			return symbols.Import{Path: pythonDottedText(name, source), Alias: alias.Content(source)}, true
+		}
+	}
+	return symbols.Import{}, false
+}
+
+// pythonDottedText returns a dotted or relative module name as written,
+// without whitespace.
+func pythonDottedText(node Node, source []byte) string {
+	return strings.Join(strings.Fields(node.Content(source)), "")
+}
+
+// isPythonDotted reports whether node is a name or a chain of attribute
+// accesses on one (a.b.c).
+func isPythonDotted(node Node) bool {
+	switch node.Type() {
+	case "identifier":
+		return true
+	case "attribute":
+		attr := node.ChildByFieldName("attribute")
+		return !attr.IsNull() && attr.Type() == "identifier" && isPythonDotted(node.ChildByFieldName("object"))
+	}
+	return false
+}
+
+// jsImports collects the ES module imports and re-exports and the
+// CommonJS require calls under node, one Import per bound name:
+//
+//	import x from './a'               Name default, Alias x
+//	import {a, b as c} from './a'     Name a; Name b, Alias c
+//	import * as ns from './a'         Name *, Alias ns
+//	const ns = require('./a')         Name *, Alias ns
+//	const {a, b: c} = require('./a')  Name a; Name b, Alias c
+//	export {a as b} from './a'        Name a, Alias b, Reexport
+//	export * from './a'               Name *, Reexport
+func jsImports(node Node, source []byte, out *[]symbols.Import) {
+	for i := uint32(0); i < node.NamedChildCount(); i++ {
+		child := node.NamedChild(i)
+		line := int(child.StartPoint().Row) + 1
+		switch child.Type() {
+		case "import_statement":
+			path := jsStringText(child.ChildByFieldName("source"), source)
+			for j := uint32(0); j < child.NamedChildCount(); j++ {
+				switch clause := child.NamedChild(j); clause.Type() {
+				case "import_clause":
+					if path != "" {
+						jsImportClause(clause, path, line, source, out)
+					}
+				case "import_require_clause":
+					// TypeScript: import ns = require('./a')
+					id, req := clause.NamedChild(0), clause.NamedChild(1)
+					if !id.IsNull() && id.Type() == "identifier" && !req.IsNull() && req.Type() == "string" {
+						*out = append(*out, symbols.Import{Path: jsStringText(req, source), Name: "*", Alias: id.Content(source), Line: line})
+					}
+				}
+			}
+		case "export_statement":
+			path := jsStringText(child.ChildByFieldName("source"), source)
+			if path == "" {
+				jsImports(child, source, out) // export const x = require(...)
+				continue
+			}
+			star := true
+			for j := uint32(0); j < child.NamedChildCount(); j++ {
+				switch clause := child.NamedChild(j); clause.Type() {
+				case "export_clause":
+					star = false
+					for k := uint32(0); k < clause.NamedChildCount(); k++ {
+						spec := clause.NamedChild(k)
+						if spec.Type() != "export_specifier" {
+							continue
+						}
+						imp := jsSpecifier(spec, source)
+						imp.Path, imp.Reexport, imp.Line = path, true, line
+						*out = append(*out, imp)
+					}
+				case "namespace_export":
+					star = false
+					if id := clause.NamedChild(0); !id.IsNull() {
+						*out = append(*out, symbols.Import{Path: path, Name: "*", Alias: id.Content(source), Reexport: true, Line: line})
+					}
+				}
+			}
+			if star {
+				*out = append(*out, symbols.Import{Path: path, Name: "*", Reexport: true, Line: line})
+			}
+		case "variable_declarator":
+			jsRequire(child, line, source, out)
+			jsImports(child, source, out)
+		default:
+			jsImports(child, source, out)
+		}
+	}
+}
+
+// jsImportClause reads the bindings of import x, {a as b}, * as ns.
+func jsImportClause(clause Node, path string, line int, source []byte, out *[]symbols.Import) {
+	for i := uint32(0); i < clause.NamedChildCount(); i++ {
+		switch part := clause.NamedChild(i); part.Type() {
+		case "identifier":
+			*out = append(*out, symbols.Import{Path: path, Name: "default", Alias: part.Content(source), Line: line})
+		case "namespace_import":
+			if id := part.NamedChild(0); !id.IsNull() {
+				*out = append(*out, symbols.Import{Path: path, Name: "*", Alias: id.Content(source), Line: line})
+			}
+		case "named_imports":
+			for j := uint32(0); j < part.NamedChildCount(); j++ {
+				if spec := part.NamedChild(j); spec.Type() == "import_specifier" {
+					imp := jsSpecifier(spec, source)
+					imp.Path, imp.Line = path, line
+					*out = append(*out, imp)
+				}
+			}
+		}
+	}
+}
+
+// jsSpecifier reads an import or export specifier (a, or a as b).
+func jsSpecifier(spec Node, source []byte) symbols.Import {
+	imp := symbols.Import{Name: jsStringText(spec.ChildByFieldName("name"), source)}
+	if alias := spec.ChildByFieldName("alias"); !alias.IsNull() {
+		imp.Alias = jsStringText(alias, source)
+	}
+	return imp
+}
+
+// jsRequire records const ns = require('./a') and const {a, b: c} =
+// require('./a').
+func jsRequire(decl Node, line int, source []byte, out *[]symbols.Import) {
+	value := decl.ChildByFieldName("value")
+	name := decl.ChildByFieldName("name")
+	if value.IsNull() || name.IsNull() || value.Type() != "call_expression" {
+		return
+	}
+	fn := value.ChildByFieldName("function")
+	args := value.ChildByFieldName("arguments")
+	if fn.IsNull() || fn.Content(source) != "require" || args.IsNull() || args.NamedChildCount() != 1 {
+		return
+	}
+	path := jsStringText(args.NamedChild(0), source)
+	if path == "" {
+		return
+	}
+	switch name.Type() {
+	case "identifier":
+		*out = append(*out, symbols.Import{Path: path, Name: "*", Alias: name.Content(source), Line: line})
+	case "object_pattern":
+		for i := uint32(0); i < name.NamedChildCount(); i++ {
+			switch prop := name.NamedChild(i); prop.Type() {
+			case "shorthand_property_identifier_pattern":
+				*out = append(*out, symbols.Import{Path: path, Name: prop.Content(source), Line: line})
+			case "pair_pattern":
+				key := prop.ChildByFieldName("key")
+				val := prop.ChildByFieldName("value")
+				if !key.IsNull() && !val.IsNull() && val.Type() == "identifier" {
+					*out = append(*out, symbols.Import{Path: path, Name: key.Content(source), Alias: val.Content(source), Line: line})
+				}
+			}
+		}
+	}
+}
+
+// jsStringText returns the text of a string literal without its quotes, or
+// the text of any other node; "" for a null node.
+func jsStringText(node Node, source []byte) string {
+	if node.IsNull() {
+		return ""
+	}
+	text := node.Content(source)
+	if node.Type() == "string" {
+		text = strings.Trim(text, "'\"`")
+	}
+	return text
+}
+
+// goImports collects the import_spec nodes of a Go file, both single
+// imports and grouped import blocks.
+func goImports(root Node, source []byte) []symbols.Import {
+	var imports []symbols.Import
+	for i := uint32(0); i < root.NamedChildCount(); i++ {
+		decl := root.NamedChild(i)
+		if decl.Type() != "import_declaration" {
+			continue
+		}
+		for j := uint32(0); j < decl.NamedChildCount(); j++ {
+			child := decl.NamedChild(j)
+			switch child.Type() {
+			case "import_spec":
+				imports = append(imports, goImportSpec(child, source))
+			case "import_spec_list":
+				for k := uint32(0); k < child.NamedChildCount(); k++ {
+					if spec := child.NamedChild(k); spec.Type() == "import_spec" {
+						imports = append(imports, goImportSpec(spec, source))
+					}
+				}
+			}
+		}
+	}
+	return imports
+}
+
+func goImportSpec(spec Node, source []byte) symbols.Import {
+	imp := symbols.Import{Line: int(spec.StartPoint().Row) + 1}
+	if path := spec.ChildByFieldName("path"); !path.IsNull() {
+		imp.Path = strings.Trim(path.Content(source), "\"`")
+	}
+	if name := spec.ChildByFieldName("name"); !name.IsNull() {
+		imp.Alias = name.Content(source)
+	}
+	return imp
+}
+
+// rustUseDeclarations flattens every use declaration under node into one
+// Import per imported name: use a::{b, c as d, e::*} yields a::b, a::c
+// (alias d) and the glob a::e::*. A self item imports its prefix.
+func rustUseDeclarations(node Node, source []byte, out *[]symbols.Import) {
+	for i := uint32(0); i < node.NamedChildCount(); i++ {
+		child := node.NamedChild(i)
+		if child.Type() != "use_declaration" {
+			rustUseDeclarations(child, source, out)
+			continue
+		}
+		if arg := child.ChildByFieldName("argument"); !arg.IsNull() {
+			rustUseClause(arg, "", int(child.StartPoint().Row)+1, source, out)
+		}
+	}
+}
+
+func rustUseClause(node Node, prefix string, line int, source []byte, out *[]symbols.Import) {
+	switch node.Type() {
+	case "identifier", "crate", "super", "scoped_identifier":
+		*out = append(*out, symbols.Import{Path: rustJoinPath(prefix, rustPathText(node, source)), Line: line})
+	case "self":
+		if prefix != "" {
+			*out = append(*out, symbols.Import{Path: prefix, Line: line})
+		}
+	case "use_as_clause":
+		path := node.ChildByFieldName("path")
+		alias := node.ChildByFieldName("alias")
+		if path.IsNull() || alias.IsNull() {
+			return
+		}
+		imp := symbols.Import{Path: rustJoinPath(prefix, rustPathText(path, source)), Alias: alias.Content(source), Line: line}
+		if path.Type() == "self" {
+			imp.Path = prefix
+		}
+		*out = append(*out, imp)
+	case "use_wildcard":
+		path := prefix
+		if node.NamedChildCount() > 0 {
+			path = rustJoinPath(prefix, rustPathText(node.NamedChild(0), source))
+		}
+		*out = append(*out, symbols.Import{Path: rustJoinPath(path, "*"), Line: line})
+	case "scoped_use_list":
+		if path := node.ChildByFieldName("path"); !path.IsNull() {
+			prefix = rustJoinPath(prefix, rustPathText(path, source))
+		}
+		if list := node.ChildByFieldName("list"); !list.IsNull() {
+			rustUseClause(list, prefix, line, source, out)
+		}
+	case "use_list":
+		for i := uint32(0); i < node.NamedChildCount(); i++ {
+			rustUseClause(node.NamedChild(i), prefix, line, source, out)
+		}
+	}
+}
+
+// rustPathText returns a path as written, without whitespace or a leading
+// :: (use ::std::fmt).
+func rustPathText(node Node, source []byte) string {
+	return strings.TrimPrefix(strings.Join(strings.Fields(node.Content(source)), ""), "::")
+}
+
+func rustJoinPath(prefix, path string) string {
+	if prefix == "" {
+		return path
+	}
+	return prefix + "::" + path
+}
+
+// refQualifier returns the qualifier written before a referenced name, such
+// as pkg in pkg.Func or pkg.Type, or the path a::b in a::b::name, or "" when
+// the name is not qualified by a plain identifier or path.
+func refQualifier(langName string, node Node, source []byte) string {
+	parent := node.Parent()
+	if parent.IsNull() {
+		return ""
+	}
+	switch langName {
+	case "go":
+		switch parent.Type() {
+		case "selector_expression":
+			field := parent.ChildByFieldName("field")
+			operand := parent.ChildByFieldName("operand")
+			if !field.IsNull() && field.StartByte() == node.StartByte() &&
+				!operand.IsNull() && operand.Type() == "identifier" {
+				return operand.Content(source)
+			}
+		case "qualified_type":
+			name := parent.ChildByFieldName("name")
+			pkg := parent.ChildByFieldName("package")
+			if !name.IsNull() && name.StartByte() == node.StartByte() && !pkg.IsNull() {
+				return pkg.Content(source)
+			}
+		}
+	case "java":
+		switch parent.Type() {
+		case "method_invocation", "field_access":
+			field := "name"
+			if parent.Type() == "field_access" {
+				field = "field"
+			}
+			name := parent.ChildByFieldName(field)
+			object := parent.ChildByFieldName("object")
+			if !name.IsNull() && name.StartByte() == node.StartByte() &&
+				!object.IsNull() && object.Type() == "identifier" {
+				return object.Content(source)
+			}
+		case "scoped_identifier":
+			name := parent.ChildByFieldName("name")
+			scope := parent.ChildByFieldName("scope")
+			if !name.IsNull() && name.StartByte() == node.StartByte() && !scope.IsNull() {
+				return javaNameText(scope, source)
+			}
+		case "scoped_type_identifier":
+			// Map.Entry: the type identifiers are children without field names
+			count := parent.NamedChildCount()
+			if count > 1 && parent.NamedChild(count-1).StartByte() == node.StartByte() {
+				prefix := string(source[parent.StartByte():node.StartByte()])
+				return strings.TrimSuffix(strings.Join(strings.Fields(prefix), ""), ".")
+			}
+		}
+	case "python":
+		if parent.Type() == "attribute" {
+			attr := parent.ChildByFieldName("attribute")
+			object := parent.ChildByFieldName("object")
+			if !attr.IsNull() && attr.StartByte() == node.StartByte() && !object.IsNull() && isPythonDotted(object) {
+				return pythonDottedText(object, source)
+			}
+		}
+	case "javascript", "typescript":
+		switch parent.Type() {
+		case "member_expression":
+			property := parent.ChildByFieldName("property")
+			object := parent.ChildByFieldName("object")
+			if !property.IsNull() && property.StartByte() == node.StartByte() &&
+				!object.IsNull() && object.Type() == "identifier" {
+				return object.Content(source)
+			}
+		case "nested_type_identifier":
+			// ns.Type in a TypeScript type position
+			name := parent.ChildByFieldName("name")
+			module := parent.ChildByFieldName("module")
+			if !name.IsNull() && name.StartByte() == node.StartByte() &&
+				!module.IsNull() && module.Type() == "identifier" {
+				return module.Content(source)
+			}
+		}
+	case "rust":
+		switch parent.Type() {
+		case "scoped_identifier", "scoped_type_identifier":
+			name := parent.ChildByFieldName("name")
+			path := parent.ChildByFieldName("path")
+			if name.IsNull() || name.StartByte() != node.StartByte() || path.IsNull() {
+				return ""
+			}
+			switch path.Type() {
+			case "identifier", "scoped_identifier", "crate", "self", "super":
+				return rustPathText(path, source)
+			}
+		}
+	}
+	return ""
+}
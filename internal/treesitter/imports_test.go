@@ -0,0 +1,246 @@
+package treesitter
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+func TestExtractRustUseDeclarations(t *testing.T) {
+	if err := VerifyLanguages([]string{"rust"}); err != nil {
+		t.Skip("Rust parser not available:", err)
+	}
+
+	extractor, err := NewExtractor("rust")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer extractor.Close()
+
+	source := []byte(`use std::io::{self, Read as R};
+use crate::http::{handlers::*, Config};
+use super::router;
+pub use ::shop_settings::Config as Settings;
+
+mod inner {
+    use self::deep::Item;
+}
+
+fn main() {
+    let c = http::Config::new(8080);
+}
+`)
+	result, err := extractor.Extract("main.rs", source)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	want := []symbols.Import{
+		{Path: "std::io", Line: 1},
+		{Path: "std::io::Read", Alias: "R", Line: 1},
+		{Path: "crate::http::handlers::*", Line: 2},
+		{Path: "crate::http::Config", Line: 2},
+		{Path: "super::router", Line: 3},
+		{Path: "shop_settings::Config", Alias: "Settings", Line: 4},
+		{Path: "self::deep::Item", Line: 7},
+	}
+	if !reflect.DeepEqual(result.Imports, want) {
+		t.Errorf("imports:\n got %+v\nwant %+v", result.Imports, want)
+	}
+
+	qualifiers := map[string]string{}
+	for _, ref := range result.Refs {
+		if ref.StartLine == 11 {
+			qualifiers[ref.Name] = ref.Qualifier
+		}
+	}
+	if qualifiers["new"] != "http::Config" {
+		t.Errorf("qualifier of new = %q, want http::Config", qualifiers["new"])
+	}
+	if qualifiers["Config"] != "http" {
+		t.Errorf("qualifier of Config = %q, want http", qualifiers["Config"])
+	}
+}
+
+func TestExtractJavaPackageAndImports(t *testing.T) {
+	if err := VerifyLanguages([]string{"java"}); err != nil {
+		t.Skip("Java parser not available:", err)
+	}
+
+	extractor, err := NewExtractor("java")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer extractor.Close()
+
+	source := []byte(`package com.shop.orders;
+
+import com.shop.http.Request;
+import java.util.*;
+import static java.lang.Math.max;
+import static com.shop.Limits.*;
+
+public class Order {
+    public Order() {
+    }
+
+    static class Builder {
+        Order build() {
+            Map.Entry<String, Integer> e = null;
+            return new Order();
+        }
+    }
+}
+`)
+	result, err := extractor.Extract("Order.java", source)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if result.Package != "com.shop.orders" {
+		t.Errorf("package = %q, want com.shop.orders", result.Package)
+	}
+	want := []symbols.Import{
+		{Path: "com.shop.http.Request", Line: 3},
+		{Path: "java.util.*", Line: 4},
+		{Path: "java.lang.Math.max", Static: true, Line: 5},
+		{Path: "com.shop.Limits.*", Static: true, Line: 6},
+	}
+	if !reflect.DeepEqual(result.Imports, want) {
+		t.Errorf("imports:\n got %+v\nwant %+v", result.Imports, want)
+	}
+
+	qualified := map[string]string{}
+	for _, sym := range result.Symbols {
+		qualified[sym.Name+"/"+sym.Kind.String()] = sym.QualifiedName
+	}
+	for key, fqn := range map[string]string{
+		"Order/class":       "com.shop.orders.Order",
+		"Order/constructor": "com.shop.orders.Order",
+		"Builder/class":     "com.shop.orders.Order.Builder",
+		"build/method":      "com.shop.orders.Order.Builder.build",
+	} {
+		if qualified[key] != fqn {
+			t.Errorf("qualified name of %s = %q, want %q", key, qualified[key], fqn)
+		}
+	}
+
+	for _, ref := range result.Refs {
+		if ref.Name == "Entry" && ref.Qualifier != "Map" {
+			t.Errorf("qualifier of Entry = %q, want Map", ref.Qualifier)
+		}
+		if ref.Name == "Request" && ref.StartLine == 3 && ref.Qualifier != "com.shop.http" {
+			t.Errorf("qualifier of imported Request = %q, want com.shop.http", ref.Qualifier)
+		}
+	}
+}
+
+func TestExtractPythonImports(t *testing.T) {
+	if err := VerifyLanguages([]string{"python"}); err != nil {
+		t.Skip("Python parser not available:", err)
+	}
+
+	extractor, err := NewExtractor("python")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer extractor.Close()
+
+	source := []byte(`import os, shop.catalog as cat
+from . import helpers
+from ..cart import Cart as C, total
+from shop.util import *
+
+__all__ = ["C", "total"]
+
+try:
+    import ujson as json
+except ImportError:
+    import json
+
+cat.render(os.path.join("a", "b"))
+`)
+	result, err := extractor.Extract("main.py", source)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	want := []symbols.Import{
+		{Path: "os", Line: 1},
+		{Path: "shop.catalog", Alias: "cat", Line: 1},
+		{Path: ".", Name: "helpers", Line: 2},
+		{Path: "..cart", Name: "Cart", Alias: "C", Line: 3},
+		{Path: "..cart", Name: "total", Line: 3},
+		{Path: "shop.util", Name: "*", Line: 4},
+		{Path: "ujson", Alias: "json", Line: 9},
+		{Path: "json", Line: 11},
+	}
+	if !reflect.DeepEqual(result.Imports, want) {
+		t.Errorf("imports:\n got %+v\nwant %+v", result.Imports, want)
+	}
+	if !reflect.DeepEqual(result.Exports, []string{"C", "total"}) {
+		t.Errorf("exports = %q, want [C total]", result.Exports)
+	}
+
+	qualifiers := map[string]string{}
+	for _, ref := range result.Refs {
+		if ref.StartLine == 13 {
+			qualifiers[ref.Name] = ref.Qualifier
+		}
+	}
+	if qualifiers["render"] != "cat" || qualifiers["join"] != "os.path" {
+		t.Errorf("qualifiers = %v, want render: cat, join: os.path", qualifiers)
+	}
+}
+
+func TestExtractJSImports(t *testing.T) {
+	if err := VerifyLanguages([]string{"typescript"}); err != nil {
+		t.Skip("TypeScript parser not available:", err)
+	}
+
+	extractor, err := NewExtractor("typescript")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer extractor.Close()
+
+	source := []byte(`import def, { a, b as c } from "./a";
+import * as ns from "./ns";
+import legacy = require("./legacy");
+export { x as y } from "./x";
+export * from "./all";
+export * as more from "./more";
+const lib = require("./lib");
+const { p, q: r } = require("./lib");
+
+ns.render(lib);
+`)
+	result, err := extractor.Extract("main.ts", source)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	want := []symbols.Import{
+		{Path: "./a", Name: "default", Alias: "def", Line: 1},
+		{Path: "./a", Name: "a", Line: 1},
+		{Path: "./a", Name: "b", Alias: "c", Line: 1},
+		{Path: "./ns", Name: "*", Alias: "ns", Line: 2},
+		{Path: "./legacy", Name: "*", Alias: "legacy", Line: 3},
+		{Path: "./x", Name: "x", Alias: "y", Reexport: true, Line: 4},
+		{Path: "./all", Name: "*", Reexport: true, Line: 5},
+		{Path: "./more", Name: "*", Alias: "more", Reexport: true, Line: 6},
+		{Path: "./lib", Name: "*", Alias: "lib", Line: 7},
+		{Path: "./lib", Name: "p", Line: 8},
+		{Path: "./lib", Name: "q", Alias: "r", Line: 8},
+	}
+	if !reflect.DeepEqual(result.Imports, want) {
+		t.Errorf("imports:\n got %+v\nwant %+v", result.Imports, want)
+	}
+
+	for _, ref := range result.Refs {
+		if ref.StartLine == 10 && ref.Name == "render" && ref.Qualifier != "ns" {
+			t.Errorf("render qualifier = %q, want ns", ref.Qualifier)
+		}
+	}
+}
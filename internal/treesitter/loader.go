@@ -5,11 +5,17 @@ import (
 	"sync"
 	"unsafe"
 
+	tree_sitter_kotlin "github.com/tree-sitter-grammars/tree-sitter-kotlin/bindings/go"
 	tree_sitter "github.com/tree-sitter/go-tree-sitter"
+	tree_sitter_c_sharp "github.com/tree-sitter/tree-sitter-c-sharp/bindings/go"
+	tree_sitter_c "github.com/tree-sitter/tree-sitter-c/bindings/go"
+	tree_sitter_cpp "github.com/tree-sitter/tree-sitter-cpp/bindings/go"
 	tree_sitter_go "github.com/tree-sitter/tree-sitter-go/bindings/go"
 	tree_sitter_java "github.com/tree-sitter/tree-sitter-java/bindings/go"
 	tree_sitter_javascript "github.com/tree-sitter/tree-sitter-javascript/bindings/go"
+	tree_sitter_php "github.com/tree-sitter/tree-sitter-php/bindings/go"
 	tree_sitter_python "github.com/tree-sitter/tree-sitter-python/bindings/go"
+	tree_sitter_ruby "github.com/tree-sitter/tree-sitter-ruby/bindings/go"
 	tree_sitter_rust "github.com/tree-sitter/tree-sitter-rust/bindings/go"
 	tree_sitter_typescript "github.com/tree-sitter/tree-sitter-typescript/bindings/go"
 )
@@ -34,6 +40,12 @@ var languageMap = map[string]func() unsafe.Pointer{
 	"javascript": tree_sitter_javascript.Language,
 	"typescript": tree_sitter_typescript.LanguageTypescript,
 	"tsx":        tree_sitter_typescript.LanguageTSX,
+	"c":          tree_sitter_c.Language,
+	"cpp":        tree_sitter_cpp.Language,
+	"csharp":     tree_sitter_c_sharp.Language,
+	"kotlin":     tree_sitter_kotlin.Language,
+	"ruby":       tree_sitter_ruby.Language,
+	"php":        tree_sitter_php.LanguagePHP,
 }
 
 // LoadLanguage loads a tree-sitter language by name.
@@ -116,5 +128,5 @@ func VerifyLanguages(requiredLangs []string) error {
 
 // RequiredLanguages returns the list of language identifiers required by MesDX.
 func RequiredLanguages() []string {
-	return []string{"go", "java", "rust", "python", "javascript", "typescript"}
+	return []string{"go", "java", "rust", "python", "javascript", "typescript", "c", "cpp", "csharp", "kotlin", "ruby", "php"}
 }
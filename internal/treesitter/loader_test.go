@@ -42,7 +42,7 @@ func TestVerifyLanguages(t *testing.T) {
 
 func TestRequiredLanguages(t *testing.T) {
 	langs := RequiredLanguages()
-	expected := []string{"go", "java", "rust", "python", "javascript", "typescript"}
+	expected := []string{"go", "java", "rust", "python", "javascript", "typescript", "c", "cpp", "csharp", "kotlin", "ruby", "php"}
 	
 	if len(langs) != len(expected) {
 		t.Errorf("RequiredLanguages() returned %d languages, want %d", len(langs), len(expected))
@@ -0,0 +1,379 @@
+package treesitter
+
+import (
+	"cmp"
+	_ "embed"
+	"fmt"
+	"slices"
+)
+
+//go:embed queries/locals/go.scm
+var goLocalsQuery string
+
+//go:embed queries/locals/java.scm
+var javaLocalsQuery string
+
+//go:embed queries/locals/rust.scm
+var rustLocalsQuery string
+
+//go:embed queries/locals/python.scm
+var pythonLocalsQuery string
+
+//go:embed queries/locals/typescript.scm
+var typescriptLocalsQuery string
+
+//go:embed queries/locals/javascript.scm
+var javascriptLocalsQuery string
+
+// embeddedLocalsQuery returns the scope query compiled into the binary for
+// langName, or "" when locals are not tracked for the language. Like
+// tree-sitter's locals.scm, it captures @local.scope nodes (functions,
+// blocks, closures), the @local.definition of each parameter and local
+// variable, and the @local.reference identifiers that may name one.
+// Python's global and nonlocal declarations are captured as @local.global
+// and @local.nonlocal.
+func embeddedLocalsQuery(langName string) string {
+	switch langName {
+	case "go":
+		return goLocalsQuery
+	case "java":
+		return javaLocalsQuery
+	case "rust":
+		return rustLocalsQuery
+	case "python":
+		return pythonLocalsQuery
+	case "typescript":
+		return typescriptLocalsQuery
+	case "javascript":
+		return javascriptLocalsQuery
+	default:
+		return ""
+	}
+}
+
+// localScope is a function, block or closure found by a locals query.
+type localScope struct {
+	start, end uint32 // byte range
+	parent     int    // index of the enclosing scope, or -1
+	defs       map[string][]localDef
+	globals    map[string]bool // Python: declared global in this scope
+	nonlocals  map[string]bool // Python: declared nonlocal in this scope
+}
+
+// localDef is the declaration of a local variable or parameter.
+type localDef struct {
+	node    Node // the declared name
+	pos     Point
+	start   uint32 // byte offset of the name
+	visible uint32 // byte offset from which the name is in scope
+}
+
+// localBindings is the outcome of resolving the locals of a file.
+type localBindings struct {
+	// refs maps the "name:row:col" of each reference to or declaration of
+	// a local to the position of the declaration it binds.
+	refs map[string]Point
+	// decls maps a binding position to the names declaring the variable:
+	// the binding itself and any later redeclaration or reassignment that
+	// binds to it.
+	decls map[Point][]Node
+}
+
+// localScopes is the scope tree of a file, sorted by start offset with
+// enclosing scopes before the scopes they contain.
+type localScopes struct {
+	scopes []localScope
+	// hoisted is set for languages in which a local is visible throughout
+	// its scope rather than from its declaration on (Python).
+	hoisted bool
+	// shadowing is set for languages in which declaring a name again in
+	// the same scope introduces a new variable (Rust let) rather than
+	// reusing the first one (Go :=).
+	shadowing bool
+}
+
+// declarationEnds lists the declarations whose initializer is evaluated
+// before the names they declare come into scope, so that x := x + 1 reads
+// the outer x.
+var declarationEnds = map[string]bool{
+	"short_var_declaration": true,
+	"var_spec":              true,
+	"const_spec":            true,
+	"let_declaration":       true,
+	"variable_declarator":   true,
+}
+
+// patternWrappers lists the nodes between a declared name and its
+// declaration (a, b := ..., let (a, b) = ..., const [a, b] = ...).
+var patternWrappers = map[string]bool{
+	"expression_list": true,
+	"pattern_list":    true,
+	"mut_pattern":     true,
+	"tuple_pattern":   true,
+	"array_pattern":   true,
+	"object_pattern":  true,
+}
+
+// signatureNodes lists the function types and bodiless signatures whose
+// parameters declare nothing in the enclosing scope (cb func(ctx Context)).
+var signatureNodes = map[string]bool{
+	"function_type":       true,
+	"method_elem":         true,
+	"method_spec":         true,
+	"call_signature":      true,
+	"method_signature":    true,
+	"construct_signature": true,
+}
+
+// memberFields maps member accesses to the field holding the member's
+// name, which never refers to a local (obj.name, name(), Type::name,
+// f(name=value)).
+var memberFields = map[string]string{
+	"field_access":      "field",
+	"method_invocation": "name",
+	"attribute":         "attribute",
+	"keyword_argument":  "name",
+	"scoped_identifier": "name",
+}
+
+// resolveLocals runs the extractor's locals query over root and returns
+// the declaration each reference to a local variable or parameter resolves
+// to. A declaration maps to itself, or to the first declaration of the same
+// variable when it redeclares one (err in a second := statement, a
+// reassigned Python name).
+func (e *Extractor) resolveLocals(root Node, source []byte) localBindings {
+	if e.locals == nil {
+		return localBindings{}
+	}
+	cursor := NewQueryCursor()
+	defer cursor.Close()
+	cursor.ExecWithText(e.locals, root, source)
+
+	captureNames := make([]string, e.locals.CaptureCount())
+	for i := range captureNames {
+		captureNames[i] = e.locals.CaptureNameForID(uint32(i))
+	}
+
+	var scopeNodes, defNodes, refNodes, globalNodes, nonlocalNodes []Node
+	for {
+		match := cursor.NextMatch()
+		if match == nil {
+			break
+		}
+		for _, c := range match.Captures {
+			switch captureNames[c.Index] {
+			case "local.scope":
+				scopeNodes = append(scopeNodes, c.Node)
+			case "local.definition":
+				defNodes = append(defNodes, c.Node)
+			case "local.reference":
+				refNodes = append(refNodes, c.Node)
+			case "local.global":
+				globalNodes = append(globalNodes, c.Node)
+			case "local.nonlocal":
+				nonlocalNodes = append(nonlocalNodes, c.Node)
+			}
+		}
+	}
+
+	ls := newLocalScopes(scopeNodes)
+	ls.hoisted = e.langName == "python"
+	ls.shadowing = e.langName == "rust"
+	for _, n := range globalNodes {
+		if s := ls.innermost(n.StartByte()); s >= 0 {
+			ls.scopes[s].globals[n.Content(source)] = true
+		}
+	}
+	for _, n := range nonlocalNodes {
+		if s := ls.innermost(n.StartByte()); s >= 0 {
+			ls.scopes[s].nonlocals[n.Content(source)] = true
+		}
+	}
+
+	isDef := make(map[uint32]bool, len(defNodes))
+	for _, n := range defNodes {
+		name := n.Content(source)
+		s := ls.innermost(n.StartByte())
+		if name == "" || s < 0 || isSignatureParameter(n) {
+			continue
+		}
+		isDef[n.StartByte()] = true
+		ls.scopes[s].defs[name] = append(ls.scopes[s].defs[name], localDef{
+			node:    n,
+			pos:     n.StartPoint(),
+			start:   n.StartByte(),
+			visible: visibleFrom(n),
+		})
+	}
+
+	for _, scope := range ls.scopes {
+		for _, defs := range scope.defs {
+			slices.SortFunc(defs, func(a, b localDef) int { return cmp.Compare(a.start, b.start) })
+		}
+	}
+	bindings := localBindings{refs: map[string]Point{}, decls: map[Point][]Node{}}
+	for s := range ls.scopes {
+		for name, defs := range ls.scopes[s].defs {
+			for _, d := range defs {
+				if b, ok := ls.declarationBinding(name, d, s); ok {
+					bindings.refs[localKey(name, d.pos)] = b.pos
+					bindings.decls[b.pos] = append(bindings.decls[b.pos], d.node)
+				}
+			}
+		}
+	}
+	for _, n := range refNodes {
+		if isDef[n.StartByte()] || isMemberName(n) {
+			continue
+		}
+		name := n.Content(source)
+		if b, ok := ls.lookup(name, n.StartByte(), ls.innermost(n.StartByte())); ok {
+			bindings.refs[localKey(name, n.StartPoint())] = b.pos
+		}
+	}
+	return bindings
+}
+
+// localKey returns the key of a name at a position in localBindings.refs,
+// which is also the key of the extractor's refs.
+func localKey(name string, p Point) string {
+	return fmt.Sprintf("%s:%d:%d", name, p.Row, p.Column)
+}
+
+// newLocalScopes builds the scope tree of the given scope nodes.
+func newLocalScopes(nodes []Node) *localScopes {
+	slices.SortFunc(nodes, func(a, b Node) int {
+		if c := cmp.Compare(a.StartByte(), b.StartByte()); c != 0 {
+			return c
+		}
+		return cmp.Compare(b.EndByte(), a.EndByte())
+	})
+	ls := &localScopes{}
+	var stack []int
+	for _, n := range nodes {
+		start, end := n.StartByte(), n.EndByte()
+		if last := len(ls.scopes) - 1; last >= 0 && ls.scopes[last].start == start && ls.scopes[last].end == end {
+			continue // captured by several patterns
+		}
+		for len(stack) > 0 && ls.scopes[stack[len(stack)-1]].end <= start {
+			stack = stack[:len(stack)-1]
+		}
+		parent := -1
+		if len(stack) > 0 {
+			parent = stack[len(stack)-1]
+		}
+		stack = append(stack, len(ls.scopes))
+		ls.scopes = append(ls.scopes, localScope{
+			start:     start,
+			end:       end,
+			parent:    parent,
+			defs:      map[string][]localDef{},
+			globals:   map[string]bool{},
+			nonlocals: map[string]bool{},
+		})
+	}
+	return ls
+}
+
+// innermost returns the index of the innermost scope containing offset, or
+// -1 at file level.
+func (ls *localScopes) innermost(offset uint32) int {
+	best := -1
+	for i, s := range ls.scopes {
+		if s.start > offset {
+			break
+		}
+		if offset < s.end {
+			best = i
+		}
+	}
+	return best
+}
+
+// lookup returns the declaration a reference to name at offset resolves to,
+// searching scope s and then the scopes enclosing it.
+func (ls *localScopes) lookup(name string, offset uint32, s int) (localDef, bool) {
+	for ; s >= 0; s = ls.scopes[s].parent {
+		scope := &ls.scopes[s]
+		if scope.globals[name] {
+			return localDef{}, false
+		}
+		if scope.nonlocals[name] {
+			continue
+		}
+		var best localDef
+		found := false
+		for _, d := range scope.defs[name] { // sorted by start
+			if !ls.hoisted && d.visible > offset {
+				continue
+			}
+			if !found || ls.shadowing {
+				best, found = d, true
+			}
+		}
+		if found {
+			return best, true
+		}
+	}
+	return localDef{}, false
+}
+
+// declarationBinding returns the declaration that d, declared in scope s,
+// binds: d itself, the first declaration of the name in s when the
+// language reuses it, or the enclosing one for a Python nonlocal name.
+func (ls *localScopes) declarationBinding(name string, d localDef, s int) (localDef, bool) {
+	scope := &ls.scopes[s]
+	switch {
+	case scope.globals[name]:
+		return localDef{}, false
+	case scope.nonlocals[name]:
+		return ls.lookup(name, d.start, scope.parent)
+	case ls.shadowing:
+		return d, true
+	}
+	return scope.defs[name][0], true
+}
+
+// visibleFrom returns the byte offset from which a declared name is in
+// scope: the end of its declaration when the initializer cannot see it,
+// else the name itself.
+func visibleFrom(name Node) uint32 {
+	decl := name.Parent()
+	for patternWrappers[decl.Type()] {
+		decl = decl.Parent()
+	}
+	if declarationEnds[decl.Type()] {
+		return decl.EndByte()
+	}
+	return name.StartByte()
+}
+
+// isSignatureParameter reports whether a declared name is a parameter of a
+// function type or bodiless signature rather than a local.
+func isSignatureParameter(name Node) bool {
+	n := name.Parent()
+	for i := 0; i < 3 && !n.IsNull(); i++ {
+		if signatureNodes[n.Type()] {
+			return true
+		}
+		n = n.Parent()
+	}
+	return false
+}
+
+// isMemberName reports whether an identifier names a member or keyword
+// argument rather than a variable.
+func isMemberName(node Node) bool {
+	parent := node.Parent()
+	if field, ok := memberFields[parent.Type()]; ok {
+		member := parent.ChildByFieldName(field)
+		return !member.IsNull() && member.StartByte() == node.StartByte()
+	}
+	// Go: the key of a keyed element in a composite literal names a field
+	// (User{name: name}).
+	if parent.Type() == "literal_element" {
+		grand := parent.Parent()
+		return grand.Type() == "keyed_element" && grand.NamedChild(0).StartByte() == parent.StartByte()
+	}
+	return false
+}
@@ -0,0 +1,95 @@
+;; C symbol definitions and references
+
+;; Function definitions
+(function_definition
+  declarator: (function_declarator
+    declarator: (identifier) @def.function))
+
+;; Function definitions returning pointers (e.g. char *name(void))
+(function_definition
+  declarator: (pointer_declarator
+    declarator: (function_declarator
+      declarator: (identifier) @def.function)))
+
+;; Struct definitions
+(struct_specifier
+  name: (type_identifier) @def.struct
+  body: (field_declaration_list))
+
+;; Union definitions
+(union_specifier
+  name: (type_identifier) @def.struct
+  body: (field_declaration_list))
+
+;; Enum definitions
+(enum_specifier
+  name: (type_identifier) @def.enum
+  body: (enumerator_list))
+
+;; Enum constants
+(enumerator
+  name: (identifier) @def.const)
+
+;; Typedefs
+(type_definition
+  declarator: (type_identifier) @def.typealias)
+
+(type_definition
+  declarator: (pointer_declarator
+    declarator: (type_identifier) @def.typealias))
+
+;; Object-like macros
+(preproc_def
+  name: (identifier) @def.const)
+
+;; Function-like macros
+(preproc_function_def
+  name: (identifier) @def.function)
+
+;; Struct fields
+(field_declaration
+  declarator: (field_identifier) @def.field)
+
+(field_declaration
+  declarator: (pointer_declarator
+    declarator: (field_identifier) @def.field))
+
+;; Variable declarations
+(declaration
+  declarator: (init_declarator
+    declarator: (identifier) @def.var))
+
+(declaration
+  declarator: (identifier) @def.var)
+
+;; Include directives
+(preproc_include
+  path: (string_literal) @ref.import)
+
+(preproc_include
+  path: (system_lib_string) @ref.import)
+
+;; Function calls
+(call_expression
+  function: (identifier) @ref.call)
+
+(call_expression
+  function: (field_expression
+    field: (field_identifier) @ref.call))
+
+;; Assignment left-hand side (writes)
+(assignment_expression
+  left: (identifier) @ref.write)
+
+(assignment_expression
+  left: (field_expression
+    field: (field_identifier) @ref.write))
+
+;; Identifiers as references
+(identifier) @ref.identifier
+
+;; Type identifiers
+(type_identifier) @ref.type
+
+;; Field identifiers
+(field_identifier) @ref.field
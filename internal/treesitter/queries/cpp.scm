@@ -0,0 +1,217 @@
+;; C++ symbol definitions and references
+
+;; Free function definitions (file scope and namespace scope)
+(translation_unit
+  (function_definition
+    declarator: (function_declarator
+      declarator: (identifier) @def.function)))
+
+(declaration_list
+  (function_definition
+    declarator: (function_declarator
+      declarator: (identifier) @def.function)))
+
+(template_declaration
+  (function_definition
+    declarator: (function_declarator
+      declarator: (identifier) @def.function)))
+
+;; Free functions returning pointers or references
+(function_definition
+  declarator: (pointer_declarator
+    declarator: (function_declarator
+      declarator: (identifier) @def.function)))
+
+(function_definition
+  declarator: (reference_declarator
+    (function_declarator
+      declarator: (identifier) @def.function)))
+
+;; Class definitions
+(class_specifier
+  name: (type_identifier) @def.class
+  body: (field_declaration_list))
+
+;; Struct definitions
+(struct_specifier
+  name: (type_identifier) @def.struct
+  body: (field_declaration_list))
+
+;; Union definitions
+(union_specifier
+  name: (type_identifier) @def.struct
+  body: (field_declaration_list))
+
+;; Enum definitions (plain and scoped)
+(enum_specifier
+  name: (type_identifier) @def.enum
+  body: (enumerator_list))
+
+;; Enum constants
+(enumerator
+  name: (identifier) @def.const)
+
+;; Namespaces
+(namespace_definition
+  name: (namespace_identifier) @def.module)
+
+;; Typedefs and using-aliases
+(type_definition
+  declarator: (type_identifier) @def.typealias)
+
+(alias_declaration
+  name: (type_identifier) @def.typealias)
+
+;; Object-like macros
+(preproc_def
+  name: (identifier) @def.const)
+
+;; Function-like macros
+(preproc_function_def
+  name: (identifier) @def.function)
+
+;; Methods defined inside a class body
+(class_specifier
+  name: (type_identifier) @container.name
+  body: (field_declaration_list
+    (function_definition
+      declarator: (function_declarator
+        declarator: (field_identifier) @def.method))))
+
+(struct_specifier
+  name: (type_identifier) @container.name
+  body: (field_declaration_list
+    (function_definition
+      declarator: (function_declarator
+        declarator: (field_identifier) @def.method))))
+
+;; Method declarations inside a class body (including pure virtual methods)
+(class_specifier
+  name: (type_identifier) @container.name
+  body: (field_declaration_list
+    (field_declaration
+      declarator: (function_declarator
+        declarator: (field_identifier) @def.method))))
+
+(struct_specifier
+  name: (type_identifier) @container.name
+  body: (field_declaration_list
+    (field_declaration
+      declarator: (function_declarator
+        declarator: (field_identifier) @def.method))))
+
+;; Constructors defined inside a class body
+(class_specifier
+  name: (type_identifier) @container.name
+  body: (field_declaration_list
+    (function_definition
+      declarator: (function_declarator
+        declarator: (identifier) @def.constructor))))
+
+(struct_specifier
+  name: (type_identifier) @container.name
+  body: (field_declaration_list
+    (function_definition
+      declarator: (function_declarator
+        declarator: (identifier) @def.constructor))))
+
+;; Out-of-line method definitions (Type::method)
+(function_definition
+  declarator: (function_declarator
+    declarator: (qualified_identifier
+      scope: (namespace_identifier) @container.name
+      name: (identifier) @def.method)))
+
+(function_definition
+  declarator: (pointer_declarator
+    declarator: (function_declarator
+      declarator: (qualified_identifier
+        scope: (namespace_identifier) @container.name
+        name: (identifier) @def.method))))
+
+(function_definition
+  declarator: (reference_declarator
+    (function_declarator
+      declarator: (qualified_identifier
+        scope: (namespace_identifier) @container.name
+        name: (identifier) @def.method))))
+
+;; Data members
+(field_declaration
+  declarator: (field_identifier) @def.field)
+
+(field_declaration
+  declarator: (pointer_declarator
+    declarator: (field_identifier) @def.field))
+
+;; Variable declarations
+(declaration
+  declarator: (init_declarator
+    declarator: (identifier) @def.var))
+
+(declaration
+  declarator: (identifier) @def.var)
+
+;; Include directives
+(preproc_include
+  path: (string_literal) @ref.import)
+
+(preproc_include
+  path: (system_lib_string) @ref.import)
+
+;; using-declarations (using ns::Name;)
+(using_declaration
+  (qualified_identifier
+    name: (identifier) @ref.import))
+
+;; Base classes
+(base_class_clause
+  (type_identifier) @ref.inherit)
+
+(base_class_clause
+  (qualified_identifier
+    name: (type_identifier) @ref.inherit))
+
+(base_class_clause
+  (template_type
+    name: (type_identifier) @ref.inherit))
+
+;; Overriding methods (override specifier), declared or defined in the
+;; class body
+(function_declarator
+  declarator: (field_identifier) @ref.override
+  (virtual_specifier) @_specifier
+  (#eq? @_specifier "override"))
+
+;; Function/method calls
+(call_expression
+  function: (identifier) @ref.call)
+
+(call_expression
+  function: (field_expression
+    field: (field_identifier) @ref.call))
+
+(call_expression
+  function: (qualified_identifier
+    name: (identifier) @ref.call))
+
+(call_expression
+  function: (template_function
+    name: (identifier) @ref.call))
+
+;; Assignment left-hand side (writes)
+(assignment_expression
+  left: (identifier) @ref.write)
+
+(assignment_expression
+  left: (field_expression
+    field: (field_identifier) @ref.write))
+
+;; Identifiers as references
+(identifier) @ref.identifier
+
+;; Type identifiers
+(type_identifier) @ref.type
+
+;; Field identifiers
+(field_identifier) @ref.field
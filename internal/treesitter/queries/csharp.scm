@@ -0,0 +1,229 @@
+;; C# symbol definitions and references
+
+;; Namespaces (block-scoped and file-scoped)
+(namespace_declaration
+  name: (_) @def.module)
+
+(file_scoped_namespace_declaration
+  name: (_) @def.module)
+
+;; Class declarations (each part of a partial class is its own definition)
+(class_declaration
+  name: (identifier) @def.class)
+
+;; Record declarations
+(record_declaration
+  name: (identifier) @def.class)
+
+;; Interface declarations
+(interface_declaration
+  name: (identifier) @def.interface)
+
+;; Struct declarations
+(struct_declaration
+  name: (identifier) @def.struct)
+
+;; Enum declarations
+(enum_declaration
+  name: (identifier) @def.enum)
+
+;; Enum members
+(enum_member_declaration
+  name: (identifier) @def.const)
+
+;; Delegates
+(delegate_declaration
+  name: (identifier) @def.typealias)
+
+;; Methods, constructors, properties and fields, attributed to the
+;; immediately enclosing type so that members of every partial part share
+;; the same container.
+(class_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (method_declaration
+      name: (identifier) @def.method)))
+
+(struct_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (method_declaration
+      name: (identifier) @def.method)))
+
+(record_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (method_declaration
+      name: (identifier) @def.method)))
+
+(interface_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (method_declaration
+      name: (identifier) @def.method)))
+
+(class_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (constructor_declaration
+      name: (identifier) @def.constructor)))
+
+(struct_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (constructor_declaration
+      name: (identifier) @def.constructor)))
+
+(record_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (constructor_declaration
+      name: (identifier) @def.constructor)))
+
+(class_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (property_declaration
+      name: (identifier) @def.property)))
+
+(struct_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (property_declaration
+      name: (identifier) @def.property)))
+
+(record_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (property_declaration
+      name: (identifier) @def.property)))
+
+(interface_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (property_declaration
+      name: (identifier) @def.property)))
+
+(class_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (field_declaration
+      (variable_declaration
+        (variable_declarator
+          (identifier) @def.field)))))
+
+(struct_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (field_declaration
+      (variable_declaration
+        (variable_declarator
+          (identifier) @def.field)))))
+
+(record_declaration
+  name: (identifier) @container.name
+  body: (declaration_list
+    (field_declaration
+      (variable_declaration
+        (variable_declarator
+          (identifier) @def.field)))))
+
+;; Local variable declarations
+(local_declaration_statement
+  (variable_declaration
+    (variable_declarator
+      (identifier) @def.var)))
+
+;; Using directives
+(using_directive
+  (qualified_name
+    name: (identifier) @ref.import))
+
+(using_directive
+  (identifier) @ref.import)
+
+;; Base types. Classes may extend a class and implement interfaces; the
+;; extractor reclassifies interface-style names (IFoo) as "implements".
+(class_declaration
+  (base_list
+    (identifier) @ref.inherit))
+
+(class_declaration
+  (base_list
+    (generic_name
+      (identifier) @ref.inherit)))
+
+(class_declaration
+  (base_list
+    (qualified_name
+      name: (identifier) @ref.inherit)))
+
+(record_declaration
+  (base_list
+    (identifier) @ref.inherit))
+
+(record_declaration
+  (base_list
+    (generic_name
+      (identifier) @ref.inherit)))
+
+;; Interfaces extend other interfaces
+(interface_declaration
+  (base_list
+    (identifier) @ref.inherit))
+
+(interface_declaration
+  (base_list
+    (generic_name
+      (identifier) @ref.inherit)))
+
+;; Structs can only implement interfaces
+(struct_declaration
+  (base_list
+    (identifier) @ref.implements))
+
+(struct_declaration
+  (base_list
+    (generic_name
+      (identifier) @ref.implements)))
+
+;; Overriding methods (override modifier)
+(method_declaration
+  (modifier) @_modifier
+  name: (identifier) @ref.override
+  (#eq? @_modifier "override"))
+
+;; Attributes
+(attribute
+  name: (identifier) @ref.attribute)
+
+;; Method calls
+(invocation_expression
+  function: (identifier) @ref.call)
+
+(invocation_expression
+  function: (member_access_expression
+    name: (identifier) @ref.call))
+
+(invocation_expression
+  function: (generic_name
+    (identifier) @ref.call))
+
+;; Object creation
+(object_creation_expression
+  type: (identifier) @ref.call)
+
+;; Assignment left-hand side (writes)
+(assignment_expression
+  left: (identifier) @ref.write)
+
+(assignment_expression
+  left: (member_access_expression
+    name: (identifier) @ref.write))
+
+;; Member access
+(member_access_expression
+  name: (identifier) @ref.property)
+
+;; Identifiers as references
+(identifier) @ref.identifier
@@ -24,6 +24,14 @@
     name: (type_identifier) @def.interface
     type: (interface_type)))
 
+;; Interface methods
+(type_declaration
+  (type_spec
+    name: (type_identifier) @container.name
+    type: (interface_type
+      (method_elem
+        name: (field_identifier) @def.method))))
+
 ;; Function type definitions
 (type_declaration
   (type_spec
@@ -61,6 +69,30 @@
 (field_declaration
   name: (field_identifier) @def.field)
 
+;; Embedded struct fields and interfaces (promoting their methods)
+(field_declaration
+  !name
+  type: (type_identifier) @ref.inherit.embed)
+
+(field_declaration
+  !name
+  type: (qualified_type
+    name: (type_identifier) @ref.inherit.embed))
+
+(field_declaration
+  !name
+  type: (generic_type
+    type: (type_identifier) @ref.inherit.embed))
+
+(interface_type
+  (type_elem
+    (type_identifier) @ref.inherit.embed))
+
+(interface_type
+  (type_elem
+    (qualified_type
+      name: (type_identifier) @ref.inherit.embed)))
+
 ;; Const and var declarations
 (const_declaration
   (const_spec
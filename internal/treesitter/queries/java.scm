@@ -43,6 +43,14 @@
   name: (identifier) @ref.annotation
   (#eq? @ref.annotation "Override"))
 
+;; Overriding methods (@Override on the method)
+(method_declaration
+  (modifiers
+    (marker_annotation
+      name: (identifier) @_override))
+  name: (identifier) @ref.override
+  (#eq? @_override "Override"))
+
 ;; Method calls
 (method_invocation
   name: (identifier) @ref.call)
@@ -51,6 +59,34 @@
 (assignment_expression
   left: (identifier) @ref.write)
 
+;; Superclass (class Foo extends Base)
+(superclass
+  (type_identifier) @ref.inherit)
+
+(superclass
+  (generic_type
+    (type_identifier) @ref.inherit))
+
+;; Implemented interfaces (class Foo implements Runnable)
+(super_interfaces
+  (type_list
+    (type_identifier) @ref.implements))
+
+(super_interfaces
+  (type_list
+    (generic_type
+      (type_identifier) @ref.implements)))
+
+;; Interfaces extend other interfaces
+(extends_interfaces
+  (type_list
+    (type_identifier) @ref.inherit))
+
+(extends_interfaces
+  (type_list
+    (generic_type
+      (type_identifier) @ref.inherit)))
+
 ;; Identifiers as references
 (identifier) @ref.identifier
 
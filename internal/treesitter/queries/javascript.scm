@@ -65,6 +65,46 @@
   left: (member_expression
     property: (property_identifier) @ref.write))
 
+;; Base class (class Foo extends Base)
+(class_heritage
+  (identifier) @ref.inherit)
+
+;; Prototype chains: Child.prototype = Object.create(Parent.prototype). The
+;; constructor whose prototype is set is recorded as the container of the ref.
+(assignment_expression
+  left: (member_expression
+    object: (identifier) @container.name
+    property: (property_identifier) @_proto)
+  right: (call_expression
+    function: (member_expression
+      object: (identifier) @_object
+      property: (property_identifier) @_create)
+    arguments: (arguments
+      (member_expression
+        object: (identifier) @ref.inherit.prototype
+        property: (property_identifier) @_parent_proto)))
+  (#eq? @_proto "prototype")
+  (#eq? @_object "Object")
+  (#eq? @_create "create")
+  (#eq? @_parent_proto "prototype"))
+
+;; Object.setPrototypeOf(Child.prototype, Parent.prototype)
+(call_expression
+  function: (member_expression
+    object: (identifier) @_object
+    property: (property_identifier) @_set)
+  arguments: (arguments
+    (member_expression
+      object: (identifier) @container.name
+      property: (property_identifier) @_proto)
+    (member_expression
+      object: (identifier) @ref.inherit.prototype
+      property: (property_identifier) @_parent_proto))
+  (#eq? @_object "Object")
+  (#eq? @_set "setPrototypeOf")
+  (#eq? @_proto "prototype")
+  (#eq? @_parent_proto "prototype"))
+
 ;; Identifiers as references
 (identifier) @ref.identifier
 
@@ -0,0 +1,150 @@
+;; Kotlin symbol definitions and references
+
+;; Package header
+(package_header
+  (identifier) @def.package)
+
+;; Class, data class, enum class and interface declarations
+(class_declaration
+  (type_identifier) @def.class)
+
+;; Object declarations (singletons)
+(object_declaration
+  (type_identifier) @def.class)
+
+;; Named companion objects
+(companion_object
+  (type_identifier) @def.class)
+
+;; Top-level and extension functions. Extension functions get their
+;; receiver type as container in the extractor.
+(source_file
+  (function_declaration
+    (simple_identifier) @def.function))
+
+;; Methods declared in a class, object or interface body
+(class_declaration
+  (type_identifier) @container.name
+  (class_body
+    (function_declaration
+      (simple_identifier) @def.method)))
+
+(object_declaration
+  (type_identifier) @container.name
+  (class_body
+    (function_declaration
+      (simple_identifier) @def.method)))
+
+(class_declaration
+  (type_identifier) @container.name
+  (enum_class_body
+    (function_declaration
+      (simple_identifier) @def.method)))
+
+;; Companion object members belong to the enclosing class
+(class_declaration
+  (type_identifier) @container.name
+  (class_body
+    (companion_object
+      (class_body
+        (function_declaration
+          (simple_identifier) @def.method)))))
+
+(class_declaration
+  (type_identifier) @container.name
+  (class_body
+    (companion_object
+      (class_body
+        (property_declaration
+          (variable_declaration
+            (simple_identifier) @def.property))))))
+
+;; Properties declared in a class or object body
+(class_declaration
+  (type_identifier) @container.name
+  (class_body
+    (property_declaration
+      (variable_declaration
+        (simple_identifier) @def.property))))
+
+(object_declaration
+  (type_identifier) @container.name
+  (class_body
+    (property_declaration
+      (variable_declaration
+        (simple_identifier) @def.property))))
+
+;; Primary-constructor parameters (the properties of data classes)
+(class_parameter
+  (simple_identifier) @def.property)
+
+;; Top-level and extension properties
+(source_file
+  (property_declaration
+    (variable_declaration
+      (simple_identifier) @def.var)))
+
+;; Enum entries
+(enum_entry
+  (simple_identifier) @def.const)
+
+;; Type aliases
+(type_alias
+  (type_identifier) @def.typealias)
+
+;; Imports
+(import_header
+  (identifier
+    (simple_identifier) @ref.import .))
+
+;; Supertypes: a constructor invocation is a superclass, a bare type is an
+;; implemented interface.
+(delegation_specifier
+  (constructor_invocation
+    (user_type
+      (type_identifier) @ref.inherit)))
+
+(delegation_specifier
+  (user_type
+    (type_identifier) @ref.implements))
+
+;; Overriding methods (override modifier)
+(function_declaration
+  (modifiers
+    (member_modifier) @_modifier)
+  (simple_identifier) @ref.override
+  (#eq? @_modifier "override"))
+
+;; Annotations
+(annotation
+  (user_type
+    (type_identifier) @ref.annotation))
+
+(annotation
+  (constructor_invocation
+    (user_type
+      (type_identifier) @ref.annotation)))
+
+;; Function calls
+(call_expression
+  (simple_identifier) @ref.call)
+
+(call_expression
+  (navigation_expression
+    (navigation_suffix
+      (simple_identifier) @ref.call)))
+
+;; Assignment left-hand side (writes)
+(assignment
+  (directly_assignable_expression
+    (simple_identifier) @ref.write))
+
+;; Property access
+(navigation_suffix
+  (simple_identifier) @ref.property)
+
+;; Identifiers as references
+(simple_identifier) @ref.identifier
+
+;; Type identifiers
+(type_identifier) @ref.type
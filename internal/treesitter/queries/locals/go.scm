@@ -0,0 +1,45 @@
+;; Go local scopes and bindings
+
+;; Scopes
+(function_declaration) @local.scope
+(method_declaration) @local.scope
+(func_literal) @local.scope
+(block) @local.scope
+(if_statement) @local.scope
+(for_statement) @local.scope
+(expression_switch_statement) @local.scope
+(type_switch_statement) @local.scope
+(expression_case) @local.scope
+(type_case) @local.scope
+(default_case) @local.scope
+(communication_case) @local.scope
+
+;; Parameters, receivers and named results
+(parameter_declaration
+  name: (identifier) @local.definition)
+
+(variadic_parameter_declaration
+  name: (identifier) @local.definition)
+
+;; x := ..., for k, v := range ..., switch v := x.(type)
+(short_var_declaration
+  left: (expression_list
+    (identifier) @local.definition))
+
+(range_clause
+  left: (expression_list
+    (identifier) @local.definition))
+
+(type_switch_statement
+  alias: (expression_list
+    (identifier) @local.definition))
+
+;; var and const declarations inside a function
+(var_spec
+  name: (identifier) @local.definition)
+
+(const_spec
+  name: (identifier) @local.definition)
+
+;; References
+(identifier) @local.reference
@@ -0,0 +1,42 @@
+;; Java local scopes and bindings
+
+;; Scopes
+(method_declaration) @local.scope
+(constructor_declaration) @local.scope
+(lambda_expression) @local.scope
+(block) @local.scope
+(for_statement) @local.scope
+(enhanced_for_statement) @local.scope
+(catch_clause) @local.scope
+(try_with_resources_statement) @local.scope
+
+;; Parameters
+(formal_parameter
+  name: (identifier) @local.definition)
+
+(spread_parameter
+  (variable_declarator
+    name: (identifier) @local.definition))
+
+(catch_formal_parameter
+  name: (identifier) @local.definition)
+
+(lambda_expression
+  parameters: (identifier) @local.definition)
+
+(inferred_parameters
+  (identifier) @local.definition)
+
+;; Local variables
+(local_variable_declaration
+  declarator: (variable_declarator
+    name: (identifier) @local.definition))
+
+(enhanced_for_statement
+  name: (identifier) @local.definition)
+
+(resource
+  name: (identifier) @local.definition)
+
+;; References
+(identifier) @local.reference
@@ -0,0 +1,48 @@
+;; JavaScript local scopes and bindings
+
+;; Scopes
+(function_declaration) @local.scope
+(function_expression) @local.scope
+(generator_function_declaration) @local.scope
+(generator_function) @local.scope
+(arrow_function) @local.scope
+(method_definition) @local.scope
+(statement_block) @local.scope
+(for_statement) @local.scope
+(for_in_statement) @local.scope
+(catch_clause) @local.scope
+
+;; Parameters
+(formal_parameters
+  (identifier) @local.definition)
+
+(formal_parameters
+  (assignment_pattern
+    left: (identifier) @local.definition))
+
+(formal_parameters
+  (rest_pattern
+    (identifier) @local.definition))
+
+(arrow_function
+  parameter: (identifier) @local.definition)
+
+(catch_clause
+  parameter: (identifier) @local.definition)
+
+;; let, const and var declarations, including loop variables
+(variable_declarator
+  name: (identifier) @local.definition)
+
+(for_in_statement
+  left: (identifier) @local.definition)
+
+;; Destructuring
+(object_pattern
+  (shorthand_property_identifier_pattern) @local.definition)
+
+(array_pattern
+  (identifier) @local.definition)
+
+;; References
+(identifier) @local.reference
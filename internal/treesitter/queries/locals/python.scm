@@ -0,0 +1,70 @@
+;; Python local scopes and bindings
+
+;; Scopes (a name bound anywhere in a function is local to all of it)
+(function_definition) @local.scope
+(lambda) @local.scope
+(list_comprehension) @local.scope
+(set_comprehension) @local.scope
+(dictionary_comprehension) @local.scope
+(generator_expression) @local.scope
+
+;; Parameters
+(parameters
+  (identifier) @local.definition)
+
+(parameters
+  (default_parameter
+    name: (identifier) @local.definition))
+
+(parameters
+  (typed_parameter
+    (identifier) @local.definition))
+
+(parameters
+  (typed_default_parameter
+    name: (identifier) @local.definition))
+
+(parameters
+  (list_splat_pattern
+    (identifier) @local.definition))
+
+(parameters
+  (dictionary_splat_pattern
+    (identifier) @local.definition))
+
+(lambda_parameters
+  (identifier) @local.definition)
+
+;; Assignments and loop variables
+(assignment
+  left: (identifier) @local.definition)
+
+(assignment
+  left: (pattern_list
+    (identifier) @local.definition))
+
+(augmented_assignment
+  left: (identifier) @local.definition)
+
+(for_statement
+  left: (identifier) @local.definition)
+
+(for_statement
+  left: (pattern_list
+    (identifier) @local.definition))
+
+(for_in_clause
+  left: (identifier) @local.definition)
+
+(named_expression
+  name: (identifier) @local.definition)
+
+;; global and nonlocal declarations
+(global_statement
+  (identifier) @local.global)
+
+(nonlocal_statement
+  (identifier) @local.nonlocal)
+
+;; References
+(identifier) @local.reference
@@ -0,0 +1,43 @@
+;; Rust local scopes and bindings
+
+;; Scopes
+(function_item) @local.scope
+(closure_expression) @local.scope
+(block) @local.scope
+(for_expression) @local.scope
+(if_expression) @local.scope
+(while_expression) @local.scope
+(match_arm) @local.scope
+
+;; Parameters
+(parameter
+  pattern: (identifier) @local.definition)
+
+(parameter
+  pattern: (mut_pattern
+    (identifier) @local.definition))
+
+(closure_parameters
+  (identifier) @local.definition)
+
+;; let bindings, loop variables and destructuring (Some(x), Ok(v))
+(let_declaration
+  pattern: (identifier) @local.definition)
+
+(let_declaration
+  pattern: (mut_pattern
+    (identifier) @local.definition))
+
+(let_declaration
+  pattern: (tuple_pattern
+    (identifier) @local.definition))
+
+(for_expression
+  pattern: (identifier) @local.definition)
+
+(tuple_struct_pattern
+  type: (_)
+  (identifier) @local.definition)
+
+;; References
+(identifier) @local.reference
@@ -0,0 +1,179 @@
+;; PHP symbol definitions and references
+
+;; Namespaces
+(namespace_definition
+  name: (namespace_name) @def.module)
+
+;; Class declarations
+(class_declaration
+  name: (name) @def.class)
+
+;; Interface declarations
+(interface_declaration
+  name: (name) @def.interface)
+
+;; Trait declarations
+(trait_declaration
+  name: (name) @def.trait)
+
+;; Enum declarations
+(enum_declaration
+  name: (name) @def.enum)
+
+;; Enum cases
+(enum_case
+  name: (name) @def.const)
+
+;; Function definitions
+(function_definition
+  name: (name) @def.function)
+
+;; Methods
+(class_declaration
+  name: (name) @container.name
+  body: (declaration_list
+    (method_declaration
+      name: (name) @def.method)))
+
+(interface_declaration
+  name: (name) @container.name
+  body: (declaration_list
+    (method_declaration
+      name: (name) @def.method)))
+
+(trait_declaration
+  name: (name) @container.name
+  body: (declaration_list
+    (method_declaration
+      name: (name) @def.method)))
+
+(enum_declaration
+  name: (name) @container.name
+  body: (enum_declaration_list
+    (method_declaration
+      name: (name) @def.method)))
+
+;; Properties
+(class_declaration
+  name: (name) @container.name
+  body: (declaration_list
+    (property_declaration
+      (property_element
+        (variable_name
+          (name) @def.property)))))
+
+(trait_declaration
+  name: (name) @container.name
+  body: (declaration_list
+    (property_declaration
+      (property_element
+        (variable_name
+          (name) @def.property)))))
+
+;; Constructor-promoted properties
+(property_promotion_parameter
+  name: (variable_name
+    (name) @def.property))
+
+;; Class constants
+(class_declaration
+  name: (name) @container.name
+  body: (declaration_list
+    (const_declaration
+      (const_element
+        (name) @def.const))))
+
+(interface_declaration
+  name: (name) @container.name
+  body: (declaration_list
+    (const_declaration
+      (const_element
+        (name) @def.const))))
+
+;; Top-level constants
+(program
+  (const_declaration
+    (const_element
+      (name) @def.const)))
+
+;; use statements (imports)
+(namespace_use_clause
+  (qualified_name
+    (name) @ref.import))
+
+(namespace_use_clause
+  (name) @ref.import)
+
+;; Base classes (extends)
+(base_clause
+  (name) @ref.inherit)
+
+(base_clause
+  (qualified_name
+    (name) @ref.inherit))
+
+;; Implemented interfaces
+(class_interface_clause
+  (name) @ref.implements)
+
+(class_interface_clause
+  (qualified_name
+    (name) @ref.implements))
+
+;; Trait use inside a class body
+(use_declaration
+  (name) @ref.implements)
+
+(use_declaration
+  (qualified_name
+    (name) @ref.implements))
+
+;; Attributes
+(attribute
+  (name) @ref.attribute)
+
+;; Function calls
+(function_call_expression
+  function: (name) @ref.call)
+
+(function_call_expression
+  function: (qualified_name
+    (name) @ref.call))
+
+;; Method calls ($obj->method(), Class::method())
+(member_call_expression
+  name: (name) @ref.call)
+
+(nullsafe_member_call_expression
+  name: (name) @ref.call)
+
+(scoped_call_expression
+  name: (name) @ref.call)
+
+;; Object creation (new Foo)
+(object_creation_expression
+  (name) @ref.call)
+
+(object_creation_expression
+  (qualified_name
+    (name) @ref.call))
+
+;; Property writes
+(assignment_expression
+  left: (member_access_expression
+    name: (name) @ref.write))
+
+(assignment_expression
+  left: (variable_name
+    (name) @ref.write))
+
+;; Property reads
+(member_access_expression
+  name: (name) @ref.property)
+
+;; Qualified names (\App\Models\Invoice)
+(qualified_name
+  (name) @ref.type)
+
+;; Names as references
+(name) @ref.identifier
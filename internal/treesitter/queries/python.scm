@@ -95,3 +95,18 @@
 ;; misses them. We capture the enclosing (type) node and walk its subtree
 ;; in the extractor to find all string literals inside.
 (type) @ref.annotation
+
+;; Base classes (class Foo(Base, mixins.Logged))
+(class_definition
+  superclasses: (argument_list
+    (identifier) @ref.inherit))
+
+(class_definition
+  superclasses: (argument_list
+    (attribute
+      attribute: (identifier) @ref.inherit)))
+
+(class_definition
+  superclasses: (argument_list
+    (subscript
+      value: (identifier) @ref.inherit)))
@@ -0,0 +1,115 @@
+;; Ruby symbol definitions and references
+
+;; Class definitions
+(class
+  name: (constant) @def.class)
+
+(class
+  name: (scope_resolution
+    name: (constant) @def.class))
+
+;; Module definitions
+(module
+  name: (constant) @def.module)
+
+(module
+  name: (scope_resolution
+    name: (constant) @def.module))
+
+;; Instance methods (def name) inside a class or module body
+(class
+  name: (constant) @container.name
+  (_
+    (method
+      name: (_) @def.method)))
+
+(module
+  name: (constant) @container.name
+  (_
+    (method
+      name: (_) @def.method)))
+
+;; Class methods (def self.name)
+(class
+  name: (constant) @container.name
+  (_
+    (singleton_method
+      name: (_) @def.method)))
+
+(module
+  name: (constant) @container.name
+  (_
+    (singleton_method
+      name: (_) @def.method)))
+
+;; Top-level methods
+(program
+  (method
+    name: (_) @def.function))
+
+;; attr_accessor / attr_reader / attr_writer generated properties
+(class
+  name: (constant) @container.name
+  (_
+    (call
+      method: (identifier) @_attr
+      arguments: (argument_list
+        (simple_symbol) @def.property))
+    (#match? @_attr "^attr_(accessor|reader|writer)$")))
+
+(module
+  name: (constant) @container.name
+  (_
+    (call
+      method: (identifier) @_attr
+      arguments: (argument_list
+        (simple_symbol) @def.property))
+    (#match? @_attr "^attr_(accessor|reader|writer)$")))
+
+;; Constants
+(assignment
+  left: (constant) @def.const)
+
+;; Local variables
+(assignment
+  left: (identifier) @def.var)
+
+;; require / require_relative / load
+(call
+  method: (identifier) @_req
+  arguments: (argument_list
+    (string
+      (string_content) @ref.import))
+  (#match? @_req "^(require|require_relative|load)$"))
+
+;; Superclass
+(superclass
+  (constant) @ref.inherit)
+
+(superclass
+  (scope_resolution
+    name: (constant) @ref.inherit))
+
+;; Mixins (include / extend / prepend)
+(call
+  method: (identifier) @_mixin
+  arguments: (argument_list
+    (constant) @ref.implements)
+  (#match? @_mixin "^(include|extend|prepend)$"))
+
+;; Method calls
+(call
+  method: (identifier) @ref.call)
+
+;; Qualified constant references (Foo::Bar)
+(scope_resolution
+  name: (constant) @ref.type)
+
+;; Instance variable writes
+(assignment
+  left: (instance_variable) @ref.write)
+
+;; Identifiers and constants as references
+(identifier) @ref.identifier
+
+(constant) @ref.type
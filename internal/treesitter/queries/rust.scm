@@ -16,6 +16,19 @@
 (trait_item
   name: (type_identifier) @def.trait)
 
+;; Trait methods, required (fn name(&self);) or provided
+(trait_item
+  name: (type_identifier) @container.name
+  body: (declaration_list
+    (function_signature_item
+      name: (identifier) @def.method)))
+
+(trait_item
+  name: (type_identifier) @container.name
+  body: (declaration_list
+    (function_item
+      name: (identifier) @def.method)))
+
 ;; Impl blocks for methods (simple type)
 (impl_item
   type: (type_identifier) @container.name
@@ -94,6 +107,54 @@
 (assignment_expression
   left: (identifier) @ref.write)
 
+;; Trait implementations (impl Trait for Type); the implementing type is
+;; recorded as the container of the ref.
+(impl_item
+  trait: (type_identifier) @ref.implements
+  type: (type_identifier) @container.name)
+
+(impl_item
+  trait: (type_identifier) @ref.implements
+  type: (generic_type
+    type: (type_identifier) @container.name))
+
+(impl_item
+  trait: (generic_type
+    type: (type_identifier) @ref.implements)
+  type: (type_identifier) @container.name)
+
+(impl_item
+  trait: (generic_type
+    type: (type_identifier) @ref.implements)
+  type: (generic_type
+    type: (type_identifier) @container.name))
+
+(impl_item
+  trait: (scoped_type_identifier
+    name: (type_identifier) @ref.implements)
+  type: (type_identifier) @container.name)
+
+;; Trait methods implemented in an impl block (overrides)
+(impl_item
+  trait: (_)
+  type: (type_identifier) @container.name
+  body: (declaration_list
+    (function_item
+      name: (identifier) @ref.override)))
+
+(impl_item
+  trait: (_)
+  type: (generic_type
+    type: (type_identifier) @container.name)
+  body: (declaration_list
+    (function_item
+      name: (identifier) @ref.override)))
+
+;; Supertraits (trait Sub: Super)
+(trait_item
+  bounds: (trait_bounds
+    (type_identifier) @ref.inherit))
+
 ;; Identifiers as references
 (identifier) @ref.identifier
 
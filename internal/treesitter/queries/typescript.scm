@@ -93,6 +93,31 @@
   left: (member_expression
     property: (property_identifier) @ref.write))
 
+;; Base class (class Foo extends Base)
+(extends_clause
+  value: (identifier) @ref.inherit)
+
+;; Implemented interfaces (class Foo implements Bar)
+(implements_clause
+  (type_identifier) @ref.implements)
+
+(implements_clause
+  (generic_type
+    name: (type_identifier) @ref.implements))
+
+;; Overriding methods (override modifier)
+(method_definition
+  (override_modifier)
+  name: (property_identifier) @ref.override)
+
+;; Interfaces extend other interfaces
+(extends_type_clause
+  type: (type_identifier) @ref.inherit)
+
+(extends_type_clause
+  type: (generic_type
+    name: (type_identifier) @ref.inherit))
+
 ;; Identifiers as references
 (identifier) @ref.identifier
 
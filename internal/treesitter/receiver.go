@@ -0,0 +1,613 @@
+package treesitter
+
+import (
+	"strings"
+	"unicode"
+	"unicode/utf8"
+)
+
+// maxReceiverDepth bounds the chain of declarations, assignments and field
+// accesses followed to infer the type of one receiver.
+const maxReceiverDepth = 8
+
+// receiverInference infers the type of the value a member is accessed on
+// (repo in repo.Save(), this.repo in this.repo.save()) from the declared
+// type of a local or parameter, the constructor call it was initialized
+// with, the fields of the types declared in the file, and the class or
+// impl enclosing this and self.
+type receiverInference struct {
+	langName string
+	root     Node
+	source   []byte
+	bindings localBindings
+	fields   map[string]map[string][]fieldDecl // type -> field -> declarations, built on first use
+}
+
+// fieldDecl is a declaration of or assignment to a field of a type declared
+// in the file: its declared type, or the value it is initialized with.
+type fieldDecl struct {
+	typ   Node
+	value Node
+}
+
+// memberAccesses maps the member access expressions of the languages with
+// locals to the fields holding the member's name and the value it is
+// accessed on.
+var memberAccesses = map[string][2]string{
+	"selector_expression": {"field", "operand"},    // Go
+	"method_invocation":   {"name", "object"},      // Java
+	"field_access":        {"field", "object"},     // Java
+	"field_expression":    {"field", "value"},      // Rust
+	"attribute":           {"attribute", "object"}, // Python
+	"member_expression":   {"property", "object"},  // JavaScript, TypeScript
+}
+
+// transparentTypes lists, per language, the wrappers whose members are
+// those of the wrapped type (Box<Repo>, Optional[Repo]).
+var transparentTypes = map[string]map[string]bool{
+	"rust":   {"Box": true, "Rc": true, "Arc": true},
+	"python": {"Optional": true},
+}
+
+// rustConstructorNames lists the associated functions that return their
+// type by convention (Repo::new(), Repo::default()); Repo::with_* and
+// Repo::from_* are treated the same way.
+var rustConstructorNames = map[string]bool{
+	"new":     true,
+	"default": true,
+	"from":    true,
+}
+
+func newReceiverInference(langName string, root Node, source []byte, bindings localBindings) *receiverInference {
+	return &receiverInference{
+		langName: langName,
+		root:     root,
+		source:   source,
+		bindings: bindings,
+	}
+}
+
+// receiverType returns the type name of the value the member named by node
+// is accessed on, or "" when node is not a member or its receiver's type
+// cannot be inferred.
+func (ri *receiverInference) receiverType(node Node) string {
+	parent := node.Parent()
+	access, ok := memberAccesses[parent.Type()]
+	if !ok {
+		return ""
+	}
+	member := parent.ChildByFieldName(access[0])
+	if member.IsNull() || member.StartByte() != node.StartByte() {
+		return ""
+	}
+	return ri.exprType(parent.ChildByFieldName(access[1]), 0)
+}
+
+// exprType returns the type name of the value of an expression, or "".
+func (ri *receiverInference) exprType(expr Node, depth int) string {
+	if expr.IsNull() || depth > maxReceiverDepth {
+		return ""
+	}
+	switch expr.Type() {
+	case "identifier":
+		return ri.localType(expr, depth)
+	case "this", "self":
+		return enclosingTypeName(ri.langName, expr, ri.source)
+	case "parenthesized_expression", "non_null_expression":
+		return ri.exprType(expr.NamedChild(0), depth+1)
+	}
+	if access, ok := memberAccesses[expr.Type()]; ok {
+		// a field of a typed value: this.repo, self.repo, s.repo
+		member := expr.ChildByFieldName(access[0])
+		owner := ri.exprType(expr.ChildByFieldName(access[1]), depth+1)
+		if member.IsNull() || owner == "" {
+			return ""
+		}
+		return ri.fieldType(owner, member.Content(ri.source), depth)
+	}
+	return ri.constructedType(expr, depth)
+}
+
+// localType returns the type of the local variable or parameter an
+// identifier refers to, from the first of its declarations that tells it.
+func (ri *receiverInference) localType(ident Node, depth int) string {
+	binding, ok := ri.bindings.refs[localKey(ident.Content(ri.source), ident.StartPoint())]
+	if !ok {
+		return ""
+	}
+	for _, decl := range ri.bindings.decls[binding] {
+		if t := ri.declaredType(decl, depth+1); t != "" {
+			return t
+		}
+	}
+	return ""
+}
+
+// declaredType returns the type of the local or parameter declared by the
+// given name: its written type, or that of the value it is initialized or
+// assigned with.
+func (ri *receiverInference) declaredType(name Node, depth int) string {
+	decl := name.Parent()
+	if decl.Type() == "mut_pattern" {
+		decl = decl.Parent() // Rust let mut x, mut x: T
+	}
+	switch decl.Type() {
+	case "parameter_declaration", "variadic_parameter_declaration", "formal_parameter",
+		"enhanced_for_statement", "parameter", "typed_parameter":
+		// parameters and Java for-each variables, declared with a type
+		return ri.typeOf(decl.ChildByFieldName("type"))
+	case "resource", "typed_default_parameter", "required_parameter", "optional_parameter":
+		// Java try-with-resources, Python and TypeScript parameters with a default
+		if t := ri.typeOf(decl.ChildByFieldName("type")); t != "" {
+			return t
+		}
+		return ri.exprType(decl.ChildByFieldName("value"), depth)
+	case "var_spec": // Go var x T = ..., var x = ...
+		if t := ri.typeOf(decl.ChildByFieldName("type")); t != "" {
+			return t
+		}
+		return ri.exprType(valueAt(decl, decl.ChildByFieldName("value"), name), depth)
+	case "expression_list":
+		if stmt := decl.Parent(); stmt.Type() == "short_var_declaration" {
+			return ri.exprType(valueAt(decl, stmt.ChildByFieldName("right"), name), depth)
+		}
+	case "variable_declarator":
+		if stmt := decl.Parent(); stmt.Type() == "local_variable_declaration" {
+			// Java: the type is declared once for all declarators
+			if t := ri.typeOf(stmt.ChildByFieldName("type")); t != "" && t != "var" {
+				return t
+			}
+		} else if t := ri.typeOf(decl.ChildByFieldName("type")); t != "" {
+			return t
+		}
+		return ri.exprType(decl.ChildByFieldName("value"), depth)
+	case "let_declaration": // Rust
+		if t := ri.typeOf(decl.ChildByFieldName("type")); t != "" {
+			return t
+		}
+		return ri.exprType(decl.ChildByFieldName("value"), depth)
+	case "assignment": // Python x: T = ..., x = ...
+		if t := ri.typeOf(decl.ChildByFieldName("type")); t != "" {
+			return t
+		}
+		return ri.exprType(decl.ChildByFieldName("right"), depth)
+	case "pattern_list":
+		if stmt := decl.Parent(); stmt.Type() == "assignment" {
+			return ri.exprType(valueAt(decl, stmt.ChildByFieldName("right"), name), depth)
+		}
+	case "default_parameter", "named_expression": // Python
+		return ri.exprType(decl.ChildByFieldName("value"), depth)
+	case "assignment_pattern": // JavaScript parameter with a default
+		return ri.exprType(decl.ChildByFieldName("right"), depth)
+	case "parameters": // Python: self and cls
+		if decl.NamedChild(0).StartByte() == name.StartByte() {
+			return pythonMethodClass(decl.Parent(), ri.source)
+		}
+	}
+	return ""
+}
+
+// constructedType returns the type an expression constructs, converts to
+// or asserts (&Repo{}, new Repo(), Repo::new(), Repo(), x.(Repo)), or "".
+func (ri *receiverInference) constructedType(expr Node, depth int) string {
+	switch expr.Type() {
+	case "composite_literal", "type_assertion_expression", "object_creation_expression", "cast_expression":
+		// Go Repo{} and x.(Repo), Java new Repo() and (Repo) x
+		return ri.typeOf(expr.ChildByFieldName("type"))
+	case "struct_expression": // Rust
+		return ri.typeOf(expr.ChildByFieldName("name"))
+	case "new_expression": // JavaScript, TypeScript
+		return ri.typeOf(expr.ChildByFieldName("constructor"))
+	case "as_expression": // TypeScript
+		return ri.typeOf(expr.NamedChild(1))
+	case "unary_expression": // Go &Repo{}
+		if op := expr.ChildByFieldName("operator"); !op.IsNull() && op.Content(ri.source) == "&" {
+			return ri.exprType(expr.ChildByFieldName("operand"), depth+1)
+		}
+	case "reference_expression": // Rust &Repo::new()
+		return ri.exprType(expr.ChildByFieldName("value"), depth+1)
+	case "try_expression": // Rust Repo::open(path)?
+		return ri.exprType(expr.NamedChild(0), depth+1)
+	case "call_expression", "call":
+		return ri.constructorCallType(expr.ChildByFieldName("function"))
+	}
+	return ""
+}
+
+// constructorCallType returns the type a call to fn constructs by the
+// language's conventions, or "": Go new(Repo) and NewRepo(), Rust
+// Repo::new(), and Python calls of a class name.
+func (ri *receiverInference) constructorCallType(fn Node) string {
+	if fn.IsNull() {
+		return ""
+	}
+	switch ri.langName {
+	case "go":
+		name := fn
+		if fn.Type() == "selector_expression" {
+			name = fn.ChildByFieldName("field")
+		}
+		text := name.Content(ri.source)
+		if text == "new" {
+			args := fn.Parent().ChildByFieldName("arguments")
+			return ri.typeOf(args.NamedChild(0))
+		}
+		if rest, ok := strings.CutPrefix(text, "New"); ok && startsUpper(rest) {
+			return rest
+		}
+	case "rust":
+		if fn.Type() != "scoped_identifier" {
+			return ""
+		}
+		name := fn.ChildByFieldName("name").Content(ri.source)
+		if rustConstructorNames[name] || strings.HasPrefix(name, "with_") ||
+			strings.HasPrefix(name, "from_") || strings.HasPrefix(name, "new_") {
+			return ri.typeOf(fn.ChildByFieldName("path"))
+		}
+	case "python":
+		name := fn
+		if fn.Type() == "attribute" {
+			name = fn.ChildByFieldName("attribute")
+		}
+		if name.Type() == "identifier" && startsUpper(name.Content(ri.source)) {
+			return name.Content(ri.source)
+		}
+	}
+	return ""
+}
+
+// typeOf returns the name of the type a type expression names (see
+// typeName), with Rust's Self replaced by the type of the enclosing impl.
+func (ri *receiverInference) typeOf(typ Node) string {
+	if typ.IsNull() {
+		return ""
+	}
+	name := typeName(ri.langName, typ.Content(ri.source))
+	if name == "Self" && ri.langName == "rust" {
+		return enclosingTypeName(ri.langName, typ, ri.source)
+	}
+	return name
+}
+
+// fieldType returns the type of a field of a type declared in the file.
+func (ri *receiverInference) fieldType(owner, field string, depth int) string {
+	if ri.fields == nil {
+		ri.fields = map[string]map[string][]fieldDecl{}
+		ri.collectFields(ri.root)
+	}
+	for _, d := range ri.fields[owner][field] {
+		if t := ri.typeOf(d.typ); t != "" {
+			return t
+		}
+		if t := ri.exprType(d.value, depth+1); t != "" {
+			return t
+		}
+	}
+	return ""
+}
+
+// collectFields records the field declarations found under n: struct and
+// class fields, TypeScript parameter properties, and the fields assigned
+// through self and this in Python and JavaScript methods.
+func (ri *receiverInference) collectFields(n Node) {
+	add := func(owner string, name Node, d fieldDecl) {
+		if owner == "" || name.IsNull() {
+			return
+		}
+		if ri.fields[owner] == nil {
+			ri.fields[owner] = map[string][]fieldDecl{}
+		}
+		field := name.Content(ri.source)
+		ri.fields[owner][field] = append(ri.fields[owner][field], d)
+	}
+
+	switch ri.langName {
+	case "go":
+		if n.Type() == "type_spec" {
+			if body := n.ChildByFieldName("type"); body.Type() == "struct_type" {
+				owner := n.ChildByFieldName("name").Content(ri.source)
+				forEachStructField(body, func(name, typ Node) {
+					add(owner, name, fieldDecl{typ: typ})
+				})
+			}
+		}
+	case "rust":
+		if n.Type() == "struct_item" {
+			if body := n.ChildByFieldName("body"); body.Type() == "field_declaration_list" {
+				owner := n.ChildByFieldName("name").Content(ri.source)
+				forEachStructField(body, func(name, typ Node) {
+					add(owner, name, fieldDecl{typ: typ})
+				})
+			}
+		}
+	case "java":
+		switch n.Type() {
+		case "field_declaration":
+			owner := enclosingTypeName(ri.langName, n, ri.source)
+			typ := n.ChildByFieldName("type")
+			for i := uint32(0); i < n.NamedChildCount(); i++ {
+				if d := n.NamedChild(i); d.Type() == "variable_declarator" {
+					add(owner, d.ChildByFieldName("name"), fieldDecl{typ: typ, value: d.ChildByFieldName("value")})
+				}
+			}
+		case "record_declaration":
+			owner := n.ChildByFieldName("name").Content(ri.source)
+			params := n.ChildByFieldName("parameters")
+			for i := uint32(0); i < params.NamedChildCount(); i++ {
+				if p := params.NamedChild(i); p.Type() == "formal_parameter" {
+					add(owner, p.ChildByFieldName("name"), fieldDecl{typ: p.ChildByFieldName("type")})
+				}
+			}
+		}
+	case "python":
+		if n.Type() == "assignment" {
+			left := n.ChildByFieldName("left")
+			d := fieldDecl{typ: n.ChildByFieldName("type"), value: n.ChildByFieldName("right")}
+			switch {
+			case left.Type() == "attribute" && left.ChildByFieldName("object").Content(ri.source) == "self":
+				add(enclosingTypeName(ri.langName, n, ri.source), left.ChildByFieldName("attribute"), d)
+			case left.Type() == "identifier" && isClassBodyStatement(n.Parent()):
+				add(enclosingTypeName(ri.langName, n, ri.source), left, d)
+			}
+		}
+	case "javascript", "typescript":
+		switch n.Type() {
+		case "public_field_definition":
+			add(enclosingTypeName(ri.langName, n, ri.source), n.ChildByFieldName("name"),
+				fieldDecl{typ: n.ChildByFieldName("type"), value: n.ChildByFieldName("value")})
+		case "field_definition":
+			add(enclosingTypeName(ri.langName, n, ri.source), n.ChildByFieldName("property"),
+				fieldDecl{value: n.ChildByFieldName("value")})
+		case "required_parameter", "optional_parameter":
+			// constructor(private repo: Repo)
+			if hasParameterProperty(n) {
+				add(enclosingTypeName(ri.langName, n, ri.source), n.ChildByFieldName("pattern"),
+					fieldDecl{typ: n.ChildByFieldName("type")})
+			}
+		case "assignment_expression":
+			if left := n.ChildByFieldName("left"); left.Type() == "member_expression" &&
+				left.ChildByFieldName("object").Type() == "this" {
+				add(enclosingTypeName(ri.langName, n, ri.source), left.ChildByFieldName("property"),
+					fieldDecl{value: n.ChildByFieldName("right")})
+			}
+		}
+	}
+
+	for i := uint32(0); i < n.NamedChildCount(); i++ {
+		ri.collectFields(n.NamedChild(i))
+	}
+}
+
+// forEachStructField calls fn with the name and type of each named field
+// of a Go struct type or Rust field declaration list.
+func forEachStructField(body Node, fn func(name, typ Node)) {
+	if body.Type() == "struct_type" {
+		body = body.NamedChild(0) // Go field_declaration_list
+	}
+	for i := uint32(0); i < body.NamedChildCount(); i++ {
+		field := body.NamedChild(i)
+		if field.Type() != "field_declaration" {
+			continue
+		}
+		typ := field.ChildByFieldName("type")
+		for j := uint32(0); j < field.NamedChildCount(); j++ {
+			if name := field.NamedChild(j); name.Type() == "field_identifier" {
+				fn(name, typ)
+			}
+		}
+	}
+}
+
+// hasParameterProperty reports whether a TypeScript constructor parameter
+// also declares a field (private repo: Repo, readonly repo: Repo).
+func hasParameterProperty(param Node) bool {
+	for i := uint32(0); i < param.NamedChildCount(); i++ {
+		switch param.NamedChild(i).Type() {
+		case "accessibility_modifier", "override_modifier":
+			return true
+		}
+	}
+	for i := uint32(0); i < param.ChildCount(); i++ {
+		if param.Child(i).Type() == "readonly" {
+			return true
+		}
+	}
+	return false
+}
+
+// isClassBodyStatement reports whether a Python statement lies directly in
+// the body of a class.
+func isClassBodyStatement(stmt Node) bool {
+	if stmt.Type() != "expression_statement" {
+		return false
+	}
+	block := stmt.Parent()
+	return block.Type() == "block" && block.Parent().Type() == "class_definition"
+}
+
+// pythonMethodClass returns the name of the class a Python function is a
+// method of, or "" for functions outside a class body and static methods.
+func pythonMethodClass(fn Node, source []byte) string {
+	if fn.Type() != "function_definition" {
+		return ""
+	}
+	scope := fn.Parent()
+	if scope.Type() == "decorated_definition" {
+		for i := uint32(0); i < scope.NamedChildCount(); i++ {
+			if d := scope.NamedChild(i); d.Type() == "decorator" && strings.Contains(d.Content(source), "staticmethod") {
+				return ""
+			}
+		}
+		scope = scope.Parent()
+	}
+	if scope.Type() != "block" || scope.Parent().Type() != "class_definition" {
+		return ""
+	}
+	return scope.Parent().ChildByFieldName("name").Content(source)
+}
+
+// enclosingTypeName returns the name of the class, struct or trait whose
+// body or impl block contains node, as this or self refers to it, or "".
+func enclosingTypeName(langName string, node Node, source []byte) string {
+	for child, n := node, node.Parent(); !n.IsNull(); child, n = n, n.Parent() {
+		var name Node
+		switch langName {
+		case "java":
+			switch n.Type() {
+			case "class_declaration", "interface_declaration", "enum_declaration", "record_declaration":
+				name = n.ChildByFieldName("name")
+			case "object_creation_expression":
+				if child.Type() == "class_body" {
+					return "" // anonymous class
+				}
+			}
+		case "python":
+			if n.Type() == "class_definition" {
+				name = n.ChildByFieldName("name")
+			}
+		case "javascript", "typescript":
+			switch n.Type() {
+			case "class_declaration", "class", "abstract_class_declaration":
+				if name = n.ChildByFieldName("name"); name.IsNull() {
+					return ""
+				}
+			case "function_declaration", "function_expression", "function",
+				"generator_function_declaration", "generator_function":
+				return "" // this is bound by the call
+			}
+		case "rust":
+			switch n.Type() {
+			case "impl_item":
+				return typeName(langName, n.ChildByFieldName("type").Content(source))
+			case "trait_item":
+				name = n.ChildByFieldName("name")
+			}
+		}
+		if !name.IsNull() {
+			return name.Content(source)
+		}
+	}
+	return ""
+}
+
+// valueAt returns the value assigned to name by a declaration or assignment
+// whose left side is names and right side is values, pairing them by
+// position (a, b := x, y), or a null node when there is no such value.
+func valueAt(names, values Node, name Node) Node {
+	if values.IsNull() {
+		return Node{}
+	}
+	index := 0
+	for i := uint32(0); i < names.NamedChildCount(); i++ {
+		n := names.NamedChild(i)
+		if n.StartByte() == name.StartByte() {
+			break
+		}
+		if n.Type() == "identifier" {
+			index++
+		}
+	}
+	switch values.Type() {
+	case "expression_list", "pattern_list":
+		if index < int(values.NamedChildCount()) {
+			return values.NamedChild(uint32(index))
+		}
+		return Node{}
+	}
+	if index == 0 {
+		return values
+	}
+	return Node{}
+}
+
+// typeName returns the name of the type a type expression refers to,
+// without pointers, references, type arguments and package or module
+// qualifiers, and with the transparent wrappers of the language removed
+// (*catalog.Store[T] -> Store, &mut Box<Repo> -> Repo, Repo | None -> Repo).
+// It returns "" for types without members of their own, such as slices,
+// maps, function types and unions.
+func typeName(langName, text string) string {
+	// a TypeScript annotation (: Repo) or Python forward reference ("Repo")
+	t := strings.TrimSpace(strings.TrimPrefix(strings.TrimSpace(text), ":"))
+	t = stripTypePrefixes(strings.Trim(t, `"'`))
+	if strings.Contains(t, "|") {
+		// an optional type: Repo | null, Repo | None
+		var kept []string
+		for _, part := range strings.Split(t, "|") {
+			switch part = strings.TrimSpace(part); part {
+			case "null", "undefined", "None":
+			default:
+				kept = append(kept, part)
+			}
+		}
+		if len(kept) != 1 {
+			return ""
+		}
+		return typeName(langName, kept[0])
+	}
+	if i := strings.IndexAny(t, "<["); i > 0 {
+		closer := byte('>')
+		if t[i] == '[' {
+			closer = ']'
+		}
+		args := strings.TrimSpace(t[i+1 : len(t)-1])
+		if t[len(t)-1] != closer || args == "" {
+			return "" // Go map[K]V, Java Repo[]
+		}
+		if transparentTypes[langName][lastTypeSegment(t[:i])] {
+			return typeName(langName, args)
+		}
+		t = t[:i]
+	}
+	t = lastTypeSegment(t)
+	if !isTypeIdentifier(t) {
+		return ""
+	}
+	return t
+}
+
+// stripTypePrefixes removes the pointer and reference markers before a
+// type (*Repo, &'a mut Repo, &dyn Repo).
+func stripTypePrefixes(t string) string {
+	for {
+		switch {
+		case strings.HasPrefix(t, "*"), strings.HasPrefix(t, "&"):
+			t = strings.TrimSpace(t[1:])
+		case strings.HasPrefix(t, "'"): // Rust lifetime
+			_, t, _ = strings.Cut(t, " ")
+		case strings.HasPrefix(t, "mut "), strings.HasPrefix(t, "dyn "):
+			t = strings.TrimSpace(t[4:])
+		default:
+			return t
+		}
+	}
+}
+
+// lastTypeSegment returns the last segment of a qualified type name
+// (catalog.Store, crate::repo::Repo, App\Repo).
+func lastTypeSegment(t string) string {
+	if i := strings.LastIndexAny(t, ".:\\"); i >= 0 {
+		return t[i+1:]
+	}
+	return t
+}
+
+// isTypeIdentifier reports whether s is a plain identifier.
+func isTypeIdentifier(s string) bool {
+	if s == "" {
+		return false
+	}
+	for i, r := range s {
+		if !(r == '_' || r == '$' || unicode.IsLetter(r) || (i > 0 && unicode.IsDigit(r))) {
+			return false
+		}
+	}
+	return true
+}
+
+// startsUpper reports whether s starts with an upper-case letter, as type
+// names do by convention.
+func startsUpper(s string) bool {
+	r, _ := utf8.DecodeRuneInString(s)
+	return unicode.IsUpper(r)
+}
@@ -0,0 +1,548 @@
+package treesitter
+
+import (
+	"bytes"
+	"strings"
+	"unicode"
+	"unicode/utf8"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// declarationWrappers lists the nodes between a declared name and the
+// declaration carrying its modifiers (the variable_declarator of a Java
+// field, the property_element of a PHP property).
+var declarationWrappers = map[string]bool{
+	"variable_declarator":  true,
+	"variable_declaration": true,
+	"property_element":     true,
+	"variable_name":        true,
+	"const_element":        true,
+}
+
+// bodyNodes lists the bodies found without a body field, which end a
+// declaration's signature.
+var bodyNodes = map[string]bool{
+	"block":                  true,
+	"function_body":          true,
+	"class_body":             true,
+	"enum_class_body":        true,
+	"body_statement":         true,
+	"compound_statement":     true,
+	"declaration_list":       true,
+	"field_declaration_list": true,
+}
+
+// functionValues lists the function expressions that make a variable,
+// field or property a function (const save = (user) => {...}).
+var functionValues = map[string]bool{
+	"arrow_function":      true,
+	"function_expression": true,
+	"function":            true,
+}
+
+// skippedParameters lists the children of a parameter list that declare
+// no parameter.
+var skippedParameters = map[string]bool{
+	"comment":              true,
+	"line_comment":         true,
+	"block_comment":        true,
+	"attribute_item":       true,
+	"attribute_list":       true,
+	"keyword_separator":    true,
+	"positional_separator": true,
+	"parameter_modifiers":  true,
+	"receiver_parameter":   true,
+	"self_parameter":       true,
+}
+
+// describeSymbol fills in the signature, visibility, receiver, type
+// parameters, parameters and return type of the symbol whose name node is
+// given. Parameters and the return type are only set for functions,
+// methods and constructors, and for variables holding a function.
+func describeSymbol(langName string, sym *symbols.Symbol, name Node, source []byte) {
+	decl := name.Parent()
+	if langName == "c" || langName == "cpp" {
+		decl = enclosingDeclaration(decl)
+	}
+	sym.Visibility = visibility(langName, name, source)
+
+	fn, callable := decl, false
+	if value := decl.ChildByFieldName("value"); functionValues[value.Type()] {
+		fn, callable = value, true
+	}
+	switch sym.Kind {
+	case symbols.KindFunction, symbols.KindMethod, symbols.KindConstructor:
+		callable = true
+	case symbols.KindClass, symbols.KindInterface, symbols.KindStruct,
+		symbols.KindEnum, symbols.KindTrait, symbols.KindTypeAlias:
+	default:
+		if !callable {
+			return
+		}
+	}
+
+	sym.TypeParams = typeParameters(langName, decl, fn, source)
+	var params Node
+	if callable {
+		params = parameterList(langName, name, fn)
+		sym.Params = parameters(langName, params, source)
+		sym.ReturnType = returnType(langName, decl, fn, params, source)
+		sym.Receiver = receiver(langName, name, decl, params, source)
+	}
+	if sym.Signature == "" {
+		sym.Signature = signatureText(langName, decl, fn, params, source)
+	}
+}
+
+// signatureText returns the header of a declaration as written, from its
+// start to its body, on one line (func (s *Store) Save(u User) error,
+// class Repo(Base)).
+func signatureText(langName string, decl, fn, params Node, source []byte) string {
+	body := fn.ChildByFieldName("body")
+	for i := uint32(0); body.IsNull() && i < fn.NamedChildCount(); i++ {
+		if child := fn.NamedChild(i); bodyNodes[child.Type()] {
+			body = child
+		}
+	}
+	start, end := decl.StartByte(), decl.EndByte()
+	if !body.IsNull() {
+		end = body.StartByte()
+	} else {
+		// Without a body the header ends at the first brace or line break
+		// after the parameters (type Store struct {, class Repo < Base).
+		from := start
+		if !params.IsNull() {
+			from = params.EndByte()
+		}
+		if i := bytes.IndexAny(source[from:end], "{\n"); i >= 0 {
+			end = from + uint32(i)
+		}
+	}
+	text := strings.Join(strings.Fields(string(source[start:end])), " ")
+	text = strings.TrimRight(text, " {:;=")
+	if langName == "go" && (decl.Type() == "type_spec" || decl.Type() == "type_alias") {
+		text = "type " + text
+	}
+	return text
+}
+
+// parameterList returns the parameter list of a function, or a null node.
+func parameterList(langName string, name, fn Node) Node {
+	switch langName {
+	case "c", "cpp":
+		// int (*name)(void): the parameters sit on the function declarator
+		// around the name.
+		for n := name.Parent(); declaratorNodes[n.Type()]; n = n.Parent() {
+			if n.Type() == "function_declarator" {
+				return n.ChildByFieldName("parameters")
+			}
+		}
+		return Node{}
+	case "kotlin":
+		return childOfType(fn, "function_value_parameters")
+	}
+	if params := fn.ChildByFieldName("parameters"); !params.IsNull() {
+		return params
+	}
+	return fn.ChildByFieldName("parameter") // x => x + 1
+}
+
+// parameters returns the parameters declared by a parameter list, or nil
+// for a null list.
+func parameters(langName string, list Node, source []byte) []symbols.Parameter {
+	if list.IsNull() {
+		return nil
+	}
+	if list.Type() == "identifier" {
+		return []symbols.Parameter{{Name: list.Content(source)}}
+	}
+	params := []symbols.Parameter{}
+	for i := uint32(0); i < list.NamedChildCount(); i++ {
+		p := list.NamedChild(i)
+		if skippedParameters[p.Type()] {
+			continue
+		}
+		if langName == "go" {
+			params = append(params, goParameters(p, source)...)
+			continue
+		}
+		params = append(params, parameter(p, source))
+	}
+	return params
+}
+
+// goParameters returns the parameters of a Go parameter declaration, which
+// may name several of the same type (a, b int) or none (int).
+func goParameters(decl Node, source []byte) []symbols.Parameter {
+	typ := typeText(decl.ChildByFieldName("type"), source)
+	if decl.Type() == "variadic_parameter_declaration" {
+		typ = "..." + typ
+	}
+	var params []symbols.Parameter
+	for i := uint32(0); i < decl.NamedChildCount(); i++ {
+		if n := decl.NamedChild(i); n.Type() == "identifier" {
+			params = append(params, symbols.Parameter{Name: n.Content(source), Type: typ})
+		}
+	}
+	if len(params) == 0 {
+		params = append(params, symbols.Parameter{Type: typ})
+	}
+	return params
+}
+
+// parameter returns the name and type of one parameter in any of the other
+// languages.
+func parameter(p Node, source []byte) symbols.Parameter {
+	var name, typ Node
+	switch p.Type() {
+	case "identifier", "simple_identifier", "list_splat_pattern",
+		"dictionary_splat_pattern", "rest_pattern", "object_pattern", "array_pattern":
+		return symbols.Parameter{Name: p.Content(source)}
+	case "typed_parameter": // Python: name: str, *args: int
+		name, typ = p.NamedChild(0), p.ChildByFieldName("type")
+	case "parameter":
+		name, typ = p.ChildByFieldName("name"), p.ChildByFieldName("type")
+		if name.IsNull() {
+			name = p.ChildByFieldName("pattern") // Rust
+		}
+		if name.IsNull() && typ.IsNull() { // Kotlin: name: Type
+			name, typ = p.NamedChild(0), p.NamedChild(1)
+		}
+	case "spread_parameter": // Java: String... names
+		last := p.NamedChild(p.NamedChildCount() - 1)
+		return symbols.Parameter{
+			Name: declaredName(last, source),
+			Type: typeText(p.NamedChild(0), source) + "...",
+		}
+	default:
+		typ = p.ChildByFieldName("type")
+		for _, field := range []string{"name", "pattern", "left", "declarator"} {
+			if name = p.ChildByFieldName(field); !name.IsNull() {
+				break
+			}
+		}
+	}
+	param := symbols.Parameter{Type: typeText(typ, source)}
+	if !name.IsNull() {
+		param.Name = declaredName(name, source)
+	} else if typ.IsNull() {
+		param.Name = p.Content(source) // Ruby &block, C ...
+	}
+	return param
+}
+
+// declaredName returns the name declared by a declarator or pattern,
+// without the C pointer and reference declarators around it (*name) or a
+// Java initializer.
+func declaredName(n Node, source []byte) string {
+	for {
+		inner := n.ChildByFieldName("declarator")
+		if inner.IsNull() {
+			inner = n.ChildByFieldName("name")
+		}
+		if inner.IsNull() && (n.Type() == "pointer_declarator" || n.Type() == "reference_declarator") {
+			inner = n.NamedChild(0)
+		}
+		if inner.IsNull() {
+			return n.Content(source)
+		}
+		n = inner
+	}
+}
+
+// returnType returns the declared return type of a function as written.
+func returnType(langName string, decl, fn, params Node, source []byte) string {
+	var typ Node
+	switch langName {
+	case "go":
+		typ = fn.ChildByFieldName("result")
+	case "java", "c", "cpp":
+		if decl.Type() != "constructor_declaration" {
+			typ = decl.ChildByFieldName("type")
+		}
+	case "csharp":
+		if typ = decl.ChildByFieldName("returns"); typ.IsNull() {
+			typ = decl.ChildByFieldName("type")
+		}
+	case "kotlin":
+		// fun name(...): Type: the type follows the parameters.
+		if next := params.NextNamedSibling(); !next.IsNull() &&
+			next.Type() != "function_body" && next.Type() != "type_constraints" {
+			typ = next
+		}
+	default:
+		typ = fn.ChildByFieldName("return_type")
+	}
+	return typeText(typ, source)
+}
+
+// receiver returns the receiver type of a method as written, for the
+// languages that declare it with the method.
+func receiver(langName string, name, decl, params Node, source []byte) string {
+	switch langName {
+	case "go":
+		recv := decl.ChildByFieldName("receiver")
+		for i := uint32(0); i < recv.NamedChildCount(); i++ {
+			if p := recv.NamedChild(i); p.Type() == "parameter_declaration" {
+				return typeText(p.ChildByFieldName("type"), source)
+			}
+		}
+	case "kotlin":
+		return kotlinReceiverType(name, source)
+	case "rust":
+		// fn push(&mut self, ...) in impl<T> Stack<T>: &mut Stack<T>.
+		self := childOfType(params, "self_parameter")
+		if self.IsNull() {
+			return ""
+		}
+		typ := "Self"
+		for n := decl.Parent(); !n.IsNull(); n = n.Parent() {
+			if n.Type() == "impl_item" {
+				typ = typeText(n.ChildByFieldName("type"), source)
+				break
+			}
+		}
+		prefix := strings.TrimSuffix(self.Content(source), "self")
+		if !strings.HasPrefix(prefix, "&") {
+			prefix = "" // mut self
+		}
+		return prefix + typ
+	}
+	return ""
+}
+
+// typeParameters returns the type parameters of a generic declaration as
+// written, one per parameter.
+func typeParameters(langName string, decl, fn Node, source []byte) []string {
+	var list Node
+	switch langName {
+	case "kotlin":
+		list = childOfType(decl, "type_parameters")
+	case "cpp":
+		if n := decl.Parent(); n.Type() == "template_declaration" {
+			list = n.ChildByFieldName("parameters")
+		}
+	default:
+		if list = decl.ChildByFieldName("type_parameters"); list.IsNull() {
+			list = fn.ChildByFieldName("type_parameters")
+		}
+	}
+	var typeParams []string
+	for i := uint32(0); i < list.NamedChildCount(); i++ {
+		p := list.NamedChild(i)
+		if langName != "go" {
+			typeParams = append(typeParams, typeText(p, source))
+			continue
+		}
+		// Go: [K, V comparable] declares K comparable and V comparable.
+		for _, param := range goParameters(p, source) {
+			typeParams = append(typeParams, strings.TrimSpace(param.Name+" "+param.Type))
+		}
+	}
+	return typeParams
+}
+
+// typeText returns a type as written on one line, without the colon of a
+// TypeScript type annotation.
+func typeText(n Node, source []byte) string {
+	if n.IsNull() {
+		return ""
+	}
+	text := strings.Join(strings.Fields(n.Content(source)), " ")
+	return strings.TrimSpace(strings.TrimPrefix(text, ":"))
+}
+
+// childOfType returns the first named child of n of the given type, or a
+// null node.
+func childOfType(n Node, typ string) Node {
+	for i := uint32(0); i < n.NamedChildCount(); i++ {
+		if child := n.NamedChild(i); child.Type() == typ {
+			return child
+		}
+	}
+	return Node{}
+}
+
+// visibility returns the declared visibility of the definition whose name
+// node is given or, without a modifier, the language's default for it.
+func visibility(langName string, name Node, source []byte) symbols.Visibility {
+	text := name.Content(source)
+	switch langName {
+	case "go":
+		if r, _ := utf8.DecodeRuneInString(text); unicode.IsUpper(r) {
+			return symbols.VisibilityPublic
+		}
+		return symbols.VisibilityPrivate
+	case "python":
+		if strings.HasPrefix(text, "_") && !strings.HasSuffix(text, "__") {
+			return symbols.VisibilityPrivate
+		}
+		return symbols.VisibilityPublic
+	}
+
+	owner := name.Parent()
+	if langName == "c" || langName == "cpp" {
+		owner = enclosingDeclaration(owner)
+	}
+	for declarationWrappers[owner.Type()] {
+		owner = owner.Parent()
+	}
+	switch langName {
+	case "ruby":
+		return rubyVisibility(owner, source)
+	case "c", "cpp":
+		return cVisibility(owner, source)
+	}
+	if v := declaredVisibility(owner, source); v != symbols.VisibilityUnknown {
+		return v
+	}
+
+	container := owner.Parent()
+	switch langName {
+	case "java":
+		// Interface members and enum constants are public; anything else
+		// without a modifier is package-private.
+		if owner.Type() == "enum_constant" || container.Type() == "interface_body" ||
+			container.Type() == "annotation_type_body" {
+			return symbols.VisibilityPublic
+		}
+		return symbols.VisibilityInternal
+	case "csharp":
+		switch {
+		case owner.Type() == "enum_member_declaration",
+			container.Parent().Type() == "interface_declaration":
+			return symbols.VisibilityPublic
+		case container.Type() == "compilation_unit",
+			container.Type() == "file_scoped_namespace_declaration",
+			container.Parent().Type() == "namespace_declaration":
+			return symbols.VisibilityInternal
+		}
+		return symbols.VisibilityPrivate
+	case "rust":
+		// Trait items, trait impl items and enum variants take the
+		// visibility of the trait or enum.
+		outer := container.Parent()
+		if owner.Type() == "enum_variant" || outer.Type() == "trait_item" ||
+			(outer.Type() == "impl_item" && !outer.ChildByFieldName("trait").IsNull()) {
+			return symbols.VisibilityPublic
+		}
+		return symbols.VisibilityPrivate
+	case "javascript", "typescript":
+		switch {
+		case name.Type() == "private_property_identifier": // #name
+			return symbols.VisibilityPrivate
+		case container.Type() == "class_body", container.Type() == "interface_body",
+			container.Type() == "object_type", container.Type() == "enum_body",
+			isExported(name):
+			return symbols.VisibilityPublic
+		}
+		return symbols.VisibilityInternal
+	}
+	return symbols.VisibilityPublic // Kotlin, PHP
+}
+
+// isExported reports whether a JavaScript or TypeScript declaration is
+// exported from its module (export const name, export default class).
+func isExported(n Node) bool {
+	for ; !n.IsNull(); n = n.Parent() {
+		switch n.Type() {
+		case "export_statement":
+			return true
+		case "statement_block", "class_body", "program":
+			return false
+		}
+	}
+	return false
+}
+
+// declaredVisibility returns the visibility named by the modifiers of a
+// declaration (public, pub(crate), private), or VisibilityUnknown when it
+// has none.
+func declaredVisibility(decl Node, source []byte) symbols.Visibility {
+	for i := uint32(0); i < decl.NamedChildCount(); i++ {
+		child := decl.NamedChild(i)
+		switch child.Type() {
+		case "modifiers", "modifier", "visibility_modifier", "accessibility_modifier":
+		default:
+			continue
+		}
+		text := child.Content(source)
+		if text == "pub" {
+			return symbols.VisibilityPublic
+		}
+		if strings.HasPrefix(text, "pub(") { // pub(crate), pub(super)
+			return symbols.VisibilityInternal
+		}
+		for _, word := range strings.Fields(text) {
+			switch word {
+			case "public", "private", "protected", "internal":
+				return symbols.Visibility(word)
+			}
+		}
+	}
+	return symbols.VisibilityUnknown
+}
+
+// rubyVisibility returns the visibility of a Ruby method: the one given by
+// a private, protected or public call wrapping it (private def name) or
+// preceding it in its class body, else public.
+func rubyVisibility(method Node, source []byte) symbols.Visibility {
+	if args := method.Parent(); args.Type() == "argument_list" {
+		if v := rubyVisibilityCall(args.Parent().ChildByFieldName("method"), source); v != symbols.VisibilityUnknown {
+			return v
+		}
+	}
+	v := symbols.VisibilityPublic
+	body := method.Parent()
+	for i := uint32(0); i < body.NamedChildCount(); i++ {
+		child := body.NamedChild(i)
+		if child.StartByte() >= method.StartByte() {
+			break
+		}
+		if child.Type() == "identifier" {
+			if called := rubyVisibilityCall(child, source); called != symbols.VisibilityUnknown {
+				v = called
+			}
+		}
+	}
+	return v
+}
+
+// rubyVisibilityCall returns the visibility set by a call to private,
+// protected or public, or VisibilityUnknown for any other method.
+func rubyVisibilityCall(method Node, source []byte) symbols.Visibility {
+	switch text := method.Content(source); text {
+	case "private", "protected", "public":
+		return symbols.Visibility(text)
+	}
+	return symbols.VisibilityUnknown
+}
+
+// cVisibility returns the visibility of a C or C++ declaration: the access
+// section it is declared in inside a class or struct, or private for a
+// static declaration, which is local to its file.
+func cVisibility(decl Node, source []byte) symbols.Visibility {
+	if list := decl.Parent(); list.Type() == "field_declaration_list" {
+		v := symbols.VisibilityPublic
+		if list.Parent().Type() == "class_specifier" {
+			v = symbols.VisibilityPrivate
+		}
+		for i := uint32(0); i < list.NamedChildCount(); i++ {
+			child := list.NamedChild(i)
+			if child.StartByte() >= decl.StartByte() {
+				break
+			}
+			if child.Type() == "access_specifier" {
+				v = symbols.Visibility(child.Content(source))
+			}
+		}
+		return v
+	}
+	for i := uint32(0); i < decl.NamedChildCount(); i++ {
+		if child := decl.NamedChild(i); child.Type() == "storage_class_specifier" && child.Content(source) == "static" {
+			return symbols.VisibilityPrivate
+		}
+	}
+	return symbols.VisibilityPublic
+}
@@ -0,0 +1,120 @@
+package treesitter
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"strings"
+
+	tree_sitter "github.com/tree-sitter/go-tree-sitter"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+// Repository-local queries live in <queryDir>/<lang>.scm (normally
+// .mesdx/queries). They use the same capture conventions as the embedded
+// queries (@def.*, @ref.*, @container.name) and are appended to the embedded
+// query, unless the file's first line is the replace directive, in which
+// case the embedded query is dropped.
+
+// UserQueryReplaceDirective, on the first line of a user query, makes it
+// replace the embedded query instead of extending it.
+const UserQueryReplaceDirective = ";; mesdx:replace"
+
+// UserQueryError describes an invalid repository-local query file.
+type UserQueryError struct {
+	Path    string
+	Line    int // 1-based
+	Column  int // 1-based
+	Message string
+}
+
+func (e *UserQueryError) Error() string {
+	return fmt.Sprintf("%s:%d:%d: %s", e.Path, e.Line, e.Column, e.Message)
+}
+
+// UserQueryPath returns the path of the repository-local query for langName.
+func UserQueryPath(queryDir, langName string) string {
+	return filepath.Join(queryDir, langName+".scm")
+}
+
+// readUserQuery returns the user query for langName and whether it replaces
+// the embedded query. A missing file (or empty queryDir) yields "".
+func readUserQuery(queryDir, langName string) (string, bool, error) {
+	if queryDir == "" {
+		return "", false, nil
+	}
+	data, err := os.ReadFile(UserQueryPath(queryDir, langName))
+	if errors.Is(err, fs.ErrNotExist) {
+		return "", false, nil
+	}
+	if err != nil {
+		return "", false, err
+	}
+	src := string(data)
+	first, _, _ := strings.Cut(src, "\n")
+	return src, strings.TrimSpace(first) == UserQueryReplaceDirective, nil
+}
+
+// ValidateUserQuery compiles the repository-local query for langName on its
+// own, so syntax errors, unknown node types and unknown @def.* kinds are
+// reported with a line and column in the user's file. It returns nil when
+// there is no such file.
+func ValidateUserQuery(queryDir, langName string) error {
+	src, _, err := readUserQuery(queryDir, langName)
+	if err != nil || src == "" {
+		return err
+	}
+	lang, err := LoadLanguage(langName)
+	if err != nil {
+		return fmt.Errorf("load language %s: %w", langName, err)
+	}
+
+	path := UserQueryPath(queryDir, langName)
+	q, qerr := tree_sitter.NewQuery(lang.lang, src)
+	if qerr != nil {
+		msg := qerr.Message
+		if msg == "" {
+			msg = qerr.Error()
+		}
+		return &UserQueryError{Path: path, Line: int(qerr.Row) + 1, Column: int(qerr.Column) + 1, Message: msg}
+	}
+	defer q.Close()
+
+	for _, name := range q.CaptureNames() {
+		if strings.HasPrefix(name, "def.") && mapCaptureToKind(name) == symbols.KindUnknown {
+			line, col := capturePosition(src, name)
+			return &UserQueryError{Path: path, Line: line, Column: col,
+				Message: fmt.Sprintf("unknown definition capture @%s", name)}
+		}
+	}
+	return nil
+}
+
+// capturePosition returns the 1-based line and column of the first @name in src.
+func capturePosition(src, name string) (int, int) {
+	off := strings.Index(src, "@"+name)
+	if off < 0 {
+		return 1, 1
+	}
+	before := src[:off]
+	line := strings.Count(before, "\n") + 1
+	col := off - (strings.LastIndex(before, "\n") + 1) + 1
+	return line, col
+}
+
+// UserQueryFingerprint returns a hash of the repository-local query for
+// langName, or "" when there is none. Indexers compare it across runs to
+// decide whether files of that language must be re-extracted.
+func UserQueryFingerprint(queryDir, langName string) string {
+	src, _, err := readUserQuery(queryDir, langName)
+	if err != nil || src == "" {
+		return ""
+	}
+	sum := sha256.Sum256([]byte(src))
+	return hex.EncodeToString(sum[:])
+}
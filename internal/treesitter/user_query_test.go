@@ -0,0 +1,118 @@
+package treesitter
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/mesdx/cli/internal/symbols"
+)
+
+const userQueryGoSource = `package main
+
+type Person struct{}
+
+func run() {
+retry:
+	for {
+		break retry
+	}
+}
+`
+
+func writeUserQuery(t *testing.T, langName, src string) string {
+	t.Helper()
+	dir := t.TempDir()
+	if err := os.WriteFile(filepath.Join(dir, langName+".scm"), []byte(src), 0644); err != nil {
+		t.Fatal(err)
+	}
+	return dir
+}
+
+func hasSymbol(result *symbols.FileResult, name string, kind symbols.SymbolKind) bool {
+	for _, s := range result.Symbols {
+		if s.Name == name && s.Kind == kind {
+			return true
+		}
+	}
+	return false
+}
+
+func TestUserQueryAppendsToEmbedded(t *testing.T) {
+	dir := writeUserQuery(t, "go", "(labeled_statement label: (label_name) @def.const)\n")
+
+	extractor, err := NewExtractorWithQueryDir("go", dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer extractor.Close()
+
+	result, err := extractor.Extract("main.go", []byte(userQueryGoSource))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !hasSymbol(result, "retry", symbols.KindConstant) {
+		t.Errorf("expected label retry from the user query; got %+v", result.Symbols)
+	}
+	if !hasSymbol(result, "Person", symbols.KindStruct) {
+		t.Errorf("expected embedded query to still find Person; got %+v", result.Symbols)
+	}
+}
+
+func TestUserQueryReplacesEmbedded(t *testing.T) {
+	dir := writeUserQuery(t, "go", UserQueryReplaceDirective+"\n(function_declaration name: (identifier) @def.function)\n")
+
+	extractor, err := NewExtractorWithQueryDir("go", dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer extractor.Close()
+
+	result, err := extractor.Extract("main.go", []byte(userQueryGoSource))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !hasSymbol(result, "run", symbols.KindFunction) {
+		t.Errorf("expected run from the user query; got %+v", result.Symbols)
+	}
+	if hasSymbol(result, "Person", symbols.KindStruct) {
+		t.Error("embedded query should have been replaced")
+	}
+}
+
+func TestUserQueryErrorPosition(t *testing.T) {
+	tests := []struct {
+		name, src string
+		line, col int
+	}{
+		{"unknown node type", "; comment\n(function_declaration name: (identifer) @def.function)\n", 2, 30},
+		{"unknown def kind", "(function_declaration\n  name: (identifier) @def.fuction)\n", 2, 22},
+	}
+	for _, tt := range tests {
+		dir := writeUserQuery(t, "go", tt.src)
+
+		err := ValidateUserQuery(dir, "go")
+		var qerr *UserQueryError
+		if !errors.As(err, &qerr) {
+			t.Fatalf("%s: expected UserQueryError, got %v", tt.name, err)
+		}
+		if qerr.Line != tt.line || qerr.Column != tt.col {
+			t.Errorf("%s: error at %d:%d, want %d:%d (%v)", tt.name, qerr.Line, qerr.Column, tt.line, tt.col, qerr)
+		}
+		if _, err := NewExtractorWithQueryDir("go", dir); err == nil {
+			t.Errorf("%s: expected NewExtractorWithQueryDir to fail", tt.name)
+		}
+	}
+}
+
+func TestUserQueryFingerprint(t *testing.T) {
+	if fp := UserQueryFingerprint(t.TempDir(), "go"); fp != "" {
+		t.Errorf("fingerprint without a query file = %q, want empty", fp)
+	}
+	a := UserQueryFingerprint(writeUserQuery(t, "go", "(identifier) @ref.identifier\n"), "go")
+	b := UserQueryFingerprint(writeUserQuery(t, "go", "(type_identifier) @ref.type\n"), "go")
+	if a == "" || a == b {
+		t.Errorf("expected distinct non-empty fingerprints, got %q and %q", a, b)
+	}
+}